@@ -0,0 +1,127 @@
+//! 스레드 CPU 코어 고정 모듈
+//!
+//! 워커 스레드를 CPU 코어 하나에 고정하여 스레드가 다른 코어(다른 NUMA 노드)로 옮겨 다니지 않게 합니다.
+//! 멀티 소켓 서버에서 `--cpu-list`로 한 소켓의 코어만 지정하면 워커가 그 소켓의 메모리 가까이에
+//! 머물러, 메모리 매핑으로 큰 파일을 읽는 경로에서 원격 메모리 접근이 줄어듭니다.
+
+use core_affinity::CoreId;
+
+use crate::error::{JConvertError, Result};
+
+/// CPU 번호 목록 파싱 (`0-3,8,10-11`, Linux `taskset -c` 형식)
+///
+/// # Examples
+/// ```
+/// use jconvert::affinity::parse_cpu_list;
+///
+/// assert_eq!(parse_cpu_list("0-3,8"), Ok(vec![0, 1, 2, 3, 8]));
+/// assert!(parse_cpu_list("3-1").is_err());
+/// ```
+pub fn parse_cpu_list(text: &str) -> std::result::Result<Vec<usize>, String> {
+    let parse = |value: &str| {
+        value
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| format!("잘못된 CPU 번호: {}", value.trim()))
+    };
+
+    let mut cpus = Vec::new();
+    for part in text.split(',').filter(|part| !part.trim().is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                if start > end {
+                    return Err(format!("잘못된 CPU 범위: {}", part.trim()));
+                }
+                cpus.extend(start..=end);
+            }
+            None => cpus.push(parse(part)?),
+        }
+    }
+
+    if cpus.is_empty() {
+        return Err("CPU 목록이 비어 있습니다".to_string());
+    }
+    cpus.sort_unstable();
+    cpus.dedup();
+    Ok(cpus)
+}
+
+/// 워커 스레드별로 고정할 CPU 코어 배치
+#[derive(Debug, Clone)]
+pub struct ThreadPinning {
+    cores: Vec<CoreId>,
+}
+
+impl ThreadPinning {
+    /// 현재 프로세스가 쓸 수 있는 코어로 배치 생성
+    ///
+    /// # Arguments
+    /// * `cpu_list` - 고정할 CPU 번호 (None이면 쓸 수 있는 모든 코어)
+    pub fn new(cpu_list: Option<&[usize]>) -> Result<Self> {
+        let available =
+            core_affinity::get_core_ids().ok_or_else(|| JConvertError::ThreadPoolError {
+                reason: "이 플랫폼에서는 CPU 코어 목록을 확인할 수 없습니다".to_string(),
+            })?;
+        Self::from_available(available, cpu_list)
+    }
+
+    /// 사용 가능한 코어 중 지정한 CPU 번호만 골라 배치 생성
+    fn from_available(available: Vec<CoreId>, cpu_list: Option<&[usize]>) -> Result<Self> {
+        let cores: Vec<CoreId> = match cpu_list {
+            Some(cpus) => available
+                .into_iter()
+                .filter(|core| cpus.contains(&core.id))
+                .collect(),
+            None => available,
+        };
+
+        if cores.is_empty() {
+            return Err(JConvertError::ThreadPoolError {
+                reason: "지정한 CPU 중 이 프로세스가 사용할 수 있는 코어가 없습니다".to_string(),
+            });
+        }
+        Ok(Self { cores })
+    }
+
+    /// 고정에 사용할 코어 수
+    pub fn core_count(&self) -> usize {
+        self.cores.len()
+    }
+
+    /// 워커 번호에 대응하는 코어 (워커가 코어보다 많으면 순서대로 다시 배치)
+    pub fn core_for(&self, worker: usize) -> CoreId {
+        self.cores[worker % self.cores.len()]
+    }
+
+    /// 현재 스레드를 워커 번호에 대응하는 코어에 고정 (실패하면 `false`)
+    pub fn pin_current(&self, worker: usize) -> bool {
+        core_affinity::set_for_current(self.core_for(worker))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("2, 0-1 ,1"), Ok(vec![0, 1, 2]));
+        assert_eq!(parse_cpu_list("7"), Ok(vec![7]));
+        assert!(parse_cpu_list("").is_err());
+        assert!(parse_cpu_list("a-3").is_err());
+    }
+
+    #[test]
+    fn test_thread_pinning_assignment() {
+        let available: Vec<CoreId> = (0..8).map(|id| CoreId { id }).collect();
+
+        let pinning =
+            ThreadPinning::from_available(available.clone(), Some(&[4, 5, 6, 7])).unwrap();
+        assert_eq!(pinning.core_count(), 4);
+        assert_eq!(pinning.core_for(0).id, 4);
+        assert_eq!(pinning.core_for(5).id, 5);
+
+        assert!(ThreadPinning::from_available(available, Some(&[16])).is_err());
+    }
+}
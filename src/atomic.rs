@@ -0,0 +1,170 @@
+//! 원자적 출력 모듈
+//!
+//! 출력을 임시 파일(`output.jsonl.<프로세스 ID>-<순번>.tmp`)에 먼저 쓰고, 모두 성공하면 디스크에 동기화한 뒤
+//! 이름을 바꿔 출력 파일을 교체합니다.
+//! 실행이 중간에 끊기거나 실패해도 잘린 출력 파일이 남지 않으므로 후속 작업이 불완전한 결과를 읽지 않습니다.
+//! 임시 파일은 새로 만들 때만 열므로, 같은 출력을 쓰는 다른 실행이나 이미 있는 파일을 덮어쓰지 않습니다.
+
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// 임시 파일 확장자
+pub const TEMP_SUFFIX: &str = ".tmp";
+
+/// 같은 프로세스 안에서 임시 파일 이름이 겹치지 않도록 붙이는 순번
+static TEMP_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// 출력 파일에 대응하는 새 임시 파일 경로 (`dir/output.jsonl` → `dir/output.jsonl.<프로세스 ID>-<순번>.tmp`)
+///
+/// 부를 때마다 다른 경로를 돌려줍니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::atomic::temp_path;
+/// use std::path::Path;
+///
+/// let target = Path::new("out/result.jsonl");
+/// let temp = temp_path(target);
+/// assert_eq!(temp.parent(), Some(Path::new("out")));
+/// let name = temp.file_name().unwrap().to_str().unwrap();
+/// assert!(name.starts_with("result.jsonl.") && name.ends_with(".tmp"));
+/// assert_ne!(temp, temp_path(target));
+/// ```
+pub fn temp_path(target: &Path) -> PathBuf {
+    let mut name = target.file_name().map(OsString::from).unwrap_or_default();
+    name.push(format!(
+        ".{}-{}{}",
+        std::process::id(),
+        TEMP_SEQUENCE.fetch_add(1, Ordering::Relaxed),
+        TEMP_SUFFIX
+    ));
+    target.with_file_name(name)
+}
+
+/// 임시 파일에 쓴 뒤 이름을 바꿔 완성하는 출력 파일
+///
+/// `commit` 전에 버려지면(에러로 중단 등) 임시 파일을 지우고 기존 출력 파일은 그대로 둡니다.
+#[derive(Debug)]
+pub struct AtomicOutput {
+    target: PathBuf,
+    temp: PathBuf,
+    committed: bool,
+}
+
+impl AtomicOutput {
+    /// 임시 파일을 새로 만들고 쓰기용 핸들 반환
+    ///
+    /// 같은 이름의 파일이 이미 있으면(이전 실행이 남긴 파일 등) 다른 이름으로 다시 만듭니다.
+    ///
+    /// # Arguments
+    /// * `target` - 완성 후 출력 파일 경로
+    pub fn create(target: impl Into<PathBuf>) -> io::Result<(Self, File)> {
+        let target = target.into();
+        loop {
+            let temp = temp_path(&target);
+            match OpenOptions::new().write(true).create_new(true).open(&temp) {
+                Ok(file) => {
+                    return Ok((
+                        Self {
+                            target,
+                            temp,
+                            committed: false,
+                        },
+                        file,
+                    ))
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// 완성 후 출력 파일 경로
+    pub fn target(&self) -> &Path {
+        &self.target
+    }
+
+    /// 임시 파일 경로
+    pub fn temp(&self) -> &Path {
+        &self.temp
+    }
+
+    /// 임시 파일을 디스크에 동기화한 뒤 이름을 출력 파일로 바꿔 완성 (쓰기 핸들은 먼저 플러시해야 함)
+    ///
+    /// 동기화하지 않고 이름을 바꾸면 전원 장애 뒤에 내용 없는 출력 파일이 남을 수 있습니다.
+    pub fn commit(mut self) -> io::Result<()> {
+        OpenOptions::new()
+            .write(true)
+            .open(&self.temp)?
+            .sync_all()?;
+        fs::rename(&self.temp, &self.target)?;
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl Drop for AtomicOutput {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = fs::remove_file(&self.temp);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_atomic_output_commit_replaces_target() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("out.jsonl");
+        fs::write(&target, "old\n").unwrap();
+
+        let (output, mut file) = AtomicOutput::create(&target).unwrap();
+        let temp = output.temp().to_path_buf();
+        writeln!(file, "new").unwrap();
+        // 완성 전에는 기존 파일이 그대로 유지
+        assert_eq!(fs::read_to_string(&target).unwrap(), "old\n");
+        assert!(temp.exists());
+
+        drop(file);
+        output.commit().unwrap();
+        assert_eq!(fs::read_to_string(&target).unwrap(), "new\n");
+        assert!(!temp.exists());
+    }
+
+    #[test]
+    fn test_atomic_output_dropped_without_commit() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("out.jsonl");
+
+        let (output, mut file) = AtomicOutput::create(&target).unwrap();
+        let temp = output.temp().to_path_buf();
+        writeln!(file, "partial").unwrap();
+        drop(file);
+        drop(output);
+
+        assert!(!target.exists());
+        assert!(!temp.exists());
+    }
+
+    #[test]
+    fn test_atomic_outputs_use_separate_temp_files() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("out.jsonl");
+        // 이전 실행이 남긴 임시 파일은 건드리지 않음
+        let stale = dir.path().join("out.jsonl.tmp");
+        fs::write(&stale, "stale\n").unwrap();
+
+        let (first, _) = AtomicOutput::create(&target).unwrap();
+        let (second, _) = AtomicOutput::create(&target).unwrap();
+        assert_ne!(first.temp(), second.temp());
+        assert_eq!(fs::read_to_string(&stale).unwrap(), "stale\n");
+    }
+}
@@ -0,0 +1,229 @@
+//! 기준 실행 비교 모듈
+//!
+//! `--baseline last_stats.json`으로 이전 실행의 `--stats-json` 보고서를 읽어 이번 실행의 통계와 비교합니다.
+//! 파일 수나 출력 용량이 허용 범위(`--baseline-tolerance`)보다 많이 줄거나 에러가 급증하면
+//! 이상 변화로 보고하여, 매일 같은 데이터를 병합하는 작업에서 원본 누락이나 품질 저하를 잡아냅니다.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use crate::error::{JConvertError, Result};
+use crate::report::RunReport;
+
+/// 기본 허용 범위 (%)
+pub const DEFAULT_TOLERANCE: f64 = 10.0;
+
+/// 지표가 나빠지는 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// 줄어들면 나빠짐 (파일 수, 용량)
+    Drop,
+    /// 늘어나면 나빠짐 (에러 수)
+    Rise,
+}
+
+/// 비교하는 지표 (보고서 필드 이름, 표시 이름, 나빠지는 방향)
+const METRICS: &[(&str, &str, Direction)] = &[
+    ("total_files", "전체 파일", Direction::Drop),
+    ("success", "성공 파일", Direction::Drop),
+    ("errors", "에러 파일", Direction::Rise),
+    ("validation_failed", "유효성 검사 실패", Direction::Rise),
+    ("unreadable", "읽기 권한 없음", Direction::Rise),
+    ("bytes_read", "입력 용량", Direction::Drop),
+    ("bytes_written", "출력 용량", Direction::Drop),
+];
+
+/// 지표 하나의 비교 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Deviation {
+    /// 보고서 필드 이름
+    pub metric: &'static str,
+    /// 표시 이름
+    pub label: &'static str,
+    /// 나빠지는 방향
+    pub direction: Direction,
+    /// 기준 실행의 값
+    pub baseline: f64,
+    /// 이번 실행의 값
+    pub current: f64,
+    /// 허용 범위를 넘어 나빠졌는지
+    pub exceeded: bool,
+}
+
+impl Deviation {
+    /// 기준 대비 변화율 (%, 기준 값이 0이면 None)
+    pub fn change_percent(&self) -> Option<f64> {
+        (self.baseline != 0.0).then(|| (self.current - self.baseline) / self.baseline * 100.0)
+    }
+}
+
+/// 이전 실행의 보고서 (`--stats-json`으로 저장한 파일)
+#[derive(Debug, Clone)]
+pub struct Baseline {
+    report: Value,
+}
+
+impl Baseline {
+    /// 보고서 파일 읽기
+    pub fn load(path: &Path) -> Result<Self> {
+        let invalid = |reason: String| JConvertError::BaselineError {
+            reason: format!("{} ({})", reason, path.display()),
+        };
+
+        let file = File::open(path).map_err(|e| invalid(e.to_string()))?;
+        let report: Value =
+            serde_json::from_reader(BufReader::new(file)).map_err(|e| invalid(e.to_string()))?;
+        if !report.is_object() {
+            return Err(invalid("실행 보고서 객체가 아닙니다".to_string()));
+        }
+        Ok(Self { report })
+    }
+
+    /// 보고서 값에서 기준 생성
+    pub fn from_value(report: Value) -> Self {
+        Self { report }
+    }
+
+    /// 기준 실행의 모드 (`convert`, `validate`, `stdin`)
+    pub fn mode(&self) -> Option<&str> {
+        self.report.get("mode").and_then(Value::as_str)
+    }
+
+    /// 이번 실행의 보고서와 비교
+    ///
+    /// 기준 보고서에 있는 지표만 비교합니다. 줄어들면 나쁜 지표는 기준보다 `tolerance`% 넘게 줄었을 때,
+    /// 늘어나면 나쁜 지표는 `tolerance`% 넘게 늘었을 때 (기준이 0이면 하나라도 생겼을 때) 허용 범위를 넘습니다.
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::{Baseline, RunMode, RunReport, Statistics};
+    /// use serde_json::json;
+    ///
+    /// let baseline = Baseline::from_value(json!({"total_files": 100, "errors": 0}));
+    /// let stats = Statistics::new(85);
+    /// stats.increment_error();
+    ///
+    /// let deviations = baseline.compare(&RunReport::new(RunMode::Convert, &stats), 10.0);
+    /// assert_eq!(deviations.len(), 2);
+    /// assert!(deviations.iter().all(|d| d.exceeded));
+    /// assert_eq!(deviations[0].change_percent(), Some(-15.0));
+    /// ```
+    pub fn compare(&self, current: &RunReport, tolerance: f64) -> Vec<Deviation> {
+        let current = match serde_json::to_value(current) {
+            Ok(value) => value,
+            Err(_) => return Vec::new(),
+        };
+        let ratio = tolerance / 100.0;
+
+        METRICS
+            .iter()
+            .filter_map(|&(metric, label, direction)| {
+                let baseline = self.report.get(metric)?.as_f64()?;
+                let current = current.get(metric)?.as_f64()?;
+                let exceeded = match direction {
+                    Direction::Drop => current < baseline * (1.0 - ratio),
+                    Direction::Rise => current > baseline * (1.0 + ratio),
+                };
+                Some(Deviation {
+                    metric,
+                    label,
+                    direction,
+                    baseline,
+                    current,
+                    exceeded,
+                })
+            })
+            .collect()
+    }
+}
+
+/// 허용 범위 파싱 (`10`, `10%`, 0 이상)
+pub fn parse_tolerance(text: &str) -> std::result::Result<f64, String> {
+    let number = text.trim().trim_end_matches('%').trim();
+    match number.parse::<f64>() {
+        Ok(value) if value.is_finite() && value >= 0.0 => Ok(value),
+        _ => Err(format!(
+            "허용 범위는 0 이상의 백분율이어야 합니다 (예: 10, 5%): {}",
+            text
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::RunMode;
+    use crate::stats::Statistics;
+    use serde_json::json;
+
+    fn report(total: usize, success: usize, errors: usize) -> RunReport {
+        let stats = Statistics::new(total);
+        for _ in 0..success {
+            stats.increment_success();
+        }
+        for _ in 0..errors {
+            stats.increment_error();
+        }
+        stats.add_bytes_written(1000);
+        RunReport::new(RunMode::Convert, &stats)
+    }
+
+    fn exceeded(deviations: &[Deviation]) -> Vec<&'static str> {
+        deviations
+            .iter()
+            .filter(|d| d.exceeded)
+            .map(|d| d.metric)
+            .collect()
+    }
+
+    #[test]
+    fn test_compare_within_tolerance() {
+        let baseline = Baseline::from_value(json!({
+            "mode": "convert", "total_files": 100, "success": 98, "errors": 2,
+            "bytes_written": 1050, "elapsed_secs": 3.5
+        }));
+        let deviations = baseline.compare(&report(95, 93, 2), 10.0);
+        assert_eq!(baseline.mode(), Some("convert"));
+        assert_eq!(deviations.len(), 4);
+        assert!(exceeded(&deviations).is_empty());
+    }
+
+    #[test]
+    fn test_compare_detects_drop_and_spike() {
+        let baseline = Baseline::from_value(json!({
+            "total_files": 100, "success": 100, "errors": 0, "unreadable": 0
+        }));
+        let deviations = baseline.compare(&report(80, 77, 3), 10.0);
+        assert_eq!(exceeded(&deviations), ["total_files", "success", "errors"]);
+
+        let errors = deviations.iter().find(|d| d.metric == "errors").unwrap();
+        assert_eq!(errors.change_percent(), None);
+        assert_eq!((errors.baseline, errors.current), (0.0, 3.0));
+
+        // 허용 범위 0이면 조금만 나빠져도 넘음
+        let deviations = baseline.compare(&report(99, 99, 0), 0.0);
+        assert_eq!(exceeded(&deviations), ["total_files", "success"]);
+    }
+
+    #[test]
+    fn test_load_and_parse_tolerance() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("last_stats.json");
+        report(3, 3, 0).write(&path).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        assert!(exceeded(&baseline.compare(&report(3, 3, 0), 0.0)).is_empty());
+
+        std::fs::write(&path, "[1, 2]").unwrap();
+        assert!(Baseline::load(&path).is_err());
+        assert!(Baseline::load(&dir.path().join("none.json")).is_err());
+
+        assert_eq!(parse_tolerance("10"), Ok(10.0));
+        assert_eq!(parse_tolerance(" 2.5% "), Ok(2.5));
+        assert!(parse_tolerance("-1").is_err());
+        assert!(parse_tolerance("abc").is_err());
+    }
+}
@@ -0,0 +1,251 @@
+//! 번들 출력 모듈
+//!
+//! `--output-format bundle`은 실행 정보를 담은 JSON 메타데이터 머리글 한 줄 뒤에 JSONL 본문을 이어
+//! 파일 하나로 저장합니다. 받는 쪽은 별도 파일 없이 실행 ID, 원본, 건수, 스키마 해시와
+//! 본문 SHA-256으로 출처와 무결성을 확인할 수 있습니다.
+//!
+//! 건수와 해시는 본문을 모두 쓴 뒤에야 알 수 있으므로, 본문을 임시 파일에 쓴 뒤 머리글과 합쳐 완성합니다.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::checksum::{hex, sha256_hex};
+use crate::rng::{time_seed, Rng};
+use crate::schema::FieldSchema;
+use crate::stats::Statistics;
+
+/// 번들 머리글의 형식 식별자
+pub const BUNDLE_FORMAT: &str = "jconvert-bundle/1";
+
+/// 본문 임시 파일 확장자 (`output.jsonl` → `output.jsonl.body`)
+const BODY_SUFFIX: &str = ".body";
+
+/// 출력 형식
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// 레코드만 담은 JSONL
+    #[default]
+    Jsonl,
+    /// 메타데이터 머리글 한 줄 + JSONL 본문
+    Bundle,
+}
+
+/// 번들 메타데이터 머리글 (번들 파일의 첫 줄)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleHeader {
+    /// 형식 식별자 (`jconvert-bundle/1`)
+    pub format: String,
+    /// 실행 ID (16진수 16자리)
+    pub run_id: String,
+    /// 생성 시각 (Unix timestamp, 초)
+    pub created_at: u64,
+    /// 만든 도구와 버전
+    pub generator: String,
+    /// 입력 폴더 (표준 입력은 `-`)
+    pub sources: Vec<String>,
+    /// 전체 입력 파일 수
+    pub total_files: usize,
+    /// 성공 파일 수
+    pub success: usize,
+    /// 에러 파일 수
+    pub errors: usize,
+    /// 본문 레코드(줄) 수
+    pub records: u64,
+    /// 본문 바이트 수
+    pub body_bytes: u64,
+    /// 본문의 SHA-256 (16진수)
+    pub body_sha256: String,
+    /// 본문 레코드의 필드 경로와 타입 목록의 SHA-256 (레코드 순서, 값과 무관)
+    pub schema_hash: String,
+}
+
+impl BundleHeader {
+    /// 새 실행의 머리글 생성 (건수와 해시는 `write_bundle`에서 채움)
+    pub fn new(sources: Vec<String>) -> Self {
+        Self {
+            format: BUNDLE_FORMAT.to_string(),
+            run_id: format!(
+                "{:016x}",
+                Rng::derive(time_seed(), u64::from(std::process::id())).next_u64()
+            ),
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            generator: format!("jconvert {}", env!("CARGO_PKG_VERSION")),
+            sources,
+            total_files: 0,
+            success: 0,
+            errors: 0,
+            records: 0,
+            body_bytes: 0,
+            body_sha256: String::new(),
+            schema_hash: String::new(),
+        }
+    }
+
+    /// 처리 통계의 파일 수 설정
+    pub fn with_stats(mut self, stats: &Statistics) -> Self {
+        self.total_files = stats.total_files;
+        self.success = stats.get_success_count();
+        self.errors = stats.get_error_count();
+        self
+    }
+
+    /// 번들 파일의 머리글 읽기
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::bundle::BundleHeader;
+    ///
+    /// let bundle = "{\"format\":\"jconvert-bundle/1\",\"run_id\":\"00000000000000ff\",\"created_at\":0,\
+    ///     \"generator\":\"jconvert\",\"sources\":[\"./data\"],\"total_files\":1,\"success\":1,\"errors\":0,\
+    ///     \"records\":1,\"body_bytes\":9,\"body_sha256\":\"\",\"schema_hash\":\"\"}\n{\"id\":1}\n";
+    /// let header = BundleHeader::read(bundle.as_bytes()).unwrap();
+    /// assert_eq!(header.run_id, "00000000000000ff");
+    /// assert_eq!(header.records, 1);
+    /// ```
+    pub fn read<R: BufRead>(mut reader: R) -> io::Result<Self> {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let header: Self = serde_json::from_str(&line)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if header.format != BUNDLE_FORMAT {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("지원하지 않는 번들 형식입니다: {}", header.format),
+            ));
+        }
+        Ok(header)
+    }
+}
+
+/// 번들 본문을 쓸 임시 파일 경로 (`dir/output.jsonl` → `dir/output.jsonl.body`)
+pub fn body_path(target: &Path) -> PathBuf {
+    let mut name = target.file_name().map(OsString::from).unwrap_or_default();
+    name.push(BODY_SUFFIX);
+    target.with_file_name(name)
+}
+
+/// 본문 파일을 읽어 머리글의 건수와 해시를 채운 뒤 머리글과 본문을 기록
+///
+/// 빈 줄과 `#` 주석 줄(`--annotate`)은 레코드 수와 스키마에 포함하지 않습니다.
+///
+/// # Returns
+/// 기록한 머리글
+pub fn write_bundle<W: Write>(
+    body: &Path,
+    mut header: BundleHeader,
+    output: &mut W,
+) -> io::Result<BundleHeader> {
+    let mut reader = BufReader::new(File::open(body)?);
+    let mut hasher = Sha256::new();
+    let mut schema = FieldSchema::new();
+    let mut line = Vec::new();
+    header.records = 0;
+    header.body_bytes = 0;
+    loop {
+        line.clear();
+        let read = reader.read_until(b'\n', &mut line)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&line);
+        header.body_bytes += read as u64;
+
+        let trimmed = line.trim_ascii();
+        if trimmed.is_empty() || trimmed.starts_with(b"#") {
+            continue;
+        }
+        header.records += 1;
+        if let Ok(value) = serde_json::from_slice::<Value>(trimmed) {
+            schema.add_record(&value);
+        }
+    }
+    header.body_sha256 = hex(&hasher.finalize());
+    header.schema_hash = schema_hash(&schema);
+
+    serde_json::to_writer(&mut *output, &header)?;
+    writeln!(output)?;
+    io::copy(&mut File::open(body)?.take(header.body_bytes), output)?;
+    output.flush()?;
+    Ok(header)
+}
+
+/// 스키마의 필드 경로와 타입 목록 해시
+fn schema_hash(schema: &FieldSchema) -> String {
+    let listing: String = schema
+        .fields()
+        .iter()
+        .flat_map(|(path, types)| {
+            types
+                .keys()
+                .map(move |json_type| format!("{}:{}\n", path, json_type.name()))
+        })
+        .collect();
+    sha256_hex(listing.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle(body: &str) -> (BundleHeader, String) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = body_path(&dir.path().join("out.jsonl"));
+        std::fs::write(&path, body).unwrap();
+
+        let mut output = Vec::new();
+        let header = write_bundle(
+            &path,
+            BundleHeader::new(vec!["./data".to_string()]),
+            &mut output,
+        )
+        .unwrap();
+        (header, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_write_and_read_bundle() {
+        let body = "{\"id\":1,\"tags\":[\"a\"]}\n# source: b.json\n{\"id\":2,\"tags\":[]}\n";
+        let (header, output) = bundle(body);
+
+        assert_eq!(header.records, 2);
+        assert_eq!(header.body_bytes, body.len() as u64);
+        assert_eq!(header.body_sha256, sha256_hex(body.as_bytes()));
+        assert_eq!(header.run_id.len(), 16);
+        assert_eq!(header.sources, ["./data"]);
+
+        let (first, rest) = output.split_once('\n').unwrap();
+        assert_eq!(rest, body);
+        assert_eq!(BundleHeader::read(output.as_bytes()).unwrap(), header);
+        assert!(serde_json::from_str::<Value>(first).unwrap()["format"] == BUNDLE_FORMAT);
+    }
+
+    #[test]
+    fn test_schema_hash_ignores_values_and_order() {
+        let (a, _) = bundle("{\"id\":1,\"name\":\"x\"}\n{\"id\":2}\n");
+        let (b, _) = bundle("{\"id\":9}\n{\"name\":\"y\",\"id\":3}\n");
+        let (c, _) = bundle("{\"id\":\"1\",\"name\":\"x\"}\n");
+        assert_eq!(a.schema_hash, b.schema_hash);
+        assert_ne!(a.schema_hash, c.schema_hash);
+        assert_ne!(a.body_sha256, b.body_sha256);
+    }
+
+    #[test]
+    fn test_read_rejects_other_formats() {
+        assert!(BundleHeader::read("{\"id\":1}\n".as_bytes()).is_err());
+        let (mut header, _) = bundle("");
+        assert_eq!(header.records, 0);
+        header.format = "other/2".to_string();
+        let line = serde_json::to_string(&header).unwrap();
+        assert!(BundleHeader::read(line.as_bytes()).is_err());
+    }
+}
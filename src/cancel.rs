@@ -0,0 +1,44 @@
+//! 작업 취소 모듈
+//!
+//! 라이브러리를 내장한 GUI나 서비스가 오래 걸리는 변환을 중간에 멈출 수 있도록
+//! 여러 스레드에서 공유하는 취소 토큰을 제공합니다. 작업자는 파일 사이마다 토큰을 확인하여
+//! 취소되면 남은 파일을 건너뛰고, 그때까지의 통계를 돌려줍니다.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// 여러 스레드에서 공유하는 취소 토큰
+///
+/// 복제한 토큰은 같은 취소 상태를 공유하므로, 하나를 작업에 넘기고 다른 하나로 취소할 수 있습니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let worker = token.clone();
+/// assert!(!worker.is_cancelled());
+/// token.cancel();
+/// assert!(worker.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// 취소되지 않은 토큰 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 작업 취소 요청 (이미 처리 중인 파일은 끝까지 처리함)
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// 취소가 요청되었는지 확인
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
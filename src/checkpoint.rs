@@ -0,0 +1,195 @@
+//! 체크포인트 모듈
+//!
+//! 수백만 개 파일을 변환하다 중단되어도 다시 실행하면 이어서 처리할 수 있도록
+//! 출력에 병합을 마친 입력 파일 목록과 그 시점의 출력 파일 크기를 기록합니다.
+//!
+//! 다시 실행할 때는 출력 파일을 기록된 크기로 잘라 중간에 끊긴 기록을 버린 뒤,
+//! 완료되지 않은 파일의 레코드만 이어 씁니다.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::atomic::AtomicOutput;
+use crate::error::{JConvertError, Result};
+
+/// 체크포인트를 저장하는 완료 파일 수 간격
+pub const SAVE_INTERVAL: usize = 1000;
+
+/// 중단된 변환을 이어서 처리하기 위한 진행 상태 (`--checkpoint`)
+///
+/// # Examples
+/// ```
+/// use jconvert::Checkpoint;
+/// use std::path::Path;
+///
+/// let mut checkpoint = Checkpoint::default();
+/// checkpoint.mark_completed(Path::new("data/a.json").to_path_buf());
+/// assert!(checkpoint.is_completed(Path::new("data/a.json")));
+/// assert!(!checkpoint.is_completed(Path::new("data/b.json")));
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// 출력 파일 경로
+    output: PathBuf,
+    /// 체크포인트 시점의 출력 파일 크기 (바이트)
+    output_bytes: u64,
+    /// 출력에 병합을 마친 입력 파일
+    completed: BTreeSet<PathBuf>,
+    /// 마지막 저장 이후 완료된 파일 수
+    #[serde(skip)]
+    unsaved: usize,
+}
+
+impl Checkpoint {
+    /// 체크포인트 파일 읽기 (파일이 없으면 빈 체크포인트)
+    pub fn load(path: &Path) -> Result<Self> {
+        let invalid = |reason: String| JConvertError::CheckpointError {
+            reason: format!("{} ({})", reason, path.display()),
+        };
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let file = File::open(path).map_err(|e| invalid(e.to_string()))?;
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| invalid(e.to_string()))
+    }
+
+    /// 이전 실행에서 완료한 파일이 있는지 확인 (이어서 처리해야 하는지)
+    pub fn is_resume(&self) -> bool {
+        !self.completed.is_empty()
+    }
+
+    /// 완료한 파일 수
+    pub fn completed_count(&self) -> usize {
+        self.completed.len()
+    }
+
+    /// 체크포인트 시점의 출력 파일 크기
+    pub fn output_bytes(&self) -> u64 {
+        self.output_bytes
+    }
+
+    /// 이미 출력에 병합한 파일인지 확인
+    pub fn is_completed(&self, path: &Path) -> bool {
+        self.completed.contains(path)
+    }
+
+    /// 출력에 병합한 파일 기록
+    pub fn mark_completed(&mut self, path: PathBuf) {
+        if self.completed.insert(path) {
+            self.unsaved += 1;
+        }
+    }
+
+    /// 저장 간격만큼 새로 완료한 파일이 쌓였는지 확인
+    pub fn needs_save(&self) -> bool {
+        self.unsaved >= SAVE_INTERVAL
+    }
+
+    /// 이어 쓸 출력 파일 준비 (체크포인트 이후 기록된 내용을 잘라냄)
+    ///
+    /// 출력 파일이 체크포인트와 다르거나, 없거나, 기록된 크기보다 작으면 이어서 처리할 수 없습니다.
+    pub fn truncate_output(&self, output: &Path) -> Result<()> {
+        let mismatch = |reason: String| JConvertError::CheckpointError {
+            reason: format!(
+                "{}: {} (처음부터 다시 변환하려면 체크포인트 파일을 지우세요)",
+                reason,
+                output.display()
+            ),
+        };
+
+        if self.output != output {
+            return Err(mismatch(format!(
+                "체크포인트의 출력 파일({})과 다릅니다",
+                self.output.display()
+            )));
+        }
+        let file = OpenOptions::new()
+            .write(true)
+            .open(output)
+            .map_err(|e| mismatch(format!("출력 파일을 열 수 없습니다 ({})", e)))?;
+        let len = file.metadata().map_err(|e| mismatch(e.to_string()))?.len();
+        if len < self.output_bytes {
+            return Err(mismatch(format!(
+                "출력 파일이 체크포인트보다 작습니다 ({} < {} 바이트)",
+                len, self.output_bytes
+            )));
+        }
+        file.set_len(self.output_bytes)
+            .map_err(|e| mismatch(e.to_string()))
+    }
+
+    /// 출력 파일 크기를 기록하고 체크포인트 저장
+    ///
+    /// 기록한 레코드를 출력 파일까지 플러시한 뒤 호출해야 합니다.
+    /// 저장 도중 중단되어도 이전 체크포인트가 남도록 임시 파일에 쓴 뒤 이름을 바꿉니다.
+    pub fn save(&mut self, path: &Path, output: &Path) -> Result<()> {
+        let write_error = |reason: String| JConvertError::WriteError {
+            reason: format!("{} ({})", reason, path.display()),
+        };
+
+        self.output = output.to_path_buf();
+        self.output_bytes = std::fs::metadata(output)
+            .map_err(|e| write_error(e.to_string()))?
+            .len();
+
+        let (pending, file) = AtomicOutput::create(path).map_err(|e| write_error(e.to_string()))?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer(&mut writer, self).map_err(|e| write_error(e.to_string()))?;
+        writer.flush().map_err(|e| write_error(e.to_string()))?;
+        drop(writer);
+        pending.commit().map_err(|e| write_error(e.to_string()))?;
+
+        self.unsaved = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_checkpoint_save_and_resume() {
+        let dir = TempDir::new().unwrap();
+        let state = dir.path().join("state.json");
+        let output = dir.path().join("out.jsonl");
+
+        let mut checkpoint = Checkpoint::load(&state).unwrap();
+        assert!(!checkpoint.is_resume());
+
+        std::fs::write(&output, "{\"id\":1}\n").unwrap();
+        checkpoint.mark_completed(PathBuf::from("a.json"));
+        checkpoint.save(&state, &output).unwrap();
+
+        // 체크포인트 이후 중단 직전까지 일부만 기록된 레코드
+        std::fs::write(&output, "{\"id\":1}\n{\"id\"").unwrap();
+
+        let resumed = Checkpoint::load(&state).unwrap();
+        assert!(resumed.is_resume());
+        assert!(resumed.is_completed(Path::new("a.json")));
+        assert_eq!(resumed.output_bytes(), 9);
+
+        resumed.truncate_output(&output).unwrap();
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "{\"id\":1}\n");
+
+        // 다른 출력 파일로는 이어서 처리할 수 없음
+        assert!(resumed
+            .truncate_output(&dir.path().join("other.jsonl"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_needs_save() {
+        let mut checkpoint = Checkpoint::default();
+        for i in 0..SAVE_INTERVAL {
+            assert!(!checkpoint.needs_save());
+            checkpoint.mark_completed(PathBuf::from(format!("{}.json", i)));
+        }
+        assert!(checkpoint.needs_save());
+    }
+}
@@ -0,0 +1,308 @@
+//! 체크섬 모듈
+//!
+//! `--add-checksum <FIELD>`로 각 레코드에 원본 파일이나 레코드 자체의 SHA-256 해시를 넣어
+//! 하류 시스템에서 무결성을 검사하거나 정확한 원본 파일을 찾을 수 있게 합니다.
+//! 파일 체크섬은 `sha256sum` 출력과 같은 16진수 문자열입니다.
+//!
+//! `--dedup-content`는 빠른 비암호 해시(xxh3)로 후보를 고른 뒤 바이트 비교로 확인해
+//! 이름만 다르고 내용이 똑같은 입력 파일을 찾아 처음 파일만 남깁니다.
+
+use clap::ValueEnum;
+use rayon::prelude::*;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use xxhash_rust::xxh3::Xxh3;
+
+/// 체크섬 대상
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum ChecksumScope {
+    /// 원본 파일의 바이트 (인코딩 변환 전, 파일의 모든 레코드가 같은 값)
+    #[default]
+    File,
+    /// 레코드 자체 (키를 사전순으로 정렬한 한 줄 JSON, 원본 정보와 체크섬 필드 주입 전)
+    Record,
+}
+
+/// 레코드에 넣을 체크섬 필드
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChecksumField {
+    /// 필드 이름
+    pub key: String,
+    /// 체크섬 대상
+    pub scope: ChecksumScope,
+}
+
+impl ChecksumField {
+    /// 체크섬 필드 생성
+    pub fn new(key: impl Into<String>, scope: ChecksumScope) -> Self {
+        Self {
+            key: key.into(),
+            scope,
+        }
+    }
+}
+
+/// 파일 내용의 SHA-256 (16진수)
+pub fn file_sha256(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; READ_BUFFER];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hex(&hasher.finalize()))
+}
+
+/// 파일 내용 읽기 버퍼 크기
+const READ_BUFFER: usize = 64 * 1024;
+
+/// 파일 내용의 xxh3 해시 (중복 후보 분류용, 충돌 가능)
+fn file_xxh3(path: &Path) -> io::Result<u64> {
+    let mut file = File::open(path)?;
+    let mut hasher = Xxh3::new();
+    let mut buffer = vec![0u8; READ_BUFFER];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher.digest())
+}
+
+/// 두 파일의 내용이 바이트 단위로 같은지 비교
+fn same_content(a: &Path, b: &Path) -> io::Result<bool> {
+    let mut a = BufReader::with_capacity(READ_BUFFER, File::open(a)?);
+    let mut b = BufReader::with_capacity(READ_BUFFER, File::open(b)?);
+    loop {
+        let (left, right) = (a.fill_buf()?, b.fill_buf()?);
+        if left.is_empty() || right.is_empty() {
+            return Ok(left.is_empty() && right.is_empty());
+        }
+        let len = left.len().min(right.len());
+        if left[..len] != right[..len] {
+            return Ok(false);
+        }
+        a.consume(len);
+        b.consume(len);
+    }
+}
+
+/// 내용이 같아 제외한 입력 파일
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DuplicateFiles {
+    /// (제외한 파일, 내용이 같은 남긴 파일)
+    pub pairs: Vec<(PathBuf, PathBuf)>,
+    /// 제외한 파일의 전체 크기 (처리하지 않아 절약한 바이트)
+    pub bytes: u64,
+}
+
+impl DuplicateFiles {
+    /// 제외한 파일 수
+    pub fn count(&self) -> usize {
+        self.pairs.len()
+    }
+}
+
+/// 내용이 바이트 단위로 같은 파일 중 목록에서 처음 나온 것만 남김
+///
+/// 크기가 같은 파일끼리만 xxh3를 병렬로 계산하므로 크기가 고유한 파일은 읽지 않습니다.
+/// 크기와 해시가 모두 같으면 앞서 남긴 파일과 바이트 단위로 비교해 해시 충돌로 잘못 제외하지 않습니다.
+/// 크기를 알 수 없거나 읽을 수 없는 파일은 그대로 남겨 처리 단계에서 에러로 보고되게 합니다.
+///
+/// # Returns
+/// 남긴 파일 목록 (원래 순서 유지)과 제외한 파일
+pub fn dedup_content(paths: Vec<PathBuf>) -> (Vec<PathBuf>, DuplicateFiles) {
+    let sizes: Vec<Option<u64>> = paths
+        .par_iter()
+        .map(|path| std::fs::metadata(path).ok().map(|m| m.len()))
+        .collect();
+    let mut same_size: HashMap<u64, usize> = HashMap::new();
+    for size in sizes.iter().flatten() {
+        *same_size.entry(*size).or_default() += 1;
+    }
+
+    let hashes: Vec<Option<u64>> = paths
+        .par_iter()
+        .zip(&sizes)
+        .map(|(path, size)| match size {
+            Some(size) if same_size[size] > 1 => file_xxh3(path).ok(),
+            _ => None,
+        })
+        .collect();
+
+    // (크기, 해시)가 같은 남긴 파일들 (충돌이면 내용이 다른 파일이 여럿일 수 있음)
+    let mut candidates: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    let mut duplicates = DuplicateFiles::default();
+    let mut kept = Vec::with_capacity(paths.len());
+    for (index, ((path, size), hash)) in paths.iter().zip(&sizes).zip(hashes).enumerate() {
+        if let (Some(size), Some(hash)) = (size, hash) {
+            let originals = candidates.entry((*size, hash)).or_default();
+            let original = originals
+                .iter()
+                .copied()
+                .find(|&original| same_content(&paths[original], path).unwrap_or(false));
+            if let Some(original) = original {
+                duplicates
+                    .pairs
+                    .push((path.clone(), paths[original].clone()));
+                duplicates.bytes += size;
+                continue;
+            }
+            originals.push(index);
+        }
+        kept.push(path.clone());
+    }
+    (kept, duplicates)
+}
+
+/// 레코드의 SHA-256 (16진수)
+///
+/// 빌드 기능과 관계없이 같은 값이 나오도록 중첩 객체까지 키를 사전순으로 정렬한 한 줄 JSON을 해시합니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::checksum::{record_sha256, sha256_hex};
+/// use serde_json::json;
+///
+/// let record = json!({"name": "kim", "id": 1});
+/// assert_eq!(record_sha256(&record), sha256_hex(br#"{"id":1,"name":"kim"}"#));
+/// ```
+pub fn record_sha256(record: &Value) -> String {
+    let bytes = if cfg!(feature = "preserve_order") {
+        let mut sorted = record.clone();
+        sorted.sort_all_objects();
+        serde_json::to_vec(&sorted)
+    } else {
+        serde_json::to_vec(record)
+    };
+    sha256_hex(&bytes.unwrap_or_default())
+}
+
+/// 바이트의 SHA-256 (16진수)
+///
+/// # Examples
+/// ```
+/// use jconvert::checksum::sha256_hex;
+///
+/// assert_eq!(
+///     sha256_hex(b"abc"),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hex(&hasher.finalize())
+}
+
+/// 바이트를 소문자 16진수 문자열로 변환
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_sha256_known_vectors() {
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256_hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+        // 여러 블록에 걸친 입력을 나누어 넣어도 같은 결과
+        let data = vec![b'a'; 1_000];
+        let mut hasher = Sha256::new();
+        for chunk in data.chunks(37) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hex(&hasher.finalize()), sha256_hex(&data));
+        assert_eq!(
+            sha256_hex(&vec![b'a'; 1_000_000]),
+            "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
+    }
+
+    #[test]
+    fn test_file_and_record_checksums() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("a.json");
+        std::fs::write(&path, "abc").unwrap();
+        assert_eq!(file_sha256(&path).unwrap(), sha256_hex(b"abc"));
+        assert!(file_sha256(&dir.path().join("none.json")).is_err());
+
+        // 키 순서와 관계없이 같은 레코드는 같은 값
+        assert_eq!(
+            record_sha256(&json!({"b": {"y": 1, "x": 2}, "a": 0})),
+            record_sha256(&json!({"a": 0, "b": {"x": 2, "y": 1}}))
+        );
+        assert_ne!(
+            record_sha256(&json!({"a": 0})),
+            record_sha256(&json!({"a": 1}))
+        );
+    }
+
+    #[test]
+    fn test_dedup_content_keeps_first_copy() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let write = |name: &str, content: &str| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            path
+        };
+        let a = write("a.json", r#"{"id":1}"#);
+        let b = write("b.json", r#"{"id":2}"#);
+        let copy = write("copy_of_a.json", r#"{"id":1}"#);
+        let unique = write("unique.json", r#"{"id":10}"#);
+        let missing = dir.path().join("missing.json");
+
+        let (kept, duplicates) = dedup_content(vec![
+            a.clone(),
+            b.clone(),
+            copy.clone(),
+            missing.clone(),
+            unique.clone(),
+        ]);
+        assert_eq!(kept, vec![a.clone(), b, missing, unique]);
+        assert_eq!(duplicates.count(), 1);
+        assert_eq!(duplicates.pairs, vec![(copy, a)]);
+        assert_eq!(duplicates.bytes, 8);
+    }
+
+    #[test]
+    fn test_same_content_compares_bytes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let write = |name: &str, content: &[u8]| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            path
+        };
+        // 읽기 버퍼보다 긴 내용에서 마지막 바이트만 다른 경우도 구분
+        let mut content = vec![b'x'; READ_BUFFER * 2 + 7];
+        let a = write("a.json", &content);
+        let copy = write("copy.json", &content);
+        *content.last_mut().unwrap() = b'y';
+        let changed = write("changed.json", &content);
+        let short = write("short.json", &content[..10]);
+
+        assert!(same_content(&a, &copy).unwrap());
+        assert!(!same_content(&a, &changed).unwrap());
+        assert!(!same_content(&a, &short).unwrap());
+        assert!(same_content(&a, &dir.path().join("missing.json")).is_err());
+    }
+}
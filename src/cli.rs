@@ -2,8 +2,42 @@
 //!
 //! clap을 사용한 명령줄 인자 정의 및 파싱을 담당합니다.
 
-use clap::{Parser, ValueEnum};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::baseline::{parse_tolerance, DEFAULT_TOLERANCE};
+use crate::bundle::OutputFormat;
+use crate::checksum::{ChecksumField, ChecksumScope};
+use crate::codec::{find_codec, Codec};
+use crate::dedup::{
+    parse_fp_rate, DedupKeep, Deduplicator, SpillDeduplicator, DEFAULT_CAPACITY,
+    DEFAULT_DEDUPE_MEMORY, DEFAULT_FP_RATE,
+};
+use crate::encoding::InputEncoding;
+use crate::error_log::ErrorLogFormat;
+use crate::field_order::FieldOrder;
+use crate::file_filter::{parse_time, FileFilter};
+use crate::gate::{parse_success_rate, QualityGates};
+use crate::jsonl_check::DEFAULT_CHUNK_SIZE;
+use crate::keys::KeyCase;
+use crate::limits::ParseLimits;
+use crate::parser::ParserKind;
+use crate::plan::DEFAULT_SAMPLE_SIZE;
+use crate::processor::KeyOrder;
+use crate::progress::auto_interval;
+use crate::record_size::{OversizePolicy, RecordSizeGuard};
+use crate::rng::time_seed;
+use crate::sample::{parse_rate, Sampler};
+use crate::shard::ShardLimits;
+use crate::sort::{RecordSorter, DEFAULT_SORT_MEMORY};
+use crate::stats::parse_size;
+use crate::ui::{Level, LogFormat};
+use crate::upload::RemoteTarget;
+use crate::writer::SyncPolicy;
+
+/// 표준 입출력을 뜻하는 경로 (`-i -`, `-o -`)
+pub const STDIO_PATH: &str = "-";
 
 /// 출력 파일 모드
 #[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq)]
@@ -28,7 +62,7 @@ impl std::fmt::Display for WriteMode {
 }
 
 /// jconvert CLI 인자 구조체
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 #[command(
     name = "jconvert",
     author = "YourName <your@email.com>",
@@ -52,16 +86,53 @@ JSON FOLDER TO JSONL CONVERTER
   jconvert -i ./data -o result.jsonl
   jconvert -i ./data -o result.jsonl --mode append
   jconvert -i ./data -o result.jsonl --verbose --dry-run
+  jconvert -i ./data -o result.jsonl --quiet --log-format json
   jconvert -i ./data --validate-only
   jconvert -i ./data --fields "id,name,description"
-"#
+  jconvert -i ./data --add-source --add-mtime
+  jconvert -i ./data --pipeline pipeline.json
+  jconvert -i ./data --dedupe-by "user.id" --dedupe-exact
+  jconvert -i ./data --dedupe-by id --dedupe-spill --temp-dir /scratch
+  jconvert -i ./data --sort-by timestamp --desc
+  jconvert -i ./data --exclude "*_backup_*.json" --exclude "archive/**"
+  cat big.json | jconvert --stdin -o out.jsonl
+  find ./data -name "*.json" | jconvert -i - -o - | gzip > out.jsonl.gz
+  jconvert plan -i ./data --sample 50
+  jconvert generate --count 10000 --schema schema.json --out ./fake
+"#,
+    subcommand_negates_reqs = true,
+    args_conflicts_with_subcommands = true
 )]
 pub struct Args {
-    /// JSON 파일들이 있는 입력 폴더 경로
-    #[arg(short, long)]
-    pub input: PathBuf,
+    /// 하위 명령 (지정하지 않으면 변환 실행)
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// JSON/JSONL 파일들이 있는 입력 폴더 경로 (반복 지정 가능, "-"이면 표준 입력에서 줄 단위 파일 경로 목록을 읽음)
+    #[arg(short, long, required_unless_present_any = ["stdin", "files_from"])]
+    pub input: Vec<PathBuf>,
+
+    /// 처리할 파일 경로 목록 파일 (한 줄에 경로 하나, -i와 함께 쓰면 합쳐서 처리)
+    #[arg(long, value_name = "FILE", conflicts_with = "stdin")]
+    pub files_from: Option<PathBuf>,
+
+    /// 표준 입력에서 JSON 문서(또는 연속된 여러 문서)를 읽어 변환
+    #[arg(long, conflicts_with_all = ["input", "dry_run", "validate_only"])]
+    pub stdin: bool,
 
-    /// 생성될 JSONL 파일 경로 (기본값: output.jsonl)
+    /// `.json` 파일 하나에 이어 붙거나 공백으로 구분된 여러 문서를 문서마다 한 줄로 변환
+    #[arg(long)]
+    pub multi_doc: bool,
+
+    /// GeoJSON `FeatureCollection`을 Feature 하나당 한 줄로 변환 (`.geojson` 파일도 입력으로 수집)
+    #[arg(long)]
+    pub geojson: bool,
+
+    /// Feature의 `properties` 필드를 최상위로 올림 (type, id, geometry, bbox와 겹치는 속성은 properties에 남김)
+    #[arg(long, requires = "geojson")]
+    pub flatten_properties: bool,
+
+    /// 생성될 JSONL 파일 경로 (기본값: output.jsonl, "-"이면 표준 출력, "s3://"/"gs://"이면 원격 업로드)
     #[arg(short, long, default_value = "output.jsonl")]
     pub output: PathBuf,
 
@@ -69,18 +140,107 @@ pub struct Args {
     #[arg(short, long, value_enum, default_value_t = WriteMode::Overwrite)]
     pub mode: WriteMode,
 
-    /// 파일 이름 패턴 필터 (glob 형식, 예: "*_SUM_*", "data?.json")
-    #[arg(short, long)]
-    pub pattern: Option<String>,
+    /// append 모드에서 기존 출력에 이미 있는 원본 파일(--add-source, --annotate)과 중복 제거 키(--dedupe-by)의 레코드 건너뜀
+    #[arg(long, conflicts_with = "dedupe_spill")]
+    pub skip_existing: bool,
+
+    /// 출력 파일당 최대 용량 (예: "500MB", "2GB"), 초과 시 output.0001.jsonl, output.0002.jsonl ... 로 분할
+    #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+    pub split_size: Option<u64>,
 
-    /// 상세 출력 모드
+    /// 출력 파일당 최대 레코드 수, 초과 시 다음 분할 파일로 넘어감
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub split_records: Option<u64>,
+
+    /// 출력 레코드 한 줄의 최대 크기 (예: "16MB"), 넘는 레코드는 --oversize-policy에 따라 처리
+    #[arg(long, visible_alias = "max-line-bytes", value_name = "SIZE", value_parser = parse_size)]
+    pub max_record_size: Option<u64>,
+
+    /// 크기 제한을 넘는 레코드 처리 정책
+    #[arg(long, value_enum, default_value_t = OversizePolicy::Skip, requires = "max_record_size")]
+    pub oversize_policy: OversizePolicy,
+
+    /// 출력을 마칠 때 fsync로 디스크 기록 보장
+    #[arg(long)]
+    pub fsync: bool,
+
+    /// N 레코드를 기록할 때마다 fsync (처리 속도 대신 안전성 우선)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub fsync_every: Option<u64>,
+
+    /// 분할 파일을 닫을 때마다 fsync
+    #[arg(long)]
+    pub fsync_on_rotate: bool,
+
+    /// 병합을 마친 입력 파일을 기록하는 체크포인트 파일 (다시 실행하면 남은 파일만 이어서 처리)
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["stdin", "validate_only", "split_size", "split_records"]
+    )]
+    pub checkpoint: Option<PathBuf>,
+
+    /// 출력 형식 (bundle: 실행 ID, 원본, 건수, 스키마 해시를 담은 메타데이터 머리글 한 줄 + JSONL 본문)
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t = OutputFormat::Jsonl)]
+    pub output_format: OutputFormat,
+
+    /// 출력 파일 압축 코덱 (gzip, zstd, bzip2, xz, lz4, brotli; 해당 `codec-*` 기능으로 빌드 필요)
+    #[arg(
+        long,
+        value_name = "CODEC",
+        value_parser = find_codec,
+        conflicts_with_all = ["checkpoint", "split_size", "split_records"]
+    )]
+    pub compress: Option<&'static dyn Codec>,
+
+    /// 임시 파일(.tmp)을 거치지 않고 출력 파일에 직접 기록 (이름 바꾸기를 지원하지 않는 파일 시스템용)
+    #[arg(long)]
+    pub no_atomic: bool,
+
+    /// 원격 출력(-o s3://...)의 예상 크기 (예: "200GB", 멀티파트 업로드의 파트 크기 결정에 사용)
+    #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+    pub upload_expected_size: Option<u64>,
+
+    /// 출력 파일(분할 파일 포함)이 입력 폴더 안에 있어도 입력에서 제외하지 않음
+    #[arg(long)]
+    pub include_output: bool,
+
+    /// 포함할 파일 패턴 (glob 형식, 반복 지정 가능, 예: "*_SUM_*", "data?.json")
     #[arg(short, long)]
-    pub verbose: bool,
+    pub pattern: Vec<String>,
+
+    /// 제외할 파일 패턴 (glob 형식, 반복 지정 가능, 예: "*_backup_*.json", "archive/**")
+    #[arg(long)]
+    pub exclude: Vec<String>,
+
+    /// 상세 출력 (-v: 파일별 결과, -vv: 디버그 정보)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// 에러 외의 메시지와 진행률 바를 출력하지 않음
+    #[arg(short, long, conflicts_with = "verbose", global = true)]
+    pub quiet: bool,
+
+    /// 메시지 형식 (text: 이모지/컬러 출력, json: 한 줄에 JSON 객체 하나, 메시지는 모두 표준 에러로 출력)
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t = LogFormat::Text, global = true)]
+    pub log_format: LogFormat,
+
+    /// 컬러 출력 끄기 (NO_COLOR 환경 변수도 지원)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// 내용이 바이트 단위로 같은 입력 파일은 처음 하나만 처리 (크기가 같은 파일만 xxh3 해시로 후보를 찾은 뒤 바이트 단위로 비교)
+    #[arg(long)]
+    pub dedup_content: bool,
 
     /// 실제 병합 없이 처리될 파일 목록만 표시
     #[arg(long)]
     pub dry_run: bool,
 
+    /// 드라이런에서 필드 선택/필터/변환을 적용한 처음 N개 레코드와 샘플 기반 예상 출력 크기 표시
+    #[arg(long, value_name = "N", requires = "dry_run", value_parser = clap::value_parser!(u64).range(1..))]
+    pub preview: Option<u64>,
+
     /// JSON 유효성 검사만 수행 (변환 없음)
     #[arg(long)]
     pub validate_only: bool,
@@ -89,31 +249,647 @@ pub struct Args {
     #[arg(long)]
     pub fields: Option<String>,
 
+    /// 제외할 JSON 필드 (쉼표로 구분, 점 표기법 지원, 예: "password,user.secret")
+    #[arg(long, value_name = "FIELDS")]
+    pub exclude_fields: Option<String>,
+
+    /// 모든 객체 키(중첩 포함)를 이 표기법으로 통일 (필터와 필드 선택은 바뀐 키 이름 기준)
+    #[arg(long, value_enum, value_name = "CASE")]
+    pub normalize_keys: Option<KeyCase>,
+
+    /// 레코드 최상위 키에서 뗄 접두사 (예: "data_", 필터와 필드 선택은 뗀 이름 기준)
+    #[arg(long, value_name = "PREFIX")]
+    pub strip_key_prefix: Option<String>,
+
+    /// 레코드 최상위 키에 붙일 접두사 (예: "src_", 필드 선택/이름 변경 후, 원본 정보 필드 제외)
+    #[arg(long, value_name = "PREFIX")]
+    pub key_prefix: Option<String>,
+
+    /// 입력 인코딩 (auto는 BOM과 UTF-16 패턴으로 감지, 파싱 전에 UTF-8로 변환)
+    #[arg(long, value_enum, value_name = "ENCODING", default_value_t = InputEncoding::Auto)]
+    pub encoding: InputEncoding,
+
+    /// 잘못된 UTF-8/UTF-16 바이트를 에러 대신 U+FFFD로 교체
+    #[arg(long)]
+    pub lossy: bool,
+
+    /// JSONL 입력에서 한 줄에 붙은 레코드를 나누고 여러 줄로 끊긴 레코드를 이어 붙임
+    #[arg(long)]
+    pub repair_jsonl: bool,
+
+    /// 필드 이름 변경 (쉼표로 구분된 "기존:새" 목록, 예: "old:new,user.name:username")
+    #[arg(long, value_name = "MAP", value_parser = parse_rename_spec)]
+    pub rename: Option<String>,
+
+    /// 레코드 조건 필터 (예: "status == 'active' && count > 10")
+    #[arg(long = "where", value_name = "EXPR")]
+    pub where_expr: Option<String>,
+
+    /// 레코드 변환 표현식: 결과 값이 새 레코드가 됨 (예: "{id, name: first + ' ' + last}")
+    #[arg(long, value_name = "EXPR")]
+    pub transform: Option<String>,
+
+    /// 레코드 스키마 필터: JSON Schema 파일에 맞는 레코드만 출력 (맞지 않는 레코드는 제외)
+    #[arg(long, value_name = "FILE")]
+    pub filter_schema: Option<PathBuf>,
+
+    /// 필터(스키마, --where, 파이프라인)나 --rename에서 제외된 레코드를 제외 이유(_reject_reason)와 함께 기록할 JSONL 파일
+    #[arg(long, value_name = "FILE")]
+    pub rejects: Option<PathBuf>,
+
+    /// 레코드 샘플링 비율: 필터를 통과한 레코드를 이 확률로만 출력 (예: 0.1, 10%)
+    #[arg(long, value_name = "RATE", value_parser = parse_rate, conflicts_with = "validate_only")]
+    pub sample: Option<f64>,
+
+    /// 샘플링 난수 시드 (같은 시드와 입력이면 같은 레코드 선택, 기본값: 현재 시각)
+    #[arg(long, requires = "sample")]
+    pub seed: Option<u64>,
+
+    /// 출력할 최대 레코드 수 (입력 순서대로 앞쪽 N건, 채우면 나머지 파일은 처리하지 않음, --sort-by와 함께 쓰면 정렬 후 앞쪽 N건, --dedupe-by와 함께 쓰려면 --sort-by 필요)
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u64).range(1..),
+        conflicts_with_all = ["validate_only", "checkpoint"]
+    )]
+    pub limit: Option<u64>,
+
     /// 병렬 처리 스레드 수 (기본값: CPU 코어 수)
     #[arg(short = 'j', long)]
     pub threads: Option<usize>,
 
+    /// 워커 스레드를 CPU 코어 하나씩에 고정 (NUMA 서버에서 스레드가 소켓을 옮겨 다니지 않도록)
+    #[arg(long)]
+    pub pin_threads: bool,
+
+    /// 워커를 고정할 CPU 번호 목록 (예: "0-15,32-47", 한 소켓의 코어만 지정, 기본 스레드 수는 목록의 코어 수)
+    #[arg(long, value_name = "LIST", requires = "pin_threads")]
+    pub cpu_list: Option<String>,
+
+    /// CPU/I/O 우선순위를 낮춰 다른 작업을 방해하지 않도록 실행 (값 생략 시 10, 1~19, 클수록 낮음)
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        default_missing_value = "10",
+        value_parser = clap::value_parser!(i32).range(1..=19)
+    )]
+    pub nice: Option<i32>,
+
+    /// 진행률 바를 갱신할 파일 수 간격 (기본값: 파일 수에 맞춰 자동, 1~1000)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub progress_every: Option<u64>,
+
+    /// 파일 N개를 묶어 한 작업에서 차례로 처리 (수백만 개의 작은 파일에서 작업 분배 비용 절감)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub batch_files: Option<u64>,
+
     /// 최대 폴더 탐색 깊이
     #[arg(long)]
     pub max_depth: Option<usize>,
 
+    /// 심볼릭 링크로 연결된 폴더도 탐색 (순환 링크는 건너뛰고 같은 파일은 한 번만 처리)
+    #[arg(long)]
+    pub follow_symlinks: bool,
+
+    /// 입력 폴더와 다른 파일 시스템(마운트 지점)으로 넘어가지 않음
+    #[arg(long)]
+    pub one_file_system: bool,
+
+    /// 이 크기 이상인 파일만 처리 (예: 1KB)
+    #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+    pub min_size: Option<u64>,
+
+    /// 이 크기 이하인 파일만 처리 (예: 100MB)
+    #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+    pub max_size: Option<u64>,
+
+    /// 이 시각 이후에 수정된 파일만 처리 (예: 7d, 12h, 2024-01-31, 2024-01-31T12:00:00, UTC)
+    #[arg(long, value_name = "TIME", value_parser = parse_time)]
+    pub newer_than: Option<SystemTime>,
+
+    /// 이 시각 이전에 수정된 파일만 처리 (예: 30d, 2024-01-01, UTC)
+    #[arg(long, value_name = "TIME", value_parser = parse_time)]
+    pub older_than: Option<SystemTime>,
+
+    /// 읽기 권한이 없는 파일을 경고만 하고 건너뜀 (실행을 실패로 처리하지 않음)
+    #[arg(long)]
+    pub skip_unreadable: bool,
+
+    /// 첫 에러에서 처리 중단 (종료 코드 2, 출력 파일을 만들지 않음)
+    #[arg(long, conflicts_with = "max_errors")]
+    pub fail_fast: bool,
+
+    /// 허용할 최대 에러 파일 수 (넘으면 처리 중단, 종료 코드 2)
+    #[arg(long, value_name = "N")]
+    pub max_errors: Option<usize>,
+
+    /// 최소 성공률 품질 기준 (%, 밑돌면 종료 코드 1)
+    #[arg(long, value_name = "PCT", value_parser = parse_success_rate)]
+    pub min_success_rate: Option<f64>,
+
+    /// 허용하는 최대 실패 파일 수 품질 기준 (처리는 끝까지 하고 넘으면 종료 코드 1)
+    #[arg(long, value_name = "N")]
+    pub max_error_count: Option<usize>,
+
+    /// JSON 파서 (simd: simd-json, `simd-json` 기능으로 빌드 필요, 파싱 한도가 있으면 serde_json 사용)
+    #[arg(long, value_enum, default_value_t = ParserKind::Serde)]
+    pub parser: ParserKind,
+
+    /// 신뢰할 수 없는 입력용 강화 파싱 모드 (중첩 깊이 64, 문자열 1MB, 배열 요소 100만 개 제한)
+    #[arg(long)]
+    pub hardened: bool,
+
+    /// JSON 최대 중첩 깊이 (넘으면 파싱 오류, --hardened 기본값을 덮어씀)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_json_depth: Option<u64>,
+
+    /// 최대 문자열 길이 (예: 64KB, 넘으면 파싱 오류, 객체 키 포함)
+    #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+    pub max_string_len: Option<u64>,
+
+    /// 배열 하나의 최대 요소 수 (넘으면 파싱 오류)
+    #[arg(long, value_name = "N")]
+    pub max_array_len: Option<usize>,
+
     /// 에러 로그 파일 경로
     #[arg(long)]
     pub log: Option<PathBuf>,
 
+    /// 에러 로그 파일 형식 (json: 한 줄에 에러 하나, csv: 머리글 포함)
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t = ErrorLogFormat::Text, requires = "log")]
+    pub error_log_format: ErrorLogFormat,
+
+    /// 실행 통계(건수, 용량, 처리 시간, 파일별 에러, 분할 파일 목록)를 저장할 JSON 보고서 경로
+    #[arg(long, value_name = "FILE")]
+    pub stats_json: Option<PathBuf>,
+
+    /// 이전 실행의 `--stats-json` 보고서와 통계를 비교 (허용 범위를 넘게 나빠지면 종료 코드 1)
+    #[arg(long, value_name = "FILE")]
+    pub baseline: Option<PathBuf>,
+
+    /// 기준 실행 대비 허용 범위 (%, 파일 수/용량 감소와 에러 증가에 적용)
+    #[arg(long, value_name = "PCT", value_parser = parse_tolerance, default_value_t = DEFAULT_TOLERANCE, requires = "baseline")]
+    pub baseline_tolerance: f64,
+
+    /// 하위 폴더별 집계와 가장 크거나 느린 파일 10개를 통계에 표시 (`--stats-json` 보고서에도 포함)
+    #[arg(long)]
+    pub stats_detail: bool,
+
     /// 압축된 JSON 출력 (기본값: 압축)
     #[arg(long)]
     pub pretty: bool,
+
+    /// 출력 객체의 키 순서 (preserve: 원본 순서, `preserve_order` 기능으로 빌드 필요, sort: 중첩 객체까지 사전순)
+    #[arg(long, value_enum, value_name = "ORDER", default_value_t = KeyOrder::Default)]
+    pub key_order: KeyOrder,
+
+    /// 모든 출력 레코드의 앞에 둘 최상위 필드 순서 (`*`는 나머지 필드, 예: "id,timestamp,*,_source_file")
+    #[arg(long, value_name = "FIELDS", value_parser = FieldOrder::parse)]
+    pub field_order: Option<FieldOrder>,
+
+    /// 이미 한 줄인 문서는 검증만 하고 원본 바이트를 그대로 복사 (키 순서와 숫자 표기 유지, 레코드를 바꾸는 옵션이 있으면 사용하지 않음)
+    #[arg(long, conflicts_with_all = ["pretty", "validate_only"])]
+    pub passthrough: bool,
+
+    /// 검토용 주석 출력: 각 레코드 앞에 "# source: <원본 파일>" 줄 추가 (JSONL 적재용 아님)
+    #[arg(long)]
+    pub annotate: bool,
+
+    /// 각 레코드에 원본 파일 상대 경로 필드(_source_file) 추가
+    #[arg(long)]
+    pub add_source: bool,
+
+    /// 각 레코드에 원본 파일 수정 시각 필드(_source_mtime, Unix timestamp) 추가
+    #[arg(long)]
+    pub add_mtime: bool,
+
+    /// 각 레코드에 SHA-256 체크섬 필드 추가 (대상은 --checksum-of, 예: "_checksum")
+    #[arg(long, value_name = "FIELD")]
+    pub add_checksum: Option<String>,
+
+    /// 체크섬 대상 (file: 원본 파일 바이트, record: 레코드 자체)
+    #[arg(long, value_enum, value_name = "SCOPE", default_value_t = ChecksumScope::File, requires = "add_checksum")]
+    pub checksum_of: ChecksumScope,
+
+    /// 변환 파이프라인 설정 파일 (select → rename → filter → redact → inject 단계를 순서대로 정의)
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["fields", "exclude_fields", "rename", "add_source", "add_mtime", "add_checksum"])]
+    pub pipeline: Option<PathBuf>,
+
+    /// 중복 제거 기준 필드 (점 표기법 지원, 예: "id", "user.id")
+    #[arg(long, value_name = "FIELD", alias = "dedup-key")]
+    pub dedupe_by: Option<String>,
+
+    /// 같은 키를 가진 레코드 중 남길 레코드 (last는 디스크 기반으로 판정, --dedupe-exact와 함께 쓰면 출력 레코드를 모두 메모리에 모음)
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = DedupKeep::First,
+        alias = "dedup-keep",
+        requires = "dedupe_by"
+    )]
+    pub dedupe_keep: DedupKeep,
+
+    /// 블룸 필터 근사 모드 대신 정확한 해시 집합으로 중복 제거
+    #[arg(long, requires = "dedupe_by")]
+    pub dedupe_exact: bool,
+
+    /// 근사 중복 제거의 예상 고유 키 수 (초과 시 위양성 확률 보장 없음)
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_CAPACITY, requires = "dedupe_by")]
+    pub dedupe_capacity: usize,
+
+    /// 근사 중복 제거의 목표 위양성 확률 (0보다 크고 1보다 작음, 예상 키 수 이하일 때의 상한)
+    #[arg(long, value_name = "RATE", value_parser = parse_fp_rate, default_value_t = DEFAULT_FP_RATE, requires = "dedupe_by")]
+    pub dedupe_fp_rate: f64,
+
+    /// 임시 파일로 내보내는 디스크 기반 정확한 중복 제거 (메모리보다 큰 데이터용)
+    #[arg(long, requires = "dedupe_by", conflicts_with = "dedupe_exact")]
+    pub dedupe_spill: bool,
+
+    /// 디스크 기반 중복 제거에서 한 번에 메모리에 올릴 파티션의 최대 용량 (넘는 파티션은 다시 나눔, 예: "1GB")
+    #[arg(long, value_name = "SIZE", value_parser = parse_size, default_value_t = DEFAULT_DEDUPE_MEMORY, requires = "dedupe_spill")]
+    pub dedupe_memory: u64,
+
+    /// 출력 레코드를 이 필드 값으로 정렬 (점 표기법 지원, 숫자는 수치로, 문자열은 사전순으로 비교)
+    #[arg(long, value_name = "FIELD")]
+    pub sort_by: Option<String>,
+
+    /// 내림차순 정렬
+    #[arg(long, requires = "sort_by")]
+    pub desc: bool,
+
+    /// 정렬할 레코드를 메모리에 모아 둘 최대 용량 (넘으면 임시 파일로 내보내는 외부 정렬, 예: "1GB")
+    #[arg(long, value_name = "SIZE", value_parser = parse_size, default_value_t = DEFAULT_SORT_MEMORY, requires = "sort_by")]
+    pub sort_memory: u64,
+
+    /// 디스크 기반 처리에 사용할 임시 폴더 (기본값: 시스템 임시 폴더)
+    #[arg(long, value_name = "DIR")]
+    pub temp_dir: Option<PathBuf>,
+
+    /// 파일 간 스키마 변화(새 필드, 새 타입) 감지 시 경고
+    #[arg(long)]
+    pub schema_drift: bool,
+
+    /// 스키마 변화 감지의 기준으로 삼을 앞쪽 파일 수
+    #[arg(long, value_name = "N", default_value_t = 1, requires = "schema_drift")]
+    pub schema_baseline: usize,
+
+    /// 레코드마다 타입이 다른 필드를 개수와 예시 파일과 함께 보고
+    #[arg(long)]
+    pub type_report: bool,
+
+    /// 출력 레코드 구조(필드, 타입, null 여부, 예시 값, 출현 횟수)를 추론하여 JSON Schema로 저장할 경로
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["stdin", "validate_only"])]
+    pub infer_schema: Option<PathBuf>,
+}
+
+/// 하위 명령
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// 변환 없이 입력 파일 수, 전체 용량, 크기 분포와 예상 처리 시간 보고
+    Plan(PlanArgs),
+    /// 스키마에 맞는 가짜 JSON 파일 생성 (벤치마크/테스트용)
+    Generate(GenerateArgs),
+    /// 기존 JSONL 파일을 줄마다 병렬로 검사하여 잘못된 줄 번호 보고
+    Validate(ValidateArgs),
+}
+
+/// `jconvert plan` 인자
+#[derive(clap::Args, Debug, Default)]
+pub struct PlanArgs {
+    /// JSON 파일들이 있는 입력 폴더 경로
+    #[arg(short, long)]
+    pub input: PathBuf,
+
+    /// 포함할 파일 패턴 (glob 형식, 반복 지정 가능)
+    #[arg(short, long)]
+    pub pattern: Vec<String>,
+
+    /// 제외할 파일 패턴 (glob 형식, 반복 지정 가능)
+    #[arg(long)]
+    pub exclude: Vec<String>,
+
+    /// 최대 폴더 탐색 깊이
+    #[arg(long)]
+    pub max_depth: Option<usize>,
+
+    /// 예상 시간 계산에 사용할 스레드 수 (기본값: CPU 코어 수)
+    #[arg(short = 'j', long)]
+    pub threads: Option<usize>,
+
+    /// 처리 속도 측정에 사용할 샘플 파일 수
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_SAMPLE_SIZE)]
+    pub sample: usize,
+}
+
+/// `jconvert generate` 인자
+#[derive(clap::Args, Debug)]
+pub struct GenerateArgs {
+    /// 생성할 파일 수
+    #[arg(long, value_name = "N")]
+    pub count: u64,
+
+    /// JSON Schema 파일 (object/array/string/integer/number/boolean, enum, format 등 지원)
+    #[arg(long, value_name = "FILE")]
+    pub schema: PathBuf,
+
+    /// 파일을 만들 폴더 (없으면 생성)
+    #[arg(long, value_name = "DIR")]
+    pub out: PathBuf,
+
+    /// 파일당 레코드 수 (2 이상이면 배열로 기록)
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    pub records_per_file: u64,
+
+    /// 난수 시드 (같은 시드로 같은 데이터 재생성, 기본값: 현재 시각)
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+/// `jconvert validate` 인자
+#[derive(clap::Args, Debug)]
+pub struct ValidateArgs {
+    /// 검사할 JSONL 파일 (반복 지정 가능)
+    #[arg(long, value_name = "FILE", required = true)]
+    pub jsonl: Vec<PathBuf>,
+
+    /// 병렬로 검사할 조각 크기 (예: "8MB", 조각은 줄 경계에서 나뉨)
+    #[arg(long, value_name = "SIZE", value_parser = parse_size, default_value_t = DEFAULT_CHUNK_SIZE)]
+    pub chunk_size: u64,
+
+    /// 병렬 처리 스레드 수 (기본값: CPU 코어 수)
+    #[arg(short = 'j', long)]
+    pub threads: Option<usize>,
+
+    /// 파일마다 화면에 표시할 잘못된 줄 수 (전체 목록은 --log로 저장)
+    #[arg(long, value_name = "N", default_value_t = DEFAULT_SHOWN_LINE_ERRORS)]
+    pub show: usize,
+
+    /// 잘못된 줄 전체 목록을 저장할 로그 파일 경로
+    #[arg(long)]
+    pub log: Option<PathBuf>,
+
+    /// 로그 파일 형식 (json: 한 줄에 에러 하나, csv: 머리글 포함)
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t = ErrorLogFormat::Text, requires = "log")]
+    pub error_log_format: ErrorLogFormat,
+
+    /// 레코드 경계가 깨진 파일을 복구해 제자리에 다시 씀 (복구할 수 없으면 원본 유지)
+    #[arg(long)]
+    pub fix: bool,
 }
 
+/// `jconvert validate`에서 파일마다 화면에 표시할 잘못된 줄 수 기본값
+pub const DEFAULT_SHOWN_LINE_ERRORS: usize = 20;
+
 impl Args {
     /// 필드 목록을 파싱하여 벡터로 반환
     pub fn get_fields(&self) -> Option<Vec<String>> {
-        self.fields.as_ref().map(|f| {
-            f.split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
+        self.fields.as_deref().map(split_list)
+    }
+
+    /// 표준 입력에서 파일 경로 목록을 읽는지 확인 (`-i -`)
+    pub fn reads_path_list(&self) -> bool {
+        self.input
+            .iter()
+            .any(|input| input.as_os_str() == STDIO_PATH)
+    }
+
+    /// 탐색할 입력 폴더 목록 (`-i -` 제외, 지정한 순서대로)
+    pub fn input_dirs(&self) -> Vec<&PathBuf> {
+        self.input
+            .iter()
+            .filter(|input| input.as_os_str() != STDIO_PATH)
+            .collect()
+    }
+
+    /// 입력 출처(폴더, `-i -`, `--files-from`)가 여러 개인지 확인 (같은 파일이 중복될 수 있음)
+    pub fn has_multiple_sources(&self) -> bool {
+        self.input.len() + usize::from(self.files_from.is_some()) > 1
+    }
+
+    /// 출력을 임시 파일에 쓴 뒤 이름을 바꿔 완성하는지 확인
+    ///
+    /// 단일 파일 출력의 덮어쓰기/에러 모드에만 적용됩니다
+    /// (추가 모드, 분할 출력, 표준 출력, 중단 후 이어 쓰는 `--checkpoint` 제외).
+    pub fn atomic_output(&self) -> bool {
+        !self.no_atomic
+            && self.checkpoint.is_none()
+            && self.mode != WriteMode::Append
+            && !self.writes_stdout()
+            && !self.shard_limits().is_enabled()
+            && self.remote_target().is_none()
+    }
+
+    /// 원격 출력 대상 (`-o s3://bucket/key`, `-o gs://bucket/key`)
+    pub fn remote_target(&self) -> Option<RemoteTarget> {
+        RemoteTarget::parse(&self.output)
+    }
+
+    /// 출력할 최대 메시지 수준 (`--quiet`, `-v`, `-vv`)
+    pub fn log_level(&self) -> Level {
+        Level::from_flags(self.quiet, self.verbose)
+    }
+
+    /// 파일별 상세 결과를 출력하는지 확인 (`-v` 이상)
+    pub fn is_verbose(&self) -> bool {
+        self.log_level() >= Level::Verbose
+    }
+
+    /// JSONL을 표준 출력으로 쓰는지 확인 (`-o -`)
+    pub fn writes_stdout(&self) -> bool {
+        self.output.as_os_str() == STDIO_PATH
+    }
+
+    /// 출력 분할 기준 반환
+    pub fn shard_limits(&self) -> ShardLimits {
+        ShardLimits {
+            max_bytes: self.split_size,
+            max_records: self.split_records,
+        }
+    }
+
+    /// 디스크 동기화(fsync) 정책 반환
+    pub fn sync_policy(&self) -> SyncPolicy {
+        SyncPolicy {
+            on_finish: self.fsync,
+            every_records: self.fsync_every,
+            on_rotate: self.fsync_on_rotate,
+        }
+    }
+
+    /// 레코드 샘플러 반환 (`--sample`이 없으면 None, 시드를 지정하지 않으면 현재 시각)
+    pub fn sampler(&self) -> Option<Sampler> {
+        self.sample
+            .map(|rate| Sampler::new(rate, self.seed.unwrap_or_else(time_seed)))
+    }
+
+    /// 체크섬 주입 필드 반환 (`--add-checksum`이 없으면 None)
+    pub fn checksum_field(&self) -> Option<ChecksumField> {
+        self.add_checksum
+            .as_ref()
+            .map(|key| ChecksumField::new(key.clone(), self.checksum_of))
+    }
+
+    /// 출력 줄 크기 제한 반환 (`--max-record-size`가 없으면 None)
+    pub fn record_size_guard(&self) -> Option<RecordSizeGuard> {
+        self.max_record_size
+            .map(|max_bytes| RecordSizeGuard::new(max_bytes, self.oversize_policy))
+    }
+
+    /// 실행 품질 기준 반환 (`--min-success-rate`, `--max-error-count`)
+    pub fn quality_gates(&self) -> QualityGates {
+        QualityGates {
+            min_success_rate: self.min_success_rate,
+            max_error_count: self.max_error_count,
+            skip_unreadable: self.skip_unreadable,
+        }
+    }
+
+    /// 허용할 최대 에러 수 반환 (`--fail-fast`는 0개, 지정하지 않으면 무제한)
+    pub fn error_limit(&self) -> Option<usize> {
+        if self.fail_fast {
+            Some(0)
+        } else {
+            self.max_errors
+        }
+    }
+
+    /// 진행률 바 갱신 간격 반환 (지정하지 않으면 전체 파일 수에 맞춰 자동)
+    pub fn progress_interval(&self, total: usize) -> u64 {
+        self.progress_every
+            .unwrap_or_else(|| auto_interval(total as u64))
+    }
+
+    /// 병렬 작업 하나가 처리할 최소 파일 수 (기본값: 1)
+    pub fn batch_len(&self) -> usize {
+        self.batch_files.map_or(1, |n| n as usize)
+    }
+
+    /// 파싱 한도 반환 (`--hardened` 기본값 위에 개별 한도 옵션 적용)
+    pub fn parse_limits(&self) -> ParseLimits {
+        let base = if self.hardened {
+            ParseLimits::hardened()
+        } else {
+            ParseLimits::default()
+        };
+        ParseLimits {
+            max_depth: self.max_json_depth.map(|n| n as usize).or(base.max_depth),
+            max_string_len: self
+                .max_string_len
+                .map(|n| n as usize)
+                .or(base.max_string_len),
+            max_array_len: self.max_array_len.or(base.max_array_len),
+        }
+    }
+
+    /// 파일 단위 파싱에 실제로 쓰는 파서 (파싱 한도나 --multi-doc이 있으면 serde_json)
+    pub fn effective_parser(&self) -> ParserKind {
+        if self.multi_doc || self.parse_limits().is_enabled() {
+            ParserKind::Serde
+        } else {
+            self.parser
+        }
+    }
+
+    /// 파일 크기/수정 시각 필터 반환
+    pub fn file_filter(&self) -> FileFilter {
+        FileFilter {
+            min_size: self.min_size,
+            max_size: self.max_size,
+            newer_than: self.newer_than,
+            older_than: self.older_than,
+        }
+    }
+
+    /// 필드 스키마 수집이 필요한지 확인
+    pub fn needs_schema(&self) -> bool {
+        self.schema_drift || self.type_report
+    }
+
+    /// 제외할 필드 목록을 파싱하여 벡터로 반환
+    pub fn get_exclude_fields(&self) -> Option<Vec<String>> {
+        self.exclude_fields.as_deref().map(split_list)
+    }
+
+    /// 필드 이름 변경 목록을 (기존 경로, 새 경로) 쌍으로 반환
+    pub fn get_renames(&self) -> Option<Vec<(String, String)>> {
+        self.rename.as_deref().map(|spec| {
+            split_list(spec)
+                .iter()
+                .filter_map(|pair| pair.split_once(':'))
+                .map(|(from, to)| (from.trim().to_string(), to.trim().to_string()))
                 .collect()
         })
     }
+
+    /// 디스크 기반 중복 제거를 쓰는지 확인
+    ///
+    /// 메모리 기반으로 마지막 레코드를 남기려면 출력 레코드를 모두 메모리에 모아야 하므로,
+    /// `--dedupe-keep last`는 `--dedupe-exact`를 지정하지 않으면 디스크 기반으로 판정합니다.
+    pub fn spills_dedup(&self) -> bool {
+        self.dedupe_spill || (self.dedupe_keep == DedupKeep::Last && !self.dedupe_exact)
+    }
+
+    /// 중복 제거 옵션에 맞는 메모리 기반 중복 제거기 생성
+    pub fn build_deduplicator(&self) -> Option<Deduplicator> {
+        if self.spills_dedup() {
+            return None;
+        }
+
+        self.dedupe_by.as_ref().map(|key| {
+            let deduplicator = if self.dedupe_exact {
+                Deduplicator::exact(key.clone())
+            } else {
+                Deduplicator::approximate(key.clone(), self.dedupe_capacity, self.dedupe_fp_rate)
+            };
+            deduplicator.with_keep(self.dedupe_keep)
+        })
+    }
+
+    /// 변환 단계에서 적용할 최대 레코드 수 (정렬하면 정렬기에서 적용하므로 None)
+    pub fn early_limit(&self) -> Option<u64> {
+        self.limit.filter(|_| self.sort_by.is_none())
+    }
+
+    /// 정렬 옵션이 있으면 레코드 정렬기 생성
+    pub fn build_sorter(&self) -> Option<RecordSorter> {
+        self.sort_by.as_ref().map(|key| {
+            RecordSorter::new(key.clone())
+                .with_descending(self.desc)
+                .with_memory_limit(self.sort_memory)
+                .with_limit(self.limit)
+                .with_temp_dir(self.temp_dir.clone())
+        })
+    }
+
+    /// 디스크 기반 중복 제거 옵션이 켜져 있으면 중복 제거기 생성
+    pub fn build_spill_deduplicator(&self) -> std::io::Result<Option<SpillDeduplicator>> {
+        match self.dedupe_by {
+            Some(ref key) if self.spills_dedup() => Ok(Some(
+                SpillDeduplicator::new(key.clone(), self.temp_dir.as_deref())?
+                    .with_keep(self.dedupe_keep)
+                    .with_memory_limit(self.dedupe_memory),
+            )),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// 쉼표로 구분된 목록을 공백 제거 후 벡터로 분리
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// `--rename` 값 검증 (모든 항목이 "기존:새" 형식이어야 함)
+fn parse_rename_spec(spec: &str) -> Result<String, String> {
+    for pair in split_list(spec) {
+        match pair.split_once(':') {
+            Some((from, to)) if !from.trim().is_empty() && !to.trim().is_empty() => {}
+            _ => return Err(format!("'{}'은(는) \"기존:새\" 형식이 아닙니다", pair)),
+        }
+    }
+    Ok(spec.to_string())
 }
@@ -0,0 +1,493 @@
+//! 압축 코덱 모듈
+//!
+//! `--compress gzip`처럼 출력 파일을 압축하는 코덱을 `Codec` 트레이트 뒤에 둡니다.
+//! 출력기는 코덱이 돌려준 쓰기 스트림에 레코드를 쓰고 마지막에 `FinishEncode::finish`만 부르므로,
+//! 코덱을 추가할 때는 트레이트를 구현하여 `CODECS`에 등록하고 기능 플래그만 더하면 됩니다.
+//!
+//! 기본 제공 코덱은 압축 라이브러리(flate2, zstd, bzip2, xz2, lz4_flex, brotli)로 프로세스 안에서 압축하며,
+//! 각 라이브러리는 해당 기능(`codec-gzip` 등)을 켜야 빌드에 포함됩니다.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::writer::{SyncData, SyncWrite};
+
+/// 출력 압축 코덱
+pub trait Codec: fmt::Debug + Send + Sync {
+    /// 코덱 이름 (`--compress` 값)
+    fn name(&self) -> &'static str;
+
+    /// 압축 파일 확장자 (점 제외)
+    fn extension(&self) -> &'static str;
+
+    /// 압축 파일 첫머리의 매직 바이트 (형식에 매직 바이트가 없으면 None)
+    fn magic(&self) -> Option<&'static [u8]>;
+
+    /// 이 코덱을 켜는 기능 이름 (`Cargo.toml`의 `[features]`)
+    fn feature(&self) -> &'static str;
+
+    /// 현재 빌드에서 사용할 수 있는지 확인
+    fn is_available(&self) -> bool;
+
+    /// 압축 시작
+    ///
+    /// # Arguments
+    /// * `output` - 압축한 데이터를 쓸 파일
+    ///
+    /// # Returns
+    /// 압축할 데이터를 쓰는 스트림과, 스트림을 닫은 뒤 압축을 마무리하는 핸들
+    fn encode(&self, output: File) -> io::Result<(Box<dyn SyncWrite>, Box<dyn FinishEncode>)>;
+}
+
+/// 압축 마무리 핸들
+///
+/// `finish` 전에 버려지면(에러로 중단 등) 압축 형식의 끝이 기록되었는지 보장하지 않습니다.
+pub trait FinishEncode: fmt::Debug + Send {
+    /// 남은 압축 데이터와 형식의 끝을 기록 (쓰기 스트림은 먼저 닫아야 함)
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+/// 출력 파일에 압축해 쓰는 인코더 (압축 라이브러리의 쓰기 스트림)
+trait Encoder: Write + Send {
+    /// 압축한 데이터를 쓰는 출력 파일
+    fn file(&mut self) -> &mut File;
+
+    /// 남은 데이터와 형식의 끝을 기록
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+/// 출력 파일로 인코더를 만드는 함수
+type StartEncoder = fn(File) -> io::Result<Box<dyn Encoder>>;
+
+/// 압축 라이브러리로 압축하는 기본 제공 코덱
+#[derive(Debug, Clone, Copy)]
+struct BuiltinCodec {
+    name: &'static str,
+    extension: &'static str,
+    magic: Option<&'static [u8]>,
+    feature: &'static str,
+    /// 인코더 생성 함수 (기능 없이 빌드되면 None)
+    start: Option<StartEncoder>,
+}
+
+impl Codec for BuiltinCodec {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn extension(&self) -> &'static str {
+        self.extension
+    }
+
+    fn magic(&self) -> Option<&'static [u8]> {
+        self.magic
+    }
+
+    fn feature(&self) -> &'static str {
+        self.feature
+    }
+
+    fn is_available(&self) -> bool {
+        self.start.is_some()
+    }
+
+    fn encode(&self, output: File) -> io::Result<(Box<dyn SyncWrite>, Box<dyn FinishEncode>)> {
+        let start = self.start.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!(
+                    "{} 압축을 쓰려면 `--features {}`로 빌드해야 합니다",
+                    self.name, self.feature
+                ),
+            )
+        })?;
+        let encoder = Arc::new(Mutex::new(Some(start(output)?)));
+        Ok((
+            Box::new(EncodeStream(Arc::clone(&encoder))),
+            Box::new(PendingEncode(encoder)),
+        ))
+    }
+}
+
+/// 쓰기 스트림과 마무리 핸들이 함께 쓰는 인코더 (마무리 후에는 None)
+type SharedEncoder = Arc<Mutex<Option<Box<dyn Encoder>>>>;
+
+fn lock_encoder(encoder: &SharedEncoder) -> MutexGuard<'_, Option<Box<dyn Encoder>>> {
+    encoder
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// 인코더로 이어진 쓰기 스트림
+struct EncodeStream(SharedEncoder);
+
+impl EncodeStream {
+    fn with_encoder<T>(&self, f: impl FnOnce(&mut dyn Encoder) -> io::Result<T>) -> io::Result<T> {
+        match lock_encoder(&self.0).as_deref_mut() {
+            Some(encoder) => f(encoder),
+            None => Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "압축이 이미 끝났습니다",
+            )),
+        }
+    }
+}
+
+impl Write for EncodeStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.with_encoder(|encoder| encoder.write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.with_encoder(|encoder| encoder.flush())
+    }
+}
+
+impl SyncData for EncodeStream {
+    /// 인코더가 지금까지 받은 데이터를 압축 블록으로 내보낸 뒤 출력 파일을 동기화
+    fn sync_data(&mut self) -> io::Result<()> {
+        self.with_encoder(|encoder| {
+            encoder.flush()?;
+            encoder.file().sync_data()
+        })
+    }
+}
+
+/// 마무리를 기다리는 인코더
+struct PendingEncode(SharedEncoder);
+
+impl fmt::Debug for PendingEncode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PendingEncode")
+            .field("finished", &lock_encoder(&self.0).is_none())
+            .finish()
+    }
+}
+
+impl FinishEncode for PendingEncode {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        match lock_encoder(&self.0).take() {
+            Some(encoder) => encoder.finish(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "codec-gzip")]
+impl Encoder for flate2::write::GzEncoder<File> {
+    fn file(&mut self) -> &mut File {
+        self.get_mut()
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        flate2::write::GzEncoder::finish(*self).map(drop)
+    }
+}
+
+#[cfg(feature = "codec-gzip")]
+fn gzip_encoder(output: File) -> io::Result<Box<dyn Encoder>> {
+    Ok(Box::new(flate2::write::GzEncoder::new(
+        output,
+        flate2::Compression::default(),
+    )))
+}
+
+#[cfg(feature = "codec-zstd")]
+impl Encoder for zstd::stream::write::Encoder<'static, File> {
+    fn file(&mut self) -> &mut File {
+        self.get_mut()
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        zstd::stream::write::Encoder::finish(*self).map(drop)
+    }
+}
+
+#[cfg(feature = "codec-zstd")]
+fn zstd_encoder(output: File) -> io::Result<Box<dyn Encoder>> {
+    Ok(Box::new(zstd::stream::write::Encoder::new(
+        output,
+        zstd::DEFAULT_COMPRESSION_LEVEL,
+    )?))
+}
+
+#[cfg(feature = "codec-bzip2")]
+impl Encoder for bzip2::write::BzEncoder<File> {
+    fn file(&mut self) -> &mut File {
+        self.get_mut()
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        bzip2::write::BzEncoder::finish(*self).map(drop)
+    }
+}
+
+#[cfg(feature = "codec-bzip2")]
+fn bzip2_encoder(output: File) -> io::Result<Box<dyn Encoder>> {
+    Ok(Box::new(bzip2::write::BzEncoder::new(
+        output,
+        bzip2::Compression::default(),
+    )))
+}
+
+#[cfg(feature = "codec-xz")]
+impl Encoder for xz2::write::XzEncoder<File> {
+    fn file(&mut self) -> &mut File {
+        self.get_mut()
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        xz2::write::XzEncoder::finish(*self).map(drop)
+    }
+}
+
+#[cfg(feature = "codec-xz")]
+fn xz_encoder(output: File) -> io::Result<Box<dyn Encoder>> {
+    // xz 도구의 기본 압축 수준
+    Ok(Box::new(xz2::write::XzEncoder::new(output, 6)))
+}
+
+#[cfg(feature = "codec-lz4")]
+impl Encoder for lz4_flex::frame::FrameEncoder<File> {
+    fn file(&mut self) -> &mut File {
+        self.get_mut()
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        lz4_flex::frame::FrameEncoder::finish(*self)
+            .map(drop)
+            .map_err(io::Error::from)
+    }
+}
+
+#[cfg(feature = "codec-lz4")]
+fn lz4_encoder(output: File) -> io::Result<Box<dyn Encoder>> {
+    Ok(Box::new(lz4_flex::frame::FrameEncoder::new(output)))
+}
+
+/// 쓰기 에러를 기억하는 출력 파일
+///
+/// brotli 인코더는 마무리 중 쓰기 에러를 돌려주지 않으므로 파일 쪽에서 에러를 기록해 둡니다.
+#[cfg(feature = "codec-brotli")]
+struct CheckedFile {
+    file: File,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "codec-brotli")]
+impl Write for CheckedFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.file.write(buf).inspect_err(|e| {
+            self.error
+                .get_or_insert_with(|| io::Error::new(e.kind(), e.to_string()));
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(feature = "codec-brotli")]
+impl Encoder for brotli::CompressorWriter<CheckedFile> {
+    fn file(&mut self) -> &mut File {
+        &mut self.get_mut().file
+    }
+
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        match self.into_inner().error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "codec-brotli")]
+fn brotli_encoder(output: File) -> io::Result<Box<dyn Encoder>> {
+    // brotli 도구의 기본 설정 (품질 11, 창 크기 2^22)
+    let output = CheckedFile {
+        file: output,
+        error: None,
+    };
+    Ok(Box::new(brotli::CompressorWriter::new(
+        output,
+        64 * 1024,
+        11,
+        22,
+    )))
+}
+
+/// 등록된 코덱 (빌드에서 빠진 코덱도 이름과 매직 바이트 확인을 위해 포함)
+pub static CODECS: &[&dyn Codec] = &[
+    &BuiltinCodec {
+        name: "gzip",
+        extension: "gz",
+        magic: Some(&[0x1f, 0x8b]),
+        feature: "codec-gzip",
+        #[cfg(feature = "codec-gzip")]
+        start: Some(gzip_encoder),
+        #[cfg(not(feature = "codec-gzip"))]
+        start: None,
+    },
+    &BuiltinCodec {
+        name: "zstd",
+        extension: "zst",
+        magic: Some(&[0x28, 0xb5, 0x2f, 0xfd]),
+        feature: "codec-zstd",
+        #[cfg(feature = "codec-zstd")]
+        start: Some(zstd_encoder),
+        #[cfg(not(feature = "codec-zstd"))]
+        start: None,
+    },
+    &BuiltinCodec {
+        name: "bzip2",
+        extension: "bz2",
+        magic: Some(b"BZh"),
+        feature: "codec-bzip2",
+        #[cfg(feature = "codec-bzip2")]
+        start: Some(bzip2_encoder),
+        #[cfg(not(feature = "codec-bzip2"))]
+        start: None,
+    },
+    &BuiltinCodec {
+        name: "xz",
+        extension: "xz",
+        magic: Some(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]),
+        feature: "codec-xz",
+        #[cfg(feature = "codec-xz")]
+        start: Some(xz_encoder),
+        #[cfg(not(feature = "codec-xz"))]
+        start: None,
+    },
+    &BuiltinCodec {
+        name: "lz4",
+        extension: "lz4",
+        magic: Some(&[0x04, 0x22, 0x4d, 0x18]),
+        feature: "codec-lz4",
+        #[cfg(feature = "codec-lz4")]
+        start: Some(lz4_encoder),
+        #[cfg(not(feature = "codec-lz4"))]
+        start: None,
+    },
+    &BuiltinCodec {
+        name: "brotli",
+        extension: "br",
+        magic: None,
+        feature: "codec-brotli",
+        #[cfg(feature = "codec-brotli")]
+        start: Some(brotli_encoder),
+        #[cfg(not(feature = "codec-brotli"))]
+        start: None,
+    },
+];
+
+/// 이름으로 코덱 찾기 (`--compress` 값 파서)
+///
+/// 빌드에서 빠진 코덱도 찾으며, 사용할 수 있는지는 `Codec::is_available`로 확인합니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::codec::find_codec;
+///
+/// let codec = find_codec("gzip").unwrap();
+/// assert_eq!(codec.extension(), "gz");
+/// assert_eq!(codec.feature(), "codec-gzip");
+/// assert!(find_codec("rar").is_err());
+/// ```
+pub fn find_codec(name: &str) -> Result<&'static dyn Codec, String> {
+    CODECS
+        .iter()
+        .copied()
+        .find(|codec| codec.name() == name)
+        .ok_or_else(|| {
+            let names: Vec<&str> = CODECS.iter().map(|codec| codec.name()).collect();
+            format!(
+                "알 수 없는 압축 코덱입니다: {} (사용 가능: {})",
+                name,
+                names.join(", ")
+            )
+        })
+}
+
+/// 파일 첫머리의 매직 바이트로 압축 코덱 판별
+///
+/// # Examples
+/// ```
+/// use jconvert::codec::detect_codec;
+///
+/// assert_eq!(detect_codec(&[0x1f, 0x8b, 0x08]).map(|c| c.name()), Some("gzip"));
+/// assert!(detect_codec(b"{\"id\":1}").is_none());
+/// ```
+pub fn detect_codec(head: &[u8]) -> Option<&'static dyn Codec> {
+    CODECS
+        .iter()
+        .copied()
+        .find(|codec| codec.magic().is_some_and(|magic| head.starts_with(magic)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_registry() {
+        let names: Vec<&str> = CODECS.iter().map(|codec| codec.name()).collect();
+        assert_eq!(names, ["gzip", "zstd", "bzip2", "xz", "lz4", "brotli"]);
+
+        let zstd = find_codec("zstd").unwrap();
+        assert_eq!(zstd.magic(), Some(&[0x28, 0xb5, 0x2f, 0xfd][..]));
+        assert_eq!(zstd.is_available(), cfg!(feature = "codec-zstd"));
+        assert_eq!(detect_codec(b"BZh91AY").unwrap().name(), "bzip2");
+    }
+
+    #[test]
+    fn test_encode_writes_compressed_output() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for codec in CODECS {
+            let path = dir.path().join(format!("out.jsonl.{}", codec.extension()));
+            let result = codec.encode(File::create(&path).unwrap());
+            if !codec.is_available() {
+                assert!(result.is_err(), "{}", codec.name());
+                continue;
+            }
+
+            let (mut stream, pending) = result.unwrap();
+            stream.write_all(b"{\"id\":1}\n").unwrap();
+            stream.sync_data().unwrap();
+            stream.write_all(b"{\"id\":2}\n").unwrap();
+            drop(stream);
+            pending.finish().unwrap();
+
+            let written = std::fs::read(&path).unwrap();
+            assert!(!written.is_empty(), "{}", codec.name());
+            if let Some(magic) = codec.magic() {
+                assert!(written.starts_with(magic), "{}", codec.name());
+                assert_eq!(detect_codec(&written).unwrap().name(), codec.name());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "codec-gzip")]
+    fn test_gzip_round_trip() {
+        use std::io::Read;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("out.jsonl.gz");
+        let (mut stream, pending) = find_codec("gzip")
+            .unwrap()
+            .encode(File::create(&path).unwrap())
+            .unwrap();
+        stream.write_all(b"{\"id\":1}\n").unwrap();
+        drop(stream);
+        pending.finish().unwrap();
+
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(File::open(&path).unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, "{\"id\":1}\n");
+    }
+}
@@ -0,0 +1,372 @@
+//! 라이브러리 변환 실행 모듈
+//!
+//! CLI 없이 파일 목록을 병렬로 변환하여 한 출력에 JSONL로 기록합니다.
+//! 작업자는 입력 순서대로 파일을 가져가고, 호출한 스레드는 끝난 결과를 입력 순서대로 받는 즉시 기록하므로
+//! 메모리에는 기록을 기다리는 몇 개 파일의 결과만 남습니다.
+//! `CancellationToken`으로 중간에 멈출 수 있으며, 취소되어도 그때까지 처리한 파일의 통계를 돌려줍니다.
+//! 이 순서 보장 처리(`process_in_order`)는 CLI 변환 모드도 함께 사용합니다.
+
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Condvar, Mutex};
+
+use crate::cancel::CancellationToken;
+use crate::error::{JConvertError, Result};
+use crate::exit::ExitStatus;
+use crate::processor::{process_file, ProcessOptions};
+use crate::stats::Statistics;
+
+/// 변환 실행 결과
+#[derive(Debug)]
+pub struct Conversion {
+    /// 처리 통계 (취소되었으면 처리한 파일까지의 부분 통계)
+    pub stats: Statistics,
+    /// 취소되어 처리하지 않은 파일 수
+    pub cancelled_files: usize,
+}
+
+impl Conversion {
+    /// 취소되어 일부 파일을 처리하지 않았는지 확인
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled_files > 0
+    }
+
+    /// 종료 상태 (취소되었으면 중단, 아니면 실패 파일 수로 결정)
+    pub fn exit_status(&self) -> ExitStatus {
+        if self.is_cancelled() {
+            ExitStatus::Aborted
+        } else {
+            ExitStatus::from_failures(
+                self.stats.get_error_count() + self.stats.get_unreadable_count(),
+            )
+        }
+    }
+}
+
+/// 여러 파일을 병렬로 변환하여 입력 순서대로 기록
+///
+/// 작업자는 파일을 하나 처리하기 전마다 `cancel`을 확인하여, 취소되면 남은 파일을 건너뜁니다.
+/// 결과는 끝나는 대로 입력 순서에 맞춰 바로 기록하며, 취소 전에 처리를 마친 파일은 모두 기록합니다.
+/// 작업자는 기록보다 작업자 수의 4배 넘게 앞서지 않으므로 메모리 사용량은 전체 데이터 크기와 관계없습니다.
+///
+/// # Arguments
+/// * `files` - 변환할 파일 목록
+/// * `options` - 처리 옵션
+/// * `output` - 레코드를 한 줄씩 기록할 출력
+/// * `cancel` - 취소 토큰
+///
+/// # Errors
+/// 출력에 쓰지 못하면 에러를 반환합니다 (파일별 변환 실패는 통계에 집계).
+///
+/// # Examples
+/// ```
+/// use jconvert::{convert_files, CancellationToken, ProcessOptions};
+///
+/// let dir = tempfile::TempDir::new().unwrap();
+/// let path = dir.path().join("a.json");
+/// std::fs::write(&path, r#"{"id": 1}"#).unwrap();
+///
+/// let mut output = Vec::new();
+/// let run = convert_files(vec![path], &ProcessOptions::new(), &mut output, &CancellationToken::new())
+///     .unwrap();
+/// assert_eq!(output, b"{\"id\":1}\n");
+/// assert_eq!(run.stats.get_success_count(), 1);
+/// assert!(!run.is_cancelled());
+/// ```
+pub fn convert_files<W: Write>(
+    files: Vec<PathBuf>,
+    options: &ProcessOptions,
+    output: &mut W,
+    cancel: &CancellationToken,
+) -> Result<Conversion> {
+    let stats = Statistics::new(files.len());
+    let write_error = |e: std::io::Error| JConvertError::WriteError {
+        reason: e.to_string(),
+    };
+    let mut cancelled_files = 0;
+
+    process_in_order(
+        files.len(),
+        1,
+        |index| (!cancel.is_cancelled()).then(|| process_file(files[index].clone(), options)),
+        |_, result| {
+            let Some(result) = result else {
+                cancelled_files += 1;
+                return Ok(());
+            };
+            stats.record_result(&result);
+            for json_line in &result.json_lines {
+                output
+                    .write_all(json_line.as_bytes())
+                    .and_then(|()| output.write_all(b"\n"))
+                    .map_err(write_error)?;
+                stats.add_bytes_written(json_line.len() as u64 + 1);
+            }
+            Ok(())
+        },
+    )?;
+    output.flush().map_err(write_error)?;
+
+    Ok(Conversion {
+        stats,
+        cancelled_files,
+    })
+}
+
+/// 파일들을 병렬로 처리하고 결과를 입력 순서대로 넘김
+///
+/// 작업자는 `batch`개씩 입력 순서대로 파일 번호를 가져가 `process`를 실행하고,
+/// 호출한 스레드는 끝난 결과를 입력 순서대로 받는 즉시 `consume`에 넘깁니다.
+/// 작업자는 아직 넘기지 않은 가장 앞 파일보다 일정 범위 넘게 앞서지 않으므로
+/// 메모리에는 기다리는 몇 개 파일의 결과만 남습니다.
+///
+/// # Arguments
+/// * `count` - 처리할 파일 수
+/// * `batch` - 작업자가 한 번에 가져갈 파일 수 (1 이상)
+/// * `process` - 파일 번호로 결과를 만드는 작업 (작업자 스레드에서 실행)
+/// * `consume` - 파일 번호와 결과를 입력 순서대로 받는 작업 (호출한 스레드에서 실행)
+///
+/// # Errors
+/// `consume`이 실패하면 작업자를 멈추고 그 에러를 반환합니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::convert::process_in_order;
+///
+/// let mut seen = Vec::new();
+/// process_in_order(100, 4, |index| index * 2, |index, doubled| {
+///     seen.push((index, doubled));
+///     Ok::<(), ()>(())
+/// })
+/// .unwrap();
+/// assert_eq!(seen, (0..100).map(|i| (i, i * 2)).collect::<Vec<_>>());
+/// ```
+pub fn process_in_order<T: Send, E>(
+    count: usize,
+    batch: usize,
+    process: impl Fn(usize) -> T + Sync,
+    consume: impl FnMut(usize, T) -> std::result::Result<(), E>,
+) -> std::result::Result<(), E> {
+    let batch = batch.max(1);
+    let window = reorder_window() * batch;
+    let progress = WriteProgress::default();
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    // 결과를 받는 쪽은 Send가 아닐 수 있으므로 호출한 스레드에서, 처리는 별도 스레드의 병렬 작업자에서 수행
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            (0..rayon::current_num_threads())
+                .into_par_iter()
+                .for_each_with(sender, |sender, _| loop {
+                    let start = next.fetch_add(batch, Ordering::Relaxed);
+                    if start >= count || !progress.wait_for_slot(start, window) {
+                        break;
+                    }
+                    for index in start..count.min(start + batch) {
+                        if sender.send((index, process(index))).is_err() {
+                            return;
+                        }
+                    }
+                });
+        });
+
+        let consumed = consume_in_order(receiver, count, &progress, consume);
+        if consumed.is_err() {
+            progress.stop();
+        }
+        consumed
+    })
+}
+
+/// 기록을 기다리며 메모리에 둘 수 있는 최대 파일 수
+///
+/// 작업자는 아직 기록하지 않은 가장 앞 파일보다 이만큼 넘게 앞선 파일을 가져가지 않습니다.
+fn reorder_window() -> usize {
+    rayon::current_num_threads() * 4
+}
+
+/// 기록을 마친 파일 수 (작업자가 기록보다 너무 앞서지 않도록 공유)
+#[derive(Debug, Default)]
+struct WriteProgress {
+    written: Mutex<usize>,
+    advanced: Condvar,
+    stopped: AtomicBool,
+}
+
+impl WriteProgress {
+    /// `index`번 파일을 처리해도 될 때까지 대기
+    ///
+    /// # Returns
+    /// 처리해도 되면 true, 기록이 실패하여 멈췄으면 false
+    fn wait_for_slot(&self, index: usize, window: usize) -> bool {
+        let mut written = self.written.lock().unwrap_or_else(|e| e.into_inner());
+        while index >= *written + window && !self.stopped.load(Ordering::Relaxed) {
+            written = self
+                .advanced
+                .wait(written)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+        !self.stopped.load(Ordering::Relaxed)
+    }
+
+    /// 파일 하나를 기록했음을 알림
+    fn advance(&self) {
+        *self.written.lock().unwrap_or_else(|e| e.into_inner()) += 1;
+        self.advanced.notify_all();
+    }
+
+    /// 기다리는 작업자를 모두 멈춤
+    fn stop(&self) {
+        let _guard = self.written.lock().unwrap_or_else(|e| e.into_inner());
+        self.stopped.store(true, Ordering::Relaxed);
+        self.advanced.notify_all();
+    }
+}
+
+/// 작업자가 보낸 결과를 입력 순서대로 넘김
+fn consume_in_order<T, E>(
+    receiver: Receiver<(usize, T)>,
+    count: usize,
+    progress: &WriteProgress,
+    mut consume: impl FnMut(usize, T) -> std::result::Result<(), E>,
+) -> std::result::Result<(), E> {
+    let mut pending: BTreeMap<usize, T> = BTreeMap::new();
+
+    for index in 0..count {
+        let result = loop {
+            if let Some(result) = pending.remove(&index) {
+                break result;
+            }
+            let (done, result) = receiver
+                .recv()
+                .expect("작업자는 모든 파일의 결과를 보낸 뒤 끝남");
+            pending.insert(done, result);
+        };
+        consume(index, result)?;
+        progress.advance();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixtures(dir: &tempfile::TempDir) -> Vec<PathBuf> {
+        let files: Vec<PathBuf> = ["a.json", "b.json", "broken.json"]
+            .iter()
+            .map(|name| dir.path().join(name))
+            .collect();
+        std::fs::write(&files[0], r#"{"id": 1}"#).unwrap();
+        std::fs::write(&files[1], r#"{"id": 2}"#).unwrap();
+        std::fs::write(&files[2], r#"{"id": "#).unwrap();
+        files
+    }
+
+    #[test]
+    fn test_convert_files_in_input_order() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut output = Vec::new();
+        let run = convert_files(
+            fixtures(&dir),
+            &ProcessOptions::new(),
+            &mut output,
+            &CancellationToken::new(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"id\":1}\n{\"id\":2}\n"
+        );
+        assert_eq!(run.stats.get_success_count(), 2);
+        assert_eq!(run.stats.get_error_count(), 1);
+        assert_eq!(run.stats.get_bytes_written(), 18);
+        assert_eq!(run.exit_status(), ExitStatus::CompletedWithErrors);
+    }
+
+    #[test]
+    fn test_cancelled_conversion_returns_partial_stats() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let mut output = Vec::new();
+        let run =
+            convert_files(fixtures(&dir), &ProcessOptions::new(), &mut output, &cancel).unwrap();
+        assert!(output.is_empty());
+        assert_eq!(run.stats.total_files, 3);
+        assert_eq!(run.cancelled_files, 3);
+        assert_eq!(run.stats.get_success_count(), 0);
+        assert_eq!(run.exit_status(), ExitStatus::Aborted);
+    }
+
+    /// 첫 기록 때 취소하는 출력
+    struct CancelOnWrite<'a> {
+        written: Vec<u8>,
+        cancel: &'a CancellationToken,
+    }
+
+    impl Write for CancelOnWrite<'_> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.cancel.cancel();
+            self.written.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_results_written_before_all_files_processed() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let total = reorder_window() + 50;
+        let files: Vec<PathBuf> = (0..total)
+            .map(|i| {
+                let path = dir.path().join(format!("{}.json", i));
+                std::fs::write(&path, format!(r#"{{"id": {}}}"#, i)).unwrap();
+                path
+            })
+            .collect();
+
+        let cancel = CancellationToken::new();
+        let mut output = CancelOnWrite {
+            written: Vec::new(),
+            cancel: &cancel,
+        };
+        let run = convert_files(files, &ProcessOptions::new(), &mut output, &cancel).unwrap();
+
+        // 첫 결과를 기록할 때 작업자는 기록 대기 범위 안의 파일까지만 처리했음
+        assert!(output.written.starts_with(b"{\"id\":0}\n"));
+        assert!(run.cancelled_files >= 50);
+        assert_eq!(run.stats.get_success_count() + run.cancelled_files, total);
+    }
+
+    #[test]
+    fn test_write_error_stops_workers() {
+        struct Failing;
+        impl Write for Failing {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("disk full"))
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let result = convert_files(
+            fixtures(&dir),
+            &ProcessOptions::new(),
+            &mut Failing,
+            &CancellationToken::new(),
+        );
+        assert!(matches!(result, Err(JConvertError::WriteError { .. })));
+    }
+}
@@ -0,0 +1,682 @@
+//! 중복 제거 모듈
+//!
+//! 키 필드 값을 기준으로 출력 레코드의 중복을 제거합니다.
+//! 기본은 블룸 필터를 사용한 근사 모드이며, 정확한 해시 집합 모드와
+//! 메모리보다 큰 데이터를 위한 디스크 기반 정확 모드를 선택할 수 있습니다.
+
+use clap::ValueEnum;
+use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::processor::get_nested_field;
+use crate::spill::{self, SpillDir, SpillEntry};
+
+/// 근사 모드 기본 예상 키 수
+pub const DEFAULT_CAPACITY: usize = 10_000_000;
+
+/// 근사 모드 기본 위양성 확률
+pub const DEFAULT_FP_RATE: f64 = 0.0001;
+
+/// 디스크 기반 중복 제거에서 파티션 하나를 나누는 하위 파티션 수
+pub const SPILL_PARTITIONS: usize = 64;
+
+/// 디스크 기반 중복 제거에서 한 번에 메모리에 올릴 파티션의 기본 최대 용량 (256MB)
+pub const DEFAULT_DEDUPE_MEMORY: u64 = 256 * 1024 * 1024;
+
+/// 파티션을 다시 나누는 최대 깊이
+///
+/// 같은 키만 담긴 파티션은 키 해시로 더 나눌 수 없으므로 이 깊이에서는 한도를 넘어도 메모리에 올립니다.
+const MAX_PARTITION_DEPTH: u32 = 4;
+
+/// 근사 중복 제거 위양성 확률 파싱 (`--dedupe-fp-rate`, 0보다 크고 1보다 작아야 함)
+///
+/// # Examples
+/// ```
+/// use jconvert::dedup::parse_fp_rate;
+///
+/// assert_eq!(parse_fp_rate("0.001"), Ok(0.001));
+/// assert!(parse_fp_rate("0").is_err());
+/// assert!(parse_fp_rate("1").is_err());
+/// assert!(parse_fp_rate("NaN").is_err());
+/// ```
+pub fn parse_fp_rate(text: &str) -> Result<f64, String> {
+    let text = text.trim();
+    let rate = text
+        .parse::<f64>()
+        .map_err(|_| format!("잘못된 위양성 확률: {}", text))?;
+    if rate > 0.0 && rate < 1.0 {
+        Ok(rate)
+    } else {
+        Err(format!(
+            "위양성 확률은 0보다 크고 1보다 작아야 합니다 (예: 0.0001): {}",
+            text
+        ))
+    }
+}
+
+/// 같은 키를 가진 레코드 중 남길 레코드
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum DedupKeep {
+    /// 처음 나온 레코드 유지
+    #[default]
+    First,
+    /// 마지막에 나온 레코드 유지
+    Last,
+}
+
+/// 블룸 필터
+///
+/// 예상 원소 수 `n`과 목표 위양성 확률 `p`로부터 비트 수
+/// `m = -n·ln(p) / (ln 2)²`, 해시 함수 수 `k = (m/n)·ln 2`를 계산합니다.
+/// 삽입된 원소 수가 `n` 이하이면 새 원소를 "이미 본 원소"로 잘못 판정할
+/// 확률이 `p` 이하로 유지됩니다. 이미 본 원소를 새 원소로 판정하는 경우는 없습니다.
+#[derive(Debug, Clone)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: u64,
+    num_hashes: u32,
+    inserted: usize,
+    capacity: usize,
+}
+
+impl BloomFilter {
+    /// 예상 원소 수와 목표 위양성 확률로 블룸 필터 생성
+    ///
+    /// # Arguments
+    /// * `capacity` - 예상 원소 수
+    /// * `fp_rate` - 목표 위양성 확률 (0과 1 사이)
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::dedup::BloomFilter;
+    ///
+    /// let mut filter = BloomFilter::with_rate(1000, 0.01);
+    /// assert!(filter.insert(b"a"));
+    /// assert!(!filter.insert(b"a"));
+    /// ```
+    pub fn with_rate(capacity: usize, fp_rate: f64) -> Self {
+        let capacity = capacity.max(1);
+        let fp_rate = fp_rate.clamp(f64::MIN_POSITIVE, 0.5);
+        let ln2 = std::f64::consts::LN_2;
+
+        let num_bits = (-(capacity as f64) * fp_rate.ln() / (ln2 * ln2)).ceil() as u64;
+        let num_bits = num_bits.max(64);
+        let num_hashes = ((num_bits as f64 / capacity as f64) * ln2).round().max(1.0) as u32;
+
+        Self {
+            bits: vec![0; num_bits.div_ceil(64) as usize],
+            num_bits,
+            num_hashes,
+            inserted: 0,
+            capacity,
+        }
+    }
+
+    /// 원소 삽입
+    ///
+    /// # Returns
+    /// 처음 보는 원소이면 true, 이미 있을 수 있는 원소이면 false
+    pub fn insert(&mut self, item: &[u8]) -> bool {
+        let (h1, h2) = hash_pair(item);
+        let mut is_new = false;
+
+        for i in 0..self.num_hashes as u64 {
+            let bit = h1.wrapping_add(i.wrapping_mul(h2)) % self.num_bits;
+            let (word, mask) = ((bit / 64) as usize, 1u64 << (bit % 64));
+            if self.bits[word] & mask == 0 {
+                self.bits[word] |= mask;
+                is_new = true;
+            }
+        }
+
+        if is_new {
+            self.inserted += 1;
+        }
+        is_new
+    }
+
+    /// 원소 포함 여부 확인 (위양성 가능)
+    pub fn contains(&self, item: &[u8]) -> bool {
+        let (h1, h2) = hash_pair(item);
+        (0..self.num_hashes as u64).all(|i| {
+            let bit = h1.wrapping_add(i.wrapping_mul(h2)) % self.num_bits;
+            self.bits[(bit / 64) as usize] & (1u64 << (bit % 64)) != 0
+        })
+    }
+
+    /// 필터가 사용하는 메모리 (바이트)
+    pub fn memory_bytes(&self) -> u64 {
+        self.bits.len() as u64 * 8
+    }
+
+    /// 예상 원소 수 초과 여부 (초과 시 위양성 확률 보장이 깨짐)
+    pub fn is_over_capacity(&self) -> bool {
+        self.inserted > self.capacity
+    }
+}
+
+/// 이중 해싱용 해시 값 쌍 계산
+fn hash_pair(item: &[u8]) -> (u64, u64) {
+    let mut hasher = DefaultHasher::new();
+    item.hash(&mut hasher);
+    let h1 = hasher.finish();
+
+    let mut hasher = DefaultHasher::new();
+    (h1, item).hash(&mut hasher);
+    // 짝수 간격이면 일부 비트만 순환할 수 있으므로 홀수로 고정
+    let h2 = hasher.finish() | 1;
+
+    (h1, h2)
+}
+
+/// 이미 본 키의 집합
+#[derive(Debug, Clone)]
+pub enum KeySet {
+    /// 정확한 해시 집합 (메모리 사용량이 키 수에 비례)
+    Exact(HashSet<String>),
+    /// 블룸 필터 기반 근사 집합 (고정 메모리, 위양성 가능)
+    Approximate(BloomFilter),
+}
+
+impl KeySet {
+    /// 키 삽입
+    ///
+    /// # Returns
+    /// 처음 보는 키이면 true
+    pub fn insert(&mut self, key: &str) -> bool {
+        match self {
+            KeySet::Exact(set) => set.insert(key.to_string()),
+            KeySet::Approximate(filter) => filter.insert(key.as_bytes()),
+        }
+    }
+}
+
+/// 키 필드 기준 레코드 중복 제거기
+#[derive(Debug, Clone)]
+pub struct Deduplicator {
+    key_path: String,
+    seen: KeySet,
+    keep: DedupKeep,
+}
+
+impl Deduplicator {
+    /// 정확한 해시 집합을 사용하는 중복 제거기 생성
+    ///
+    /// # Arguments
+    /// * `key_path` - 중복 판정 기준 필드 (점 표기법으로 중첩 필드 지정 가능)
+    pub fn exact(key_path: impl Into<String>) -> Self {
+        Self {
+            key_path: key_path.into(),
+            seen: KeySet::Exact(HashSet::new()),
+            keep: DedupKeep::First,
+        }
+    }
+
+    /// 블룸 필터를 사용하는 근사 중복 제거기 생성
+    ///
+    /// 고유 키 수가 `capacity` 이하이면 고유 레코드가 중복으로 잘못 제거될
+    /// 확률이 레코드당 `fp_rate` 이하입니다.
+    pub fn approximate(key_path: impl Into<String>, capacity: usize, fp_rate: f64) -> Self {
+        Self {
+            key_path: key_path.into(),
+            seen: KeySet::Approximate(BloomFilter::with_rate(capacity, fp_rate)),
+            keep: DedupKeep::First,
+        }
+    }
+
+    /// 같은 키 중 남길 레코드 설정
+    pub fn with_keep(mut self, keep: DedupKeep) -> Self {
+        self.keep = keep;
+        self
+    }
+
+    /// 같은 키 중 남길 레코드 반환
+    pub fn keep(&self) -> DedupKeep {
+        self.keep
+    }
+
+    /// 직렬화된 레코드가 이미 본 키를 가지는지 확인하고 키를 기록
+    ///
+    /// 마지막 레코드를 남기려면 레코드를 역순으로 넣어야 합니다.
+    /// 키 필드가 없거나 파싱할 수 없는 레코드는 중복으로 취급하지 않습니다.
+    pub fn is_duplicate(&mut self, json_line: &str) -> bool {
+        let key = line_key(json_line, &self.key_path);
+        self.is_duplicate_key(key.as_deref())
+    }
+
+    /// 미리 뽑아 둔 키(`record_key`)가 이미 본 키인지 확인하고 키를 기록
+    ///
+    /// 키가 없는 레코드(None)는 중복으로 취급하지 않습니다.
+    pub fn is_duplicate_key(&mut self, key: Option<&str>) -> bool {
+        key.is_some_and(|key| !self.seen.insert(key))
+    }
+
+    /// 이미 출력된 키를 본 것으로 기록 (`--skip-existing`)
+    ///
+    /// 기록한 키를 가진 레코드는 `keep` 설정과 관계없이 모두 중복으로 제거됩니다.
+    pub fn with_seen_keys<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for key in keys {
+            self.seen.insert(key.as_ref());
+        }
+        self
+    }
+
+    /// 근사 모드에서 예상 키 수를 초과했는지 확인
+    pub fn is_over_capacity(&self) -> bool {
+        match &self.seen {
+            KeySet::Exact(_) => false,
+            KeySet::Approximate(filter) => filter.is_over_capacity(),
+        }
+    }
+}
+
+/// 디스크 기반 정확한 중복 제거기
+///
+/// 레코드를 키 해시에 따라 임시 파티션 파일로 내보낸 뒤, 파티션 하나씩
+/// 메모리에 올려 중복을 제거하고 원래 순서대로 다시 병합합니다.
+/// 메모리 한도보다 큰 파티션은 다른 해시로 다시 나누어 처리하므로
+/// 메모리 사용량은 전체 데이터 크기와 관계없이 한도 안에 머뭅니다.
+#[derive(Debug)]
+pub struct SpillDeduplicator {
+    key_path: String,
+    keep: DedupKeep,
+    memory_limit: u64,
+    dir: SpillDir,
+    partitions: Vec<BufWriter<File>>,
+    partition_bytes: Vec<u64>,
+    next_seq: u64,
+    duplicates: usize,
+    splits: usize,
+}
+
+impl SpillDeduplicator {
+    /// 디스크 기반 중복 제거기 생성
+    ///
+    /// # Arguments
+    /// * `key_path` - 중복 판정 기준 필드 (점 표기법으로 중첩 필드 지정 가능)
+    /// * `temp_dir` - 임시 파일을 만들 폴더 (None이면 시스템 임시 폴더)
+    pub fn new(key_path: impl Into<String>, temp_dir: Option<&Path>) -> io::Result<Self> {
+        let dir = SpillDir::new(temp_dir)?;
+        let partitions = (0..SPILL_PARTITIONS)
+            .map(|i| spill::create_run(&dir.file(&format!("partition-{}", i))))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            key_path: key_path.into(),
+            keep: DedupKeep::First,
+            memory_limit: DEFAULT_DEDUPE_MEMORY,
+            dir,
+            partitions,
+            partition_bytes: vec![0; SPILL_PARTITIONS],
+            next_seq: 0,
+            duplicates: 0,
+            splits: 0,
+        })
+    }
+
+    /// 같은 키 중 남길 레코드 설정
+    pub fn with_keep(mut self, keep: DedupKeep) -> Self {
+        self.keep = keep;
+        self
+    }
+
+    /// 한 번에 메모리에 올릴 파티션의 최대 용량 설정 (넘는 파티션은 다시 나눔)
+    pub fn with_memory_limit(mut self, memory_limit: u64) -> Self {
+        self.memory_limit = memory_limit;
+        self
+    }
+
+    /// 레코드를 파티션 파일에 기록 (키는 `json_line`을 파싱하여 추출)
+    pub fn push(&mut self, json_line: &str) -> io::Result<()> {
+        let key = line_key(json_line, &self.key_path);
+        self.push_keyed(key.as_deref(), json_line)
+    }
+
+    /// 미리 뽑아 둔 키(`record_key`)로 레코드를 파티션 파일에 기록 (출력에는 `line`을 사용)
+    pub fn push_keyed(&mut self, key: Option<&str>, line: &str) -> io::Result<()> {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+
+        // 키가 없는 레코드는 중복 판정 대상이 아니므로 빈 키로 기록
+        let entry = SpillEntry {
+            seq,
+            key: key.unwrap_or_default().to_string(),
+            line: line.to_string(),
+        };
+        let partition = partition_index(&entry, 0);
+        self.partition_bytes[partition] += entry_bytes(&entry);
+        spill::write_entry(&mut self.partitions[partition], &entry)
+    }
+
+    /// 파티션별 중복 제거 후 남은 레코드를 원래 순서대로 전달
+    ///
+    /// # Returns
+    /// 제거된 중복 레코드 수
+    pub fn finish<F>(mut self, mut emit: F) -> io::Result<usize>
+    where
+        F: FnMut(&str) -> io::Result<()>,
+    {
+        let kept_runs = self.dedup_partitions()?;
+        spill::merge_runs_by_seq(&kept_runs, |entry| emit(&entry.line))?;
+        Ok(self.duplicates)
+    }
+
+    /// 모든 파티션의 중복을 제거하고, 파티션마다 `seq` 순서로 남은 레코드 파일 반환
+    fn dedup_partitions(&mut self) -> io::Result<Vec<PathBuf>> {
+        for partition in &mut self.partitions {
+            partition.flush()?;
+        }
+        self.partitions.clear();
+
+        let partition_bytes = std::mem::take(&mut self.partition_bytes);
+        let mut kept_runs: Vec<PathBuf> = Vec::with_capacity(SPILL_PARTITIONS);
+        for (i, bytes) in partition_bytes.into_iter().enumerate() {
+            kept_runs.push(self.dedup_partition(&format!("partition-{}", i), bytes, 0)?);
+        }
+        Ok(kept_runs)
+    }
+
+    /// 파티션 하나의 중복을 제거하고 남은 레코드 파일 반환
+    ///
+    /// 파티션이 메모리 한도보다 크면 다음 깊이의 해시로 하위 파티션에 나누어 각각 처리한 뒤,
+    /// 하위 결과를 `seq` 순서로 병합합니다. 같은 키는 항상 같은 하위 파티션으로 가므로 결과는 같습니다.
+    fn dedup_partition(&mut self, name: &str, bytes: u64, depth: u32) -> io::Result<PathBuf> {
+        let partition_path = self.dir.file(name);
+        let kept_path = self.dir.file(&format!("{}.kept", name));
+
+        if bytes > self.memory_limit && depth < MAX_PARTITION_DEPTH {
+            self.splits += 1;
+            let sub_bytes = self.split_partition(name, depth + 1)?;
+            std::fs::remove_file(&partition_path)?;
+
+            let mut sub_runs = Vec::with_capacity(SPILL_PARTITIONS);
+            for (i, sub_bytes) in sub_bytes.into_iter().enumerate() {
+                sub_runs.push(self.dedup_partition(
+                    &format!("{}-{}", name, i),
+                    sub_bytes,
+                    depth + 1,
+                )?);
+            }
+
+            let mut kept = spill::create_run(&kept_path)?;
+            spill::merge_runs_by_seq(&sub_runs, |entry| spill::write_entry(&mut kept, &entry))?;
+            kept.flush()?;
+            for run in &sub_runs {
+                std::fs::remove_file(run)?;
+            }
+            return Ok(kept_path);
+        }
+
+        let mut entries = spill::read_run(&partition_path)?;
+
+        // 마지막 레코드를 남기려면 역순으로 판정한 뒤 다시 원래 순서로 되돌림
+        if self.keep == DedupKeep::Last {
+            entries.reverse();
+        }
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut survivors: Vec<SpillEntry> = Vec::with_capacity(entries.len());
+        for entry in entries {
+            if entry.key.is_empty() || seen.insert(entry.key.clone()) {
+                survivors.push(entry);
+            } else {
+                self.duplicates += 1;
+            }
+        }
+        if self.keep == DedupKeep::Last {
+            survivors.reverse();
+        }
+
+        let mut kept = spill::create_run(&kept_path)?;
+        for entry in &survivors {
+            spill::write_entry(&mut kept, entry)?;
+        }
+        kept.flush()?;
+        std::fs::remove_file(&partition_path)?;
+        Ok(kept_path)
+    }
+
+    /// 파티션 파일을 한 항목씩 읽어 하위 파티션 파일로 나눔
+    ///
+    /// # Returns
+    /// 하위 파티션별 기록한 용량
+    fn split_partition(&self, name: &str, depth: u32) -> io::Result<Vec<u64>> {
+        let mut reader = std::io::BufReader::new(File::open(self.dir.file(name))?);
+        let mut writers = (0..SPILL_PARTITIONS)
+            .map(|i| spill::create_run(&self.dir.file(&format!("{}-{}", name, i))))
+            .collect::<io::Result<Vec<_>>>()?;
+        let mut bytes = vec![0; SPILL_PARTITIONS];
+
+        while let Some(entry) = spill::read_entry(&mut reader)? {
+            let partition = partition_index(&entry, depth);
+            bytes[partition] += entry_bytes(&entry);
+            spill::write_entry(&mut writers[partition], &entry)?;
+        }
+        for writer in &mut writers {
+            writer.flush()?;
+        }
+        Ok(bytes)
+    }
+}
+
+/// 항목이 들어갈 파티션 번호
+///
+/// 깊이마다 다른 해시를 써서 다시 나눌 때 고르게 퍼지게 합니다.
+/// 키가 없는 레코드는 중복 판정 대상이 아니므로 순번으로 흩어 둡니다.
+fn partition_index(entry: &SpillEntry, depth: u32) -> usize {
+    let mut hasher = DefaultHasher::new();
+    if entry.key.is_empty() {
+        (depth, entry.seq).hash(&mut hasher);
+    } else {
+        (depth, &entry.key).hash(&mut hasher);
+    }
+    hasher.finish() as usize % SPILL_PARTITIONS
+}
+
+/// 스필 파일에서 항목이 차지하는 용량
+fn entry_bytes(entry: &SpillEntry) -> u64 {
+    (16 + entry.key.len() + entry.line.len()) as u64
+}
+
+/// 레코드에서 중복 판정용 키 문자열 추출
+///
+/// 키 값의 JSON 표현을 사용하므로 숫자 `1`과 문자열 `"1"`은 다른 키입니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::dedup::record_key;
+/// use serde_json::json;
+///
+/// let record = json!({"user": {"id": 1}});
+/// assert_eq!(record_key(&record, "user.id").as_deref(), Some("1"));
+/// assert_eq!(record_key(&record, "missing"), None);
+/// ```
+pub fn record_key(record: &Value, key_path: &str) -> Option<String> {
+    get_nested_field(record, key_path).map(|value| value.to_string())
+}
+
+/// 직렬화된 레코드 줄을 파싱하여 중복 판정용 키 문자열 추출 (파싱할 수 없으면 None)
+pub(crate) fn line_key(json_line: &str, key_path: &str) -> Option<String> {
+    let record: Value = serde_json::from_str(json_line).ok()?;
+    record_key(&record, key_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bloom_filter_no_false_negatives() {
+        let mut filter = BloomFilter::with_rate(1000, 0.01);
+        for i in 0..1000 {
+            filter.insert(format!("key-{}", i).as_bytes());
+        }
+        for i in 0..1000 {
+            assert!(filter.contains(format!("key-{}", i).as_bytes()));
+        }
+        assert!(!filter.is_over_capacity());
+    }
+
+    #[test]
+    fn test_parse_fp_rate_errors() {
+        assert_eq!(parse_fp_rate(" 1e-5 "), Ok(0.00001));
+        assert!(parse_fp_rate("-0.1").is_err());
+        assert!(parse_fp_rate("inf").is_err());
+        assert!(parse_fp_rate("abc").is_err());
+    }
+
+    #[test]
+    fn test_bloom_filter_false_positive_bound() {
+        let mut filter = BloomFilter::with_rate(10_000, 0.01);
+        for i in 0..10_000 {
+            filter.insert(format!("in-{}", i).as_bytes());
+        }
+
+        let false_positives = (0..10_000)
+            .filter(|i| filter.contains(format!("out-{}", i).as_bytes()))
+            .count();
+
+        // 목표 1%에 여유를 둔 상한
+        assert!(false_positives < 200, "위양성 {}건", false_positives);
+    }
+
+    #[test]
+    fn test_deduplicator_exact_nested_key() {
+        let mut dedup = Deduplicator::exact("user.id");
+
+        assert!(!dedup.is_duplicate(r#"{"user":{"id":1},"v":"a"}"#));
+        assert!(dedup.is_duplicate(r#"{"user":{"id":1},"v":"b"}"#));
+        assert!(!dedup.is_duplicate(r#"{"user":{"id":"1"}}"#));
+        assert!(!dedup.is_duplicate(r#"{"other":true}"#));
+        assert!(!dedup.is_duplicate(r#"{"other":true}"#));
+    }
+
+    #[test]
+    fn test_deduplicator_approximate() {
+        let mut dedup = Deduplicator::approximate("id", 100, 0.001);
+
+        assert!(!dedup.is_duplicate(r#"{"id":1}"#));
+        assert!(!dedup.is_duplicate(r#"{"id":2}"#));
+        assert!(dedup.is_duplicate(r#"{"id":1}"#));
+    }
+
+    #[test]
+    fn test_deduplicator_with_seen_keys() {
+        let mut dedup = Deduplicator::exact("id")
+            .with_keep(DedupKeep::Last)
+            .with_seen_keys(["1", "\"a\""]);
+
+        assert!(dedup.is_duplicate(r#"{"id":1}"#));
+        assert!(dedup.is_duplicate(r#"{"id":"a"}"#));
+        assert!(!dedup.is_duplicate(r#"{"id":2}"#));
+    }
+
+    #[test]
+    fn test_spill_deduplicator_preserves_order() {
+        let mut dedup = SpillDeduplicator::new("id", None).unwrap();
+        for line in [
+            r#"{"id":1,"v":"a"}"#,
+            r#"{"id":2,"v":"b"}"#,
+            r#"{"v":"no-key"}"#,
+            r#"{"id":1,"v":"c"}"#,
+            r#"{"v":"no-key"}"#,
+            r#"{"id":3,"v":"d"}"#,
+        ] {
+            dedup.push(line).unwrap();
+        }
+
+        let mut output = Vec::new();
+        let duplicates = dedup
+            .finish(|line| {
+                output.push(line.to_string());
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(duplicates, 1);
+        assert_eq!(
+            output,
+            vec![
+                r#"{"id":1,"v":"a"}"#,
+                r#"{"id":2,"v":"b"}"#,
+                r#"{"v":"no-key"}"#,
+                r#"{"v":"no-key"}"#,
+                r#"{"id":3,"v":"d"}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spill_deduplicator_keep_last() {
+        let mut dedup = SpillDeduplicator::new("id", None)
+            .unwrap()
+            .with_keep(DedupKeep::Last);
+        for line in [
+            r#"{"id":1,"v":"a"}"#,
+            r#"{"id":2,"v":"b"}"#,
+            r#"{"id":1,"v":"c"}"#,
+        ] {
+            dedup.push(line).unwrap();
+        }
+
+        let mut output = Vec::new();
+        let duplicates = dedup
+            .finish(|line| {
+                output.push(line.to_string());
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(duplicates, 1);
+        assert_eq!(output, vec![r#"{"id":2,"v":"b"}"#, r#"{"id":1,"v":"c"}"#]);
+    }
+
+    #[test]
+    fn test_spill_deduplicator_repartitions_over_memory_limit() {
+        let lines: Vec<String> = (0..2000)
+            .map(|i| match i % 7 {
+                0 => format!(r#"{{"v":{}}}"#, i),
+                _ => format!(r#"{{"id":{},"v":{}}}"#, i % 300, i),
+            })
+            .collect();
+
+        for keep in [DedupKeep::First, DedupKeep::Last] {
+            let mut expected = Vec::new();
+            let mut unlimited = SpillDeduplicator::new("id", None).unwrap().with_keep(keep);
+            lines.iter().for_each(|line| unlimited.push(line).unwrap());
+            let expected_duplicates = unlimited
+                .finish(|line| {
+                    expected.push(line.to_string());
+                    Ok(())
+                })
+                .unwrap();
+
+            // 파티션 하나(평균 약 1KB)가 한도를 넘도록 작게 잡아 다시 나누게 함
+            let mut dedup = SpillDeduplicator::new("id", None)
+                .unwrap()
+                .with_keep(keep)
+                .with_memory_limit(256);
+            lines.iter().for_each(|line| dedup.push(line).unwrap());
+            let runs = dedup.dedup_partitions().unwrap();
+            assert!(dedup.splits > 0);
+
+            let mut output = Vec::new();
+            spill::merge_runs_by_seq(&runs, |entry| {
+                output.push(entry.line);
+                Ok(())
+            })
+            .unwrap();
+            assert_eq!(dedup.duplicates, expected_duplicates);
+            assert_eq!(output, expected);
+            assert_eq!(output.len(), 300 + lines.len().div_ceil(7));
+        }
+    }
+}
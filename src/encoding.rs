@@ -0,0 +1,287 @@
+//! 입력 인코딩 변환 모듈
+//!
+//! JSON 파싱 전에 입력 바이트를 UTF-8로 맞춥니다 (`--encoding`, `--lossy`).
+//! 자동 감지는 BOM(UTF-8, UTF-16 LE/BE)과 BOM 없는 UTF-16의 0 바이트 패턴을 확인하며,
+//! 이미 BOM 없는 UTF-8인 입력은 복사하지 않고 그대로 사용합니다.
+
+use clap::ValueEnum;
+
+/// UTF-8 BOM
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
+/// UTF-16 리틀 엔디언 BOM
+const UTF16_LE_BOM: &[u8] = &[0xFF, 0xFE];
+
+/// UTF-16 빅 엔디언 BOM
+const UTF16_BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+/// 인코딩 감지에 필요한 파일 앞부분 크기
+pub const SNIFF_LEN: usize = 4;
+
+/// 입력 파일 인코딩
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum InputEncoding {
+    /// BOM과 UTF-16 패턴으로 자동 감지 (감지되지 않으면 UTF-8)
+    #[default]
+    Auto,
+    /// UTF-8 (앞의 BOM은 제거)
+    #[value(name = "utf-8")]
+    Utf8,
+    /// UTF-16 (BOM으로 바이트 순서 판별, 없으면 0 바이트 패턴으로 추정)
+    #[value(name = "utf-16")]
+    Utf16,
+    /// Latin-1 (ISO-8859-1, 바이트 하나가 문자 하나)
+    Latin1,
+}
+
+/// UTF-16 바이트 순서
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Endian {
+    Little,
+    Big,
+}
+
+/// 파일 앞부분만 보고 UTF-8 변환이 필요한지 확인
+///
+/// `false`이면 원본 바이트를 그대로 파싱해도 됩니다. `--lossy`는 잘못된 UTF-8을
+/// 찾으려면 전체를 읽어야 하므로 항상 `true`입니다.
+///
+/// # Arguments
+/// * `head` - 파일의 처음 `SNIFF_LEN` 바이트 (파일이 더 짧으면 전체)
+/// * `encoding` - 입력 인코딩
+/// * `lossy` - 잘못된 바이트를 U+FFFD로 바꿀지 여부
+///
+/// # Examples
+/// ```
+/// use jconvert::encoding::{needs_decoding, InputEncoding};
+///
+/// assert!(!needs_decoding(b"{\"a\"", InputEncoding::Auto, false));
+/// assert!(needs_decoding(b"\xEF\xBB\xBF{", InputEncoding::Auto, false));
+/// assert!(needs_decoding(b"{\0\"\0", InputEncoding::Auto, false));
+/// assert!(needs_decoding(b"{}", InputEncoding::Latin1, false));
+/// ```
+pub fn needs_decoding(head: &[u8], encoding: InputEncoding, lossy: bool) -> bool {
+    if lossy {
+        return true;
+    }
+    match encoding {
+        InputEncoding::Auto => head.starts_with(UTF8_BOM) || detect_utf16(head).is_some(),
+        InputEncoding::Utf8 => head.starts_with(UTF8_BOM),
+        InputEncoding::Utf16 | InputEncoding::Latin1 => true,
+    }
+}
+
+/// 입력 바이트를 UTF-8로 변환
+///
+/// # Arguments
+/// * `bytes` - 입력 전체 바이트
+/// * `encoding` - 입력 인코딩
+/// * `lossy` - 잘못된 바이트를 에러 대신 U+FFFD로 바꿀지 여부
+///
+/// # Returns
+/// 변환한 UTF-8 바이트 (원본을 그대로 써도 되면 None), 잘못된 입력이면 에러 메시지
+///
+/// # Examples
+/// ```
+/// use jconvert::encoding::{decode, InputEncoding};
+///
+/// let utf16 = b"\xFF\xFE{\0}\0";
+/// assert_eq!(decode(utf16, InputEncoding::Auto, false), Ok(Some(b"{}".to_vec())));
+/// assert_eq!(decode(b"\"caf\xE9\"", InputEncoding::Latin1, false), Ok(Some("\"café\"".as_bytes().to_vec())));
+/// assert_eq!(decode(b"{}", InputEncoding::Auto, false), Ok(None));
+/// assert!(decode(b"\"\xFF\"", InputEncoding::Utf8, false).is_err());
+/// ```
+pub fn decode(
+    bytes: &[u8],
+    encoding: InputEncoding,
+    lossy: bool,
+) -> Result<Option<Vec<u8>>, String> {
+    match encoding {
+        InputEncoding::Auto => match detect_utf16(bytes) {
+            Some(endian) => decode_utf16(bytes, endian, lossy).map(Some),
+            None => decode_utf8(bytes, lossy),
+        },
+        InputEncoding::Utf8 => decode_utf8(bytes, lossy),
+        InputEncoding::Utf16 => {
+            let endian = detect_utf16(bytes).unwrap_or(Endian::Little);
+            decode_utf16(bytes, endian, lossy).map(Some)
+        }
+        InputEncoding::Latin1 => Ok(decode_latin1(bytes)),
+    }
+}
+
+/// BOM 또는 앞부분의 0 바이트 패턴으로 UTF-16 바이트 순서 감지
+///
+/// JSON 문서는 ASCII 문자(`{`, `[`, 공백 등)로 시작하므로 BOM이 없는 UTF-16은
+/// `xx 00 xx 00`(LE) 또는 `00 xx 00 xx`(BE) 패턴이 됩니다.
+fn detect_utf16(head: &[u8]) -> Option<Endian> {
+    if head.starts_with(UTF16_LE_BOM) {
+        return Some(Endian::Little);
+    }
+    if head.starts_with(UTF16_BE_BOM) {
+        return Some(Endian::Big);
+    }
+    match head {
+        [a, 0, b, 0, ..] if *a != 0 && *b != 0 => Some(Endian::Little),
+        [0, a, 0, b, ..] if *a != 0 && *b != 0 => Some(Endian::Big),
+        _ => None,
+    }
+}
+
+/// UTF-8 입력의 BOM 제거 (lossy면 잘못된 바이트도 교체)
+fn decode_utf8(bytes: &[u8], lossy: bool) -> Result<Option<Vec<u8>>, String> {
+    let body = bytes.strip_prefix(UTF8_BOM);
+    let text = body.unwrap_or(bytes);
+    match std::str::from_utf8(text) {
+        Ok(_) => Ok(body.map(<[u8]>::to_vec)),
+        Err(_) if lossy => Ok(Some(
+            String::from_utf8_lossy(text).into_owned().into_bytes(),
+        )),
+        Err(e) => Err(format!("잘못된 UTF-8: {}", e)),
+    }
+}
+
+/// UTF-16 입력을 UTF-8로 변환 (BOM은 제거)
+fn decode_utf16(bytes: &[u8], endian: Endian, lossy: bool) -> Result<Vec<u8>, String> {
+    let body = match endian {
+        Endian::Little => bytes.strip_prefix(UTF16_LE_BOM),
+        Endian::Big => bytes.strip_prefix(UTF16_BE_BOM),
+    }
+    .unwrap_or(bytes);
+
+    if body.len() % 2 != 0 && !lossy {
+        return Err(format!(
+            "UTF-16 입력의 길이가 홀수입니다 ({}바이트)",
+            body.len()
+        ));
+    }
+
+    let units = body.chunks_exact(2).map(|pair| match endian {
+        Endian::Little => u16::from_le_bytes([pair[0], pair[1]]),
+        Endian::Big => u16::from_be_bytes([pair[0], pair[1]]),
+    });
+
+    let mut text = String::with_capacity(body.len() / 2);
+    for (index, decoded) in char::decode_utf16(units).enumerate() {
+        match decoded {
+            Ok(c) => text.push(c),
+            Err(_) if lossy => text.push(char::REPLACEMENT_CHARACTER),
+            Err(e) => {
+                return Err(format!(
+                    "잘못된 UTF-16 ({}번째 코드 유닛): {}",
+                    index + 1,
+                    e
+                ))
+            }
+        }
+    }
+    // lossy 모드에서 남은 홀수 바이트
+    if body.len() % 2 != 0 {
+        text.push(char::REPLACEMENT_CHARACTER);
+    }
+    Ok(text.into_bytes())
+}
+
+/// Latin-1 입력을 UTF-8로 변환 (모두 ASCII면 변환할 필요 없음)
+fn decode_latin1(bytes: &[u8]) -> Option<Vec<u8>> {
+    if bytes.is_ascii() {
+        return None;
+    }
+    Some(
+        bytes
+            .iter()
+            .map(|&b| char::from(b))
+            .collect::<String>()
+            .into_bytes(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(text: &str, endian: Endian, bom: bool) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        if bom {
+            bytes.extend_from_slice(match endian {
+                Endian::Little => UTF16_LE_BOM,
+                Endian::Big => UTF16_BE_BOM,
+            });
+        }
+        for unit in text.encode_utf16() {
+            bytes.extend_from_slice(&match endian {
+                Endian::Little => unit.to_le_bytes(),
+                Endian::Big => unit.to_be_bytes(),
+            });
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_decode_utf16_with_and_without_bom() {
+        let text = r#"{"name": "김철수", "emoji": "😀"}"#;
+        for endian in [Endian::Little, Endian::Big] {
+            for bom in [true, false] {
+                let bytes = utf16(text, endian, bom);
+                assert!(needs_decoding(&bytes, InputEncoding::Auto, false));
+                let decoded = decode(&bytes, InputEncoding::Auto, false).unwrap().unwrap();
+                assert_eq!(decoded, text.as_bytes(), "{:?} bom={}", endian, bom);
+            }
+        }
+        // BOM 없는 UTF-16을 명시하면 리틀 엔디언으로 가정
+        let bytes = utf16("\"가\"", Endian::Little, false);
+        assert_eq!(
+            decode(&bytes, InputEncoding::Utf16, false)
+                .unwrap()
+                .unwrap(),
+            "\"가\"".as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_input_is_error_unless_lossy() {
+        // 짝 없는 상위 서로게이트
+        let broken = [b'"', 0, 0x00, 0xD8, b'"', 0];
+        assert!(decode(&broken, InputEncoding::Utf16, false).is_err());
+        assert_eq!(
+            decode(&broken, InputEncoding::Utf16, true)
+                .unwrap()
+                .unwrap(),
+            "\"\u{FFFD}\"".as_bytes()
+        );
+        assert!(decode(b"{}\0", InputEncoding::Utf16, false).is_err());
+
+        let invalid_utf8 = b"{\"a\":\"\xFF\"}";
+        assert!(decode(invalid_utf8, InputEncoding::Auto, false).is_err());
+        assert_eq!(
+            decode(invalid_utf8, InputEncoding::Auto, true)
+                .unwrap()
+                .unwrap(),
+            "{\"a\":\"\u{FFFD}\"}".as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_utf8_bom_is_stripped() {
+        let bytes = b"\xEF\xBB\xBF{\"a\":1}";
+        assert!(needs_decoding(bytes, InputEncoding::Utf8, false));
+        assert_eq!(
+            decode(bytes, InputEncoding::Utf8, false).unwrap().unwrap(),
+            b"{\"a\":1}"
+        );
+        // 유효한 UTF-8은 lossy여도 복사하지 않음
+        assert_eq!(decode(b"{}", InputEncoding::Utf8, true), Ok(None));
+        assert!(!needs_decoding(b"[1]", InputEncoding::Utf8, false));
+    }
+
+    #[test]
+    fn test_decode_latin1() {
+        assert_eq!(decode(b"{\"a\":1}", InputEncoding::Latin1, false), Ok(None));
+        assert_eq!(
+            decode(b"\"\xC4\xD6\xDC\"", InputEncoding::Latin1, false)
+                .unwrap()
+                .unwrap(),
+            "\"ÄÖÜ\"".as_bytes()
+        );
+    }
+}
@@ -24,14 +24,31 @@ pub enum JConvertError {
     #[error("파일을 열 수 없습니다 ({file}): {reason}")]
     FileOpenError { file: PathBuf, reason: String },
 
+    /// 파일 읽기 권한 없음
+    #[error("파일 읽기 권한이 없습니다: {file}")]
+    PermissionDenied { file: PathBuf },
+
     /// JSON 파싱 실패
     #[error("JSON 파싱 실패 ({file}): {reason}")]
     ParseError { file: PathBuf, reason: String },
 
+    /// 입력 인코딩 변환 실패
+    #[error("인코딩 변환 실패 ({file}): {reason}")]
+    EncodingError { file: PathBuf, reason: String },
+
     /// JSON 직렬화 실패
     #[error("JSON 직렬화 실패 ({file}): {reason}")]
     SerializeError { file: PathBuf, reason: String },
 
+    /// 출력 레코드가 크기 제한을 넘음 (`--oversize-policy error`)
+    #[error("{record}번째 레코드가 크기 제한을 넘었습니다 ({file}): {size}바이트 > {limit}바이트")]
+    RecordTooLarge {
+        file: PathBuf,
+        record: usize,
+        size: u64,
+        limit: u64,
+    },
+
     /// 파일 쓰기 실패
     #[error("파일 쓰기 실패: {reason}")]
     WriteError { reason: String },
@@ -44,10 +61,88 @@ pub enum JConvertError {
     #[error("유효하지 않은 패턴: {pattern}")]
     InvalidPattern { pattern: String },
 
+    /// 파이프라인 설정 파일 오류
+    #[error("파이프라인 설정 오류 ({file}): {reason}")]
+    InvalidPipeline { file: PathBuf, reason: String },
+
+    /// 유효하지 않은 표현식
+    #[error("유효하지 않은 표현식 ({expr}): {reason}")]
+    InvalidExpression { expr: String, reason: String },
+
+    /// 테스트 데이터 스키마 오류
+    #[error("스키마 오류: {reason}")]
+    InvalidSchema { reason: String },
+
+    /// 체크포인트 파일 오류
+    #[error("체크포인트 오류: {reason}")]
+    CheckpointError { reason: String },
+
+    /// 기준 실행 보고서 오류
+    #[error("기준 통계 오류: {reason}")]
+    BaselineError { reason: String },
+
+    /// 에러 허용 한도 초과로 처리 중단
+    #[error("에러가 허용 한도({limit}개)를 넘어 처리를 중단했습니다")]
+    Aborted { limit: usize },
+
     /// 처리할 파일 없음
     #[error("처리할 JSON 파일이 없습니다")]
     NoFilesFound,
 }
 
+impl JConvertError {
+    /// 에러 로그와 보고서에 기록하는 에러 종류
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::JConvertError;
+    /// use std::path::PathBuf;
+    ///
+    /// let error = JConvertError::ParseError {
+    ///     file: PathBuf::from("a.json"),
+    ///     reason: "EOF".to_string(),
+    /// };
+    /// assert_eq!(error.kind(), "parse");
+    /// ```
+    pub fn kind(&self) -> &'static str {
+        match self {
+            JConvertError::InputNotFound { .. } => "input_not_found",
+            JConvertError::NotADirectory { .. } => "not_a_directory",
+            JConvertError::OutputExists { .. } => "output_exists",
+            JConvertError::FileOpenError { .. } => "file_open",
+            JConvertError::PermissionDenied { .. } => "permission_denied",
+            JConvertError::ParseError { .. } => "parse",
+            JConvertError::EncodingError { .. } => "encoding",
+            JConvertError::SerializeError { .. } => "serialize",
+            JConvertError::RecordTooLarge { .. } => "record_too_large",
+            JConvertError::WriteError { .. } => "write",
+            JConvertError::ThreadPoolError { .. } => "thread_pool",
+            JConvertError::InvalidPattern { .. } => "invalid_pattern",
+            JConvertError::InvalidPipeline { .. } => "invalid_pipeline",
+            JConvertError::InvalidExpression { .. } => "invalid_expression",
+            JConvertError::InvalidSchema { .. } => "invalid_schema",
+            JConvertError::CheckpointError { .. } => "checkpoint",
+            JConvertError::BaselineError { .. } => "baseline",
+            JConvertError::Aborted { .. } => "aborted",
+            JConvertError::NoFilesFound => "no_files_found",
+        }
+    }
+
+    /// 파일 열기 실패를 원인에 따라 분류
+    ///
+    /// 권한 문제는 `PermissionDenied`, 그 밖의 실패는 `FileOpenError`로 변환합니다.
+    pub fn from_open_error(file: &std::path::Path, error: std::io::Error) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::PermissionDenied => JConvertError::PermissionDenied {
+                file: file.to_path_buf(),
+            },
+            _ => JConvertError::FileOpenError {
+                file: file.to_path_buf(),
+                reason: error.to_string(),
+            },
+        }
+    }
+}
+
 /// jconvert 결과 타입 별칭
 pub type Result<T> = std::result::Result<T, JConvertError>;
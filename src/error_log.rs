@@ -0,0 +1,296 @@
+//! 에러 로그 모듈
+//!
+//! `--log`로 저장하는 파일별 에러를 사람이 읽는 텍스트나 스크립트가 읽는 JSON Lines/CSV로 기록합니다.
+//! 각 항목에는 파일 경로, 에러 종류, 파서가 보고한 줄/칸과 바이트 위치, 파일 크기, 기록 시각이 담깁니다.
+
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 종류를 알 수 없는 에러
+pub const UNKNOWN_KIND: &str = "unknown";
+
+/// 에러 로그 파일 형식
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum ErrorLogFormat {
+    /// 사람이 읽는 텍스트
+    #[default]
+    Text,
+    /// 한 줄에 에러 하나인 JSON Lines
+    Json,
+    /// 머리글 줄이 있는 CSV
+    Csv,
+}
+
+/// 파일 하나의 에러
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorEntry {
+    /// 기록 시각 (Unix timestamp, 초)
+    pub timestamp: u64,
+    /// 파일 경로
+    pub path: PathBuf,
+    /// 에러 종류 (`JConvertError::kind`, 예: `parse`, `encoding`)
+    pub kind: &'static str,
+    /// 에러 메시지
+    pub message: String,
+    /// 에러가 난 줄 번호 (1부터, JSONL 입력은 파일의 줄 번호)
+    pub line: Option<u64>,
+    /// 에러가 난 칸 번호 (줄 안의 바이트 위치, 1부터)
+    pub column: Option<u64>,
+    /// 파일 처음부터 에러 위치까지의 바이트 수
+    pub byte_offset: Option<u64>,
+    /// 파일 크기
+    pub file_size: Option<u64>,
+}
+
+impl ErrorEntry {
+    /// 에러 항목 생성 (메시지에서 줄/칸 위치 추출)
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::error_log::ErrorEntry;
+    /// use std::path::PathBuf;
+    ///
+    /// let entry = ErrorEntry::new(
+    ///     PathBuf::from("a.jsonl"),
+    ///     "parse",
+    ///     "JSON 파싱 실패 (a.jsonl): 3번째 줄: expected value at line 1 column 5".to_string(),
+    /// );
+    /// assert_eq!(entry.line, Some(3));
+    /// assert_eq!(entry.column, Some(5));
+    /// ```
+    pub fn new(path: PathBuf, kind: &'static str, message: String) -> Self {
+        let (line, column) = parse_position(&message);
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            path,
+            kind,
+            message,
+            line,
+            column,
+            byte_offset: None,
+            file_size: None,
+        }
+    }
+
+    /// 파일 크기 설정
+    pub fn with_file_size(mut self, file_size: u64) -> Self {
+        self.file_size = Some(file_size);
+        self
+    }
+
+    /// 줄/칸 위치를 파일의 바이트 위치로 변환 (위치가 없거나 파일을 읽을 수 없으면 그대로)
+    pub fn locate(&mut self) {
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            self.byte_offset = File::open(&self.path)
+                .and_then(|file| byte_offset(BufReader::new(file), line, column))
+                .ok()
+                .flatten();
+        }
+    }
+}
+
+/// 에러 메시지에서 줄/칸 위치 추출
+///
+/// serde_json 메시지 끝의 `at line L column C`를 사용하고, JSONL 입력의 `N번째 줄:` 접두사가 있으면
+/// 파일의 줄 번호로 바꿉니다 (JSONL은 줄마다 따로 파싱하므로 serde_json의 줄 번호는 항상 1).
+fn parse_position(message: &str) -> (Option<u64>, Option<u64>) {
+    let (mut line, column) = match message
+        .rsplit_once(" at line ")
+        .and_then(|(_, rest)| rest.split_once(" column "))
+    {
+        Some((line, column)) => (line.parse().ok(), column.trim_end().parse().ok()),
+        None => (None, None),
+    };
+    if let Some(file_line) = jsonl_line(message) {
+        line = Some(file_line);
+    }
+    (line, column)
+}
+
+/// JSONL 에러 메시지의 `N번째 줄:` 줄 번호
+fn jsonl_line(message: &str) -> Option<u64> {
+    let (before, _) = message.split_once("번째 줄: ")?;
+    let start = before
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_ascii_digit())
+        .last()
+        .map(|(index, _)| index)?;
+    before[start..].parse().ok()
+}
+
+/// 줄/칸 위치의 바이트 위치 (파일이 그 줄보다 짧으면 None)
+fn byte_offset<R: BufRead>(mut reader: R, line: u64, column: u64) -> io::Result<Option<u64>> {
+    let mut offset = 0;
+    let mut buffer = Vec::new();
+    for _ in 1..line {
+        buffer.clear();
+        let read = reader.read_until(b'\n', &mut buffer)?;
+        if read == 0 {
+            return Ok(None);
+        }
+        offset += read as u64;
+    }
+    Ok(Some(offset + column.saturating_sub(1)))
+}
+
+/// 에러 로그 파일 작성
+pub fn write_error_log(
+    path: &Path,
+    entries: &[ErrorEntry],
+    format: ErrorLogFormat,
+) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    match format {
+        ErrorLogFormat::Text => write_text(&mut writer, entries)?,
+        ErrorLogFormat::Json => {
+            for entry in entries {
+                serde_json::to_writer(&mut writer, entry)?;
+                writeln!(writer)?;
+            }
+        }
+        ErrorLogFormat::Csv => write_csv(&mut writer, entries)?,
+    }
+    writer.flush()
+}
+
+/// 텍스트 형식 기록
+fn write_text<W: Write>(writer: &mut W, entries: &[ErrorEntry]) -> io::Result<()> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    writeln!(writer, "jconvert 에러 로그")?;
+    writeln!(writer, "생성 시간: Unix timestamp: {}", now)?;
+    writeln!(writer, "총 에러 수: {}", entries.len())?;
+    writeln!(writer, "{}", "=".repeat(50))?;
+
+    for entry in entries {
+        writeln!(writer, "\n파일: {:?}", entry.path)?;
+        writeln!(writer, "종류: {}", entry.kind)?;
+        if let (Some(line), Some(column)) = (entry.line, entry.column) {
+            match entry.byte_offset {
+                Some(offset) => writeln!(
+                    writer,
+                    "위치: {}번째 줄 {}번째 칸 ({}바이트)",
+                    line, column, offset
+                )?,
+                None => writeln!(writer, "위치: {}번째 줄 {}번째 칸", line, column)?,
+            }
+        }
+        writeln!(writer, "에러: {}", entry.message)?;
+    }
+    Ok(())
+}
+
+/// CSV 형식 기록
+fn write_csv<W: Write>(writer: &mut W, entries: &[ErrorEntry]) -> io::Result<()> {
+    writeln!(
+        writer,
+        "timestamp,path,kind,line,column,byte_offset,file_size,message"
+    )?;
+    let optional = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+    for entry in entries {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{}",
+            entry.timestamp,
+            csv_field(&entry.path.to_string_lossy()),
+            entry.kind,
+            optional(entry.line),
+            optional(entry.column),
+            optional(entry.byte_offset),
+            optional(entry.file_size),
+            csv_field(&entry.message)
+        )?;
+    }
+    Ok(())
+}
+
+/// CSV 필드 (쉼표, 따옴표, 줄바꿈이 있으면 따옴표로 감쌈)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_position() {
+        assert_eq!(
+            parse_position(
+                "JSON 파싱 실패 (a.json): EOF while parsing an object at line 4 column 0"
+            ),
+            (Some(4), Some(0))
+        );
+        assert_eq!(
+            parse_position("JSON 파싱 실패 (a.jsonl): 12번째 줄: expected `:` at line 1 column 7"),
+            (Some(12), Some(7))
+        );
+        assert_eq!(
+            parse_position("파일 읽기 권한이 없습니다: a.json"),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn test_locate_byte_offset() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("bad.jsonl");
+        std::fs::write(&path, "{\"id\":1}\n{\"id\" 2}\n").unwrap();
+
+        let mut entry = ErrorEntry::new(
+            path,
+            "parse",
+            "2번째 줄: expected `:` at line 1 column 7".to_string(),
+        );
+        entry.locate();
+        assert_eq!(entry.byte_offset, Some(9 + 6));
+    }
+
+    #[test]
+    fn test_write_error_log_formats() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let entries = vec![ErrorEntry::new(
+            PathBuf::from("data/a,b.json"),
+            "parse",
+            "JSON 파싱 실패: \"x\" at line 2 column 3".to_string(),
+        )
+        .with_file_size(10)];
+
+        let csv = dir.path().join("errors.csv");
+        write_error_log(&csv, &entries, ErrorLogFormat::Csv).unwrap();
+        let content = std::fs::read_to_string(&csv).unwrap();
+        let row = content.lines().nth(1).unwrap();
+        assert!(row.ends_with(
+            ",\"data/a,b.json\",parse,2,3,,10,\"JSON 파싱 실패: \"\"x\"\" at line 2 column 3\""
+        ));
+
+        let json = dir.path().join("errors.jsonl");
+        write_error_log(&json, &entries, ErrorLogFormat::Json).unwrap();
+        let line: serde_json::Value =
+            serde_json::from_str(std::fs::read_to_string(&json).unwrap().trim()).unwrap();
+        assert_eq!(line["kind"], "parse");
+        assert_eq!(line["line"], 2);
+        assert_eq!(line["file_size"], 10);
+        assert!(line["byte_offset"].is_null());
+
+        let text = dir.path().join("errors.log");
+        write_error_log(&text, &entries, ErrorLogFormat::Text).unwrap();
+        let content = std::fs::read_to_string(&text).unwrap();
+        assert!(content.contains("총 에러 수: 1"));
+        assert!(content.contains("위치: 2번째 줄 3번째 칸"));
+    }
+}
@@ -0,0 +1,418 @@
+//! 기존 출력 검사 모듈
+//!
+//! `--mode append --skip-existing`으로 같은 입력을 여러 번 이어 붙여도 중복이 생기지 않도록
+//! 이미 있는 출력 JSONL을 읽어 기록된 원본 파일(`_source_file` 필드나 `# source:` 주석 줄)과
+//! 중복 제거 키(`--dedupe-by`) 값을 모읍니다.
+//!
+//! 이어 쓰기 전에 기존 출력의 줄 수, 줄바꿈 없이 끝난 마지막 줄, 압축된 파일 여부도 검사합니다
+//! (`--mode append --dry-run`). 실제로 이어 쓸 때는 이전 실행이 중단되어 잘린 마지막 줄을 먼저 잘라 냅니다.
+
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::codec::detect_codec;
+use crate::dedup::line_key;
+use crate::processor::get_nested_field;
+
+/// `--annotate` 출력의 원본 파일 주석 접두사
+const SOURCE_COMMENT: &str = "# source: ";
+
+/// 기존 출력에 이미 기록된 원본 파일과 중복 제거 키
+#[derive(Debug, Clone, Default)]
+pub struct ExistingOutput {
+    /// 기존 출력의 레코드 수
+    records: u64,
+    /// 레코드를 기록한 원본 파일 (상대 경로)
+    sources: HashSet<String>,
+    /// 중복 제거 키 값 (JSON 표현)
+    keys: Vec<String>,
+}
+
+impl ExistingOutput {
+    /// 기존 출력 파일 검사 (파일이 없으면 빈 결과)
+    ///
+    /// 파싱할 수 없는 줄은 건너뜁니다.
+    ///
+    /// # Arguments
+    /// * `path` - 기존 출력 파일
+    /// * `source_key` - 원본 파일 필드 이름 (`--add-source`일 때)
+    /// * `dedupe_key` - 중복 제거 키 필드 (`--dedupe-by`일 때)
+    pub fn scan(
+        path: &Path,
+        source_key: Option<&str>,
+        dedupe_key: Option<&str>,
+    ) -> io::Result<Self> {
+        match File::open(path) {
+            Ok(file) => Self::from_reader(BufReader::new(file), source_key, dedupe_key),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 리더에서 기존 출력 검사
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::ExistingOutput;
+    ///
+    /// let output = "# source: a.json\n{\"id\":1}\n{\"id\":2,\"_source_file\":\"b.json\"}\n";
+    /// let existing =
+    ///     ExistingOutput::from_reader(output.as_bytes(), Some("_source_file"), Some("id")).unwrap();
+    /// assert_eq!(existing.records(), 2);
+    /// assert!(existing.contains_source("a.json"));
+    /// assert!(existing.contains_source("b.json"));
+    /// assert_eq!(existing.keys(), ["1", "2"]);
+    /// ```
+    pub fn from_reader<R: BufRead>(
+        reader: R,
+        source_key: Option<&str>,
+        dedupe_key: Option<&str>,
+    ) -> io::Result<Self> {
+        let mut existing = Self::default();
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if let Some(source) = line.strip_prefix(SOURCE_COMMENT) {
+                existing.sources.insert(source.to_string());
+                continue;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(key) = source_key {
+                let Ok(record) = serde_json::from_str::<Value>(line) else {
+                    continue;
+                };
+                if let Some(Value::String(source)) = get_nested_field(&record, key) {
+                    existing.sources.insert(source.clone());
+                }
+            }
+            if let Some(key) = dedupe_key.and_then(|key| line_key(line, key)) {
+                existing.keys.push(key);
+            }
+            existing.records += 1;
+        }
+        Ok(existing)
+    }
+
+    /// 기존 출력의 레코드 수
+    pub fn records(&self) -> u64 {
+        self.records
+    }
+
+    /// 원본 파일의 레코드가 이미 기록되었는지 확인
+    pub fn contains_source(&self, source: &str) -> bool {
+        self.sources.contains(source)
+    }
+
+    /// 기존 출력의 중복 제거 키 값
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+}
+
+/// 줄바꿈 없이 끝난 마지막 줄
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrailingLine {
+    /// 마지막 줄이 시작하는 바이트 위치
+    pub offset: u64,
+    /// 마지막 줄 내용
+    pub content: Vec<u8>,
+    /// 완전한 JSON 레코드인지 (줄바꿈만 빠진 경우)
+    pub complete: bool,
+}
+
+impl TrailingLine {
+    /// 마지막 줄 길이
+    pub fn len(&self) -> u64 {
+        self.content.len() as u64
+    }
+
+    /// 마지막 줄이 비었는지 확인 (검사 결과에서는 항상 false)
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+}
+
+/// 이어 쓰기 대상 출력 파일 검사 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AppendTarget {
+    /// 파일 크기
+    pub bytes: u64,
+    /// 줄바꿈으로 끝난 줄 수
+    pub lines: u64,
+    /// JSON으로 파싱할 수 없는 줄 수 (빈 줄과 `#` 주석 줄 제외)
+    pub invalid_lines: u64,
+    /// 줄바꿈 없이 끝난 마지막 줄
+    pub trailing: Option<TrailingLine>,
+    /// 압축 형식 (매직 바이트로 판별, 압축 파일이면 줄은 검사하지 않음)
+    pub compression: Option<&'static str>,
+}
+
+impl AppendTarget {
+    /// 이어 쓸 출력 파일 검사 (파일이 없으면 None)
+    pub fn inspect(path: &Path) -> io::Result<Option<Self>> {
+        match File::open(path) {
+            Ok(file) => Self::from_reader(BufReader::new(file)).map(Some),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 리더에서 출력 검사
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::AppendTarget;
+    ///
+    /// let target = AppendTarget::from_reader(&b"{\"id\":1}\n{\"id\":2,\"na"[..]).unwrap();
+    /// assert_eq!(target.lines, 1);
+    /// let trailing = target.trailing.unwrap();
+    /// assert_eq!(trailing.offset, 9);
+    /// assert!(!trailing.complete);
+    ///
+    /// let gzip = AppendTarget::from_reader(&[0x1f, 0x8b, 0x08, 0x00][..]).unwrap();
+    /// assert_eq!(gzip.compression, Some("gzip"));
+    /// assert!(gzip.has_conflicts());
+    /// ```
+    pub fn from_reader<R: BufRead>(mut reader: R) -> io::Result<Self> {
+        let mut target = Self {
+            compression: compression_format(reader.fill_buf()?),
+            ..Self::default()
+        };
+        if target.compression.is_some() {
+            target.bytes = io::copy(&mut reader, &mut io::sink())?;
+            return Ok(target);
+        }
+
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            let read = reader.read_until(b'\n', &mut line)?;
+            if read == 0 {
+                break;
+            }
+            let offset = target.bytes;
+            target.bytes += read as u64;
+
+            let complete = is_valid_record(&line);
+            if line.last() != Some(&b'\n') {
+                target.trailing = Some(TrailingLine {
+                    offset,
+                    content: line.clone(),
+                    complete,
+                });
+                break;
+            }
+            target.lines += 1;
+            if !complete && !is_blank_or_comment(&line) {
+                target.invalid_lines += 1;
+            }
+        }
+        Ok(target)
+    }
+
+    /// 이어 쓰면 출력이 손상되는 문제가 있는지 확인 (압축 파일이거나 마지막 줄이 잘림)
+    pub fn has_conflicts(&self) -> bool {
+        self.compression.is_some() || self.trailing.is_some()
+    }
+}
+
+/// 매직 바이트로 압축 형식 판별 (압축 파일에 평문 JSONL을 이어 쓰면 파일이 손상됨)
+fn compression_format(head: &[u8]) -> Option<&'static str> {
+    detect_codec(head).map(|codec| codec.name())
+}
+
+/// 마지막 줄을 찾을 때 파일 끝에서부터 한 번에 읽는 크기
+const TAIL_CHUNK: u64 = 64 * 1024;
+
+/// 이어 쓰기 전에 출력 파일의 마지막 줄 복구
+///
+/// 마지막 줄이 줄바꿈 없이 끝나면, 완전한 JSON 레코드는 줄바꿈을 붙이고 잘린 줄은 잘라 내어
+/// 이어 쓴 레코드가 항상 새 줄에서 시작하도록 합니다. 파일 끝부분만 읽으므로 큰 출력도 빠르게 처리합니다.
+///
+/// # Returns
+/// 고친 마지막 줄 (`complete`가 false면 잘라 낸 조각), 파일이 없거나 고칠 것이 없으면 None
+///
+/// # Errors
+/// 파일이 압축 파일이면 `InvalidData` 에러를 반환합니다 (평문을 이어 쓰면 손상되므로).
+pub fn repair_trailing_line(path: &Path) -> io::Result<Option<TrailingLine>> {
+    let mut file = match OpenOptions::new().read(true).write(true).open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let mut head = [0u8; 8];
+    let read = file.read(&mut head)?;
+    if let Some(format) = compression_format(&head[..read]) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} 압축 파일에는 이어 쓸 수 없습니다", format),
+        ));
+    }
+
+    let len = file.metadata()?.len();
+    let offset = match last_line_start(&mut file, len)? {
+        Some(offset) => offset,
+        None => return Ok(None),
+    };
+
+    let mut content = Vec::with_capacity((len - offset) as usize);
+    file.seek(SeekFrom::Start(offset))?;
+    file.read_to_end(&mut content)?;
+    let complete = is_valid_record(&content);
+    if complete {
+        file.write_all(b"\n")?;
+    } else {
+        file.set_len(offset)?;
+    }
+    file.sync_all()?;
+
+    Ok(Some(TrailingLine {
+        offset,
+        content,
+        complete,
+    }))
+}
+
+/// 줄바꿈 없이 끝난 마지막 줄의 시작 위치 (파일이 비었거나 줄바꿈으로 끝나면 None)
+fn last_line_start(file: &mut File, len: u64) -> io::Result<Option<u64>> {
+    let mut end = len;
+    let mut chunk = Vec::new();
+    while end > 0 {
+        let start = end.saturating_sub(TAIL_CHUNK);
+        chunk.resize((end - start) as usize, 0);
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut chunk)?;
+        if end == len && chunk.last() == Some(&b'\n') {
+            return Ok(None);
+        }
+        if let Some(newline) = chunk.iter().rposition(|&b| b == b'\n') {
+            return Ok(Some(start + newline as u64 + 1));
+        }
+        end = start;
+    }
+    Ok((len > 0).then_some(0))
+}
+
+/// JSON으로 파싱되는 줄인지 확인
+fn is_valid_record(line: &[u8]) -> bool {
+    serde_json::from_slice::<serde::de::IgnoredAny>(line).is_ok()
+}
+
+/// 빈 줄이나 `#` 주석 줄인지 확인
+fn is_blank_or_comment(line: &[u8]) -> bool {
+    match line.iter().position(|b| !b.is_ascii_whitespace()) {
+        Some(start) => line[start] == b'#',
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_scan_missing_file_is_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let existing =
+            ExistingOutput::scan(&dir.path().join("none.jsonl"), Some("_source_file"), None)
+                .unwrap();
+        assert_eq!(existing.records(), 0);
+        assert!(!existing.contains_source("a.json"));
+    }
+
+    #[test]
+    fn test_scan_skips_invalid_lines() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "{{\"id\":1,\"_source_file\":\"sub/a.json\"}}").unwrap();
+        writeln!(file, "{{\"id\":2,\"_sour").unwrap();
+        writeln!(file).unwrap();
+        writeln!(file, "{{\"user\":{{\"id\":\"3\"}}}}").unwrap();
+
+        let existing = ExistingOutput::scan(file.path(), Some("_source_file"), Some("id")).unwrap();
+        assert_eq!(existing.records(), 2);
+        assert!(existing.contains_source("sub/a.json"));
+        assert_eq!(existing.keys(), ["1"]);
+
+        let existing = ExistingOutput::scan(file.path(), None, Some("user.id")).unwrap();
+        assert!(!existing.contains_source("sub/a.json"));
+        assert_eq!(existing.keys(), ["\"3\""]);
+    }
+
+    #[test]
+    fn test_inspect_append_target() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(
+            AppendTarget::inspect(&dir.path().join("none.jsonl")).unwrap(),
+            None
+        );
+
+        let path = dir.path().join("out.jsonl");
+        std::fs::write(&path, "{\"id\":1}\n# source: a.json\n\nnot json\n").unwrap();
+        let target = AppendTarget::inspect(&path).unwrap().unwrap();
+        assert_eq!(target.lines, 4);
+        assert_eq!(target.invalid_lines, 1);
+        assert_eq!(target.bytes, 36);
+        assert!(!target.has_conflicts());
+
+        // 줄바꿈만 빠진 완전한 레코드
+        std::fs::write(&path, "{\"id\":1}\n{\"id\":2}").unwrap();
+        let target = AppendTarget::inspect(&path).unwrap().unwrap();
+        assert_eq!(target.lines, 1);
+        let trailing = target.trailing.unwrap();
+        assert_eq!((trailing.offset, trailing.len()), (9, 8));
+        assert!(trailing.complete);
+    }
+
+    #[test]
+    fn test_inspect_compressed_output() {
+        let zstd = AppendTarget::from_reader(&[0x28, 0xb5, 0x2f, 0xfd, 0x0a, 0x00][..]).unwrap();
+        assert_eq!(zstd.compression, Some("zstd"));
+        assert_eq!(zstd.bytes, 6);
+        assert_eq!(zstd.lines, 0);
+        assert_eq!(zstd.trailing, None);
+    }
+
+    #[test]
+    fn test_repair_trailing_line() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("out.jsonl");
+        assert_eq!(repair_trailing_line(&path).unwrap(), None);
+
+        // 잘린 줄은 잘라 냄
+        std::fs::write(&path, "{\"id\":1}\n{\"id\":2,\"na").unwrap();
+        let fragment = repair_trailing_line(&path).unwrap().unwrap();
+        assert_eq!(fragment.offset, 9);
+        assert_eq!(fragment.content, b"{\"id\":2,\"na");
+        assert!(!fragment.complete);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"id\":1}\n");
+        assert_eq!(repair_trailing_line(&path).unwrap(), None);
+
+        // 줄바꿈만 빠진 레코드는 줄바꿈을 붙임
+        std::fs::write(&path, "{\"id\":1}").unwrap();
+        assert!(repair_trailing_line(&path).unwrap().unwrap().complete);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"id\":1}\n");
+
+        // 파일 끝 읽기 단위보다 긴 줄
+        let long = format!(
+            "{{\"id\":1}}\n{{\"text\":\"{}",
+            "x".repeat(TAIL_CHUNK as usize * 2)
+        );
+        std::fs::write(&path, &long).unwrap();
+        let fragment = repair_trailing_line(&path).unwrap().unwrap();
+        assert_eq!(fragment.offset, 9);
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 9);
+
+        std::fs::write(&path, [0x1f, 0x8b, 0x08, 0x00]).unwrap();
+        let err = repair_trailing_line(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
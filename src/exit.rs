@@ -0,0 +1,149 @@
+//! 종료 상태 모듈
+//!
+//! 실행 결과를 프로세스 종료 코드로 구분하고, 에러 허용 한도(`--fail-fast`, `--max-errors`)를 관리합니다.
+//!
+//! | 종료 코드 | 의미 |
+//! |-----------|------|
+//! | 0 | 에러 없이 완료 |
+//! | 1 | 완료했지만 실패/무효 파일이 있거나 기준 실행보다 나빠짐 (`--baseline`), 또는 품질 기준 미달 |
+//! | 2 | 중단됨 (에러 허용 한도 초과, 잘못된 인자 등 치명적 에러) |
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// 실행 결과에 따른 종료 상태 (심각한 순서로 정렬)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExitStatus {
+    /// 에러 없이 완료
+    Clean,
+    /// 완료했지만 실패/무효 파일이 있음
+    CompletedWithErrors,
+    /// 중단됨
+    Aborted,
+}
+
+impl ExitStatus {
+    /// 프로세스 종료 코드
+    pub fn code(self) -> u8 {
+        match self {
+            ExitStatus::Clean => 0,
+            ExitStatus::CompletedWithErrors => 1,
+            ExitStatus::Aborted => 2,
+        }
+    }
+
+    /// 실패 파일 수로 완료 상태 결정
+    pub fn from_failures(failures: usize) -> Self {
+        if failures == 0 {
+            ExitStatus::Clean
+        } else {
+            ExitStatus::CompletedWithErrors
+        }
+    }
+
+    /// 품질 기준 평가 결과 반영
+    ///
+    /// 기준 미달이면 완료 상태를 실패로 올리고, 기준을 지켜도 이미 정해진 상태를 낮추지 않습니다.
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::ExitStatus;
+    ///
+    /// assert_eq!(ExitStatus::Clean.with_gates(false), ExitStatus::CompletedWithErrors);
+    /// assert_eq!(ExitStatus::CompletedWithErrors.with_gates(true), ExitStatus::CompletedWithErrors);
+    /// ```
+    pub fn with_gates(self, passed: bool) -> Self {
+        if passed {
+            self
+        } else {
+            self.max(ExitStatus::CompletedWithErrors)
+        }
+    }
+}
+
+/// 여러 스레드에서 공유하는 에러 허용 한도
+///
+/// 한도를 넘는 에러가 기록되면 중단 상태가 되어 이후 작업을 건너뛸 수 있습니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::exit::ErrorBudget;
+///
+/// let budget = ErrorBudget::new(Some(1));
+/// budget.record_error();
+/// assert!(!budget.is_exhausted());
+/// budget.record_error();
+/// assert!(budget.is_exhausted());
+/// ```
+#[derive(Debug, Default)]
+pub struct ErrorBudget {
+    /// 허용하는 최대 에러 수 (None이면 무제한)
+    limit: Option<usize>,
+    /// 기록된 에러 수
+    count: AtomicUsize,
+    /// 한도 초과 여부
+    exhausted: AtomicBool,
+}
+
+impl ErrorBudget {
+    /// 허용 에러 수로 생성 (`Some(0)`이면 첫 에러에서 중단)
+    pub fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            ..Default::default()
+        }
+    }
+
+    /// 에러 하나 기록 (한도를 넘으면 중단 상태로 전환)
+    pub fn record_error(&self) {
+        let count = self.count.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.limit.is_some_and(|limit| count > limit) {
+            self.exhausted.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// 한도를 넘어 중단해야 하는지 확인
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted.load(Ordering::Relaxed)
+    }
+
+    /// 허용하는 최대 에러 수
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(ExitStatus::from_failures(0), ExitStatus::Clean);
+        assert_eq!(ExitStatus::from_failures(3).code(), 1);
+        assert_eq!(ExitStatus::Aborted.code(), 2);
+    }
+
+    #[test]
+    fn test_gates_only_escalate() {
+        // 실패 파일이 있으면 기준을 지켜도 종료 코드 1
+        let failed = ExitStatus::from_failures(2);
+        assert_eq!(failed.with_gates(true).code(), 1);
+        assert_eq!(ExitStatus::Clean.with_gates(true), ExitStatus::Clean);
+        assert_eq!(ExitStatus::Clean.with_gates(false).code(), 1);
+        assert_eq!(ExitStatus::Aborted.with_gates(false), ExitStatus::Aborted);
+    }
+
+    #[test]
+    fn test_error_budget_limits() {
+        let unlimited = ErrorBudget::new(None);
+        for _ in 0..100 {
+            unlimited.record_error();
+        }
+        assert!(!unlimited.is_exhausted());
+
+        let fail_fast = ErrorBudget::new(Some(0));
+        assert!(!fail_fast.is_exhausted());
+        fail_fast.record_error();
+        assert!(fail_fast.is_exhausted());
+    }
+}
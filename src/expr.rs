@@ -0,0 +1,902 @@
+//! 표현식 모듈
+//!
+//! `--where`, `--transform` 등에서 사용하는 작은 표현식 언어의 파서와 평가기입니다.
+//!
+//! # 문법
+//!
+//! - 필드 참조: `status`, `user.name`, `.user.name` (점 표기법, 없는 필드는 `null`)
+//! - 레코드 전체: `.`
+//! - 리터럴: `'active'`, `"active"`, `10`, `3.5`, `true`, `false`, `null`
+//! - 비교: `==`, `!=`, `<`, `<=`, `>`, `>=`
+//! - 논리: `&&`, `||`, `!`, 괄호
+//! - 산술: `+`, `-`, `*`, `/`, `%` (`+`는 문자열 연결, 배열 연결, 객체 병합도 지원)
+//! - 객체/배열 생성: `{id, name: first + ' ' + last, "tag key": 1}`, `[a, b]`
+//! - 조건: `if age >= 18 then 'adult' elif age >= 13 then 'teen' else 'child' end`
+//! - 함수: `lower(s)`, `upper(s)`, `trim(s)`, `length(x)`, `tostring(x)`, `tonumber(x)`,
+//!   `coalesce(a, b, ...)`, `join(array, sep)`
+//!
+//! ```
+//! use jconvert::expr::Expr;
+//! use serde_json::json;
+//!
+//! let expr = Expr::parse("status == 'active' && count > 10").unwrap();
+//! assert!(expr.matches(&json!({"status": "active", "count": 11})));
+//! assert!(!expr.matches(&json!({"status": "active", "count": 3})));
+//!
+//! let transform = Expr::parse("{id, name: upper(first) + ' ' + last, total: price * qty}").unwrap();
+//! assert_eq!(
+//!     transform.eval(&json!({"id": 1, "first": "kim", "last": "lee", "price": 5, "qty": 3})),
+//!     json!({"id": 1, "name": "KIM lee", "total": 15})
+//! );
+//! ```
+
+use serde_json::{Map, Number, Value};
+use std::cmp::Ordering;
+
+use crate::error::{JConvertError, Result};
+use crate::processor::get_nested_field;
+
+/// 이항 연산자
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+/// 내장 함수
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Function {
+    /// 소문자 변환
+    Lower,
+    /// 대문자 변환
+    Upper,
+    /// 앞뒤 공백 제거
+    Trim,
+    /// 문자열 글자 수, 배열/객체 요소 수
+    Length,
+    /// 문자열로 변환 (문자열이 아니면 JSON 표기)
+    ToString,
+    /// 숫자로 변환 (변환할 수 없으면 `null`)
+    ToNumber,
+    /// `null`이 아닌 첫 번째 값
+    Coalesce,
+    /// 배열 요소를 구분자로 이어 붙인 문자열
+    Join,
+}
+
+impl Function {
+    /// 이름으로 함수 찾기
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "lower" => Function::Lower,
+            "upper" => Function::Upper,
+            "trim" => Function::Trim,
+            "length" => Function::Length,
+            "tostring" => Function::ToString,
+            "tonumber" => Function::ToNumber,
+            "coalesce" => Function::Coalesce,
+            "join" => Function::Join,
+            _ => return None,
+        })
+    }
+
+    /// 인자 수가 맞는지 확인
+    fn accepts(self, count: usize) -> bool {
+        match self {
+            Function::Coalesce => count >= 1,
+            Function::Join => count == 2,
+            _ => count == 1,
+        }
+    }
+
+    /// 인자 값으로 함수 호출
+    fn call(self, args: Vec<Value>) -> Value {
+        let mut args = args.into_iter();
+        let first = args.next().unwrap_or(Value::Null);
+        match self {
+            Function::Lower => map_str(first, str::to_lowercase),
+            Function::Upper => map_str(first, str::to_uppercase),
+            Function::Trim => map_str(first, |s| s.trim().to_string()),
+            Function::Length => match first {
+                Value::Null => Value::from(0),
+                Value::String(s) => Value::from(s.chars().count()),
+                Value::Array(a) => Value::from(a.len()),
+                Value::Object(o) => Value::from(o.len()),
+                _ => Value::Null,
+            },
+            Function::ToString => Value::String(to_text(&first)),
+            Function::ToNumber => match first {
+                Value::Number(_) => first,
+                Value::String(s) => parse_number(s.trim()).unwrap_or(Value::Null),
+                _ => Value::Null,
+            },
+            Function::Coalesce => std::iter::once(first)
+                .chain(args)
+                .find(|value| !value.is_null())
+                .unwrap_or(Value::Null),
+            Function::Join => match (first, args.next()) {
+                (Value::Array(items), Some(Value::String(sep))) => Value::String(
+                    items
+                        .iter()
+                        .filter(|item| !item.is_null())
+                        .map(to_text)
+                        .collect::<Vec<_>>()
+                        .join(&sep),
+                ),
+                _ => Value::Null,
+            },
+        }
+    }
+}
+
+/// 파싱된 표현식
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// 리터럴 값
+    Literal(Value),
+    /// 필드 참조 (점 표기법 경로)
+    Field(String),
+    /// 레코드 전체 (`.`)
+    Identity,
+    /// 논리 부정
+    Not(Box<Expr>),
+    /// 부호 반전
+    Neg(Box<Expr>),
+    /// 이항 연산
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    /// 객체 생성 (키, 값 표현식)
+    Object(Vec<(String, Expr)>),
+    /// 배열 생성
+    Array(Vec<Expr>),
+    /// 조건 (조건, 참일 때, 거짓일 때)
+    If(Box<Expr>, Box<Expr>, Box<Expr>),
+    /// 내장 함수 호출
+    Call(Function, Vec<Expr>),
+}
+
+impl Expr {
+    /// 표현식 문자열 파싱
+    pub fn parse(source: &str) -> Result<Self> {
+        let invalid = |reason: String| JConvertError::InvalidExpression {
+            expr: source.to_string(),
+            reason,
+        };
+
+        let tokens = tokenize(source).map_err(invalid)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or().map_err(invalid)?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(token) => Err(invalid(format!("예상하지 못한 토큰: {}", token))),
+        }
+    }
+
+    /// 레코드에 대해 표현식 평가
+    pub fn eval(&self, record: &Value) -> Value {
+        match self {
+            Expr::Literal(value) => value.clone(),
+            Expr::Field(path) => get_nested_field(record, path)
+                .cloned()
+                .unwrap_or(Value::Null),
+            Expr::Identity => record.clone(),
+            Expr::Not(inner) => Value::Bool(!is_truthy(&inner.eval(record))),
+            Expr::Neg(inner) => arithmetic(BinaryOp::Sub, Value::from(0), inner.eval(record)),
+            Expr::Binary(BinaryOp::And, left, right) => {
+                Value::Bool(is_truthy(&left.eval(record)) && is_truthy(&right.eval(record)))
+            }
+            Expr::Binary(BinaryOp::Or, left, right) => {
+                Value::Bool(is_truthy(&left.eval(record)) || is_truthy(&right.eval(record)))
+            }
+            Expr::Binary(
+                op
+                @ (BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem),
+                left,
+                right,
+            ) => arithmetic(*op, left.eval(record), right.eval(record)),
+            Expr::Binary(op, left, right) => {
+                let (left, right) = (left.eval(record), right.eval(record));
+                Value::Bool(compare(*op, &left, &right))
+            }
+            Expr::Object(entries) => Value::Object(
+                entries
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.eval(record)))
+                    .collect::<Map<String, Value>>(),
+            ),
+            Expr::Array(items) => {
+                Value::Array(items.iter().map(|item| item.eval(record)).collect())
+            }
+            Expr::If(condition, then, otherwise) => {
+                if is_truthy(&condition.eval(record)) {
+                    then.eval(record)
+                } else {
+                    otherwise.eval(record)
+                }
+            }
+            Expr::Call(function, args) => {
+                function.call(args.iter().map(|arg| arg.eval(record)).collect())
+            }
+        }
+    }
+
+    /// 레코드가 조건을 만족하는지 확인
+    pub fn matches(&self, record: &Value) -> bool {
+        is_truthy(&self.eval(record))
+    }
+}
+
+/// 값의 참/거짓 판정 (`null`, `false`, `0`, 빈 문자열/배열/객체는 거짓)
+pub fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|f| f != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(o) => !o.is_empty(),
+    }
+}
+
+/// 비교 연산 평가
+///
+/// 숫자끼리는 수치로, 문자열끼리는 사전순으로 비교합니다.
+/// 타입이 다르면 `!=`만 참입니다.
+fn compare(op: BinaryOp, left: &Value, right: &Value) -> bool {
+    let ordering = match (left, right) {
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .zip(b.as_f64())
+            .and_then(|(a, b)| a.partial_cmp(&b)),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ if left == right => Some(Ordering::Equal),
+        _ => None,
+    };
+
+    match (op, ordering) {
+        (BinaryOp::Eq, Some(o)) => o == Ordering::Equal,
+        (BinaryOp::Ne, Some(o)) => o != Ordering::Equal,
+        (BinaryOp::Ne, None) => true,
+        (BinaryOp::Lt, Some(o)) => o == Ordering::Less,
+        (BinaryOp::Le, Some(o)) => o != Ordering::Greater,
+        (BinaryOp::Gt, Some(o)) => o == Ordering::Greater,
+        (BinaryOp::Ge, Some(o)) => o != Ordering::Less,
+        _ => false,
+    }
+}
+
+/// 산술 연산 평가
+///
+/// 정수끼리는 넘치지 않는 한 정수로 계산하고, 나누어떨어지지 않는 나눗셈이나 실수가 섞이면 실수로 계산합니다.
+/// `+`는 문자열 연결(한쪽이 문자열이면 숫자/불리언도 문자로 이어 붙임), 배열 연결, 객체 병합(오른쪽 우선)도 지원하며
+/// 한쪽이 `null`이면 다른 쪽을 그대로 반환합니다. 계산할 수 없으면(0으로 나누기 등) `null`입니다.
+fn arithmetic(op: BinaryOp, left: Value, right: Value) -> Value {
+    match (op, left, right) {
+        (BinaryOp::Add, Value::Null, other) | (BinaryOp::Add, other, Value::Null) => other,
+        (
+            BinaryOp::Add,
+            Value::String(a),
+            b @ (Value::String(_) | Value::Number(_) | Value::Bool(_)),
+        ) => Value::String(a + &to_text(&b)),
+        (BinaryOp::Add, a @ (Value::Number(_) | Value::Bool(_)), Value::String(b)) => {
+            Value::String(to_text(&a) + &b)
+        }
+        (BinaryOp::Add, Value::Array(mut a), Value::Array(b)) => {
+            a.extend(b);
+            Value::Array(a)
+        }
+        (BinaryOp::Add, Value::Object(mut a), Value::Object(b)) => {
+            a.extend(b);
+            Value::Object(a)
+        }
+        (op, Value::Number(a), Value::Number(b)) => {
+            if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+                let int = match op {
+                    BinaryOp::Add => a.checked_add(b),
+                    BinaryOp::Sub => a.checked_sub(b),
+                    BinaryOp::Mul => a.checked_mul(b),
+                    BinaryOp::Div if b != 0 && a % b == 0 => a.checked_div(b),
+                    BinaryOp::Rem => a.checked_rem(b),
+                    _ => None,
+                };
+                if let Some(int) = int {
+                    return Value::from(int);
+                }
+            }
+            let (Some(a), Some(b)) = (a.as_f64(), b.as_f64()) else {
+                return Value::Null;
+            };
+            let float = match op {
+                BinaryOp::Add => a + b,
+                BinaryOp::Sub => a - b,
+                BinaryOp::Mul => a * b,
+                BinaryOp::Div if b != 0.0 => a / b,
+                BinaryOp::Rem if b != 0.0 => a % b,
+                _ => return Value::Null,
+            };
+            Number::from_f64(float).map_or(Value::Null, Value::Number)
+        }
+        _ => Value::Null,
+    }
+}
+
+/// 문자열이면 변환 함수 적용 (아니면 `null`)
+fn map_str(value: Value, f: impl Fn(&str) -> String) -> Value {
+    match value {
+        Value::String(s) => Value::String(f(&s)),
+        _ => Value::Null,
+    }
+}
+
+/// 값을 문자로 표기 (문자열은 그대로, 나머지는 JSON 표기)
+fn to_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// 어휘 토큰
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Literal(Value),
+    Op(&'static str),
+    LParen,
+    RParen,
+    /// 레코드 전체 (`.`)
+    Dot,
+    /// 구분 기호 (`{`, `}`, `[`, `]`, `,`, `:`)
+    Punct(char),
+    /// 조건식 키워드 (`if`, `then`, `elif`, `else`, `end`)
+    Keyword(&'static str),
+}
+
+impl Token {
+    /// 값으로 끝나는 토큰인지 확인 (뒤따르는 `-`를 뺄셈으로 볼지 결정)
+    fn ends_value(&self) -> bool {
+        matches!(
+            self,
+            Token::Ident(_)
+                | Token::Literal(_)
+                | Token::RParen
+                | Token::Dot
+                | Token::Punct(']' | '}')
+                | Token::Keyword("end")
+        )
+    }
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Ident(name) => write!(f, "{}", name),
+            Token::Literal(value) => write!(f, "{}", value),
+            Token::Op(op) => write!(f, "{}", op),
+            Token::LParen => write!(f, "("),
+            Token::RParen => write!(f, ")"),
+            Token::Dot => write!(f, "."),
+            Token::Punct(c) => write!(f, "{}", c),
+            Token::Keyword(keyword) => write!(f, "{}", keyword),
+        }
+    }
+}
+
+/// 조건식 키워드 (같은 이름의 필드는 `.end`처럼 점으로 시작하여 참조)
+const KEYWORDS: &[&str] = &["if", "then", "elif", "else", "end"];
+
+/// 연산자 목록 (긴 것부터 검사)
+const OPERATORS: &[&str] = &[
+    "==", "!=", "<=", ">=", "&&", "||", "<", ">", "!", "+", "-", "*", "/", "%",
+];
+
+/// 표현식 문자열을 토큰으로 분리
+fn tokenize(source: &str) -> std::result::Result<Vec<Token>, String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut i = 0;
+    let is_ident_start = |c: &char| c.is_alphabetic() || *c == '_';
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '(' {
+            tokens.push(Token::LParen);
+            i += 1;
+        } else if c == ')' {
+            tokens.push(Token::RParen);
+            i += 1;
+        } else if matches!(c, '{' | '}' | '[' | ']' | ',' | ':') {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        } else if c == '.' && !chars.get(i + 1).is_some_and(is_ident_start) {
+            tokens.push(Token::Dot);
+            i += 1;
+        } else if c == '\'' || c == '"' {
+            let (text, next) = read_string(&chars, i)?;
+            tokens.push(Token::Literal(Value::String(text)));
+            i = next;
+        } else if c.is_ascii_digit()
+            || (c == '-'
+                && chars.get(i + 1).is_some_and(|d| d.is_ascii_digit())
+                && !tokens.last().is_some_and(Token::ends_value))
+        {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            tokens.push(Token::Literal(parse_number(&text)?));
+        } else if is_ident_start(&c) || c == '.' {
+            // `.user.name`처럼 점으로 시작하는 필드 참조도 허용
+            let start = if c == '.' { i + 1 } else { i };
+            i = start;
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+            {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let keyword = KEYWORDS.iter().find(|keyword| **keyword == word);
+            tokens.push(match (word.as_str(), keyword) {
+                (_, _) if c == '.' => Token::Ident(word),
+                ("true", _) => Token::Literal(Value::Bool(true)),
+                ("false", _) => Token::Literal(Value::Bool(false)),
+                ("null", _) => Token::Literal(Value::Null),
+                (_, Some(keyword)) => Token::Keyword(keyword),
+                _ => Token::Ident(word),
+            });
+        } else {
+            let rest: String = chars[i..].iter().take(2).collect();
+            let op = OPERATORS
+                .iter()
+                .find(|op| rest.starts_with(**op))
+                .ok_or_else(|| format!("알 수 없는 문자: '{}'", c))?;
+            tokens.push(Token::Op(op));
+            i += op.len();
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// 따옴표로 감싼 문자열 리터럴 읽기 (`\` 이스케이프 지원)
+fn read_string(chars: &[char], start: usize) -> std::result::Result<(String, usize), String> {
+    let quote = chars[start];
+    let mut text = String::new();
+    let mut i = start + 1;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if i + 1 < chars.len() => {
+                text.push(chars[i + 1]);
+                i += 2;
+            }
+            c if c == quote => return Ok((text, i + 1)),
+            c => {
+                text.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    Err("닫히지 않은 문자열".to_string())
+}
+
+/// 숫자 리터럴 파싱 (정수는 정수로 유지)
+fn parse_number(text: &str) -> std::result::Result<Value, String> {
+    if let Ok(int) = text.parse::<i64>() {
+        return Ok(Value::from(int));
+    }
+    text.parse::<f64>()
+        .ok()
+        .and_then(Number::from_f64)
+        .map(Value::Number)
+        .ok_or_else(|| format!("잘못된 숫자: {}", text))
+}
+
+/// 재귀 하강 파서
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// 다음 토큰이 주어진 연산자이면 소비
+    fn eat_op(&mut self, op: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Op(o)) if *o == op) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 다음 토큰이 주어진 구분 기호이면 소비
+    fn eat_punct(&mut self, punct: char) -> bool {
+        if self.peek() == Some(&Token::Punct(punct)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 다음 토큰이 주어진 키워드이면 소비
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Keyword(word)) if *word == keyword) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 주어진 키워드를 소비하고, 없으면 에러
+    fn expect_keyword(&mut self, keyword: &str) -> std::result::Result<(), String> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(format!("'{}'가 필요합니다", keyword))
+        }
+    }
+
+    fn parse_or(&mut self) -> std::result::Result<Expr, String> {
+        let mut left = self.parse_and()?;
+        while self.eat_op("||") {
+            let right = self.parse_and()?;
+            left = Expr::Binary(BinaryOp::Or, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> std::result::Result<Expr, String> {
+        let mut left = self.parse_unary()?;
+        while self.eat_op("&&") {
+            let right = self.parse_unary()?;
+            left = Expr::Binary(BinaryOp::And, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> std::result::Result<Expr, String> {
+        if self.eat_op("!") {
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> std::result::Result<Expr, String> {
+        let left = self.parse_additive()?;
+        let op = match self.peek() {
+            Some(Token::Op("==")) => BinaryOp::Eq,
+            Some(Token::Op("!=")) => BinaryOp::Ne,
+            Some(Token::Op("<")) => BinaryOp::Lt,
+            Some(Token::Op("<=")) => BinaryOp::Le,
+            Some(Token::Op(">")) => BinaryOp::Gt,
+            Some(Token::Op(">=")) => BinaryOp::Ge,
+            _ => return Ok(left),
+        };
+        self.pos += 1;
+        let right = self.parse_additive()?;
+        Ok(Expr::Binary(op, Box::new(left), Box::new(right)))
+    }
+
+    fn parse_additive(&mut self) -> std::result::Result<Expr, String> {
+        let mut left = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op("+")) => BinaryOp::Add,
+                Some(Token::Op("-")) => BinaryOp::Sub,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.parse_multiplicative()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> std::result::Result<Expr, String> {
+        let mut left = self.parse_prefix()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op("*")) => BinaryOp::Mul,
+                Some(Token::Op("/")) => BinaryOp::Div,
+                Some(Token::Op("%")) => BinaryOp::Rem,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.parse_prefix()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(right));
+        }
+    }
+
+    fn parse_prefix(&mut self) -> std::result::Result<Expr, String> {
+        if self.eat_op("-") {
+            return Ok(Expr::Neg(Box::new(self.parse_prefix()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> std::result::Result<Expr, String> {
+        match self.next() {
+            Some(Token::Literal(value)) => Ok(Expr::Literal(value)),
+            Some(Token::Dot) => Ok(Expr::Identity),
+            Some(Token::Keyword("if")) => self.parse_if(),
+            Some(Token::Ident(name)) if self.peek() == Some(&Token::LParen) => {
+                self.pos += 1;
+                self.parse_call(&name)
+            }
+            Some(Token::Ident(path)) => Ok(Expr::Field(path)),
+            Some(Token::LParen) => {
+                let expr = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(expr),
+                    _ => Err("닫는 괄호가 없습니다".to_string()),
+                }
+            }
+            Some(Token::Punct('{')) => self.parse_object(),
+            Some(Token::Punct('[')) => {
+                let items = self.parse_list(']')?;
+                Ok(Expr::Array(items))
+            }
+            Some(token) => Err(format!("예상하지 못한 토큰: {}", token)),
+            None => Err("표현식이 끝났습니다".to_string()),
+        }
+    }
+
+    /// `if` 뒤의 조건식 (`elif`는 중첩 조건으로 변환)
+    fn parse_if(&mut self) -> std::result::Result<Expr, String> {
+        let condition = self.parse_or()?;
+        self.expect_keyword("then")?;
+        let then = self.parse_or()?;
+        let otherwise = if self.eat_keyword("elif") {
+            return Ok(Expr::If(
+                Box::new(condition),
+                Box::new(then),
+                Box::new(self.parse_if()?),
+            ));
+        } else {
+            self.expect_keyword("else")?;
+            self.parse_or()?
+        };
+        self.expect_keyword("end")?;
+        Ok(Expr::If(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    /// 함수 이름과 여는 괄호 뒤의 인자 목록
+    fn parse_call(&mut self, name: &str) -> std::result::Result<Expr, String> {
+        let function =
+            Function::from_name(name).ok_or_else(|| format!("알 수 없는 함수: {}", name))?;
+        let args = self.parse_args()?;
+        if !function.accepts(args.len()) {
+            return Err(format!(
+                "{} 함수의 인자 수가 맞지 않습니다: {}개",
+                name,
+                args.len()
+            ));
+        }
+        Ok(Expr::Call(function, args))
+    }
+
+    /// 닫는 괄호까지의 쉼표로 구분된 인자 목록
+    fn parse_args(&mut self) -> std::result::Result<Vec<Expr>, String> {
+        let mut args = Vec::new();
+        if self.peek() == Some(&Token::RParen) {
+            self.pos += 1;
+            return Ok(args);
+        }
+        loop {
+            args.push(self.parse_or()?);
+            match self.next() {
+                Some(Token::Punct(',')) => {}
+                Some(Token::RParen) => return Ok(args),
+                _ => return Err("닫는 괄호가 없습니다".to_string()),
+            }
+        }
+    }
+
+    /// 닫는 기호까지의 쉼표로 구분된 표현식 목록
+    fn parse_list(&mut self, close: char) -> std::result::Result<Vec<Expr>, String> {
+        let mut items = Vec::new();
+        if self.eat_punct(close) {
+            return Ok(items);
+        }
+        loop {
+            items.push(self.parse_or()?);
+            if self.eat_punct(close) {
+                return Ok(items);
+            }
+            if !self.eat_punct(',') {
+                return Err(format!("'{}'가 없습니다", close));
+            }
+        }
+    }
+
+    /// 여는 중괄호 뒤의 객체 항목 (`키: 값`, 필드 이름만 쓰면 같은 이름의 필드 값)
+    fn parse_object(&mut self) -> std::result::Result<Expr, String> {
+        let mut entries = Vec::new();
+        if self.eat_punct('}') {
+            return Ok(Expr::Object(entries));
+        }
+        loop {
+            let (key, shorthand) = match self.next() {
+                Some(Token::Ident(path)) => {
+                    // `{user.name}`의 키는 마지막 이름 (`name`)
+                    let key = path.rsplit('.').next().unwrap_or(&path).to_string();
+                    (key, Some(Expr::Field(path)))
+                }
+                Some(Token::Literal(Value::String(key))) => (key, None),
+                _ => return Err("객체 키가 필요합니다".to_string()),
+            };
+            let value = if self.eat_punct(':') {
+                self.parse_or()?
+            } else {
+                shorthand.ok_or_else(|| format!("'{}' 키의 값이 필요합니다", key))?
+            };
+            entries.push((key, value));
+            if self.eat_punct('}') {
+                return Ok(Expr::Object(entries));
+            }
+            if !self.eat_punct(',') {
+                return Err("'}'가 없습니다".to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn matches(source: &str, record: Value) -> bool {
+        Expr::parse(source).unwrap().matches(&record)
+    }
+
+    #[test]
+    fn test_comparisons_and_logic() {
+        let record = json!({"status": "active", "count": 12, "user": {"age": 30.5}});
+
+        assert!(matches("status == 'active' && count > 10", record.clone()));
+        assert!(matches("count >= 12 && count <= 12", record.clone()));
+        assert!(matches("user.age < 31 || missing", record.clone()));
+        assert!(matches("!(status != \"active\")", record.clone()));
+        assert!(!matches(
+            "status == 'inactive' || count < 0",
+            record.clone()
+        ));
+        assert!(matches("missing == null && count != 'x'", record));
+    }
+
+    #[test]
+    fn test_mixed_types_and_truthiness() {
+        assert!(!matches("id > 'a'", json!({"id": 1})));
+        assert!(matches("id == 1.0", json!({"id": 1})));
+        assert!(matches("tags", json!({"tags": [1]})));
+        assert!(!matches("tags", json!({"tags": []})));
+    }
+
+    fn eval(source: &str, record: Value) -> Value {
+        Expr::parse(source).unwrap().eval(&record)
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let record = json!({"a": 7, "b": 2, "x": 1.5, "big": i64::MAX});
+        assert_eq!(eval("a + b * 3", record.clone()), json!(13));
+        assert_eq!(eval("(a + b) * 3", record.clone()), json!(27));
+        assert_eq!(eval("a - 10", record.clone()), json!(-3));
+        assert_eq!(eval("a-1", record.clone()), json!(6));
+        assert_eq!(eval("-a", record.clone()), json!(-7));
+        assert_eq!(eval("a / b", record.clone()), json!(3.5));
+        assert_eq!(eval("8 / b", record.clone()), json!(4));
+        assert_eq!(eval("a % b", record.clone()), json!(1));
+        assert_eq!(eval("a * x", record.clone()), json!(10.5));
+        assert_eq!(eval("a / 0", record.clone()), Value::Null);
+        // 넘치면 실수로 계산
+        assert_eq!(
+            eval("big + 1", record.clone()),
+            json!(i64::MAX as f64 + 1.0)
+        );
+        assert!(matches("a * 2 > 10 && a % 2 == 1", record));
+    }
+
+    #[test]
+    fn test_concatenation_and_merge() {
+        let record = json!({"first": "Kim", "last": "Lee", "age": 30, "tags": ["a"]});
+        assert_eq!(eval("first + ' ' + last", record.clone()), json!("Kim Lee"));
+        assert_eq!(eval("'age: ' + age", record.clone()), json!("age: 30"));
+        assert_eq!(eval("missing + last", record.clone()), json!("Lee"));
+        assert_eq!(eval("tags + ['b']", record.clone()), json!(["a", "b"]));
+        assert_eq!(
+            eval(
+                ". + {age: age + 1, adult: true}",
+                json!({"id": 1, "age": 17})
+            ),
+            json!({"id": 1, "age": 18, "adult": true})
+        );
+    }
+
+    #[test]
+    fn test_objects_arrays_and_conditionals() {
+        let record = json!({"id": 1, "user": {"name": "kim", "age": 15}, "end": 9});
+        assert_eq!(
+            eval(
+                "{id, user.name, \"user age\": .user.age, pair: [id, .end]}",
+                record.clone()
+            ),
+            json!({"id": 1, "name": "kim", "user age": 15, "pair": [1, 9]})
+        );
+        assert_eq!(
+            eval(
+                "if user.age >= 18 then 'adult' elif user.age >= 13 then 'teen' else 'child' end",
+                record.clone()
+            ),
+            json!("teen")
+        );
+        assert_eq!(eval("{}", record.clone()), json!({}));
+        assert_eq!(eval("[]", record), json!([]));
+    }
+
+    #[test]
+    fn test_functions() {
+        let record = json!({"name": "  Kim ", "tags": ["a", "b", null], "n": "42", "f": "1.5"});
+        assert_eq!(eval("upper(trim(name))", record.clone()), json!("KIM"));
+        assert_eq!(eval("lower('ABC')", record.clone()), json!("abc"));
+        assert_eq!(eval("length(tags)", record.clone()), json!(3));
+        assert_eq!(eval("length(name)", record.clone()), json!(6));
+        assert_eq!(eval("tonumber(n) + 1", record.clone()), json!(43));
+        assert_eq!(eval("tonumber(f)", record.clone()), json!(1.5));
+        assert_eq!(eval("tonumber(name)", record.clone()), Value::Null);
+        assert_eq!(
+            eval("tostring(tags)", record.clone()),
+            json!("[\"a\",\"b\",null]")
+        );
+        assert_eq!(eval("coalesce(missing, n, 0)", record.clone()), json!("42"));
+        assert_eq!(eval("join(tags, ',')", record), json!("a,b"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Expr::parse("status == ").is_err());
+        assert!(Expr::parse("(a == 1").is_err());
+        assert!(Expr::parse("a == 'open").is_err());
+        assert!(Expr::parse("a = 1").is_err());
+        assert!(Expr::parse("a == 1 b").is_err());
+        assert!(Expr::parse("nope(a)").is_err());
+        assert!(Expr::parse("lower(a, b)").is_err());
+        assert!(Expr::parse("if a then b end").is_err());
+        assert!(Expr::parse("{a: 1").is_err());
+        assert!(Expr::parse("{'key'}").is_err());
+        assert!(Expr::parse("[1, 2").is_err());
+        assert!(Expr::parse("then").is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! 필드 순서 모듈
+//!
+//! `--field-order "id,timestamp,*"`로 지정한 최상위 필드를 모든 출력 레코드의 앞(또는 `*` 뒤에 쓰면 끝)에
+//! 지정한 순서대로 배치합니다. 사람이 읽거나 첫 레코드로 열 순서를 정하는 로더가 다루기 쉬워집니다.
+//!
+//! 순서는 직렬화 단계에서 적용하므로 `preserve_order` 기능 없이 빌드해도 동작합니다.
+//! 나머지 필드(`*`)는 `--key-order`에 따른 원래 순서를 따릅니다.
+
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// 나머지 필드 자리 표시
+const REST: &str = "*";
+
+/// 출력 레코드의 최상위 필드 순서
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldOrder {
+    /// 맨 앞에 둘 필드 (`*` 앞)
+    head: Vec<String>,
+    /// 맨 끝에 둘 필드 (`*` 뒤)
+    tail: Vec<String>,
+}
+
+impl FieldOrder {
+    /// 필드 순서 파싱
+    ///
+    /// 쉼표로 구분한 필드 이름과 나머지 필드를 뜻하는 `*` 하나로 이루어집니다.
+    /// `*`가 없으면 나머지 필드는 지정한 필드 뒤에 옵니다.
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::FieldOrder;
+    /// use serde_json::json;
+    ///
+    /// let order = FieldOrder::parse("id,timestamp,*,_source_file").unwrap();
+    /// let record = json!({"_source_file": "a.json", "name": "kim", "timestamp": 7, "id": 1});
+    /// assert_eq!(
+    ///     serde_json::to_string(&order.apply(&record)).unwrap(),
+    ///     r#"{"id":1,"timestamp":7,"name":"kim","_source_file":"a.json"}"#
+    /// );
+    ///
+    /// assert!(FieldOrder::parse("id,*,*").is_err());
+    /// ```
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut head = Vec::new();
+        let mut tail = Vec::new();
+        let mut seen_rest = false;
+
+        for field in spec.split(',').map(str::trim) {
+            if field.is_empty() {
+                return Err(format!("'{}'에 빈 필드 이름이 있습니다", spec));
+            }
+            if field == REST {
+                if seen_rest {
+                    return Err("'*'는 한 번만 쓸 수 있습니다".to_string());
+                }
+                seen_rest = true;
+                continue;
+            }
+            if head.iter().chain(&tail).any(|f: &String| f == field) {
+                return Err(format!("'{}' 필드가 두 번 지정되었습니다", field));
+            }
+            if seen_rest {
+                tail.push(field.to_string());
+            } else {
+                head.push(field.to_string());
+            }
+        }
+
+        if head.is_empty() && tail.is_empty() {
+            return Err("순서를 정할 필드를 하나 이상 지정해야 합니다".to_string());
+        }
+        Ok(Self { head, tail })
+    }
+
+    /// 값을 이 순서로 직렬화하는 래퍼
+    ///
+    /// 최상위 값이 배열이면 각 요소 객체에 순서를 적용하고, 객체가 아닌 값은 그대로 직렬화합니다.
+    pub fn apply<'a>(&'a self, value: &'a Value) -> Ordered<'a> {
+        Ordered { order: self, value }
+    }
+
+    /// 지정한 필드인지 확인
+    fn is_listed(&self, key: &str) -> bool {
+        self.head.iter().chain(&self.tail).any(|field| field == key)
+    }
+
+    /// 객체를 이 순서로 직렬화
+    fn serialize_object<S: Serializer>(
+        &self,
+        map: &Map<String, Value>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_map(Some(map.len()))?;
+        for key in &self.head {
+            if let Some(value) = map.get(key) {
+                state.serialize_entry(key, value)?;
+            }
+        }
+        for (key, value) in map {
+            if !self.is_listed(key) {
+                state.serialize_entry(key, value)?;
+            }
+        }
+        for key in &self.tail {
+            if let Some(value) = map.get(key) {
+                state.serialize_entry(key, value)?;
+            }
+        }
+        state.end()
+    }
+}
+
+impl fmt::Display for FieldOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields: Vec<&str> = self
+            .head
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once(REST))
+            .chain(self.tail.iter().map(String::as_str))
+            .collect();
+        write!(f, "{}", fields.join(", "))
+    }
+}
+
+/// 필드 순서를 적용하여 직렬화하는 값
+#[derive(Debug, Clone, Copy)]
+pub struct Ordered<'a> {
+    order: &'a FieldOrder,
+    value: &'a Value,
+}
+
+impl Serialize for Ordered<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.value {
+            Value::Object(map) => self.order.serialize_object(map, serializer),
+            Value::Array(items) => {
+                let mut state = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    match item {
+                        Value::Object(map) => state.serialize_element(&OrderedObject {
+                            order: self.order,
+                            map,
+                        })?,
+                        other => state.serialize_element(other)?,
+                    }
+                }
+                state.end()
+            }
+            other => other.serialize(serializer),
+        }
+    }
+}
+
+/// 배열 요소 객체 (배열 안의 배열에는 순서를 적용하지 않음)
+struct OrderedObject<'a> {
+    order: &'a FieldOrder,
+    map: &'a Map<String, Value>,
+}
+
+impl Serialize for OrderedObject<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.order.serialize_object(self.map, serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn ordered(spec: &str, value: &Value) -> String {
+        serde_json::to_string(&FieldOrder::parse(spec).unwrap().apply(value)).unwrap()
+    }
+
+    #[test]
+    fn test_listed_fields_first_and_last() {
+        let record = json!({"a": 1, "b": 2, "id": 9, "z": 0});
+        assert_eq!(ordered("id", &record), r#"{"id":9,"a":1,"b":2,"z":0}"#);
+        assert_eq!(ordered("z,*,a", &record), r#"{"z":0,"b":2,"id":9,"a":1}"#);
+        assert_eq!(ordered("*,id", &record), r#"{"a":1,"b":2,"z":0,"id":9}"#);
+        // 없는 필드는 건너뜀
+        assert_eq!(
+            ordered("missing,id,*", &record),
+            r#"{"id":9,"a":1,"b":2,"z":0}"#
+        );
+    }
+
+    #[test]
+    fn test_array_records_and_scalars() {
+        let document = json!([{"b": 1, "id": 1}, [{"b": 2, "id": 2}], 3]);
+        assert_eq!(
+            ordered("id", &document),
+            r#"[{"id":1,"b":1},[{"b":2,"id":2}],3]"#
+        );
+        assert_eq!(ordered("id", &json!("text")), r#""text""#);
+    }
+
+    #[test]
+    fn test_parse_errors_and_display() {
+        assert!(FieldOrder::parse("id,,name").is_err());
+        assert!(FieldOrder::parse("*").is_err());
+        assert!(FieldOrder::parse("id,*,id").is_err());
+        assert_eq!(
+            FieldOrder::parse(" id , ts ,*,src").unwrap().to_string(),
+            "id, ts, *, src"
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! 파일 메타데이터 필터 모듈
+//!
+//! 입력 파일을 크기와 수정 시각으로 거릅니다 (`--min-size`, `--max-size`, `--newer-than`, `--older-than`).
+//! `find -size`/`-mtime`으로 미리 고르지 않아도 최근 파일이나 적당한 크기의 파일만 병합할 수 있습니다.
+
+use std::fs::Metadata;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 크기와 수정 시각 기준 입력 파일 필터
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FileFilter {
+    /// 최소 파일 크기 (바이트, 포함)
+    pub min_size: Option<u64>,
+    /// 최대 파일 크기 (바이트, 포함)
+    pub max_size: Option<u64>,
+    /// 이 시각 이후에 수정된 파일만
+    pub newer_than: Option<SystemTime>,
+    /// 이 시각 이전에 수정된 파일만
+    pub older_than: Option<SystemTime>,
+}
+
+impl FileFilter {
+    /// 조건이 하나라도 설정되어 있는지 확인
+    pub fn is_enabled(&self) -> bool {
+        self.min_size.is_some()
+            || self.max_size.is_some()
+            || self.newer_than.is_some()
+            || self.older_than.is_some()
+    }
+
+    /// 수정 시각 조건이 있는지 확인
+    fn checks_mtime(&self) -> bool {
+        self.newer_than.is_some() || self.older_than.is_some()
+    }
+
+    /// 파일 크기와 수정 시각이 조건에 맞는지 확인
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::file_filter::FileFilter;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let filter = FileFilter {
+    ///     max_size: Some(1024),
+    ///     newer_than: Some(UNIX_EPOCH + Duration::from_secs(100)),
+    ///     ..Default::default()
+    /// };
+    /// assert!(filter.matches(512, Some(UNIX_EPOCH + Duration::from_secs(200))));
+    /// assert!(!filter.matches(2048, Some(UNIX_EPOCH + Duration::from_secs(200))));
+    /// assert!(!filter.matches(512, Some(UNIX_EPOCH + Duration::from_secs(50))));
+    /// ```
+    pub fn matches(&self, size: u64, modified: Option<SystemTime>) -> bool {
+        if self.min_size.is_some_and(|min| size < min)
+            || self.max_size.is_some_and(|max| size > max)
+        {
+            return false;
+        }
+        if !self.checks_mtime() {
+            return true;
+        }
+
+        // 수정 시각을 알 수 없는 파일은 시각 조건이 있으면 제외
+        let Some(modified) = modified else {
+            return false;
+        };
+        self.newer_than.is_none_or(|time| modified > time)
+            && self.older_than.is_none_or(|time| modified < time)
+    }
+
+    /// 파일 메타데이터가 조건에 맞는지 확인
+    pub fn matches_metadata(&self, metadata: &Metadata) -> bool {
+        self.matches(metadata.len(), metadata.modified().ok())
+    }
+}
+
+/// 시각 문자열 파싱
+///
+/// 현재 시각 기준 상대 기간(`30m`, `12h`, `7d`, `2w`, 단위 `s`/`m`/`h`/`d`/`w`)이나
+/// UTC 날짜/시각(`2024-01-31`, `2024-01-31T12:00:00`)을 지원합니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::file_filter::parse_time;
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// assert_eq!(
+///     parse_time("1970-01-02"),
+///     Ok(UNIX_EPOCH + Duration::from_secs(86_400))
+/// );
+/// assert!(parse_time("7d").unwrap() < SystemTime::now());
+/// assert!(parse_time("2024-13-01").is_err());
+/// ```
+pub fn parse_time(text: &str) -> Result<SystemTime, String> {
+    let text = text.trim();
+    if text.contains('-') {
+        return parse_datetime(text);
+    }
+
+    let split = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+    let number: u64 = number
+        .parse()
+        .map_err(|_| format!("잘못된 시각 값: {}", text))?;
+    let unit_secs = match unit.trim().to_ascii_lowercase().as_str() {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        "" => return Err(format!("기간 단위가 필요합니다 (s, m, h, d, w): {}", text)),
+        other => return Err(format!("알 수 없는 기간 단위: {}", other)),
+    };
+
+    SystemTime::now()
+        .checked_sub(Duration::from_secs(number.saturating_mul(unit_secs)))
+        .ok_or_else(|| format!("기간이 너무 깁니다: {}", text))
+}
+
+/// UTC 날짜(`YYYY-MM-DD`) 또는 날짜/시각(`YYYY-MM-DDTHH:MM:SS`) 파싱
+fn parse_datetime(text: &str) -> Result<SystemTime, String> {
+    let invalid = || format!("잘못된 날짜 형식 (YYYY-MM-DD[THH:MM:SS]): {}", text);
+
+    let (date, time) = match text.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (text, None),
+    };
+
+    let parts: Vec<&str> = date.split('-').collect();
+    let [year, month, day] = parts[..] else {
+        return Err(invalid());
+    };
+    let year: i64 = year.parse().map_err(|_| invalid())?;
+    let month: u32 = month.parse().map_err(|_| invalid())?;
+    let day: u32 = day.parse().map_err(|_| invalid())?;
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(invalid());
+    }
+
+    let seconds_of_day = match time {
+        Some(time) => {
+            let parts: Vec<&str> = time.trim_end_matches('Z').split(':').collect();
+            let (hour, minute, second) = match parts[..] {
+                [hour, minute] => (hour, minute, "0"),
+                [hour, minute, second] => (hour, minute, second),
+                _ => return Err(invalid()),
+            };
+            let hour: i64 = hour.parse().map_err(|_| invalid())?;
+            let minute: i64 = minute.parse().map_err(|_| invalid())?;
+            let second: i64 = second.parse().map_err(|_| invalid())?;
+            if hour > 23 || minute > 59 || second > 59 {
+                return Err(invalid());
+            }
+            hour * 3600 + minute * 60 + second
+        }
+        None => 0,
+    };
+
+    let secs = days_from_civil(year, month, day) * 86_400 + seconds_of_day;
+    let offset = Duration::from_secs(secs.unsigned_abs());
+    let time = if secs >= 0 {
+        UNIX_EPOCH.checked_add(offset)
+    } else {
+        UNIX_EPOCH.checked_sub(offset)
+    };
+    time.ok_or_else(invalid)
+}
+
+/// 윤년 여부
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// 해당 월의 일 수
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 1970-01-01부터의 일 수 (그레고리력)
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // 3월을 한 해의 시작으로 보면 윤일이 해의 마지막 날이 되어 계산이 단순해짐
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = (i64::from(month) + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_dates() {
+        let at = |secs: u64| Ok(UNIX_EPOCH + Duration::from_secs(secs));
+        assert_eq!(parse_time("1970-01-01"), at(0));
+        assert_eq!(parse_time("2000-03-01"), at(951_868_800));
+        assert_eq!(parse_time("2024-02-29T12:30:15"), at(1_709_209_815));
+        assert_eq!(parse_time("2024-02-29 12:30"), at(1_709_209_800));
+        assert_eq!(parse_time("2024-02-29T12:30:15Z"), at(1_709_209_815));
+
+        assert!(parse_time("2023-02-29").is_err());
+        assert!(parse_time("2024-01-01T24:00").is_err());
+        assert!(parse_time("2024-01").is_err());
+        assert!(parse_time("7").is_err());
+        assert!(parse_time("7y").is_err());
+    }
+
+    #[test]
+    fn test_parse_time_relative() {
+        let now = SystemTime::now();
+        let week_ago = parse_time("1w").unwrap();
+        let elapsed = now.duration_since(week_ago).unwrap_or_default();
+        assert!(elapsed.as_secs() <= 7 * 24 * 3600);
+        assert!(elapsed.as_secs() >= 7 * 24 * 3600 - 5);
+    }
+
+    #[test]
+    fn test_file_filter_ranges() {
+        let filter = FileFilter {
+            min_size: Some(10),
+            max_size: Some(100),
+            ..Default::default()
+        };
+        assert!(filter.is_enabled());
+        assert!(filter.matches(10, None));
+        assert!(filter.matches(100, None));
+        assert!(!filter.matches(9, None));
+        assert!(!filter.matches(101, None));
+
+        let time = |secs: u64| Some(UNIX_EPOCH + Duration::from_secs(secs));
+        let filter = FileFilter {
+            newer_than: time(100),
+            older_than: time(200),
+            ..Default::default()
+        };
+        assert!(filter.matches(0, time(150)));
+        assert!(!filter.matches(0, time(100)));
+        assert!(!filter.matches(0, time(200)));
+        assert!(!filter.matches(0, None));
+        assert!(!FileFilter::default().is_enabled());
+    }
+}
@@ -0,0 +1,183 @@
+//! 품질 기준 모듈
+//!
+//! `--min-success-rate 99.5`, `--max-error-count 10`으로 이번 실행이 지켜야 할 데이터 품질 기준을 정합니다.
+//! 기준을 넘으면 실패 파일이 없더라도 종료 코드 1로 끝나 CI에서 데이터 품질 검문으로 쓸 수 있습니다.
+//! 기준은 종료 상태를 올리기만 하므로, 기준을 지켜도 실패 파일이 있으면 종료 코드 1은 그대로입니다.
+
+use serde::Serialize;
+
+use crate::report::RunReport;
+
+/// 품질 기준 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Gate {
+    /// 최소 성공률 (`--min-success-rate`)
+    MinSuccessRate,
+    /// 최대 실패 파일 수 (`--max-error-count`)
+    MaxErrorCount,
+}
+
+/// 품질 기준 하나의 평가 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GateResult {
+    /// 기준 종류
+    pub gate: Gate,
+    /// 기준 값
+    pub limit: f64,
+    /// 이번 실행의 값
+    pub actual: f64,
+    /// 기준을 지켰는지
+    pub passed: bool,
+}
+
+/// 실행 품질 기준
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct QualityGates {
+    /// 최소 성공률 (%, 성공 파일 / 전체 파일)
+    pub min_success_rate: Option<f64>,
+    /// 허용하는 최대 실패 파일 수
+    pub max_error_count: Option<usize>,
+    /// 읽기 권한이 없는 파일을 실패로 세지 않음 (`--skip-unreadable`)
+    pub skip_unreadable: bool,
+}
+
+impl QualityGates {
+    /// 기준이 하나라도 지정되었는지 확인
+    pub fn is_enabled(&self) -> bool {
+        self.min_success_rate.is_some() || self.max_error_count.is_some()
+    }
+
+    /// 실행 보고서로 기준 평가
+    ///
+    /// 실패 파일은 에러, 유효성 검사 실패, (건너뛰지 않은) 읽기 권한 없는 파일을 합친 수입니다.
+    /// 처리한 파일이 없으면 성공률 기준은 평가하지 않습니다.
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::{QualityGates, RunMode, RunReport, Statistics};
+    ///
+    /// let stats = Statistics::new(200);
+    /// for _ in 0..199 {
+    ///     stats.increment_success();
+    /// }
+    /// stats.increment_error();
+    ///
+    /// let gates = QualityGates {
+    ///     min_success_rate: Some(99.0),
+    ///     max_error_count: Some(0),
+    ///     ..Default::default()
+    /// };
+    /// let results = gates.check(&RunReport::new(RunMode::Convert, &stats));
+    /// assert!(results[0].passed); // 99.5% >= 99%
+    /// assert!(!results[1].passed); // 실패 1개 > 0개
+    /// ```
+    pub fn check(&self, report: &RunReport) -> Vec<GateResult> {
+        let mut results = Vec::new();
+
+        if let Some(limit) = self.min_success_rate {
+            if report.total_files > 0 {
+                let actual = report.success as f64 / report.total_files as f64 * 100.0;
+                results.push(GateResult {
+                    gate: Gate::MinSuccessRate,
+                    limit,
+                    actual,
+                    passed: actual >= limit,
+                });
+            }
+        }
+
+        if let Some(limit) = self.max_error_count {
+            let actual = self.failed_files(report);
+            results.push(GateResult {
+                gate: Gate::MaxErrorCount,
+                limit: limit as f64,
+                actual: actual as f64,
+                passed: actual <= limit,
+            });
+        }
+        results
+    }
+
+    /// 실패 파일 수
+    fn failed_files(&self, report: &RunReport) -> usize {
+        let unreadable = if self.skip_unreadable {
+            0
+        } else {
+            report.unreadable
+        };
+        report.errors + report.validation_failed + unreadable
+    }
+}
+
+/// 성공률 파싱 (`99.5`, `99.5%`, 0~100)
+pub fn parse_success_rate(text: &str) -> Result<f64, String> {
+    let number = text.trim().trim_end_matches('%').trim();
+    match number.parse::<f64>() {
+        Ok(value) if (0.0..=100.0).contains(&value) => Ok(value),
+        _ => Err(format!(
+            "성공률은 0~100 사이의 백분율이어야 합니다 (예: 99.5): {}",
+            text
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::RunMode;
+    use crate::stats::Statistics;
+
+    fn report(total: usize, success: usize, errors: usize, unreadable: usize) -> RunReport {
+        let stats = Statistics::new(total);
+        (0..success).for_each(|_| stats.increment_success());
+        (0..errors).for_each(|_| stats.increment_error());
+        (0..unreadable).for_each(|_| stats.increment_unreadable());
+        RunReport::new(RunMode::Convert, &stats)
+    }
+
+    #[test]
+    fn test_disabled_gates() {
+        let gates = QualityGates::default();
+        assert!(!gates.is_enabled());
+        assert!(gates.check(&report(10, 0, 10, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_error_count_and_unreadable() {
+        let gates = QualityGates {
+            max_error_count: Some(2),
+            ..Default::default()
+        };
+        let results = gates.check(&report(10, 7, 2, 1));
+        assert_eq!(results[0].actual, 3.0);
+        assert!(!results[0].passed);
+
+        let gates = QualityGates {
+            skip_unreadable: true,
+            ..gates
+        };
+        assert!(gates.check(&report(10, 7, 2, 1))[0].passed);
+    }
+
+    #[test]
+    fn test_success_rate_boundary_and_empty_run() {
+        let gates = QualityGates {
+            min_success_rate: Some(90.0),
+            ..Default::default()
+        };
+        assert!(gates.check(&report(10, 9, 1, 0))[0].passed);
+        assert!(!gates.check(&report(10, 8, 2, 0))[0].passed);
+        // 처리한 파일이 없으면 평가하지 않음
+        assert!(gates.check(&report(0, 0, 0, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_parse_success_rate() {
+        assert_eq!(parse_success_rate("99.5"), Ok(99.5));
+        assert_eq!(parse_success_rate("100%"), Ok(100.0));
+        assert!(parse_success_rate("100.1").is_err());
+        assert!(parse_success_rate("-1").is_err());
+        assert!(parse_success_rate("high").is_err());
+    }
+}
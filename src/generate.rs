@@ -0,0 +1,394 @@
+//! 테스트 데이터 생성 모듈
+//!
+//! JSON Schema의 일부를 해석하여 스키마에 맞는 가짜 JSON 파일을 만듭니다.
+//! 실제 운영 데이터 없이 파이프라인을 벤치마크하거나 시험할 때 사용합니다.
+//!
+//! # 지원하는 스키마 키워드
+//!
+//! - `type`: `object`, `array`, `string`, `integer`, `number`, `boolean`, `null`
+//!   (배열로 여러 타입을 지정하면 그중 하나를 선택)
+//! - `object`: `properties`, `required` (`required`가 있으면 나머지 필드는 절반 확률로 생략)
+//! - `array`: `items`, `minItems`, `maxItems`
+//! - `string`: `minLength`, `maxLength`, `format` (`date-time`, `date`, `email`, `uuid`)
+//! - `integer`/`number`: `minimum`, `maximum`
+//! - 모든 타입: `enum`, `const`
+//!
+//! ```json
+//! {
+//!   "type": "object",
+//!   "required": ["id", "status"],
+//!   "properties": {
+//!     "id": { "type": "integer", "minimum": 1, "maximum": 1000000 },
+//!     "status": { "enum": ["active", "inactive"] },
+//!     "user": {
+//!       "type": "object",
+//!       "properties": { "email": { "type": "string", "format": "email" } }
+//!     },
+//!     "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 3 }
+//!   }
+//! }
+//! ```
+
+use serde_json::{Map, Number, Value};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::{JConvertError, Result};
+use crate::rng::Rng;
+
+/// 지원하는 값 타입
+const SUPPORTED_TYPES: &[&str] = &[
+    "object", "array", "string", "integer", "number", "boolean", "null",
+];
+
+/// 문자열 생성에 사용하는 문자
+const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+/// 스키마 기반 테스트 데이터 생성기
+#[derive(Debug, Clone)]
+pub struct Generator {
+    schema: Value,
+}
+
+impl Generator {
+    /// 스키마로 생성기 생성 (지원하지 않는 타입이나 잘못된 범위는 에러)
+    pub fn new(schema: Value) -> Result<Self> {
+        check_schema(&schema, "$")?;
+        Ok(Self { schema })
+    }
+
+    /// 스키마 파일을 읽어 생성기 생성
+    pub fn load(path: &Path) -> Result<Self> {
+        let invalid = |reason: String| JConvertError::InvalidSchema {
+            reason: format!("{}: {}", path.display(), reason),
+        };
+        let file = File::open(path).map_err(|e| invalid(e.to_string()))?;
+        let schema: Value =
+            serde_json::from_reader(BufReader::new(file)).map_err(|e| invalid(e.to_string()))?;
+        Self::new(schema)
+    }
+
+    /// 스키마에 맞는 값 하나 생성
+    pub fn generate(&self, rng: &mut Rng) -> Value {
+        generate_value(&self.schema, rng)
+    }
+
+    /// 테스트 파일 하나 작성
+    ///
+    /// 레코드가 하나면 객체 하나를, 여러 개면 배열을 기록합니다.
+    /// 같은 시드와 번호로는 항상 같은 내용이 만들어집니다.
+    ///
+    /// # Returns
+    /// 기록한 바이트 수
+    pub fn write_file(&self, path: &Path, seed: u64, index: u64, records: u64) -> Result<u64> {
+        let mut rng = Rng::derive(seed, index);
+        let value = if records == 1 {
+            self.generate(&mut rng)
+        } else {
+            Value::Array((0..records).map(|_| self.generate(&mut rng)).collect())
+        };
+
+        let write_error = |e: std::io::Error| JConvertError::WriteError {
+            reason: format!("{}: {}", path.display(), e),
+        };
+        let json = serde_json::to_string(&value).map_err(|e| JConvertError::SerializeError {
+            file: path.to_path_buf(),
+            reason: e.to_string(),
+        })?;
+        let mut writer = BufWriter::new(File::create(path).map_err(write_error)?);
+        writer.write_all(json.as_bytes()).map_err(write_error)?;
+        writer.flush().map_err(write_error)?;
+
+        Ok(json.len() as u64)
+    }
+}
+
+/// 생성할 파일 경로 (`dir/record_000001.json`, 번호는 1부터)
+///
+/// 번호 자릿수는 최소 6자리이며 파일 수가 더 많으면 늘어나 이름순 정렬이 유지됩니다.
+pub fn generated_path(dir: &Path, index: u64, count: u64) -> PathBuf {
+    let width = count.to_string().len().max(6);
+    dir.join(format!("record_{:0width$}.json", index + 1, width = width))
+}
+
+/// 스키마 검사 (생성 전에 한 번 수행하여 생성 중에는 실패하지 않도록 함, 레코드 스키마 필터도 사용)
+pub(crate) fn check_schema(schema: &Value, path: &str) -> Result<()> {
+    let invalid = |reason: String| JConvertError::InvalidSchema {
+        reason: format!("{}: {}", path, reason),
+    };
+
+    let Some(object) = schema.as_object() else {
+        return Err(invalid("스키마는 객체여야 합니다".to_string()));
+    };
+    if let Some(values) = object.get("enum") {
+        return match values.as_array() {
+            Some(values) if !values.is_empty() => Ok(()),
+            _ => Err(invalid(
+                "enum은 비어 있지 않은 배열이어야 합니다".to_string(),
+            )),
+        };
+    }
+    if object.contains_key("const") {
+        return Ok(());
+    }
+
+    for type_name in schema_types(schema) {
+        if !SUPPORTED_TYPES.contains(&type_name) {
+            return Err(invalid(format!("지원하지 않는 타입: {}", type_name)));
+        }
+    }
+    if let Some(min) = object.get("minimum").and_then(Value::as_f64) {
+        if object
+            .get("maximum")
+            .and_then(Value::as_f64)
+            .is_some_and(|max| max < min)
+        {
+            return Err(invalid("maximum이 minimum보다 작습니다".to_string()));
+        }
+    }
+
+    if let Some(properties) = object.get("properties").and_then(Value::as_object) {
+        for (key, child) in properties {
+            check_schema(child, &format!("{}.{}", path, key))?;
+        }
+    }
+    if let Some(items) = object.get("items") {
+        check_schema(items, &format!("{}[]", path))?;
+    }
+    Ok(())
+}
+
+/// 스키마의 타입 목록 (`type`이 없으면 `properties`/`items`로 추정, 그마저 없으면 문자열)
+fn schema_types(schema: &Value) -> Vec<&str> {
+    match schema.get("type") {
+        Some(Value::String(name)) => vec![name.as_str()],
+        Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).collect(),
+        _ if schema.get("properties").is_some() => vec!["object"],
+        _ if schema.get("items").is_some() => vec!["array"],
+        _ => vec!["string"],
+    }
+}
+
+/// 스키마에 맞는 값 생성
+fn generate_value(schema: &Value, rng: &mut Rng) -> Value {
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        return rng.choose(values).cloned().unwrap_or(Value::Null);
+    }
+    if let Some(value) = schema.get("const") {
+        return value.clone();
+    }
+
+    let types = schema_types(schema);
+    let type_name = rng.choose(&types).copied().unwrap_or("null");
+    let int = |key: &str, default: i64| schema.get(key).and_then(Value::as_i64).unwrap_or(default);
+
+    match type_name {
+        "object" => generate_object(schema, rng),
+        "array" => {
+            let min = int("minItems", 0).max(0);
+            let max = int("maxItems", min.max(5)).max(min);
+            let items = schema.get("items").cloned().unwrap_or(Value::Null);
+            let len = rng.range(min, max);
+            Value::Array(
+                (0..len)
+                    .map(|_| {
+                        if items.is_object() {
+                            generate_value(&items, rng)
+                        } else {
+                            Value::Null
+                        }
+                    })
+                    .collect(),
+            )
+        }
+        "string" => Value::String(generate_string(schema, rng)),
+        "integer" => {
+            let (min, max) = numeric_bounds(schema);
+            Value::from(rng.range(min.ceil() as i64, max.floor() as i64))
+        }
+        "number" => {
+            let (min, max) = numeric_bounds(schema);
+            let value = ((min + rng.next_f64() * (max - min)) * 100.0).round() / 100.0;
+            Number::from_f64(value.clamp(min, max))
+                .map(Value::Number)
+                .unwrap_or(Value::Null)
+        }
+        "boolean" => Value::Bool(rng.chance(0.5)),
+        _ => Value::Null,
+    }
+}
+
+/// `minimum`/`maximum` 범위 (한쪽만 있으면 다른 쪽은 1000 떨어진 값, 둘 다 없으면 0~1000)
+fn numeric_bounds(schema: &Value) -> (f64, f64) {
+    let min = schema.get("minimum").and_then(Value::as_f64);
+    let max = schema.get("maximum").and_then(Value::as_f64);
+    match (min, max) {
+        (Some(min), Some(max)) => (min, max),
+        (Some(min), None) => (min, min + 1000.0),
+        (None, Some(max)) if max >= 0.0 => (0.0, max),
+        (None, Some(max)) => (max - 1000.0, max),
+        (None, None) => (0.0, 1000.0),
+    }
+}
+
+/// 객체 생성 (`required`에 없는 필드는 절반 확률로 생략)
+fn generate_object(schema: &Value, rng: &mut Rng) -> Value {
+    let required: Option<Vec<&str>> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|keys| keys.iter().filter_map(Value::as_str).collect());
+
+    let mut map = Map::new();
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        for (key, child) in properties {
+            let include = match required {
+                Some(ref required) => required.contains(&key.as_str()) || rng.chance(0.5),
+                None => true,
+            };
+            if include {
+                map.insert(key.clone(), generate_value(child, rng));
+            }
+        }
+    }
+    Value::Object(map)
+}
+
+/// 문자열 생성 (`format`이 있으면 해당 형식을 따름)
+fn generate_string(schema: &Value, rng: &mut Rng) -> String {
+    match schema.get("format").and_then(Value::as_str) {
+        Some("date-time") => format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            rng.range(2000, 2030),
+            rng.range(1, 12),
+            rng.range(1, 28),
+            rng.range(0, 23),
+            rng.range(0, 59),
+            rng.range(0, 59)
+        ),
+        Some("date") => format!(
+            "{:04}-{:02}-{:02}",
+            rng.range(2000, 2030),
+            rng.range(1, 12),
+            rng.range(1, 28)
+        ),
+        Some("email") => {
+            let user = random_word(rng, 8);
+            let domain = random_word(rng, 6);
+            format!("{}@{}.com", user, domain)
+        }
+        Some("uuid") => {
+            let (a, b) = (rng.next_u64(), rng.next_u64());
+            format!(
+                "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+                a >> 32,
+                (a >> 16) & 0xffff,
+                a & 0xfff,
+                (b >> 48) & 0x3fff | 0x8000,
+                b & 0xffff_ffff_ffff
+            )
+        }
+        _ => {
+            let min = schema
+                .get("minLength")
+                .and_then(Value::as_i64)
+                .unwrap_or(5)
+                .max(0);
+            let max = schema
+                .get("maxLength")
+                .and_then(Value::as_i64)
+                .unwrap_or(min.max(12))
+                .max(min);
+            let len = rng.range(min, max);
+            random_word(rng, len)
+        }
+    }
+}
+
+/// 소문자 알파벳으로 된 무작위 단어
+fn random_word(rng: &mut Rng, len: i64) -> String {
+    (0..len)
+        .map(|_| *rng.choose(ALPHABET).unwrap_or(&b'a') as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{FieldSchema, JsonType};
+    use serde_json::json;
+
+    fn sample_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer", "minimum": 1, "maximum": 10 },
+                "status": { "enum": ["active", "inactive"] },
+                "email": { "type": "string", "format": "email" },
+                "tags": { "type": "array", "items": { "type": "string" }, "minItems": 1, "maxItems": 3 }
+            }
+        })
+    }
+
+    #[test]
+    fn test_generate_matches_schema() {
+        let generator = Generator::new(sample_schema()).unwrap();
+        let mut rng = Rng::new(3);
+        let mut schema = FieldSchema::new();
+        for _ in 0..50 {
+            let value = generator.generate(&mut rng);
+            let id = value["id"].as_i64().unwrap();
+            assert!((1..=10).contains(&id));
+            assert!(["active", "inactive"].contains(&value["status"].as_str().unwrap()));
+            assert!(value["email"].as_str().unwrap().contains('@'));
+            assert!((1..=3).contains(&value["tags"].as_array().unwrap().len()));
+            schema.add_record(&value);
+        }
+        assert!(schema.contains("tags[]", JsonType::String));
+    }
+
+    #[test]
+    fn test_generate_is_deterministic() {
+        let generator = Generator::new(sample_schema()).unwrap();
+        let first = generator.generate(&mut Rng::derive(9, 4));
+        let second = generator.generate(&mut Rng::derive(9, 4));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_invalid_schema() {
+        let error = Generator::new(json!({
+            "properties": { "user": { "properties": { "age": { "type": "decimal" } } } }
+        }))
+        .unwrap_err();
+        assert!(error.to_string().contains("$.user.age"));
+
+        assert!(Generator::new(json!({ "enum": [] })).is_err());
+        assert!(Generator::new(json!({ "type": "integer", "minimum": 5, "maximum": 1 })).is_err());
+    }
+
+    #[test]
+    fn test_one_sided_numeric_bounds() {
+        let mut rng = Rng::new(5);
+        let above = Generator::new(json!({ "type": "integer", "minimum": 5000 })).unwrap();
+        let below = Generator::new(json!({ "type": "number", "maximum": -10 })).unwrap();
+        for _ in 0..100 {
+            let value = above.generate(&mut rng).as_i64().unwrap();
+            assert!((5000..=6000).contains(&value));
+            assert!(below.generate(&mut rng).as_f64().unwrap() <= -10.0);
+        }
+    }
+
+    #[test]
+    fn test_generated_path() {
+        let dir = Path::new("fake");
+        assert_eq!(
+            generated_path(dir, 0, 10),
+            PathBuf::from("fake/record_000001.json")
+        );
+        assert_eq!(
+            generated_path(dir, 41, 1_000_000),
+            PathBuf::from("fake/record_0000042.json")
+        );
+    }
+}
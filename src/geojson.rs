@@ -0,0 +1,149 @@
+//! GeoJSON 처리 모듈
+//!
+//! `FeatureCollection` 문서를 Feature 하나당 레코드 하나로 펼칩니다 (`--geojson`).
+//! `--flatten-properties`를 지정하면 Feature의 `properties` 필드를 최상위로 올려
+//! 조건 필터, 필드 선택, 정렬에서 속성 이름을 바로 쓸 수 있게 합니다.
+
+use serde_json::{Map, Value};
+
+/// GeoJSON 파일 확장자 (`--geojson` 모드에서만 입력으로 수집)
+pub const GEOJSON_EXTENSION: &str = "geojson";
+
+/// 속성을 최상위로 올려도 덮어쓰지 않는 Feature 구성 키
+const FEATURE_KEYS: &[&str] = &["type", "id", "geometry", "bbox"];
+
+/// GeoJSON 문서를 Feature 목록으로 분리
+///
+/// `FeatureCollection`은 `features` 배열의 각 Feature를, `Feature` 하나뿐인 문서는 그 Feature를 반환합니다.
+/// GeoJSON Feature 문서가 아니면 원래 값을 그대로 돌려줍니다.
+///
+/// # Arguments
+/// * `json` - 파싱된 문서
+/// * `flatten` - Feature의 `properties`를 최상위로 올릴지 여부
+///
+/// # Examples
+/// ```
+/// use jconvert::geojson::split_features;
+/// use serde_json::json;
+///
+/// let collection = json!({
+///     "type": "FeatureCollection",
+///     "features": [
+///         {"type": "Feature", "geometry": null, "properties": {"name": "A"}},
+///         {"type": "Feature", "geometry": null, "properties": {"name": "B"}}
+///     ]
+/// });
+/// let features = split_features(collection, true).unwrap();
+/// assert_eq!(features[1], json!({"type": "Feature", "geometry": null, "name": "B"}));
+///
+/// assert!(split_features(json!({"id": 1}), true).is_err());
+/// ```
+pub fn split_features(json: Value, flatten: bool) -> Result<Vec<Value>, Value> {
+    let features = match json {
+        Value::Object(mut map) if is_type(&map, "FeatureCollection") => {
+            match map.remove("features") {
+                Some(Value::Array(features)) => features,
+                Some(other) => {
+                    // features가 배열이 아니면 GeoJSON으로 보지 않음
+                    map.insert("features".to_string(), other);
+                    return Err(Value::Object(map));
+                }
+                None => Vec::new(),
+            }
+        }
+        Value::Object(map) if is_type(&map, "Feature") => vec![Value::Object(map)],
+        other => return Err(other),
+    };
+
+    Ok(if flatten {
+        features.into_iter().map(flatten_properties).collect()
+    } else {
+        features
+    })
+}
+
+/// Feature의 `properties` 필드를 최상위로 올림
+///
+/// `type`, `id`, `geometry`, `bbox`나 이미 있는 최상위 키와 이름이 같은 속성은
+/// 값을 잃지 않도록 `properties` 안에 남겨 둡니다.
+fn flatten_properties(feature: Value) -> Value {
+    let Value::Object(mut map) = feature else {
+        return feature;
+    };
+    let Some(Value::Object(properties)) = map.remove("properties") else {
+        // properties가 null이거나 객체가 아니면 Feature 그대로
+        return Value::Object(map);
+    };
+
+    let mut remaining = Map::new();
+    for (key, value) in properties {
+        if FEATURE_KEYS.contains(&key.as_str()) || map.contains_key(&key) {
+            remaining.insert(key, value);
+        } else {
+            map.insert(key, value);
+        }
+    }
+    if !remaining.is_empty() {
+        map.insert("properties".to_string(), Value::Object(remaining));
+    }
+    Value::Object(map)
+}
+
+/// 객체의 `type` 필드가 주어진 GeoJSON 타입인지 확인
+fn is_type(map: &Map<String, Value>, kind: &str) -> bool {
+    map.get("type").and_then(Value::as_str) == Some(kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_split_feature_collection() {
+        let collection = json!({
+            "type": "FeatureCollection",
+            "crs": {"type": "name"},
+            "features": [
+                {"type": "Feature", "id": 1, "geometry": {"type": "Point", "coordinates": [127.0, 37.5]}, "properties": {"name": "서울"}},
+                {"type": "Feature", "id": 2, "geometry": null, "properties": null}
+            ]
+        });
+        let features = split_features(collection, false).unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[0]["properties"]["name"], "서울");
+
+        let empty = json!({"type": "FeatureCollection", "features": []});
+        assert!(split_features(empty, false).unwrap().is_empty());
+
+        // features가 배열이 아니면 원본 그대로
+        let broken = json!({"type": "FeatureCollection", "features": {}});
+        assert_eq!(split_features(broken.clone(), false), Err(broken));
+    }
+
+    #[test]
+    fn test_flatten_properties_keeps_colliding_keys() {
+        let feature = json!({
+            "type": "Feature",
+            "id": 7,
+            "geometry": null,
+            "properties": {"name": "A", "id": "p-7", "type": "park"}
+        });
+        assert_eq!(
+            split_features(feature, true).unwrap(),
+            vec![json!({
+                "type": "Feature",
+                "id": 7,
+                "geometry": null,
+                "name": "A",
+                "properties": {"id": "p-7", "type": "park"}
+            })]
+        );
+
+        let no_properties = json!({"type": "Feature", "geometry": null, "properties": null});
+        assert_eq!(
+            split_features(no_properties, true).unwrap(),
+            vec![json!({"type": "Feature", "geometry": null})]
+        );
+    }
+}
@@ -0,0 +1,370 @@
+//! 스키마 추론 모듈
+//!
+//! 변환하면서 모든 출력 레코드의 구조(필드 이름, 타입, null 여부, 예시 값, 출현 횟수)를 누적하여
+//! JSON Schema로 저장합니다 (`--infer-schema`). 처음 보는 데이터 덤프의 구조를 파악할 때 사용합니다.
+//!
+//! 만들어진 스키마는 표준 키워드(`type`, `properties`, `required`, `items`, `examples`)만으로
+//! 구조를 표현하므로 `--filter-schema`나 `jconvert generate`에 그대로 넣을 수 있고,
+//! 출현 횟수 같은 통계는 `x-` 접두사 키워드로 함께 기록합니다.
+
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use crate::error::{JConvertError, Result};
+
+/// 필드마다 남길 예시 값 수
+pub const MAX_EXAMPLES: usize = 3;
+
+/// 예시 문자열의 최대 길이 (문자 수, 넘으면 잘라냄)
+pub const MAX_EXAMPLE_CHARS: usize = 80;
+
+/// JSON Schema 방언
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// 추론에 사용하는 값 종류 (정수와 실수를 구분, null은 타입 목록 끝에 오도록 마지막)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ValueKind {
+    Boolean,
+    Integer,
+    Number,
+    String,
+    Array,
+    Object,
+    Null,
+}
+
+impl ValueKind {
+    /// JSON 값의 종류 판별
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => ValueKind::Null,
+            Value::Bool(_) => ValueKind::Boolean,
+            Value::Number(n) if n.is_i64() || n.is_u64() => ValueKind::Integer,
+            Value::Number(_) => ValueKind::Number,
+            Value::String(_) => ValueKind::String,
+            Value::Array(_) => ValueKind::Array,
+            Value::Object(_) => ValueKind::Object,
+        }
+    }
+
+    /// JSON Schema 타입 이름
+    fn name(&self) -> &'static str {
+        match self {
+            ValueKind::Null => "null",
+            ValueKind::Boolean => "boolean",
+            ValueKind::Integer => "integer",
+            ValueKind::Number => "number",
+            ValueKind::String => "string",
+            ValueKind::Array => "array",
+            ValueKind::Object => "object",
+        }
+    }
+}
+
+/// 한 위치(필드, 배열 요소)에서 관찰한 값의 누적 통계
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Node {
+    /// 값이 나타난 횟수
+    count: u64,
+    /// 종류별 출현 횟수
+    kinds: BTreeMap<ValueKind, u64>,
+    /// 서로 다른 스칼라 예시 값 (최대 `MAX_EXAMPLES`개)
+    examples: Vec<Value>,
+    /// 객체 필드
+    properties: BTreeMap<String, Node>,
+    /// 배열 요소
+    items: Option<Box<Node>>,
+}
+
+impl Node {
+    /// 값 하나를 관찰하여 누적
+    fn observe(&mut self, value: &Value) {
+        self.count += 1;
+        *self.kinds.entry(ValueKind::of(value)).or_insert(0) += 1;
+
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    self.properties
+                        .entry(key.clone())
+                        .or_default()
+                        .observe(child);
+                }
+            }
+            Value::Array(items) => {
+                let node = self.items.get_or_insert_with(Default::default);
+                items.iter().for_each(|item| node.observe(item));
+            }
+            Value::Null => {}
+            scalar => self.add_example(example_of(scalar)),
+        }
+    }
+
+    /// 예시 값 추가 (이미 있거나 가득 찼으면 무시)
+    fn add_example(&mut self, example: Value) {
+        if self.examples.len() < MAX_EXAMPLES && !self.examples.contains(&example) {
+            self.examples.push(example);
+        }
+    }
+
+    /// 다른 위치의 통계를 병합
+    fn merge(&mut self, other: Node) {
+        self.count += other.count;
+        for (kind, count) in other.kinds {
+            *self.kinds.entry(kind).or_insert(0) += count;
+        }
+        for example in other.examples {
+            self.add_example(example);
+        }
+        for (key, child) in other.properties {
+            self.properties.entry(key).or_default().merge(child);
+        }
+        if let Some(items) = other.items {
+            self.items
+                .get_or_insert_with(Default::default)
+                .merge(*items);
+        }
+    }
+
+    /// 하위 필드를 포함한 필드 수
+    fn field_count(&self) -> usize {
+        self.properties
+            .values()
+            .map(|child| 1 + child.field_count())
+            .sum::<usize>()
+            + self.items.as_ref().map_or(0, |items| items.field_count())
+    }
+
+    /// 누적 통계를 JSON Schema로 변환
+    fn to_schema(&self) -> Map<String, Value> {
+        let mut schema = Map::new();
+
+        // 정수와 실수가 섞여 있으면 number로 합침
+        let mut kinds: Vec<ValueKind> = self.kinds.keys().copied().collect();
+        if kinds.contains(&ValueKind::Number) {
+            kinds.retain(|kind| *kind != ValueKind::Integer);
+        }
+        match kinds.as_slice() {
+            [] => {}
+            [kind] => {
+                schema.insert("type".into(), json!(kind.name()));
+            }
+            kinds => {
+                let names: Vec<&str> = kinds.iter().map(ValueKind::name).collect();
+                schema.insert("type".into(), json!(names));
+            }
+        }
+
+        if !self.properties.is_empty() {
+            let objects = self.kinds.get(&ValueKind::Object).copied().unwrap_or(0);
+            let properties: Map<String, Value> = self
+                .properties
+                .iter()
+                .map(|(key, child)| (key.clone(), Value::Object(child.to_schema())))
+                .collect();
+            // 이 위치의 모든 객체에 나타난 필드만 필수
+            let required: Vec<&String> = self
+                .properties
+                .iter()
+                .filter(|(_, child)| child.count == objects)
+                .map(|(key, _)| key)
+                .collect();
+            schema.insert("properties".into(), Value::Object(properties));
+            if !required.is_empty() {
+                schema.insert("required".into(), json!(required));
+            }
+        }
+
+        if let Some(ref items) = self.items {
+            schema.insert("items".into(), Value::Object(items.to_schema()));
+        }
+        if !self.examples.is_empty() {
+            schema.insert("examples".into(), json!(self.examples));
+        }
+
+        schema.insert("x-count".into(), json!(self.count));
+        let kind_counts: Map<String, Value> = self
+            .kinds
+            .iter()
+            .map(|(kind, count)| (kind.name().to_string(), json!(count)))
+            .collect();
+        schema.insert("x-types".into(), Value::Object(kind_counts));
+        schema
+    }
+}
+
+/// 예시로 남길 값 (긴 문자열은 잘라냄)
+fn example_of(value: &Value) -> Value {
+    match value {
+        Value::String(text) if text.chars().count() > MAX_EXAMPLE_CHARS => {
+            let truncated: String = text.chars().take(MAX_EXAMPLE_CHARS).collect();
+            Value::String(format!("{}…", truncated))
+        }
+        other => other.clone(),
+    }
+}
+
+/// 출력 레코드 구조를 누적하여 JSON Schema를 추론 (`--infer-schema`)
+///
+/// # Examples
+/// ```
+/// use jconvert::infer::SchemaInference;
+/// use serde_json::json;
+///
+/// let mut inference = SchemaInference::new();
+/// inference.add_document(&json!({"id": 1, "name": "kim"}));
+/// inference.add_document(&json!({"id": 2, "name": null}));
+///
+/// let schema = inference.to_schema();
+/// assert_eq!(schema["required"], json!(["id", "name"]));
+/// assert_eq!(schema["properties"]["id"]["type"], "integer");
+/// assert_eq!(schema["properties"]["name"]["type"], json!(["string", "null"]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaInference {
+    /// 레코드 전체 통계
+    root: Node,
+    /// 레코드가 하나 이상 있었던 파일 수
+    files: u64,
+}
+
+impl SchemaInference {
+    /// 빈 추론기 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 파일 하나의 직렬화된 출력 레코드들로부터 추론
+    ///
+    /// 최상위 값이 배열이면 각 요소를 개별 레코드로 봅니다. 파싱할 수 없는 줄은 무시합니다.
+    pub fn from_lines(json_lines: &[String]) -> Self {
+        let mut inference = Self::new();
+        for line in json_lines {
+            if let Ok(value) = serde_json::from_str::<Value>(line) {
+                inference.add_document(&value);
+            }
+        }
+        inference.counted_as_file()
+    }
+
+    /// 파일 하나에서 누적한 결과로 표시 (레코드가 하나 이상이면 파일 수 1)
+    pub fn counted_as_file(mut self) -> Self {
+        self.files = u64::from(self.records() > 0);
+        self
+    }
+
+    /// 문서 하나의 레코드를 누적
+    pub fn add_document(&mut self, value: &Value) {
+        match value {
+            Value::Array(items) => items.iter().for_each(|item| self.root.observe(item)),
+            other => self.root.observe(other),
+        }
+    }
+
+    /// 다른 추론 결과를 병합 (파일 순서대로 병합하면 예시 값도 파일 순서를 따름)
+    pub fn merge(&mut self, other: SchemaInference) {
+        self.root.merge(other.root);
+        self.files += other.files;
+    }
+
+    /// 누적한 레코드 수
+    pub fn records(&self) -> u64 {
+        self.root.count
+    }
+
+    /// 누적한 필드 수 (중첩 필드, 배열 요소 필드 포함)
+    pub fn field_count(&self) -> usize {
+        self.root.field_count()
+    }
+
+    /// 추론한 JSON Schema
+    pub fn to_schema(&self) -> Value {
+        let mut schema = Map::new();
+        schema.insert("$schema".into(), json!(SCHEMA_DIALECT));
+        schema.extend(self.root.to_schema());
+        schema.insert("x-files".into(), json!(self.files));
+        Value::Object(schema)
+    }
+
+    /// 추론한 JSON Schema를 파일로 저장
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let write_error = |reason: String| JConvertError::WriteError {
+            reason: format!("{} ({})", reason, path.display()),
+        };
+
+        let file = File::create(path).map_err(|e| write_error(e.to_string()))?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer_pretty(&mut writer, &self.to_schema())
+            .map_err(|e| write_error(e.to_string()))?;
+        writeln!(writer).map_err(|e| write_error(e.to_string()))?;
+        writer.flush().map_err(|e| write_error(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema_filter::SchemaFilter;
+
+    #[test]
+    fn test_infer_nested_structure() {
+        let mut inference = SchemaInference::from_lines(&[
+            r#"[{"id":1,"price":9.5,"tags":["a","b"],"user":{"name":"kim"}},{"id":2,"price":3}]"#
+                .to_string(),
+            "not json".to_string(),
+        ]);
+        inference.merge(SchemaInference::from_lines(&[
+            r#"{"id":3,"price":1,"user":{"name":"lee","age":30}}"#.to_string(),
+        ]));
+
+        assert_eq!(inference.records(), 3);
+        assert_eq!(inference.field_count(), 6);
+
+        let schema = inference.to_schema();
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["x-files"], 2);
+        assert_eq!(schema["required"], json!(["id", "price"]));
+        assert_eq!(schema["properties"]["id"]["examples"], json!([1, 2, 3]));
+        // 정수와 실수가 섞이면 number
+        assert_eq!(schema["properties"]["price"]["type"], "number");
+        assert_eq!(
+            schema["properties"]["price"]["x-types"],
+            json!({"integer": 2, "number": 1})
+        );
+        assert_eq!(schema["properties"]["tags"]["items"]["type"], "string");
+        assert_eq!(schema["properties"]["tags"]["items"]["x-count"], 2);
+        let user = &schema["properties"]["user"];
+        assert_eq!(user["x-count"], 2);
+        assert_eq!(user["required"], json!(["name"]));
+        assert_eq!(user["properties"]["age"]["x-count"], 1);
+    }
+
+    #[test]
+    fn test_inferred_schema_accepts_observed_records() {
+        let records = [
+            json!({"id": 1, "note": null, "items": [{"sku": "A"}]}),
+            json!({"id": 2, "note": "x".repeat(200), "items": []}),
+        ];
+        let mut inference = SchemaInference::new();
+        records
+            .iter()
+            .for_each(|record| inference.add_document(record));
+
+        let schema = inference.to_schema();
+        let examples = schema["properties"]["note"]["examples"].as_array().unwrap();
+        assert_eq!(
+            examples[0].as_str().unwrap().chars().count(),
+            MAX_EXAMPLE_CHARS + 1
+        );
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("schema.json");
+        inference.write(&path).unwrap();
+        let filter = SchemaFilter::load(&path).unwrap();
+        assert!(records.iter().all(|record| filter.matches(record)));
+        assert!(!filter.matches(&json!({"id": "1", "items": []})));
+    }
+}
@@ -0,0 +1,192 @@
+//! JSONL 검사 모듈
+//!
+//! 이미 만들어진 JSONL 파일을 줄 경계에 맞춘 조각으로 나누어 병렬로 줄마다 검사하고,
+//! 잘못된 줄의 번호를 보고합니다 (`jconvert validate --jsonl`).
+//! 변환 입력과 마찬가지로 빈 줄(공백만 있는 줄 포함)은 건너뜁니다.
+
+use rayon::prelude::*;
+use serde::de::IgnoredAny;
+use std::path::{Path, PathBuf};
+
+use crate::error::{JConvertError, Result};
+use crate::processor::map_file;
+
+/// 병렬 검사 조각 크기 기본값 (8MB)
+pub const DEFAULT_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+/// 잘못된 줄
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    /// 줄 번호 (1부터)
+    pub line: u64,
+    /// 에러 메시지
+    pub reason: String,
+}
+
+/// 파일 하나의 JSONL 검사 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonlCheck {
+    /// 파일 경로
+    pub path: PathBuf,
+    /// 파일 크기
+    pub bytes: u64,
+    /// 검사한 줄 수 (빈 줄 제외)
+    pub records: u64,
+    /// 잘못된 줄 목록 (줄 번호 순)
+    pub errors: Vec<LineError>,
+}
+
+impl JsonlCheck {
+    /// 모든 줄이 유효한지 확인
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// 조각 하나의 검사 결과
+struct ChunkCheck {
+    /// 조각 안의 전체 줄 수 (빈 줄 포함)
+    lines: u64,
+    /// 검사한 줄 수 (빈 줄 제외)
+    records: u64,
+    /// 잘못된 줄 (조각 안에서의 줄 번호, 0부터)
+    errors: Vec<(u64, String)>,
+}
+
+/// JSONL 파일 검사
+///
+/// # Arguments
+/// * `path` - 검사할 JSONL 파일
+/// * `chunk_size` - 병렬로 검사할 조각 크기 (바이트, 조각은 줄 경계에서 나뉨)
+pub fn check_jsonl(path: &Path, chunk_size: u64) -> Result<JsonlCheck> {
+    let metadata = std::fs::metadata(path).map_err(|e| JConvertError::from_open_error(path, e))?;
+
+    // 빈 파일은 메모리 매핑할 수 없음
+    let (records, errors) = if metadata.len() == 0 {
+        (0, Vec::new())
+    } else {
+        check_bytes(&map_file(path)?, chunk_size)
+    };
+
+    Ok(JsonlCheck {
+        path: path.to_path_buf(),
+        bytes: metadata.len(),
+        records,
+        errors,
+    })
+}
+
+/// 바이트 전체를 조각으로 나누어 병렬 검사
+///
+/// # Returns
+/// (검사한 줄 수, 잘못된 줄 목록)
+fn check_bytes(bytes: &[u8], chunk_size: u64) -> (u64, Vec<LineError>) {
+    let chunks: Vec<ChunkCheck> = split_chunks(bytes, chunk_size.max(1) as usize)
+        .into_par_iter()
+        .map(check_chunk)
+        .collect();
+
+    // 앞 조각들의 줄 수를 더해 파일 전체 기준 줄 번호로 변환
+    let mut first_line = 1;
+    let mut records = 0;
+    let mut errors = Vec::new();
+    for chunk in chunks {
+        records += chunk.records;
+        errors.extend(chunk.errors.into_iter().map(|(line, reason)| LineError {
+            line: first_line + line,
+            reason,
+        }));
+        first_line += chunk.lines;
+    }
+    (records, errors)
+}
+
+/// 줄 경계(`\n` 바로 뒤)에 맞춰 대략 `chunk_size` 크기의 조각으로 분할
+fn split_chunks(bytes: &[u8], chunk_size: usize) -> Vec<&[u8]> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < bytes.len() {
+        let target = (start + chunk_size).min(bytes.len());
+        let end = bytes[target - 1..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(bytes.len(), |offset| target + offset);
+        chunks.push(&bytes[start..end]);
+        start = end;
+    }
+    chunks
+}
+
+/// 조각 하나의 줄마다 JSON 값 하나인지 검사
+fn check_chunk(chunk: &[u8]) -> ChunkCheck {
+    let body = chunk.strip_suffix(b"\n").unwrap_or(chunk);
+    let mut result = ChunkCheck {
+        lines: 0,
+        records: 0,
+        errors: Vec::new(),
+    };
+
+    for (index, line) in body.split(|&b| b == b'\n').enumerate() {
+        result.lines += 1;
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        result.records += 1;
+        if let Err(e) = serde_json::from_slice::<IgnoredAny>(line) {
+            result.errors.push((index as u64, describe_error(&e)));
+        }
+    }
+    result
+}
+
+/// 줄 하나의 파싱 에러 설명 (줄 안의 위치는 항상 1번째 줄이므로 열 번호만 표시)
+fn describe_error(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    let message = message
+        .rsplit_once(" at line ")
+        .map_or(message.as_str(), |(message, _)| message);
+    format!("{} ({}열)", message, error.column())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &[u8] = b"{\"id\":1}\n\n{\"id\":\n[1,2]\r\n{\"a\":1} {\"b\":2}\n\"ok\"";
+
+    #[test]
+    fn test_check_bytes_reports_line_numbers() {
+        for chunk_size in [1, 3, 10, 1024] {
+            let (records, errors) = check_bytes(SAMPLE, chunk_size);
+            assert_eq!(records, 5, "chunk size {}", chunk_size);
+            let lines: Vec<u64> = errors.iter().map(|e| e.line).collect();
+            assert_eq!(lines, vec![3, 5], "chunk size {}", chunk_size);
+        }
+    }
+
+    #[test]
+    fn test_split_chunks_on_line_boundaries() {
+        let chunks = split_chunks(b"aa\nbb\ncc", 4);
+        assert_eq!(chunks, vec![&b"aa\nbb\n"[..], &b"cc"[..]]);
+        assert_eq!(split_chunks(b"a\n", 1), vec![&b"a\n"[..]]);
+        assert!(split_chunks(b"", 4).is_empty());
+    }
+
+    #[test]
+    fn test_check_jsonl_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("out.jsonl");
+        std::fs::write(&path, "{\"id\":1}\n{bad}\n").unwrap();
+        let check = check_jsonl(&path, DEFAULT_CHUNK_SIZE).unwrap();
+        assert_eq!(check.records, 2);
+        assert_eq!(check.errors.len(), 1);
+        assert_eq!(check.errors[0].line, 2);
+        assert_eq!(check.errors[0].reason, "key must be a string (2열)");
+        assert!(!check.is_valid());
+
+        let empty = dir.path().join("empty.jsonl");
+        std::fs::write(&empty, "").unwrap();
+        assert!(check_jsonl(&empty, DEFAULT_CHUNK_SIZE).unwrap().is_valid());
+        assert!(check_jsonl(&dir.path().join("missing.jsonl"), DEFAULT_CHUNK_SIZE).is_err());
+    }
+}
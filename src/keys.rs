@@ -0,0 +1,267 @@
+//! 키 이름 정규화 모듈
+//!
+//! 소스마다 camelCase와 snake_case가 섞여 있는 객체 키를 하나의 표기법으로 통일하고
+//! (`--normalize-keys`, 중첩된 객체와 배열 안의 객체 키까지 모두 변경),
+//! 최상위 키의 접두사를 떼거나 붙입니다 (`--strip-key-prefix`, `--key-prefix`).
+
+use std::collections::HashSet;
+
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+/// 키 표기법
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum KeyCase {
+    /// snake_case (예: `user_id`)
+    Snake,
+    /// camelCase (예: `userId`)
+    Camel,
+    /// 소문자 (예: `userid`, 단어 구분은 그대로)
+    Lower,
+}
+
+impl KeyCase {
+    /// 키 하나를 이 표기법으로 변환
+    ///
+    /// 앞에 붙은 밑줄(`_id`, `__v`)은 그대로 유지합니다.
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::keys::KeyCase;
+    ///
+    /// assert_eq!(KeyCase::Snake.convert("userId"), "user_id");
+    /// assert_eq!(KeyCase::Snake.convert("HTTPServer2Name"), "http_server2_name");
+    /// assert_eq!(KeyCase::Camel.convert("created_at"), "createdAt");
+    /// assert_eq!(KeyCase::Camel.convert("_source-file"), "_sourceFile");
+    /// assert_eq!(KeyCase::Lower.convert("User_ID"), "user_id");
+    /// ```
+    pub fn convert(&self, key: &str) -> String {
+        let body = key.trim_start_matches('_');
+        let prefix = &key[..key.len() - body.len()];
+
+        let converted = match self {
+            KeyCase::Lower => body.to_lowercase(),
+            KeyCase::Snake => split_words(body)
+                .iter()
+                .map(|word| word.to_lowercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            KeyCase::Camel => split_words(body)
+                .iter()
+                .enumerate()
+                .map(|(index, word)| {
+                    if index == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+        };
+        format!("{}{}", prefix, converted)
+    }
+}
+
+/// 값 안의 모든 객체 키를 표기법에 맞게 변환 (중첩 객체와 배열 포함)
+///
+/// 여러 키가 같은 이름으로 바뀌면 (예: `userId`와 `user_id`) 객체 안에서 먼저 나온 키의 값을 유지합니다.
+pub fn normalize_keys(value: &mut Value, case: KeyCase) {
+    match value {
+        Value::Object(map) => {
+            let original = std::mem::take(map);
+            let mut normalized = Map::new();
+            for (key, mut item) in original {
+                normalize_keys(&mut item, case);
+                normalized.entry(case.convert(&key)).or_insert(item);
+            }
+            *map = normalized;
+        }
+        Value::Array(items) => {
+            for item in items {
+                normalize_keys(item, case);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 레코드 최상위 키에서 접두사 제거 (최상위 값이 배열이면 각 객체 요소에 적용)
+///
+/// 접두사를 떼면 이미 있는 다른 키와 이름이 같아지거나 빈 이름이 되는 키는 그대로 둡니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::keys::strip_key_prefix;
+/// use serde_json::json;
+///
+/// let mut record = json!({"data_id": 1, "data_name": "a", "id": 0, "data_": 2});
+/// strip_key_prefix(&mut record, "data_");
+/// assert_eq!(record, json!({"data_id": 1, "name": "a", "id": 0, "data_": 2}));
+/// ```
+pub fn strip_key_prefix(json: &mut Value, prefix: &str) {
+    for_each_record(json, |map| {
+        let unprefixed: HashSet<String> = map
+            .keys()
+            .filter(|key| !key.starts_with(prefix))
+            .cloned()
+            .collect();
+
+        let original = std::mem::take(map);
+        for (key, value) in original {
+            let key = match key.strip_prefix(prefix) {
+                Some(stripped) if !stripped.is_empty() && !unprefixed.contains(stripped) => {
+                    stripped.to_string()
+                }
+                _ => key,
+            };
+            map.entry(key).or_insert(value);
+        }
+    });
+}
+
+/// 레코드 최상위 키에 접두사 추가 (최상위 값이 배열이면 각 객체 요소에 적용)
+///
+/// # Examples
+/// ```
+/// use jconvert::keys::add_key_prefix;
+/// use serde_json::json;
+///
+/// let mut records = json!([{"id": 1, "user": {"name": "a"}}, 3]);
+/// add_key_prefix(&mut records, "src_");
+/// assert_eq!(records, json!([{"src_id": 1, "src_user": {"name": "a"}}, 3]));
+/// ```
+pub fn add_key_prefix(json: &mut Value, prefix: &str) {
+    for_each_record(json, |map| {
+        let original = std::mem::take(map);
+        for (key, value) in original {
+            map.insert(format!("{}{}", prefix, key), value);
+        }
+    });
+}
+
+/// 레코드 객체마다 함수 적용 (최상위 객체 또는 최상위 배열의 객체 요소)
+fn for_each_record(json: &mut Value, mut apply: impl FnMut(&mut Map<String, Value>)) {
+    match json {
+        Value::Object(map) => apply(map),
+        Value::Array(items) => {
+            for item in items {
+                if let Value::Object(map) = item {
+                    apply(map);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 키를 단어로 분리 (`_`, `-`, 공백, `.`과 대소문자 경계 기준, 숫자는 앞 단어에 붙임)
+fn split_words(key: &str) -> Vec<String> {
+    let chars: Vec<char> = key.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (index, &c) in chars.iter().enumerate() {
+        if matches!(c, '_' | '-' | ' ' | '.') {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|n| n.is_lowercase());
+            // "userId"의 I, "HTTPServer"의 S처럼 새 단어가 시작되는 대문자
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// 첫 글자만 대문자로, 나머지는 소문자로 변환
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(split_words("userId"), vec!["user", "Id"]);
+        assert_eq!(split_words("user_id"), vec!["user", "id"]);
+        assert_eq!(
+            split_words("XMLHttpRequest"),
+            vec!["XML", "Http", "Request"]
+        );
+        assert_eq!(split_words("address2Line"), vec!["address2", "Line"]);
+        assert_eq!(split_words("ID"), vec!["ID"]);
+        assert_eq!(split_words("first-name  x"), vec!["first", "name", "x"]);
+    }
+
+    #[test]
+    fn test_normalize_nested_keys() {
+        let mut value = json!({
+            "userId": 1,
+            "Profile": {"firstName": "Kim", "home_address": [{"zipCode": "123"}]},
+            "tags": ["camelCase"]
+        });
+        normalize_keys(&mut value, KeyCase::Snake);
+        assert_eq!(
+            value,
+            json!({
+                "user_id": 1,
+                "profile": {"first_name": "Kim", "home_address": [{"zip_code": "123"}]},
+                "tags": ["camelCase"]
+            })
+        );
+
+        normalize_keys(&mut value, KeyCase::Camel);
+        assert_eq!(
+            value,
+            json!({
+                "userId": 1,
+                "profile": {"firstName": "Kim", "homeAddress": [{"zipCode": "123"}]},
+                "tags": ["camelCase"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_key_prefix_only_top_level() {
+        let mut value = json!([{"data_id": 1, "data_meta": {"data_x": 1}}, {"data_id": 2}]);
+        strip_key_prefix(&mut value, "data_");
+        assert_eq!(value, json!([{"id": 1, "meta": {"data_x": 1}}, {"id": 2}]));
+
+        add_key_prefix(&mut value, "src_");
+        assert_eq!(
+            value,
+            json!([{"src_id": 1, "src_meta": {"data_x": 1}}, {"src_id": 2}])
+        );
+    }
+
+    #[test]
+    fn test_normalize_colliding_keys_keeps_first() {
+        let mut value = json!({"userId": 1, "user_id": 2});
+        normalize_keys(&mut value, KeyCase::Snake);
+        assert_eq!(value, json!({"user_id": 1}));
+    }
+}
@@ -5,14 +5,58 @@
 //! # 주요 기능
 //!
 //! - 🚀 **병렬 처리**: Rayon을 활용한 멀티스레드 처리로 대량 파일 고속 변환
-//! - 📊 **진행률 표시**: 처리 진행 상황을 시각적으로 확인
-//! - 📈 **상세 통계**: 성공/실패 파일 수, 입출력 용량, 성공률 등 표시
+//! - 🐢 **낮은 우선순위**: `--nice`로 CPU/I/O 우선순위를 낮춰 공유 서버의 다른 작업 보호
+//! - 📌 **스레드 고정**: `--pin-threads`로 워커를 CPU 코어에 고정하여 NUMA 서버에서 메모리 지역성 유지
+//! - 📊 **진행률 표시**: 처리 진행 상황을 시각적으로 확인 (대량 파일은 갱신 간격 자동 조절)
+//! - 📈 **상세 통계**: 성공/실패 파일 수, 입출력 용량, 성공률 등 표시 (`--stats-detail`로 폴더별 집계와 가장 크거나 느린 파일)
+//! - 🤖 **실행 보고서**: `--stats-json`으로 통계, 파일별 에러, 분할 파일 목록을 JSON으로 저장
+//! - 📉 **기준 실행 비교**: `--baseline`으로 이전 실행 보고서와 비교하여 파일 수 감소나 에러 급증 시 실패 처리
 //! - 🔍 **패턴 필터링**: glob 형식의 고급 파일 이름 필터링
-//! - 📝 **다양한 출력 모드**: 덮어쓰기, 추가, 에러 모드 지원
-//! - 🧪 **드라이런 모드**: 실제 병합 없이 처리될 파일 목록 미리 확인
-//! - ✅ **유효성 검사**: JSON 파일 유효성만 검사하는 모드
-//! - 🎯 **필드 선택**: 특정 필드만 추출하여 변환
+//! - 🔗 **안전한 폴더 탐색**: `--follow-symlinks`(순환 링크 감지)와 `--one-file-system`으로 링크와 마운트 지점 처리 제어
+//! - 🗓️ **메타데이터 필터**: 파일 크기와 수정 시각 범위로 입력 파일 선택
+//! - 📝 **다양한 출력 모드**: 덮어쓰기, 추가, 에러 모드 지원 (`--skip-existing`으로 추가 시 이미 기록된 파일/키 건너뜀, 이어 쓰기 전 잘린 마지막 줄 복구)
+//! - ⚛️ **원자적 출력**: 임시 파일에 쓴 뒤 이름을 바꿔 중단되어도 잘린 출력 파일을 남기지 않음
+//! - 🧾 **번들 출력**: `--output-format bundle`로 실행 ID, 원본, 건수, 스키마 해시를 담은 머리글과 JSONL 본문을 파일 하나로 저장
+//! - 🗜️ **출력 압축**: `codec-gzip`, `codec-zstd` 등 기능으로 빌드하면 `--compress`로 출력 파일을 압축 (코덱은 `Codec` 트레이트로 추가)
+//! - ☁️ **원격 업로드**: `remote-sink` 기능으로 빌드하면 `-o s3://...`/`-o gs://...`로 로컬 디스크 없이 멀티파트 업로드
+//! - ♻️ **체크포인트**: `--checkpoint`로 중단된 변환을 완료한 파일 이후부터 이어서 처리
+//! - 📏 **레코드 크기 제한**: `--max-record-size`와 `--oversize-policy skip|truncate-fields|error`로 지나치게 긴 출력 줄 방지
+//! - ✂️ **출력 분할**: 용량이나 레코드 수 기준으로 여러 파일에 나누어 저장
+//! - 🗃️ **여러 입력**: `-i` 반복 지정과 `--files-from` 목록을 합쳐 병합 (같은 파일은 한 번만 처리)
+//! - 🔗 **파이프 연동**: `-i -`로 표준 입력의 파일 경로 목록을 읽고 `-o -`로 표준 출력에 기록
+//! - 🗺️ **실행 계획**: 변환 전에 파일 수, 크기 분포, 예상 처리 시간 확인
+//! - 🎲 **테스트 데이터 생성**: 스키마에 맞는 가짜 JSON 파일 생성 (`jconvert generate`)
+//! - 🧪 **드라이런 모드**: 실제 병합 없이 처리될 파일 목록 미리 확인 (`--preview N`으로 변환된 레코드와 예상 출력 크기)
+//! - 🔎 **JSONL 출력 검사**: `jconvert validate --jsonl`로 기존 JSONL 파일을 병렬로 검사하여 잘못된 줄 번호 보고
+//! - 🩹 **JSONL 복구**: `--repair-jsonl`과 `validate --jsonl --fix`로 한 줄에 붙은 레코드를 나누고 끊긴 레코드를 이어 붙임
+//! - 🛑 **변환 취소**: `convert_files`/`validate_files_until`에 `CancellationToken`을 넘겨 긴 작업을 멈추고 부분 통계 확인
+//! - ✅ **유효성 검사**: JSON 파일 유효성만 검사하는 모드 (파일별 결과는 `ValidationReport`로 확인)
+//! - 🚦 **종료 코드**: 0(정상), 1(에러와 함께 완료), 2(중단)과 `--fail-fast`/`--max-errors` 에러 허용 정책
+//! - 🚧 **품질 기준**: `--min-success-rate`와 `--max-error-count`로 데이터 품질이 기준에 못 미치면 실패 처리하는 CI 검문
+//! - 🎯 **필드 선택**: 특정 필드만 추출하여 변환 (최상위 필드는 전체 트리 없이 빠르게 선택)
+//! - 🧮 **변환 표현식**: `--transform`으로 계산 필드, 문자열 연결, 조건 분기로 레코드 재구성
+//! - 🔑 **키 순서 제어**: `--key-order sort|preserve`로 출력 키를 사전순 정렬하거나 원본 순서 유지
+//! - 🏷️ **키 접두사 제거/추가**: `--strip-key-prefix`/`--key-prefix`로 최상위 키 접두사 정리
+//! - 🔤 **키 표기법 통일**: `--normalize-keys`로 모든 객체 키를 snake_case/camelCase/소문자로 변환
+//! - 🔀 **레코드 정렬**: `--sort-by`로 필드 값 순서로 정렬 (메모리보다 큰 데이터는 외부 정렬)
+//! - 🔡 **입력 인코딩 변환**: BOM/UTF-16 자동 감지와 `--encoding`/`--lossy`로 파싱 전에 UTF-8로 변환
+//! - 🌍 **GeoJSON 펼치기**: `--geojson`으로 `FeatureCollection`을 Feature 하나당 한 줄로 변환 (`--flatten-properties`로 속성을 최상위로)
+//! - ⏩ **그대로 복사**: `--passthrough`로 이미 한 줄인 문서를 재직렬화 없이 복사
+//! - 🔎 **조건 필터**: `--where` 표현식을 만족하는 레코드만 출력
+//! - 📐 **스키마 필터**: JSON Schema에 맞는 레코드만 남기고 나머지는 별도 파일로 분리
+//! - 🎲 **샘플링/개수 제한**: `--sample`로 레코드를 시드 기반으로 무작위 추출하고 `--limit`으로 출력 레코드 수 제한
+//! - 🗂️ **제외 레코드 파일**: 필터에서 제외된 모든 레코드를 제외 이유와 함께 기록
+//! - 🧭 **스키마 추론**: 모든 레코드의 필드, 타입, null 여부, 예시 값, 출현 횟수로 JSON Schema 생성
+//! - 🧬 **스키마 변화 감지**: 파일 간 새 필드/타입 등장 경고
+//! - ⚡ **SIMD 파서**: `simd-json` 기능으로 빌드하면 `--parser simd`로 파싱 처리량 향상
+//! - 🛡️ **강화 파싱 모드**: 문자열 길이, 배열 요소 수, 중첩 깊이 제한으로 신뢰할 수 없는 입력 방어
+//! - 🧹 **중복 제거**: 키 필드 기준 중복 레코드 제거 (블룸 필터 근사 모드 지원), 내용이 같은 입력 파일 제외
+//! - 📝 **구조화된 에러 로그**: `--error-log-format json|csv`로 에러 종류, 줄/칸, 바이트 위치, 파일 크기 기록
+//! - 🔐 **체크섬 필드**: `--add-checksum`으로 원본 파일이나 레코드의 SHA-256을 각 레코드에 기록
+//! - 🔢 **필드 순서 지정**: `--field-order "id,timestamp,*"`로 선택한 필드를 모든 레코드의 앞에 배치
+//! - 🧫 **테스트 도우미**: `testing` 기능으로 시드 기반의 유효/잘못된/중첩 JSON 입력 폴더를 만드는 `testing::FixtureBuilder`
 //! - 🎨 **컬러 출력**: 가독성 높은 컬러 터미널 출력
+//! - 🪵 **수준별 로그 출력**: `--quiet`, `-v`/`-vv`, `--log-format json`, `--no-color` (모든 메시지는 표준 에러)
 //!
 //! # 예제
 //!
@@ -27,15 +71,103 @@
 //! jconvert -i ./data -o result.jsonl --fields "id,name"
 //! ```
 
+pub mod affinity;
+pub mod atomic;
+pub mod baseline;
+pub mod bundle;
+pub mod cancel;
+pub mod checkpoint;
+pub mod checksum;
 pub mod cli;
+pub mod codec;
+pub mod convert;
+pub mod dedup;
+pub mod encoding;
 pub mod error;
+pub mod error_log;
+pub mod existing;
+pub mod exit;
+pub mod expr;
+pub mod field_order;
+pub mod file_filter;
+pub mod gate;
+pub mod generate;
+pub mod geojson;
+pub mod infer;
+pub mod jsonl_check;
+pub mod keys;
+pub mod limits;
+pub mod parser;
 pub mod pattern;
+pub mod pipeline;
+pub mod plan;
+pub mod priority;
 pub mod processor;
+pub mod progress;
+pub mod raw_select;
+pub mod record_limit;
+pub mod record_size;
+pub mod repair;
+pub mod report;
+pub mod rng;
+pub mod sample;
+pub mod schema;
+pub mod schema_filter;
+pub mod shard;
+pub mod sort;
+pub mod spill;
 pub mod stats;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod ui;
+pub mod upload;
+pub mod validation;
+pub mod writer;
 
 // Re-exports for convenient access
-pub use cli::{Args, WriteMode};
+pub use affinity::ThreadPinning;
+pub use atomic::AtomicOutput;
+pub use baseline::{Baseline, Deviation};
+pub use bundle::{BundleHeader, OutputFormat};
+pub use cancel::CancellationToken;
+pub use checkpoint::Checkpoint;
+pub use checksum::{ChecksumField, ChecksumScope};
+pub use cli::{Args, Command, GenerateArgs, PlanArgs, ValidateArgs, WriteMode};
+pub use codec::{Codec, FinishEncode};
+pub use convert::{convert_files, Conversion};
+pub use dedup::{DedupKeep, Deduplicator, SpillDeduplicator};
+pub use encoding::InputEncoding;
 pub use error::{JConvertError, Result};
+pub use error_log::{ErrorEntry, ErrorLogFormat};
+pub use existing::{AppendTarget, ExistingOutput, TrailingLine};
+pub use exit::{ErrorBudget, ExitStatus};
+pub use expr::Expr;
+pub use field_order::FieldOrder;
+pub use file_filter::FileFilter;
+pub use gate::{Gate, GateResult, QualityGates};
+pub use generate::Generator;
+pub use infer::SchemaInference;
+pub use jsonl_check::{check_jsonl, JsonlCheck};
+pub use keys::KeyCase;
+pub use limits::ParseLimits;
+pub use parser::ParserKind;
 pub use pattern::PatternMatcher;
-pub use processor::{process_file, validate_file, ProcessOptions, ProcessResult};
-pub use stats::{format_bytes, Statistics};
+pub use pipeline::Pipeline;
+pub use plan::{OutputEstimate, PlanSummary, SampleBenchmark};
+pub use processor::{
+    process_file, validate_file, KeyOrder, ProcessOptions, ProcessResult, RejectedRecord,
+};
+pub use record_limit::RecordLimit;
+pub use record_size::{OversizePolicy, RecordSizeGuard};
+pub use repair::{repair_file, JsonlRepairer, RepairSummary};
+pub use report::{RunMode, RunReport};
+pub use sample::Sampler;
+pub use schema::{FieldSchema, SchemaDriftTracker, TypeConflictReport};
+pub use schema_filter::SchemaFilter;
+pub use shard::{ShardLimits, ShardedOutput};
+pub use sort::RecordSorter;
+pub use stats::{format_bytes, parse_size, Statistics};
+pub use validation::{
+    validate_files, validate_files_until, FileOutcome, FileValidation, ValidationReport,
+};
+pub use writer::{OutputSink, RecordSink, RecordWriter, SyncPolicy};
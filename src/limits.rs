@@ -0,0 +1,291 @@
+//! 파싱 한도 모듈
+//!
+//! 신뢰할 수 없는 폴더를 변환할 때 악의적이거나 손상된 입력이 메모리나 CPU를
+//! 과도하게 쓰지 못하도록 문자열 길이, 배열 요소 수, 중첩 깊이를 파싱 중에 검사합니다.
+//! 한도를 넘으면 값을 끝까지 만들지 않고 즉시 파싱 오류로 중단합니다.
+
+use serde::de::{
+    DeserializeSeed, Deserializer, Error as _, IgnoredAny, MapAccess, SeqAccess, Visitor,
+};
+use serde_json::{Map, Number, Value};
+use std::fmt;
+use std::io::Read;
+
+/// 강화 모드 기본 최대 중첩 깊이
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+/// 강화 모드 기본 최대 문자열 길이 (바이트)
+pub const DEFAULT_MAX_STRING_LEN: usize = 1024 * 1024;
+
+/// 강화 모드 기본 최대 배열 요소 수
+pub const DEFAULT_MAX_ARRAY_LEN: usize = 1_000_000;
+
+/// 파싱 한도 (`None`이면 제한 없음)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// 최대 중첩 깊이 (최상위 배열/객체가 1)
+    pub max_depth: Option<usize>,
+    /// 최대 문자열 길이 (바이트, 객체 키 포함)
+    pub max_string_len: Option<usize>,
+    /// 배열 하나의 최대 요소 수
+    pub max_array_len: Option<usize>,
+}
+
+impl ParseLimits {
+    /// 강화 모드 기본 한도
+    pub fn hardened() -> Self {
+        Self {
+            max_depth: Some(DEFAULT_MAX_DEPTH),
+            max_string_len: Some(DEFAULT_MAX_STRING_LEN),
+            max_array_len: Some(DEFAULT_MAX_ARRAY_LEN),
+        }
+    }
+
+    /// 한도가 하나라도 설정되어 있는지 확인
+    pub fn is_enabled(&self) -> bool {
+        self.max_depth.is_some() || self.max_string_len.is_some() || self.max_array_len.is_some()
+    }
+}
+
+/// 바이트 버퍼에서 JSON 문서 하나를 한도를 검사하며 파싱
+///
+/// # Examples
+/// ```
+/// use jconvert::limits::{parse_slice, ParseLimits};
+///
+/// let limits = ParseLimits { max_depth: Some(2), ..Default::default() };
+/// assert!(parse_slice(br#"{"a": [1, 2]}"#, &limits).is_ok());
+/// assert!(parse_slice(br#"{"a": [[1]]}"#, &limits).is_err());
+/// ```
+pub fn parse_slice(bytes: &[u8], limits: &ParseLimits) -> serde_json::Result<Value> {
+    if !limits.is_enabled() {
+        return serde_json::from_slice(bytes);
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let value = LimitedValue::new(limits).deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// 리더에서 JSON 문서 하나를 한도를 검사하며 파싱
+pub fn parse_reader<R: Read>(reader: R, limits: &ParseLimits) -> serde_json::Result<Value> {
+    if !limits.is_enabled() {
+        return serde_json::from_reader(reader);
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let value = LimitedValue::new(limits).deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// 연속된 여러 JSON 문서를 순서대로 파싱
+///
+/// 한도가 있으면 값을 만들지 않고 문서 경계만 먼저 찾은 뒤 문서별로 한도를 검사하며 파싱합니다.
+pub fn parse_documents<'a>(
+    bytes: &'a [u8],
+    limits: &'a ParseLimits,
+) -> Box<dyn Iterator<Item = serde_json::Result<Value>> + 'a> {
+    if !limits.is_enabled() {
+        return Box::new(serde_json::Deserializer::from_slice(bytes).into_iter::<Value>());
+    }
+
+    let mut stream = serde_json::Deserializer::from_slice(bytes).into_iter::<IgnoredAny>();
+    let mut start = 0;
+    let mut failed = false;
+    Box::new(std::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+        match stream.next()? {
+            Ok(_) => {
+                let end = stream.byte_offset();
+                let document = &bytes[start..end];
+                start = end;
+                Some(parse_slice(document, limits))
+            }
+            Err(e) => {
+                failed = true;
+                Some(Err(e))
+            }
+        }
+    }))
+}
+
+/// 한도를 검사하며 `Value`를 만드는 역직렬화 시드
+struct LimitedValue<'a> {
+    limits: &'a ParseLimits,
+    depth: usize,
+}
+
+impl<'a> LimitedValue<'a> {
+    fn new(limits: &'a ParseLimits) -> Self {
+        Self { limits, depth: 0 }
+    }
+
+    /// 한 단계 안쪽 값을 위한 시드
+    fn child(&self) -> Self {
+        Self {
+            limits: self.limits,
+            depth: self.depth + 1,
+        }
+    }
+
+    /// 배열/객체에 들어갈 때 깊이 검사
+    fn check_depth<E: serde::de::Error>(&self) -> Result<(), E> {
+        match self.limits.max_depth {
+            Some(max) if self.depth + 1 > max => {
+                Err(E::custom(format!("중첩 깊이 제한 초과 (최대 {})", max)))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 문자열 길이 검사
+    fn check_string<E: serde::de::Error>(&self, len: usize) -> Result<(), E> {
+        match self.limits.max_string_len {
+            Some(max) if len > max => Err(E::custom(format!(
+                "문자열 길이 제한 초과 ({} > {} 바이트)",
+                len, max
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for LimitedValue<'_> {
+    type Value = Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for LimitedValue<'_> {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("JSON 값")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
+        Ok(Value::from(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Value, E> {
+        Ok(Number::from_f64(value).map_or(Value::Null, Value::Number))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Value, E> {
+        self.check_string(value.len())?;
+        Ok(Value::String(value.to_owned()))
+    }
+
+    fn visit_string<E: serde::de::Error>(self, value: String) -> Result<Value, E> {
+        self.check_string(value.len())?;
+        Ok(Value::String(value))
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        self.check_depth()?;
+
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element_seed(self.child())? {
+            items.push(item);
+            if let Some(max) = self.limits.max_array_len {
+                if items.len() > max {
+                    return Err(A::Error::custom(format!(
+                        "배열 요소 수 제한 초과 (최대 {})",
+                        max
+                    )));
+                }
+            }
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        self.check_depth()?;
+
+        let mut object = Map::new();
+        while let Some(key) = map.next_key::<String>()? {
+            self.check_string(key.len())?;
+            let value = map.next_value_seed(self.child())?;
+            object.insert(key, value);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_limits_accept_within_bounds() {
+        let limits = ParseLimits {
+            max_depth: Some(3),
+            max_string_len: Some(5),
+            max_array_len: Some(3),
+        };
+        let value = parse_slice(
+            br#"{"id": 1, "tags": ["a", "bb"], "n": {"x": null}}"#,
+            &limits,
+        );
+        assert_eq!(
+            value.unwrap(),
+            json!({"id": 1, "tags": ["a", "bb"], "n": {"x": null}})
+        );
+    }
+
+    #[test]
+    fn test_limits_reject_violations() {
+        let limits = ParseLimits {
+            max_depth: Some(2),
+            max_string_len: Some(3),
+            max_array_len: Some(2),
+        };
+        let error = |json: &str| {
+            parse_slice(json.as_bytes(), &limits)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(error(r#"[[[1]]]"#).contains("중첩 깊이"));
+        assert!(error(r#"{"name": "long"}"#).contains("문자열 길이"));
+        assert!(error(r#"{"long_key": 1}"#).contains("문자열 길이"));
+        assert!(error(r#"[1, 2, 3]"#).contains("배열 요소 수"));
+    }
+
+    #[test]
+    fn test_parse_documents_with_limits() {
+        let limits = ParseLimits {
+            max_array_len: Some(1),
+            ..Default::default()
+        };
+        let documents: Vec<_> = parse_documents(br#"{"a":1} [2] [3,4]"#, &limits).collect();
+
+        assert_eq!(documents.len(), 3);
+        assert_eq!(documents[0].as_ref().unwrap(), &json!({"a": 1}));
+        assert_eq!(documents[1].as_ref().unwrap(), &json!([2]));
+        assert!(documents[2].is_err());
+    }
+}
@@ -7,114 +7,777 @@ use clap::Parser;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
 use rayon::prelude::*;
-use std::fs::{File, OpenOptions};
-use std::io::{BufWriter, Write};
-use std::path::PathBuf;
-use std::sync::Mutex;
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::SystemTime;
 use walkdir::WalkDir;
 
 use jconvert::{
-    cli::{Args, WriteMode},
+    affinity::{parse_cpu_list, ThreadPinning},
+    atomic::AtomicOutput,
+    baseline::Baseline,
+    bundle::{body_path, write_bundle, BundleHeader, OutputFormat},
+    checkpoint::Checkpoint,
+    checksum::{self, ChecksumScope, DuplicateFiles},
+    cli::{Args, Command, GenerateArgs, PlanArgs, ValidateArgs, WriteMode, STDIO_PATH},
+    codec::FinishEncode,
+    convert::process_in_order,
+    debug,
+    dedup::DedupKeep,
+    detail,
+    encoding::InputEncoding,
+    error::JConvertError,
+    error_log::{self, ErrorEntry, ErrorLogFormat, UNKNOWN_KIND},
+    existing::{repair_trailing_line, AppendTarget, ExistingOutput},
+    exit::{ErrorBudget, ExitStatus},
+    expr::Expr,
+    failure,
+    file_filter::FileFilter,
+    gate::Gate,
+    generate::{generated_path, Generator},
+    infer::SchemaInference,
+    jsonl_check::check_jsonl,
+    keys::KeyCase,
+    parser::ParserKind,
     pattern::PatternMatcher,
-    processor::{process_file, ProcessOptions, ProcessResult},
-    stats::Statistics,
+    pipeline::Pipeline,
+    plan::{benchmark_sample, estimate_output, preview_records, PlanSummary, DEFAULT_SAMPLE_SIZE},
+    priority::{io_priority_for, lower_priority, PriorityOutcome},
+    processor::{
+        is_geojson_file, is_input_file, process_file, process_reader, relative_to_roots, KeyOrder,
+        ProcessOptions, ProcessResult, DEFAULT_MTIME_KEY, DEFAULT_SOURCE_KEY,
+    },
+    progress::{auto_interval, BatchedProgress},
+    record_limit::RecordLimit,
+    record_size::OversizePolicy,
+    repair,
+    report::{RunMode, RunReport},
+    rng::time_seed,
+    schema::{SchemaDrift, SchemaDriftTracker, TypeConflictReport},
+    schema_filter::SchemaFilter,
+    shard::{is_shard_name, shard_path, ShardInfo, ShardedOutput},
+    stats::{format_bytes, format_duration, FileStat, Statistics},
+    status,
+    ui::{self, LogFormat},
+    upload::{self, PendingUpload},
+    validation::{FileOutcome, ValidationReport},
+    warning,
+    writer::{OutputSink, RecordWriter, SyncWrite},
 };
 
-fn main() -> Result<()> {
-    let args = Args::parse();
+/// 단일 파일(또는 표준 출력, 원격 업로드) 또는 분할 파일 출력
+type Output = OutputSink<BufWriter<Box<dyn SyncWrite>>>;
+
+/// 레코드를 모두 기록한 뒤 완성해야 하는 출력
+enum PendingOutput {
+    /// 임시 파일 이름을 출력 파일로 바꿈
+    Atomic(AtomicOutput),
+    /// 업로드 스레드가 멀티파트 업로드를 마칠 때까지 기다림
+    Upload(PendingUpload),
+    /// 본문 임시 파일에 머리글을 붙여 번들 파일 완성 (`--output-format bundle`)
+    Bundle {
+        body: AtomicOutput,
+        target: PathBuf,
+        header: Box<BundleHeader>,
+    },
+    /// 압축 형식의 끝을 기록한 뒤 디스크에 저장 (`--compress`)
+    Compressed {
+        encoding: Box<dyn FinishEncode>,
+        file: File,
+        atomic: Option<AtomicOutput>,
+    },
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(status) => ExitCode::from(status.code()),
+        Err(e) => {
+            failure!("Error: {:?}", e);
+            ExitCode::from(ExitStatus::Aborted.code())
+        }
+    }
+}
+
+/// 실행 후 종료 상태 반환 (에러를 반환하면 중단으로 처리)
+fn run() -> Result<ExitStatus> {
+    let mut args = Args::parse();
+
+    // 메시지는 모두 표준 에러로 (표준 출력은 `-o -`의 JSONL 전용)
+    ui::init(args.log_level(), args.log_format, !args.no_color);
+
+    // 하위 명령
+    match args.command {
+        Some(Command::Plan(ref plan_args)) => {
+            return run_plan(plan_args).map(|()| ExitStatus::Clean)
+        }
+        Some(Command::Generate(ref generate_args)) => {
+            return run_generate(generate_args).map(|()| ExitStatus::Clean)
+        }
+        Some(Command::Validate(ref validate_args)) => return run_validate_jsonl(validate_args),
+        None => {}
+    }
+
+    // 헤더와 처리 단계가 같은 시드를 쓰도록 샘플링 시드를 한 번만 정함
+    if args.sample.is_some() && args.seed.is_none() {
+        args.seed = Some(time_seed());
+    }
+
+    // 우선순위는 스레드가 물려받으므로 워커 스레드를 만들기 전에 낮춤
+    let priority = args.nice.map(lower_priority);
 
     // 스레드 풀 설정
-    if let Some(threads) = args.threads {
-        rayon::ThreadPoolBuilder::new()
-            .num_threads(threads)
-            .build_global()
-            .context("스레드 풀 초기화 실패")?;
+    build_thread_pool(&args)?;
+
+    if !args.parser.is_available() {
+        anyhow::bail!(
+            "{} 파서를 쓰려면 `--features simd-json`으로 빌드해야 합니다",
+            args.parser
+        );
+    }
+    if !args.key_order.is_available() {
+        anyhow::bail!(
+            "--key-order preserve를 쓰려면 `--features preserve_order`로 빌드해야 합니다"
+        );
+    }
+    if let Some(codec) = args.compress.filter(|codec| !codec.is_available()) {
+        anyhow::bail!(
+            "{} 압축을 쓰려면 `--features {}`로 빌드해야 합니다",
+            codec.name(),
+            codec.feature()
+        );
+    }
+    if args.early_limit().is_some() && args.dedupe_by.is_some() {
+        anyhow::bail!(
+            "--limit을 --dedupe-by와 함께 쓰려면 --sort-by가 필요합니다 (정렬 없이는 중복 제거 전 레코드 수로 제한됨)"
+        );
+    }
+    if args.remote_target().is_some() && !upload::is_available() {
+        anyhow::bail!(
+            "원격 업로드(-o s3://, gs://)를 쓰려면 `--features remote-sink`로 빌드해야 합니다"
+        );
     }
 
-    // 입력 폴더 확인
-    validate_input(&args)?;
+    // 기준 실행 보고서는 같은 경로에 이번 보고서를 쓰기 전에 미리 읽음
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+
+    // 표준 입력 모드
+    if args.stdin {
+        print_header(&args, priority);
+        return run_stdin_mode(&args, baseline.as_ref());
+    }
+
+    // 입력 폴더 확인 (`-i -`는 표준 입력에서 파일 목록을 읽으므로 제외)
+    for input in args.input_dirs() {
+        validate_input(input)?;
+    }
 
     // 헤더 출력
-    print_header(&args);
+    print_header(&args, priority);
 
     // 패턴 매처 초기화
-    let pattern_matcher =
-        PatternMatcher::new(args.pattern.clone()).map_err(|e| anyhow::anyhow!("{}", e))?;
+    let pattern_matcher = PatternMatcher::with_patterns(&args.pattern, &args.exclude)
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
 
     // JSON 파일 수집
     let json_files = collect_json_files(&args, &pattern_matcher)?;
+    let json_files = exclude_output_files(&args, json_files);
+    for path in &json_files {
+        debug!("  입력 파일: {}", path.display());
+    }
+
+    // 내용이 같은 파일은 처음 하나만 처리 (`--dedup-content`)
+    let (json_files, duplicates) = if args.dedup_content {
+        skip_duplicate_content(json_files)
+    } else {
+        (json_files, DuplicateFiles::default())
+    };
+
+    // 체크포인트에 기록된 파일(이전 실행에서 병합 완료)은 건너뜀
+    let checkpoint = match args.checkpoint {
+        Some(ref path) => Some(Checkpoint::load(path)?),
+        None => None,
+    };
+    let json_files = match checkpoint {
+        Some(ref checkpoint) => skip_completed(json_files, checkpoint),
+        None => json_files,
+    };
+
+    // 기존 출력에 레코드가 있는 파일은 건너뜀 (`--skip-existing`)
+    let existing = load_existing_output(&args)?;
+    let json_files = match existing {
+        Some(ref existing) => skip_existing_sources(&args, json_files, existing),
+        None => json_files,
+    };
 
     if json_files.is_empty() {
-        println!("{}", "⚠️ 처리할 JSON 파일이 없습니다.".yellow());
-        return Ok(());
+        warning!("{}", "⚠️ 처리할 JSON 파일이 없습니다.".yellow());
+        let report = RunReport::new(RunMode::Convert, &Statistics::new(0));
+        return Ok(finish_status(
+            &args,
+            ExitStatus::Clean,
+            baseline.as_ref(),
+            &report,
+        ));
     }
 
-    println!(
+    status!(
         "  {} 발견된 파일 수: {}",
         "📋".bright_white(),
         json_files.len().to_string().bright_green()
     );
 
     // 통계 초기화
-    let stats = Statistics::new(json_files.len());
+    let stats = Statistics::new(json_files.len())
+        .with_parser(args.effective_parser())
+        .with_duplicate_files(duplicates.count(), duplicates.bytes)
+        .with_detail(args.stats_detail);
 
     // 드라이런 모드
     if args.dry_run {
         print_dry_run(&json_files);
-        return Ok(());
+        if args.mode == WriteMode::Append {
+            print_append_check(&args)?;
+        }
+        if let Some(count) = args.preview {
+            print_preview(&args, &json_files, count as usize)?;
+        }
+        return Ok(ExitStatus::Clean);
     }
 
     // 유효성 검사 모드
     if args.validate_only {
-        return run_validation_mode(&args, json_files, &stats);
+        let report = run_validation_mode(&args, json_files, &stats)?;
+        print_unreadable_notice(&args, report.count(FileOutcome::Unreadable));
+        let status = report.exit_status(args.skip_unreadable);
+        let run_report = RunReport::new(RunMode::Validate, &stats);
+        return Ok(finish_status(&args, status, baseline.as_ref(), &run_report));
+    }
+
+    // 체크포인트에서 이어서 처리: 출력 파일을 체크포인트 시점으로 되돌린 뒤 이어 쓰기
+    if let Some(ref checkpoint) = checkpoint {
+        if checkpoint.is_resume() {
+            checkpoint.truncate_output(&args.output)?;
+            args.mode = WriteMode::Append;
+        }
     }
 
     // 일반 변환 모드
-    run_conversion_mode(&args, json_files, &stats)
+    let status = run_conversion_mode(&args, json_files, &stats, checkpoint, existing.as_ref())?;
+    let report = RunReport::new(RunMode::Convert, &stats);
+    Ok(finish_status(&args, status, baseline.as_ref(), &report))
+}
+
+/// 체크포인트에 완료로 기록된 파일 제외
+fn skip_completed(json_files: Vec<PathBuf>, checkpoint: &Checkpoint) -> Vec<PathBuf> {
+    if !checkpoint.is_resume() {
+        return json_files;
+    }
+
+    let total = json_files.len();
+    let remaining: Vec<PathBuf> = json_files
+        .into_iter()
+        .filter(|path| !checkpoint.is_completed(path))
+        .collect();
+    status!(
+        "  {} 체크포인트에서 이어서 처리: 완료된 파일 {}개 건너뜀 (출력 {}부터 이어 씀)",
+        "♻️".bright_green(),
+        (total - remaining.len()).to_string().bright_green(),
+        format_bytes(checkpoint.output_bytes())
+    );
+    remaining
+}
+
+/// `--skip-existing`이면 기존 출력에서 기록된 원본 파일과 중복 제거 키 읽기
+fn load_existing_output(args: &Args) -> Result<Option<ExistingOutput>> {
+    if !args.skip_existing {
+        return Ok(None);
+    }
+    if args.mode != WriteMode::Append {
+        anyhow::bail!("--skip-existing은 --mode append와 함께 사용해야 합니다");
+    }
+    if args.writes_stdout() || args.remote_target().is_some() {
+        anyhow::bail!("--skip-existing은 로컬 출력 파일에만 사용할 수 있습니다");
+    }
+    if args.dedupe_by.is_some() && args.spills_dedup() {
+        anyhow::bail!(
+            "--skip-existing과 --dedupe-keep last를 함께 쓰려면 --dedupe-exact가 필요합니다 (디스크 기반 중복 제거는 기존 출력의 키를 알 수 없음)"
+        );
+    }
+    if !args.add_source && !args.annotate && args.dedupe_by.is_none() {
+        anyhow::bail!(
+            "--skip-existing에는 기록된 레코드를 알아볼 --add-source, --annotate 또는 --dedupe-by가 필요합니다"
+        );
+    }
+
+    let source_key = args.add_source.then_some(DEFAULT_SOURCE_KEY);
+    let existing = ExistingOutput::scan(&args.output, source_key, args.dedupe_by.as_deref())
+        .with_context(|| format!("기존 출력 파일을 읽을 수 없습니다: {:?}", args.output))?;
+    debug!(
+        "  기존 출력: 레코드 {}개, 중복 제거 키 {}개",
+        existing.records(),
+        existing.keys().len()
+    );
+    Ok(Some(existing))
+}
+
+/// 기존 출력에 레코드가 기록된 원본 파일 제외
+fn skip_existing_sources(
+    args: &Args,
+    json_files: Vec<PathBuf>,
+    existing: &ExistingOutput,
+) -> Vec<PathBuf> {
+    if !args.add_source && !args.annotate {
+        return json_files;
+    }
+
+    let roots = source_roots(args);
+    let total = json_files.len();
+    let remaining: Vec<PathBuf> = json_files
+        .into_iter()
+        .filter(|path| !existing.contains_source(&relative_to_roots(path, &roots)))
+        .collect();
+    if remaining.len() < total {
+        status!(
+            "  {} 기존 출력에 이미 있는 파일 {}개 건너뜀",
+            "⏭️".bright_green(),
+            (total - remaining.len()).to_string().bright_green()
+        );
+    }
+    remaining
 }
 
 /// 입력 경로 유효성 검사
-fn validate_input(args: &Args) -> Result<()> {
-    if !args.input.exists() {
-        anyhow::bail!("입력 폴더가 존재하지 않습니다: {:?}", args.input);
+fn validate_input(input: &Path) -> Result<()> {
+    if !input.exists() {
+        anyhow::bail!("입력 폴더가 존재하지 않습니다: {:?}", input);
     }
 
-    if !args.input.is_dir() {
-        anyhow::bail!("입력 경로가 폴더가 아닙니다: {:?}", args.input);
+    if !input.is_dir() {
+        anyhow::bail!("입력 경로가 폴더가 아닙니다: {:?}", input);
     }
 
     Ok(())
 }
 
 /// 헤더 출력
-fn print_header(args: &Args) {
-    println!("\n{}", "═".repeat(50).bright_blue());
-    println!(
+fn print_header(args: &Args, priority: Option<PriorityOutcome>) {
+    status!("\n{}", "═".repeat(50).bright_blue());
+    status!(
         "{}",
         " 🚀 JSON FOLDER TO JSONL CONVERTER".bright_white().bold()
     );
-    println!("{}", "═".repeat(50).bright_blue());
-    println!("  {} 입력 폴더: {:?}", "📂".bright_cyan(), args.input);
+    status!("{}", "═".repeat(50).bright_blue());
+    if args.stdin {
+        status!("  {} 입력: {}", "📂".bright_cyan(), "표준 입력".cyan());
+    }
+    for input in &args.input {
+        if input.as_os_str() == STDIO_PATH {
+            status!(
+                "  {} 입력: {}",
+                "📂".bright_cyan(),
+                "표준 입력 (파일 경로 목록)".cyan()
+            );
+        } else {
+            status!("  {} 입력 폴더: {:?}", "📂".bright_cyan(), input);
+        }
+    }
+    if let Some(ref list) = args.files_from {
+        status!("  {} 파일 목록: {:?}", "📂".bright_cyan(), list);
+    }
 
     if !args.validate_only {
-        println!("  {} 출력 파일: {:?}", "📄".bright_green(), args.output);
-        println!("  {} 모드: {}", "⚙️".bright_yellow(), args.mode);
+        if args.writes_stdout() {
+            status!("  {} 출력: {}", "📄".bright_green(), "표준 출력".green());
+        } else if let Some(target) = args.remote_target() {
+            status!("  {} 업로드: {}", "☁️".bright_green(), target);
+        } else {
+            status!("  {} 출력 파일: {:?}", "📄".bright_green(), args.output);
+        }
+        if args.skip_existing {
+            status!(
+                "  {} 모드: {} (기존 출력에 있는 레코드 건너뜀)",
+                "⚙️".bright_yellow(),
+                args.mode
+            );
+        } else {
+            status!("  {} 모드: {}", "⚙️".bright_yellow(), args.mode);
+        }
+    }
+
+    if !args.pattern.is_empty() {
+        status!(
+            "  {} 패턴 필터: {}",
+            "🔍".bright_magenta(),
+            args.pattern.join(", ")
+        );
+    }
+
+    if !args.exclude.is_empty() {
+        status!(
+            "  {} 제외 패턴: {}",
+            "🚫".bright_magenta(),
+            args.exclude.join(", ")
+        );
+    }
+
+    if let (Some(nice), Some(outcome)) = (args.nice, priority) {
+        let io = if outcome.io {
+            format!("I/O {}", io_priority_for(nice))
+        } else {
+            "I/O 변경 안 됨".to_string()
+        };
+        if outcome.cpu {
+            status!(
+                "  {} 낮은 우선순위: nice {}, {}",
+                "🐢".bright_white(),
+                nice,
+                io
+            );
+        } else {
+            warning!(
+                "  {} {}",
+                "⚠️".bright_yellow(),
+                "이 플랫폼에서는 우선순위를 낮출 수 없습니다".yellow()
+            );
+        }
+    }
+
+    if args.pin_threads {
+        status!(
+            "  {} 스레드 고정: CPU {}",
+            "📌".bright_white(),
+            args.cpu_list.as_deref().unwrap_or("전체")
+        );
+    }
+
+    if args.geojson {
+        status!(
+            "  {} GeoJSON: {}",
+            "🌍".bright_cyan(),
+            if args.flatten_properties {
+                "Feature마다 한 줄 (속성을 최상위로)"
+            } else {
+                "Feature마다 한 줄"
+            }
+            .cyan()
+        );
     }
 
-    if let Some(ref pattern) = args.pattern {
-        println!("  {} 패턴 필터: {}", "🔍".bright_magenta(), pattern);
+    if args.multi_doc {
+        status!(
+            "  {} 여러 문서 모드: {}",
+            "📚".bright_cyan(),
+            "파일 안의 문서마다 한 줄".cyan()
+        );
     }
 
     if let Some(ref fields) = args.fields {
-        println!("  {} 필드 선택: {}", "🎯".bright_cyan(), fields);
+        status!("  {} 필드 선택: {}", "🎯".bright_cyan(), fields);
+    }
+
+    if let Some(ref fields) = args.exclude_fields {
+        status!("  {} 필드 제외: {}", "✂️".bright_cyan(), fields);
+    }
+
+    if let Some(ref expr) = args.where_expr {
+        status!("  {} 조건 필터: {}", "🔎".bright_cyan(), expr);
+    }
+
+    if let Some(ref expr) = args.transform {
+        status!("  {} 변환 표현식: {}", "🧮".bright_cyan(), expr);
+    }
+
+    if let Some(ref schema) = args.filter_schema {
+        status!("  {} 스키마 필터: {:?}", "📐".bright_cyan(), schema);
+    }
+
+    if let Some(ref rejects) = args.rejects {
+        status!("  {} 제외 레코드 파일: {:?}", "🗂️".bright_cyan(), rejects);
+    }
+
+    if args.passthrough {
+        status!(
+            "  {} 그대로 복사: 한 줄 문서는 재직렬화하지 않음",
+            "⏩".bright_cyan()
+        );
+    }
+
+    if let Some(sampler) = args.sampler() {
+        status!(
+            "  {} 샘플링: {}% (시드 {})",
+            "🎲".bright_cyan(),
+            sampler.rate() * 100.0,
+            sampler.seed()
+        );
+    }
+
+    if let Some(limit) = args.limit {
+        status!("  {} 최대 레코드 수: {}", "🔢".bright_cyan(), limit);
+    }
+
+    if let Some(ref key) = args.sort_by {
+        status!(
+            "  {} 정렬: {} ({}, 메모리 {})",
+            "🔀".bright_cyan(),
+            key,
+            if args.desc {
+                "내림차순"
+            } else {
+                "오름차순"
+            },
+            format_bytes(args.sort_memory)
+        );
+    }
+
+    match args.key_order {
+        KeyOrder::Default => {}
+        KeyOrder::Preserve => status!("  {} 키 순서: 원본 순서 유지", "🔑".bright_cyan()),
+        KeyOrder::Sort => status!("  {} 키 순서: 사전순 정렬", "🔑".bright_cyan()),
+    }
+
+    if let Some(order) = &args.field_order {
+        status!("  {} 필드 순서: {}", "🔢".bright_cyan(), order);
+    }
+
+    if let Some(case) = args.normalize_keys {
+        let case = match case {
+            KeyCase::Snake => "snake_case",
+            KeyCase::Camel => "camelCase",
+            KeyCase::Lower => "소문자",
+        };
+        status!("  {} 키 표기법 통일: {}", "🔤".bright_cyan(), case);
+    }
+
+    if let Some(ref prefix) = args.strip_key_prefix {
+        status!("  {} 키 접두사 제거: {}", "➖".bright_cyan(), prefix);
+    }
+
+    if let Some(ref prefix) = args.key_prefix {
+        status!("  {} 키 접두사 추가: {}", "➕".bright_cyan(), prefix);
+    }
+
+    if args.encoding != InputEncoding::Auto || args.lossy {
+        let encoding = match args.encoding {
+            InputEncoding::Auto => "자동 감지",
+            InputEncoding::Utf8 => "UTF-8",
+            InputEncoding::Utf16 => "UTF-16",
+            InputEncoding::Latin1 => "Latin-1",
+        };
+        let lossy = if args.lossy {
+            " (잘못된 바이트는 U+FFFD로 교체)"
+        } else {
+            ""
+        };
+        status!(
+            "  {} 입력 인코딩: {}{}",
+            "🔡".bright_cyan(),
+            encoding,
+            lossy
+        );
+    }
+
+    if let Some(guard) = args.record_size_guard() {
+        let policy = match guard.policy() {
+            OversizePolicy::Skip => "넘으면 건너뜀",
+            OversizePolicy::TruncateFields => "넘으면 큰 필드부터 제거",
+            OversizePolicy::Error => "넘으면 파일 실패",
+        };
+        status!(
+            "  {} 레코드 크기 제한: {} ({})",
+            "📏".bright_cyan(),
+            format_bytes(guard.max_bytes()),
+            policy
+        );
+    }
+
+    if args.repair_jsonl {
+        status!("  {} JSONL 레코드 경계 복구: 사용", "🩹".bright_cyan());
+    }
+
+    if let Some(ref rename) = args.rename {
+        status!("  {} 필드 이름 변경: {}", "🔤".bright_cyan(), rename);
+    }
+
+    if args.add_source || args.add_mtime {
+        let keys: Vec<&str> = [
+            (args.add_source, DEFAULT_SOURCE_KEY),
+            (args.add_mtime, DEFAULT_MTIME_KEY),
+        ]
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, key)| *key)
+        .collect();
+        status!("  {} 원본 정보: {}", "🏷️".bright_cyan(), keys.join(", "));
+    }
+
+    if let Some(ref checksum) = args.checksum_field() {
+        let scope = match checksum.scope {
+            ChecksumScope::File => "원본 파일",
+            ChecksumScope::Record => "레코드",
+        };
+        status!(
+            "  {} 체크섬: {} ({} SHA-256)",
+            "🔐".bright_cyan(),
+            checksum.key,
+            scope
+        );
+    }
+
+    if args.annotate {
+        status!(
+            "  {} 검토용 주석 출력: 레코드마다 원본 파일 표시",
+            "📝".bright_cyan()
+        );
+    }
+
+    if let Some(ref pipeline) = args.pipeline {
+        status!("  {} 파이프라인: {:?}", "🧩".bright_magenta(), pipeline);
+    }
+
+    if let Some(ref key) = args.dedupe_by {
+        let mode = if args.spills_dedup() {
+            "디스크 기반 정확".to_string()
+        } else if args.dedupe_exact {
+            "정확".to_string()
+        } else {
+            format!("근사, 위양성 ≤ {}", args.dedupe_fp_rate)
+        };
+        let keep = match args.dedupe_keep {
+            DedupKeep::First => "처음 레코드 유지",
+            DedupKeep::Last => "마지막 레코드 유지",
+        };
+        status!(
+            "  {} 중복 제거: {} ({}, {})",
+            "🧹".bright_yellow(),
+            key,
+            mode,
+            keep
+        );
+    }
+
+    let sync = args.sync_policy();
+    if sync.is_enabled() {
+        let mut when = Vec::new();
+        if let Some(every) = sync.every_records {
+            when.push(format!("{} 레코드마다", every));
+        }
+        if sync.on_rotate {
+            when.push("분할 파일 전환 시".to_string());
+        }
+        if sync.on_finish {
+            when.push("완료 시".to_string());
+        }
+        status!("  {} fsync: {}", "💽".bright_white(), when.join(", "));
+    }
+
+    let limits = args.parse_limits();
+    if limits.is_enabled() {
+        let mut rules = Vec::new();
+        if let Some(depth) = limits.max_depth {
+            rules.push(format!("깊이 {}", depth));
+        }
+        if let Some(len) = limits.max_string_len {
+            rules.push(format!("문자열 {}", format_bytes(len as u64)));
+        }
+        if let Some(len) = limits.max_array_len {
+            rules.push(format!("배열 요소 {}개", len));
+        }
+        status!("  {} 파싱 한도: {}", "🛡️".bright_white(), rules.join(", "));
+    }
+
+    if args.parser != ParserKind::Serde {
+        status!(
+            "  {} 파서: {}",
+            "⚡".bright_yellow(),
+            args.effective_parser()
+        );
+    }
+
+    if args.type_report {
+        status!("  {} 타입 충돌 보고: 활성화", "🧬".bright_yellow());
+    }
+
+    if let Some(ref path) = args.infer_schema {
+        status!("  {} 스키마 추론: {:?}", "🧭".bright_cyan(), path);
+    }
+
+    if args.schema_drift {
+        status!(
+            "  {} 스키마 변화 감지: 앞쪽 {}개 파일 기준",
+            "🧬".bright_yellow(),
+            args.schema_baseline
+        );
     }
 
     if let Some(depth) = args.max_depth {
-        println!("  {} 최대 깊이: {}", "📏".bright_white(), depth);
+        status!("  {} 최대 깊이: {}", "📏".bright_white(), depth);
+    }
+    if args.follow_symlinks || args.one_file_system {
+        let mut rules = Vec::new();
+        if args.follow_symlinks {
+            rules.push("심볼릭 링크 폴더 탐색");
+        }
+        if args.one_file_system {
+            rules.push("다른 파일 시스템 제외");
+        }
+        status!("  {} 폴더 탐색: {}", "🔗".bright_white(), rules.join(", "));
+    }
+    if args.dedup_content {
+        status!(
+            "  {} 내용이 같은 입력 파일은 처음 하나만 처리",
+            "📑".bright_white()
+        );
+    }
+
+    let file_filter = args.file_filter();
+    if file_filter.is_enabled() {
+        let mut rules = Vec::new();
+        if let Some(size) = file_filter.min_size {
+            rules.push(format!("{} 이상", format_bytes(size)));
+        }
+        if let Some(size) = file_filter.max_size {
+            rules.push(format!("{} 이하", format_bytes(size)));
+        }
+        // 기준 시각을 현재 시각과의 차이로 표시
+        let relative = |time: SystemTime| match SystemTime::now().duration_since(time) {
+            Ok(age) => format!("{} 전", format_duration(age)),
+            Err(e) => format!("{} 후", format_duration(e.duration())),
+        };
+        if let Some(time) = file_filter.newer_than {
+            rules.push(format!("{} 이후 수정", relative(time)));
+        }
+        if let Some(time) = file_filter.older_than {
+            rules.push(format!("{} 이전 수정", relative(time)));
+        }
+        status!("  {} 파일 필터: {}", "🗓️".bright_white(), rules.join(", "));
+    }
+
+    let gates = args.quality_gates();
+    if gates.is_enabled() {
+        let mut rules = Vec::new();
+        if let Some(rate) = gates.min_success_rate {
+            rules.push(format!("성공률 {}% 이상", rate));
+        }
+        if let Some(count) = gates.max_error_count {
+            rules.push(format!("실패 파일 {}개 이하", count));
+        }
+        status!("  {} 품질 기준: {}", "🚧".bright_white(), rules.join(", "));
+    }
+
+    if let Some(ref baseline) = args.baseline {
+        status!(
+            "  {} 기준 실행: {:?} (허용 범위 {}%)",
+            "📉".bright_white(),
+            baseline,
+            args.baseline_tolerance
+        );
     }
 
     if args.dry_run {
-        println!(
+        status!(
             "  {} {}",
             "⚠️".bright_yellow(),
             "드라이런 모드 (실제 병합 없음)".yellow()
@@ -122,212 +785,1899 @@ fn print_header(args: &Args) {
     }
 
     if args.validate_only {
-        println!("  {} {}", "🔍".bright_cyan(), "유효성 검사 모드".cyan());
+        status!("  {} {}", "🔍".bright_cyan(), "유효성 검사 모드".cyan());
+    }
+
+    if args.output_format == OutputFormat::Bundle {
+        status!(
+            "  {} 출력 형식: 번들 (메타데이터 머리글 + JSONL 본문)",
+            "🧾".bright_cyan()
+        );
+    }
+
+    if let Some(codec) = args.compress {
+        status!("  {} 출력 압축: {}", "🗜️".bright_cyan(), codec.name());
     }
 
     if args.pretty {
-        println!(
+        status!(
             "  {} {}",
             "✨".bright_magenta(),
             "Pretty 출력 모드".magenta()
         );
     }
 
-    println!("{}", "═".repeat(50).bright_blue());
-    println!("\n{}", "📁 파일 검색 중...".bright_cyan());
+    status!("{}", "═".repeat(50).bright_blue());
+
+    if !args.stdin {
+        status!("\n{}", "📁 파일 검색 중...".bright_cyan());
+    }
 }
 
-/// JSON 파일 수집
-fn collect_json_files(args: &Args, pattern_matcher: &PatternMatcher) -> Result<Vec<PathBuf>> {
-    let walker = if let Some(max_depth) = args.max_depth {
-        WalkDir::new(&args.input).max_depth(max_depth)
+/// 전역 스레드 풀 설정 (`-j`, `--pin-threads`)
+///
+/// 스레드를 고정하면 워커마다 코어 하나를 배정하고, `--cpu-list`만 지정했으면 목록의 코어 수만큼 워커를 만듭니다.
+fn build_thread_pool(args: &Args) -> Result<()> {
+    let pinning = if args.pin_threads {
+        let cpus = args
+            .cpu_list
+            .as_deref()
+            .map(parse_cpu_list)
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("--cpu-list: {}", e))?;
+        Some(ThreadPinning::new(cpus.as_deref()).map_err(|e| anyhow::anyhow!("{}", e))?)
     } else {
-        WalkDir::new(&args.input)
+        None
+    };
+
+    let threads = match (&pinning, args.threads) {
+        (_, Some(threads)) => Some(threads),
+        (Some(pinning), None) if args.cpu_list.is_some() => Some(pinning.core_count()),
+        _ => None,
     };
+    if threads.is_none() && pinning.is_none() {
+        return Ok(());
+    }
+
+    let mut builder = rayon::ThreadPoolBuilder::new();
+    if let Some(threads) = threads {
+        builder = builder.num_threads(threads);
+    }
+    if let Some(pinning) = pinning {
+        builder = builder.start_handler(move |worker| {
+            pinning.pin_current(worker);
+        });
+    }
+    builder.build_global().context("스레드 풀 초기화 실패")?;
+    Ok(())
+}
 
-    let json_files: Vec<PathBuf> = walker
+/// JSON 파일 수집
+///
+/// 입력 폴더(`-i`, 반복 지정 가능), 표준 입력의 경로 목록(`-i -`), `--files-from` 목록을 지정한 순서대로 합칩니다.
+/// 출처가 여러 개이면 실제 경로가 같은 파일은 처음 한 번만 처리합니다.
+fn collect_json_files(args: &Args, pattern_matcher: &PatternMatcher) -> Result<Vec<PathBuf>> {
+    let file_filter = args.file_filter();
+    let mut json_files = Vec::new();
+    for input in &args.input {
+        if input.as_os_str() == STDIO_PATH {
+            json_files.extend(read_path_list(
+                io::stdin().lock(),
+                pattern_matcher,
+                &file_filter,
+            )?);
+        } else {
+            json_files.extend(find_json_files(
+                input,
+                walk_options(args),
+                pattern_matcher,
+                &file_filter,
+                args.geojson,
+            )?);
+        }
+    }
+    if let Some(ref list) = args.files_from {
+        let file =
+            File::open(list).with_context(|| format!("파일 목록을 열 수 없습니다: {:?}", list))?;
+        json_files.extend(read_path_list(
+            io::BufReader::new(file),
+            pattern_matcher,
+            &file_filter,
+        )?);
+    }
+
+    // 링크를 따라가면 한 폴더 안에서도 같은 파일이 여러 경로로 나올 수 있음
+    if !args.has_multiple_sources() && !args.follow_symlinks {
+        return Ok(json_files);
+    }
+    let total = json_files.len();
+    let json_files = dedup_paths(json_files);
+    if json_files.len() < total {
+        status!(
+            "  {} 여러 경로로 중복된 파일 {}개 제외",
+            "🔁".bright_yellow(),
+            total - json_files.len()
+        );
+    }
+    Ok(json_files)
+}
+
+/// 내용이 같은 입력 파일 제외 (제외한 파일은 `-vv`로 확인)
+fn skip_duplicate_content(json_files: Vec<PathBuf>) -> (Vec<PathBuf>, DuplicateFiles) {
+    let (json_files, duplicates) = checksum::dedup_content(json_files);
+    if duplicates.count() > 0 {
+        status!(
+            "  {} 내용이 같은 중복 파일 {}개 제외 ({})",
+            "📑".bright_yellow(),
+            duplicates.count(),
+            format_bytes(duplicates.bytes)
+        );
+        for (duplicate, original) in &duplicates.pairs {
+            debug!(
+                "  중복 파일: {} = {}",
+                duplicate.display(),
+                original.display()
+            );
+        }
+    }
+    (json_files, duplicates)
+}
+
+/// 실제 경로(심볼릭 링크, `..` 해석)가 같은 파일 중 처음 것만 남김 (경로를 해석할 수 없으면 그대로 비교)
+fn dedup_paths(paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let mut seen = HashSet::with_capacity(paths.len());
+    paths
         .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_file())
-        .filter(|e| {
-            e.path()
-                .extension()
-                .and_then(|s| s.to_str())
-                .map(|s| s.eq_ignore_ascii_case("json"))
-                .unwrap_or(false)
-        })
-        .filter(|e| {
-            e.path()
-                .file_name()
-                .and_then(|s| s.to_str())
-                .map(|s| pattern_matcher.matches(s))
-                .unwrap_or(false)
-        })
-        .map(|e| e.path().to_path_buf())
-        .collect();
+        .filter(|path| seen.insert(path.canonicalize().unwrap_or_else(|_| path.clone())))
+        .collect()
+}
+
+/// 원본 파일 경로를 상대 경로로 만들 기준 폴더 목록 (`--add-source`, `--annotate`, `--stats-detail`)
+///
+/// 입력 폴더가 여러 개이면 어느 폴더에서 온 파일인지 구분되도록 폴더 이름을 남깁니다 (`a/x.json`, `b/x.json`).
+fn source_roots(args: &Args) -> Vec<PathBuf> {
+    let dirs = args.input_dirs();
+    if dirs.len() == 1 {
+        return vec![dirs[0].clone()];
+    }
+    dirs.into_iter()
+        .map(|dir| dir.parent().map(Path::to_path_buf).unwrap_or_default())
+        .collect()
+}
+
+/// 파일 크기/수정 시각 필터 확인 (메타데이터를 읽을 수 없으면 처리 단계에서 에러로 보고하도록 포함)
+fn passes_file_filter(path: &Path, file_filter: &FileFilter) -> bool {
+    !file_filter.is_enabled()
+        || fs::metadata(path)
+            .map(|metadata| file_filter.matches_metadata(&metadata))
+            .unwrap_or(true)
+}
+
+/// 줄 단위 파일 경로 목록 읽기 (`-i -`, `--files-from`, 빈 줄은 무시)
+///
+/// `find`, `xargs` 등이 넘겨준 경로이므로 확장자는 검사하지 않고 패턴 필터와 크기/수정 시각 필터만 적용합니다.
+fn read_path_list(
+    reader: impl BufRead,
+    pattern_matcher: &PatternMatcher,
+    file_filter: &FileFilter,
+) -> Result<Vec<PathBuf>> {
+    let mut json_files = Vec::new();
+    for line in reader.lines() {
+        let line = line.context("파일 목록 읽기 실패")?;
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let path = PathBuf::from(line);
+        if pattern_matcher.matches_path(&path) && passes_file_filter(&path, file_filter) {
+            json_files.push(path);
+        }
+    }
+    Ok(json_files)
+}
+
+/// 폴더 탐색 설정
+#[derive(Debug, Clone, Copy, Default)]
+struct WalkOptions {
+    /// 최대 탐색 깊이
+    max_depth: Option<usize>,
+    /// 심볼릭 링크로 연결된 폴더도 탐색
+    follow_symlinks: bool,
+    /// 다른 파일 시스템으로 넘어가지 않음
+    one_file_system: bool,
+}
+
+impl WalkOptions {
+    /// 설정을 적용한 폴더 탐색기 생성
+    fn walker(&self, input: &Path) -> WalkDir {
+        let walker = WalkDir::new(input)
+            .follow_links(self.follow_symlinks)
+            .same_file_system(self.one_file_system);
+        match self.max_depth {
+            Some(max_depth) => walker.max_depth(max_depth),
+            None => walker,
+        }
+    }
+}
+
+/// 변환 인자의 폴더 탐색 설정
+fn walk_options(args: &Args) -> WalkOptions {
+    WalkOptions {
+        max_depth: args.max_depth,
+        follow_symlinks: args.follow_symlinks,
+        one_file_system: args.one_file_system,
+    }
+}
+
+/// 입력 폴더에서 패턴에 맞는 JSON 파일 탐색 (`include_geojson`이면 `.geojson` 파일 포함)
+///
+/// 심볼릭 링크를 따라갈 때 상위 폴더로 돌아가는 순환 링크는 경고 후 건너뜁니다.
+fn find_json_files(
+    input: &Path,
+    walk: WalkOptions,
+    pattern_matcher: &PatternMatcher,
+    file_filter: &FileFilter,
+    include_geojson: bool,
+) -> Result<Vec<PathBuf>> {
+    let json_files: Vec<PathBuf> = walk
+        .walker(input)
+        .into_iter()
+        .filter_map(|e| match e {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                if let (Some(path), Some(ancestor)) = (e.path(), e.loop_ancestor()) {
+                    warning!(
+                        "  {} 순환 심볼릭 링크 건너뜀: {} → {}",
+                        "⚠️".yellow(),
+                        path.display(),
+                        ancestor.display()
+                    );
+                }
+                None
+            }
+        })
+        .filter(|e| e.path().is_file())
+        .filter(|e| is_input_file(e.path()) || (include_geojson && is_geojson_file(e.path())))
+        .filter(|e| {
+            e.path()
+                .strip_prefix(input)
+                .map(|relative| pattern_matcher.matches_path(relative))
+                .unwrap_or(false)
+        })
+        .filter(|e| passes_file_filter(e.path(), file_filter))
+        .map(|e| e.path().to_path_buf())
+        .collect();
+
+    Ok(json_files)
+}
+
+/// 실행 계획 출력 (파일 수, 용량, 크기 분포, 예상 처리 시간)
+fn run_plan(plan_args: &PlanArgs) -> Result<()> {
+    validate_input(&plan_args.input)?;
+
+    status!("\n{}", "═".repeat(50).bright_blue());
+    status!("{}", " 🗺️ JSONL 변환 실행 계획".bright_white().bold());
+    status!("{}", "═".repeat(50).bright_blue());
+    status!("  {} 입력 폴더: {:?}", "📂".bright_cyan(), plan_args.input);
+
+    let pattern_matcher = PatternMatcher::with_patterns(&plan_args.pattern, &plan_args.exclude)
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    let json_files = find_json_files(
+        &plan_args.input,
+        WalkOptions {
+            max_depth: plan_args.max_depth,
+            ..Default::default()
+        },
+        &pattern_matcher,
+        &FileFilter::default(),
+        false,
+    )?;
+
+    if json_files.is_empty() {
+        warning!("{}", "⚠️ 처리할 JSON 파일이 없습니다.".yellow());
+        return Ok(());
+    }
+
+    let sizes: Vec<u64> = json_files
+        .iter()
+        .map(|path| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0))
+        .collect();
+    let summary = PlanSummary::from_sizes(&sizes);
+
+    status!(
+        "  {} 파일 수: {}",
+        "📋".bright_white(),
+        summary.file_count.to_string().bright_green()
+    );
+    status!(
+        "  {} 전체 용량: {}",
+        "💾".bright_white(),
+        format_bytes(summary.total_bytes).bright_green()
+    );
+    status!(
+        "  {} 파일 크기: 최소 {} / 중간 {} / 최대 {}",
+        "📏".bright_white(),
+        format_bytes(summary.min_bytes),
+        format_bytes(summary.median_bytes),
+        format_bytes(summary.max_bytes)
+    );
+
+    status!("\n{}", "📊 크기 분포:".bright_cyan());
+    for bucket in &summary.buckets {
+        status!(
+            "  {:<16} {:>8} 개  {:>12}",
+            bucket.label,
+            bucket.files,
+            format_bytes(bucket.bytes)
+        );
+    }
+
+    let threads = plan_args
+        .threads
+        .unwrap_or_else(rayon::current_num_threads)
+        .max(1);
+    let benchmark = benchmark_sample(&json_files, plan_args.sample, &ProcessOptions::new());
+
+    status!("\n{}", "⏱️ 예상 처리 시간:".bright_cyan());
+    if benchmark.files == 0 {
+        status!(
+            "  {}",
+            "샘플 파일이 없어 예상 시간을 계산하지 않습니다.".yellow()
+        );
+        return Ok(());
+    }
+    status!(
+        "  샘플: {} 개 파일, {} ({})",
+        benchmark.files,
+        format_bytes(benchmark.bytes),
+        format_duration(benchmark.elapsed)
+    );
+    status!(
+        "  처리 속도: {}/초 (단일 스레드)",
+        format_bytes(benchmark.throughput() as u64)
+    );
+    status!(
+        "  예상 시간: {} ({} 스레드 기준)",
+        format_duration(benchmark.project(&summary, threads))
+            .bright_green()
+            .bold(),
+        threads
+    );
+
+    Ok(())
+}
+
+/// 입력 목록에서 이전 실행의 출력 파일(분할 파일, `--stats-json`/`--baseline` 보고서, `--rejects` 파일 포함) 제외
+///
+/// 출력 파일이 입력 폴더 안에 있으면 다시 실행할 때 이전 결과가 입력으로 병합되므로
+/// 경고와 함께 제외합니다 (`--include-output`이면 그대로 둠).
+fn exclude_output_files(args: &Args, json_files: Vec<PathBuf>) -> Vec<PathBuf> {
+    if args.include_output {
+        return json_files;
+    }
+
+    // (출력 파일 경로, 분할 파일 포함 여부)
+    let mut outputs = Vec::new();
+    if !args.writes_stdout() {
+        outputs.push((args.output.as_path(), args.shard_limits().is_enabled()));
+    }
+    if let Some(ref report) = args.stats_json {
+        outputs.push((report.as_path(), false));
+    }
+    if let Some(ref baseline) = args.baseline {
+        outputs.push((baseline.as_path(), false));
+    }
+    if let Some(ref rejects) = args.rejects {
+        outputs.push((rejects.as_path(), false));
+    }
+    if let Some(ref checkpoint) = args.checkpoint {
+        outputs.push((checkpoint.as_path(), false));
+    }
+    if let Some(ref schema) = args.infer_schema {
+        outputs.push((schema.as_path(), false));
+    }
+
+    let targets: Vec<(PathBuf, &Path, bool)> = outputs
+        .into_iter()
+        .filter_map(|(output, sharded)| {
+            let dir = match output.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent.canonicalize(),
+                _ => Path::new(".").canonicalize(),
+            };
+            dir.ok().map(|dir| (dir, output, sharded))
+        })
+        .collect();
+    if targets.is_empty() {
+        return json_files;
+    }
+
+    let is_output = |path: &PathBuf| {
+        let Some(name) = path.file_name() else {
+            return false;
+        };
+        let mut parent = None;
+        targets.iter().any(|(dir, output, sharded)| {
+            let same_name = Some(name) == output.file_name()
+                || (*sharded && is_shard_name(output, &name.to_string_lossy()));
+            same_name
+                && parent
+                    .get_or_insert_with(|| path.parent().and_then(|p| p.canonicalize().ok()))
+                    .as_ref()
+                    .is_some_and(|parent| parent == dir)
+        })
+    };
+
+    let (excluded, kept): (Vec<PathBuf>, Vec<PathBuf>) =
+        json_files.into_iter().partition(is_output);
+    for path in &excluded {
+        warning!(
+            "  {} 출력 파일이 입력 폴더 안에 있어 제외합니다: {:?}",
+            "⚠️".bright_yellow(),
+            path
+        );
+    }
+    kept
+}
+
+/// 스키마에 맞는 테스트 JSON 파일 생성
+fn run_generate(generate_args: &GenerateArgs) -> Result<()> {
+    let generator = Generator::load(&generate_args.schema).map_err(|e| anyhow::anyhow!("{}", e))?;
+    let seed = generate_args.seed.unwrap_or_else(time_seed);
+
+    status!("\n{}", "═".repeat(50).bright_blue());
+    status!("{}", " 🎲 테스트 데이터 생성".bright_white().bold());
+    status!("{}", "═".repeat(50).bright_blue());
+    status!(
+        "  {} 스키마: {:?}",
+        "📐".bright_cyan(),
+        generate_args.schema
+    );
+    status!(
+        "  {} 출력 폴더: {:?}",
+        "📂".bright_cyan(),
+        generate_args.out
+    );
+    status!(
+        "  {} 파일 수: {} (파일당 레코드 {}개)",
+        "📋".bright_white(),
+        generate_args.count.to_string().bright_green(),
+        generate_args.records_per_file
+    );
+    status!("  {} 시드: {}", "🌱".bright_green(), seed);
+    status!("{}", "═".repeat(50).bright_blue());
+
+    std::fs::create_dir_all(&generate_args.out)
+        .with_context(|| format!("출력 폴더 생성 실패: {:?}", generate_args.out))?;
+
+    let stats = Statistics::new(generate_args.count as usize);
+    let pb = create_progress_bar(
+        generate_args.count as usize,
+        auto_interval(generate_args.count),
+    );
+    (0..generate_args.count)
+        .into_par_iter()
+        .try_for_each(|index| -> Result<()> {
+            let path = generated_path(&generate_args.out, index, generate_args.count);
+            let bytes = generator
+                .write_file(&path, seed, index, generate_args.records_per_file)
+                .map_err(|e| anyhow::anyhow!("{}", e))?;
+            stats.add_bytes_written(bytes);
+            stats.increment_success();
+            pb.inc();
+            Ok(())
+        })?;
+    pb.finish_with_message("완료!");
+
+    status!(
+        "\n{} {} 개 파일 생성 완료 ({}, {})",
+        "✅".bright_green(),
+        stats.get_success_count().to_string().bright_green(),
+        format_bytes(stats.get_bytes_written()),
+        format_duration(stats.elapsed())
+    );
+    status!(
+        "  {} 같은 데이터를 다시 만들려면 --seed {} 를 지정하세요\n",
+        "ℹ️".bright_blue(),
+        seed
+    );
+
+    Ok(())
+}
+
+/// JSONL 파일 검사 실행 (`jconvert validate --jsonl`)
+fn run_validate_jsonl(validate_args: &ValidateArgs) -> Result<ExitStatus> {
+    if let Some(threads) = validate_args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .context("스레드 풀 초기화 실패")?;
+    }
+
+    status!("\n{}", "═".repeat(50).bright_blue());
+    status!("{}", " 🔎 JSONL 파일 검사".bright_white().bold());
+    status!("{}", "═".repeat(50).bright_blue());
+    status!(
+        "  {} 검사할 파일 수: {}",
+        "📋".bright_white(),
+        validate_args.jsonl.len().to_string().bright_green()
+    );
+    status!(
+        "  {} 조각 크기: {}",
+        "🧩".bright_white(),
+        format_bytes(validate_args.chunk_size)
+    );
+    if validate_args.fix {
+        status!("  {} 레코드 경계 복구: 사용", "🩹".bright_white());
+    }
+    status!("{}", "═".repeat(50).bright_blue());
+
+    let stats = Statistics::new(validate_args.jsonl.len());
+    let mut errors: Vec<ErrorEntry> = Vec::new();
+    let mut records = 0;
+    let mut bad_lines = 0;
+    let mut fixed_files = 0;
+
+    for path in &validate_args.jsonl {
+        let check = match check_jsonl(path, validate_args.chunk_size) {
+            Ok(check) => check,
+            Err(e) => {
+                stats.increment_error();
+                status!(
+                    "  {} {:?}: {}",
+                    "❌".bright_red(),
+                    path,
+                    e.to_string().red()
+                );
+                errors.push(ErrorEntry::new(path.clone(), e.kind(), e.to_string()));
+                continue;
+            }
+        };
+        stats.add_bytes_read(check.bytes);
+        records += check.records;
+        bad_lines += check.errors.len();
+
+        if check.is_valid() {
+            stats.increment_success();
+            status!("  {} {:?}: 레코드 {}건", "✓".green(), path, check.records);
+            continue;
+        }
+
+        status!(
+            "  {} {:?}: 레코드 {}건 중 잘못된 줄 {}개",
+            "✗".red(),
+            path,
+            check.records,
+            check.errors.len().to_string().red()
+        );
+        for error in check.errors.iter().take(validate_args.show) {
+            status!("    {}번째 줄: {}", error.line, error.reason.dimmed());
+        }
+        if check.errors.len() > validate_args.show {
+            status!(
+                "    {}",
+                format!(
+                    "... 외 {}개 (--log로 전체 목록 저장)",
+                    check.errors.len() - validate_args.show
+                )
+                .dimmed()
+            );
+        }
+
+        if validate_args.fix {
+            match repair::repair_file(path) {
+                Ok(summary) => {
+                    stats.increment_success();
+                    stats.add_repaired(summary.repaired() as usize);
+                    fixed_files += 1;
+                    status!(
+                        "    {} 복구: 레코드 {}건 (분리 {}, 결합 {})",
+                        "🩹".bright_green(),
+                        summary.records,
+                        summary.split,
+                        summary.joined
+                    );
+                    continue;
+                }
+                Err(e) => {
+                    warning!("    {} 복구 실패: {}", "⚠️".yellow(), e);
+                }
+            }
+        }
+
+        stats.increment_validation_failed();
+        errors.extend(check.errors.into_iter().map(|error| {
+            ErrorEntry::new(
+                path.clone(),
+                "parse",
+                format!("{}번째 줄: {}", error.line, error.reason),
+            )
+            .with_file_size(check.bytes)
+        }));
+    }
+
+    if let Some(ref log_path) = validate_args.log {
+        write_error_log(log_path, &errors, validate_args.error_log_format)?;
+    }
+
+    status!(
+        "\n  {} 레코드 {}건, 잘못된 줄 {}개 ({}, {})",
+        "📊".bright_white(),
+        records,
+        if bad_lines == 0 {
+            bad_lines.to_string().green()
+        } else {
+            bad_lines.to_string().red()
+        },
+        format_bytes(stats.get_bytes_read()),
+        format_duration(stats.elapsed())
+    );
+
+    let failures = stats.get_error_count() + stats.get_validation_failed();
+    if failures == 0 && fixed_files > 0 {
+        status!(
+            "\n{} {}개 파일의 잘못된 줄을 복구했습니다!\n",
+            "✅".bright_green(),
+            fixed_files
+        );
+    } else if failures == 0 {
+        status!("\n{} 모든 줄이 유효합니다!\n", "✅".bright_green());
+    } else {
+        warning!(
+            "\n{} {} 개의 파일에 문제가 있습니다.\n",
+            "⚠️".bright_yellow(),
+            failures.to_string().red()
+        );
+    }
+
+    Ok(ExitStatus::from_failures(failures))
+}
+
+/// 드라이런 출력
+fn print_dry_run(json_files: &[PathBuf]) {
+    status!("\n{}", "📋 처리 예정 파일 목록:".bright_cyan());
+    for (i, path) in json_files.iter().enumerate() {
+        status!("  {}. {:?}", i + 1, path.file_name().unwrap_or_default());
+    }
+    status!(
+        "\n{} 총 {} 개의 파일이 처리될 예정입니다.",
+        "ℹ️".bright_blue(),
+        json_files.len().to_string().bright_green()
+    );
+}
+
+/// 드라이런 이어 쓰기 검사: 기존 출력의 줄 수와 이어 쓰면 출력이 손상되는 문제 경고
+fn print_append_check(args: &Args) -> Result<()> {
+    if args.writes_stdout() || args.remote_target().is_some() {
+        return Ok(());
+    }
+    let target = AppendTarget::inspect(&args.output)
+        .with_context(|| format!("기존 출력 파일을 읽을 수 없습니다: {:?}", args.output))?;
+
+    status!("\n{}", "📎 이어 쓰기 대상 검사:".bright_cyan());
+    let Some(target) = target else {
+        status!("  출력 파일이 없어 새로 만듭니다: {:?}", args.output);
+        return Ok(());
+    };
+
+    if let Some(format) = target.compression {
+        warning!(
+            "  {} 출력 파일이 {} 압축 파일입니다 ({}). 평문 JSONL을 이어 쓸 수 없어 변환이 실패합니다.",
+            "⚠️".yellow(),
+            format,
+            format_bytes(target.bytes)
+        );
+        return Ok(());
+    }
+
+    status!(
+        "  기존 출력: {}줄, {}",
+        target.lines.to_string().bright_green(),
+        format_bytes(target.bytes)
+    );
+    if target.invalid_lines > 0 {
+        warning!(
+            "  {} JSON으로 파싱할 수 없는 줄 {}개",
+            "⚠️".yellow(),
+            target.invalid_lines
+        );
+    }
+    match target.trailing {
+        Some(ref trailing) if trailing.complete => warning!(
+            "  {} 마지막 줄이 줄바꿈 없이 끝납니다 ({}바이트 위치). 이어 쓰기 전에 줄바꿈을 붙입니다.",
+            "⚠️".yellow(),
+            trailing.offset
+        ),
+        Some(ref trailing) => warning!(
+            "  {} 마지막 줄이 잘려 있습니다 ({}바이트 위치, {}). 이어 쓰기 전에 잘라 냅니다.",
+            "⚠️".yellow(),
+            trailing.offset,
+            format_bytes(trailing.len())
+        ),
+        None if target.invalid_lines == 0 => {
+            status!("  {} 이어 쓰기에 문제가 없습니다.", "✅".green())
+        }
+        None => {}
+    }
+    Ok(())
+}
+
+/// 미리보기에서 레코드 하나에 표시할 최대 바이트 수
+const PREVIEW_MAX_BYTES: usize = 2000;
+
+/// 드라이런 미리보기: 변환된 처음 레코드와 샘플 기반 예상 출력 크기
+///
+/// 정렬, 중복 제거, 개수 제한처럼 전체 레코드가 필요한 단계는 반영하지 않습니다.
+fn print_preview(args: &Args, json_files: &[PathBuf], count: usize) -> Result<()> {
+    let options = build_process_options(args)?;
+
+    status!(
+        "\n{}",
+        format!("🔬 변환 미리보기 (처음 {}개 레코드):", count).bright_cyan()
+    );
+    let records = preview_records(json_files, count, &options);
+    if records.is_empty() {
+        status!("  {}", "출력될 레코드가 없습니다.".yellow());
+    }
+    for (i, (path, line)) in records.iter().enumerate() {
+        status!(
+            "  {} {}",
+            format!("[{}]", i + 1).bright_white(),
+            relative_to_roots(path, &source_roots(args)).dimmed()
+        );
+        if line.len() > PREVIEW_MAX_BYTES {
+            let mut end = PREVIEW_MAX_BYTES;
+            while !line.is_char_boundary(end) {
+                end -= 1;
+            }
+            status!(
+                "{}{}",
+                &line[..end],
+                format!("… (전체 {})", format_bytes(line.len() as u64)).dimmed()
+            );
+        } else {
+            status!("{}", line);
+        }
+    }
+
+    let total_bytes: u64 = json_files
+        .iter()
+        .map(|path| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0))
+        .sum();
+    let estimate = estimate_output(json_files, DEFAULT_SAMPLE_SIZE, &options);
+
+    status!("\n{}", "📐 예상 출력:".bright_cyan());
+    status!(
+        "  샘플: {} 개 파일, 입력 {} → 출력 {} ({}줄)",
+        estimate.files,
+        format_bytes(estimate.input_bytes),
+        format_bytes(estimate.output_bytes),
+        estimate.lines
+    );
+    if estimate.failed > 0 {
+        warning!(
+            "  {} 샘플 중 {}개 파일 변환 실패",
+            "⚠️".yellow(),
+            estimate.failed
+        );
+    }
+    status!(
+        "  예상 크기: {} (약 {}줄, 전체 입력 {} 기준)",
+        format_bytes(estimate.project_bytes(total_bytes))
+            .bright_green()
+            .bold(),
+        estimate.project_lines(total_bytes),
+        format_bytes(total_bytes)
+    );
+    if args.sort_by.is_some() || args.dedupe_by.is_some() || args.limit.is_some() {
+        status!(
+            "  {} 정렬, 중복 제거, 개수 제한은 미리보기와 예상 크기에 반영되지 않습니다.",
+            "ℹ️".bright_blue()
+        );
+    }
+    Ok(())
+}
+
+/// 유효성 검사 모드 실행
+fn run_validation_mode(
+    args: &Args,
+    json_files: Vec<PathBuf>,
+    stats: &Statistics,
+) -> Result<ValidationReport> {
+    // 진행률 바 설정
+    let pb = create_progress_bar(json_files.len(), args.progress_interval(json_files.len()));
+
+    status!("\n{}", "🔍 유효성 검사 중...".bright_cyan());
+
+    let options = ProcessOptions::new()
+        .with_validate_only(true)
+        .with_parse_limits(args.parse_limits())
+        .with_multi_doc(args.multi_doc)
+        .with_parser(args.parser)
+        .with_encoding(args.encoding)
+        .with_lossy(args.lossy);
+    let budget = ErrorBudget::new(args.error_limit());
+
+    let results: Vec<ProcessResult> = json_files
+        .into_par_iter()
+        .with_min_len(args.batch_len())
+        .filter_map(|path| {
+            // 에러 허용 한도를 넘었으면 남은 파일은 건너뜀
+            if budget.is_exhausted() {
+                return None;
+            }
+            let result = process_file(path, &options);
+            pb.inc();
+            if counts_as_error(args, &result) {
+                budget.record_error();
+            }
+            Some(result)
+        })
+        .collect();
+
+    pb.finish_with_message("완료!");
+
+    for result in &results {
+        if result.reencoded {
+            stats.increment_reencoded();
+        }
+        record_file_stat(args, stats, result);
+    }
+    let report = ValidationReport::from_results(results);
+    let mut errors: Vec<ErrorEntry> = Vec::new();
+    for file in &report.files {
+        match file.outcome {
+            FileOutcome::Valid => {
+                stats.increment_success();
+                stats.add_bytes_read(file.file_size);
+
+                detail!(
+                    "  {} {:?}",
+                    "✓".green(),
+                    file.path.file_name().unwrap_or_default()
+                );
+                continue;
+            }
+            FileOutcome::Unreadable => {
+                stats.increment_unreadable();
+                if args.skip_unreadable {
+                    print_skipped_unreadable(&file.path);
+                    continue;
+                }
+            }
+            FileOutcome::Invalid => stats.increment_validation_failed(),
+        }
+        errors.extend(
+            file.error.clone().map(|error| {
+                error_entry(file.path.clone(), error, file.error_kind, file.file_size)
+            }),
+        );
+    }
+
+    // 에러 출력
+    print_errors(&errors, args.is_verbose());
+
+    // 로그 파일 작성
+    if let Some(ref log_path) = args.log {
+        write_error_log(log_path, &errors, args.error_log_format)?;
+    }
+
+    if budget.is_exhausted() {
+        abort_run(args, RunMode::Validate, stats, &errors, &budget)?;
+    }
+
+    // 통계 출력
+    stats.print_validation_summary();
+    write_stats_report(
+        args,
+        &RunReport::new(RunMode::Validate, stats).with_errors(&errors),
+    )?;
+
+    if stats.get_validation_failed() == 0 {
+        status!("\n{} 모든 파일이 유효합니다!\n", "✅".bright_green());
+    } else {
+        warning!(
+            "\n{} {} 개의 파일에 오류가 있습니다.\n",
+            "⚠️".bright_yellow(),
+            stats.get_validation_failed().to_string().red()
+        );
+    }
+
+    Ok(report)
+}
+
+/// 변환 모드 실행
+fn run_conversion_mode(
+    args: &Args,
+    json_files: Vec<PathBuf>,
+    stats: &Statistics,
+    mut checkpoint: Option<Checkpoint>,
+    existing: Option<&ExistingOutput>,
+) -> Result<ExitStatus> {
+    // 출력 파일 모드 확인
+    check_output_mode(args)?;
+    repair_append_output(args)?;
+
+    // 진행률 바 설정
+    let pb = create_progress_bar(json_files.len(), args.progress_interval(json_files.len()));
+
+    // 처리 옵션 생성
+    let options = build_process_options(args)?;
+    debug!("  처리 옵션: {:?}", options);
+
+    // 병렬 처리
+    status!("\n{}", "⚡ 병렬 처리 중...".bright_cyan());
+
+    let budget = ErrorBudget::new(args.error_limit());
+    let total_files = json_files.len();
+    let limit = args.early_limit().map(RecordLimit::new);
+
+    let (mut writer, pending) = create_record_writer(args, existing)?;
+    let mut rejects = open_rejects(args)?;
+    let mut errors: Vec<ErrorEntry> = Vec::new();
+    // 크기 제한을 넘은 레코드가 있는 파일과 레코드 수
+    let mut oversized: Vec<(PathBuf, usize)> = Vec::new();
+    let mut drift_tracker = SchemaDriftTracker::new(args.schema_baseline);
+    let mut type_report = TypeConflictReport::new();
+    let mut inferred = SchemaInference::new();
+    let roots = source_roots(args);
+    // 남은 출력 레코드 수 (`--limit`, 병렬 처리 순서와 관계없이 입력 순서대로 채움)
+    let mut remaining = args.early_limit();
+    let mut limited_files = 0;
+
+    // 결과는 처리되는 대로 입력 순서에 맞춰 바로 기록하므로 체크포인트도 처리 중에 앞으로 나아감
+    process_in_order(
+        total_files,
+        args.batch_len(),
+        |index| {
+            // 에러 허용 한도를 넘었거나 앞쪽 파일들로 최대 레코드 수를 채웠으면 남은 파일은 건너뜀
+            if budget.is_exhausted()
+                || limit
+                    .as_ref()
+                    .is_some_and(|limit| limit.is_filled_before(index))
+            {
+                return None;
+            }
+            let result = process_file(json_files[index].clone(), &options);
+            if counts_as_error(args, &result) {
+                budget.record_error();
+            }
+            if let Some(ref limit) = limit {
+                limit.record(index, result.json_lines.len() as u64);
+            }
+            pb.inc();
+            Some(result)
+        },
+        |_, result| -> Result<()> {
+            let Some(mut result) = result else {
+                limited_files += 1;
+                return Ok(());
+            };
+            if remaining == Some(0) {
+                limited_files += 1;
+                return Ok(());
+            }
+            record_file_stat(args, stats, &result);
+            stats.record_result(&result);
+
+            if let Some(error) = result.error {
+                if result.is_unreadable && args.skip_unreadable {
+                    print_skipped_unreadable(&result.path);
+                    return Ok(());
+                }
+                errors.push(error_entry(
+                    result.path,
+                    error,
+                    result.error_kind,
+                    result.file_size,
+                ));
+                return Ok(());
+            }
+
+            if result.oversized_records > 0 {
+                oversized.push((result.path.clone(), result.oversized_records));
+            }
+            write_rejects(&mut rejects, &result, stats)?;
+
+            if let Some(ref mut remaining) = remaining {
+                if result.json_lines.len() as u64 > *remaining {
+                    result.truncate_lines(*remaining as usize);
+                    // 잘린 파일 하나만 남은 줄로 다시 추론 (처리 단계의 추론에는 버린 레코드도 들어 있음)
+                    result.inference = result
+                        .inference
+                        .take()
+                        .map(|_| SchemaInference::from_lines(&result.json_lines));
+                }
+                *remaining -= result.json_lines.len() as u64;
+            }
+
+            let source = relative_to_roots(&result.path, &roots);
+            write_output_records(&mut writer, args, &result, &source, stats)?;
+
+            if let Some(ref mut checkpoint) = checkpoint {
+                checkpoint.mark_completed(result.path.clone());
+                // 기록을 미루는 중복 제거 모드에서는 완료 시점에만 저장
+                if checkpoint.needs_save() && !writer.defers_output() {
+                    writer.flush()?;
+                    save_checkpoint(args, checkpoint)?;
+                }
+            }
+
+            if args.needs_schema() {
+                // 출력 레코드가 없는 파일도 빈 스키마로 기준 파일 수에 포함
+                let schema = result.schema.take().unwrap_or_default();
+                if args.schema_drift {
+                    if let Some(drift) = drift_tracker.observe(&result.path, &schema) {
+                        stats.increment_schema_drift();
+                        print_schema_drift(&drift);
+                    }
+                }
+                if args.type_report {
+                    type_report.observe(&result.path, &schema);
+                }
+            }
+            if let Some(inference) = result.inference.take() {
+                inferred.merge(inference);
+            }
+
+            detail!(
+                "  {} {:?}",
+                "✓".green(),
+                result.path.file_name().unwrap_or_default()
+            );
+            debug!(
+                "    레코드 {}건, {}",
+                result.json_lines.len(),
+                format_bytes(result.file_size)
+            );
+            Ok(())
+        },
+    )?;
+
+    pb.finish_with_message("완료!");
+
+    // 에러 허용 한도 초과: 원자적 출력은 완성하지 않고 버린 뒤 중단
+    if budget.is_exhausted() {
+        if let Some(ref mut checkpoint) = checkpoint {
+            // 바로 기록하는 출력에는 이미 기록한 파일까지 남으므로 이어서 처리할 수 있도록 저장
+            if !writer.defers_output() {
+                writer.flush()?;
+                save_checkpoint(args, checkpoint)?;
+            }
+        }
+        drop(writer);
+        drop(pending);
+        drop(rejects);
+        print_errors(&errors, args.is_verbose());
+        if let Some(ref log_path) = args.log {
+            write_error_log(log_path, &errors, args.error_log_format)?;
+        }
+        return abort_run(args, RunMode::Convert, stats, &errors, &budget);
+    }
+
+    // 남은 레코드 기록 및 버퍼 플러시
+    status!("\n{}", "💾 JSONL 파일 저장 중...".bright_cyan());
+    let shards = finish_record_writer(writer, pending, stats)?;
+    finish_rejects(rejects)?;
+    if let Some(ref mut checkpoint) = checkpoint {
+        save_checkpoint(args, checkpoint)?;
+    }
+
+    // 에러 출력
+    print_errors(&errors, args.is_verbose());
+
+    // 로그 파일 작성
+    if let Some(ref log_path) = args.log {
+        write_error_log(log_path, &errors, args.error_log_format)?;
+    }
+
+    if limited_files > 0 {
+        status!(
+            "\n{} 최대 레코드 수({}건)를 채워 파일 {}개는 처리하지 않았습니다",
+            "⏹️".bright_yellow(),
+            args.limit.unwrap_or_default(),
+            limited_files.to_string().yellow()
+        );
+    }
+
+    // 타입 충돌 보고서 출력
+    if args.type_report {
+        print_type_report(&type_report);
+    }
+
+    // 추론한 스키마 저장
+    if let Some(ref path) = args.infer_schema {
+        inferred.write(path)?;
+        status!(
+            "\n{} 스키마 추론: 레코드 {}건, 필드 {}개 → {:?}",
+            "🧭".bright_cyan(),
+            inferred.records(),
+            inferred.field_count(),
+            path
+        );
+    }
+
+    // 통계 출력
+    stats.print_summary();
+    print_oversized_files(&oversized);
+    print_shards(&shards);
+    write_stats_report(
+        args,
+        &RunReport::new(RunMode::Convert, stats)
+            .with_errors(&errors)
+            .with_oversized_files(&oversized)
+            .with_shards(&shards),
+    )?;
+
+    status!(
+        "\n{} 저장 완료: {}\n",
+        "✅".bright_green(),
+        saved_target(args, &shards)
+    );
+
+    Ok(completion_status(args, stats))
+}
+
+/// 건너뛴 읽기 권한 없는 파일 안내 (상세 출력 모드)
+fn print_skipped_unreadable(path: &std::path::Path) {
+    detail!("  {} 권한 없음, 건너뜀: {:?}", "🔒".bright_yellow(), path);
+}
+
+/// 파일 하나의 크기와 처리 시간을 상세 통계에 추가 (`--stats-detail`)
+fn record_file_stat(args: &Args, stats: &Statistics, result: &ProcessResult) {
+    stats.record_file(FileStat {
+        path: relative_to_roots(&result.path, &source_roots(args)),
+        bytes: result.file_size,
+        elapsed_secs: result.elapsed.as_secs_f64(),
+        failed: result.error.is_some(),
+    });
+}
+
+/// 에러 허용 한도에 포함되는 실패인지 확인 (`--skip-unreadable`로 건너뛴 파일 제외)
+fn counts_as_error(args: &Args, result: &ProcessResult) -> bool {
+    result.error.is_some() && !(result.is_unreadable && args.skip_unreadable)
+}
+
+/// 종료 상태 결정
+///
+/// 실패/무효 파일이나 건너뛰지 않은 읽기 권한 없는 파일이 있으면 에러와 함께 완료(1)로 처리합니다.
+fn completion_status(args: &Args, stats: &Statistics) -> ExitStatus {
+    let unreadable = stats.get_unreadable_count();
+    print_unreadable_notice(args, unreadable);
+
+    let unreadable = if args.skip_unreadable { 0 } else { unreadable };
+    ExitStatus::from_failures(stats.get_error_count() + stats.get_validation_failed() + unreadable)
+}
+
+/// 건너뛰지 않은 읽기 권한 없는 파일 안내
+fn print_unreadable_notice(args: &Args, unreadable: usize) {
+    if unreadable > 0 && !args.skip_unreadable {
+        status!(
+            "{} 읽기 권한이 없는 파일이 {}개 있습니다 (--skip-unreadable로 건너뛸 수 있습니다)\n",
+            "❌".bright_red(),
+            unreadable.to_string().red()
+        );
+    }
+}
+
+/// 에러 허용 한도 초과로 실행 중단 (`--stats-json` 보고서는 남김)
+fn abort_run(
+    args: &Args,
+    mode: RunMode,
+    stats: &Statistics,
+    errors: &[ErrorEntry],
+    budget: &ErrorBudget,
+) -> Result<ExitStatus> {
+    write_stats_report(args, &RunReport::new(mode, stats).with_errors(errors))?;
+    Err(JConvertError::Aborted {
+        limit: budget.limit().unwrap_or_default(),
+    }
+    .into())
+}
+
+/// CLI 인자로부터 변환 처리 옵션 생성
+fn build_process_options(args: &Args) -> Result<ProcessOptions> {
+    let pipeline = args
+        .pipeline
+        .as_deref()
+        .map(Pipeline::load)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let predicate = args
+        .where_expr
+        .as_deref()
+        .map(Expr::parse)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let transform = args
+        .transform
+        .as_deref()
+        .map(Expr::parse)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let schema_filter = args
+        .filter_schema
+        .as_deref()
+        .map(SchemaFilter::load)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    Ok(ProcessOptions::new()
+        .with_fields(args.get_fields())
+        .with_exclude_fields(args.get_exclude_fields())
+        .with_renames(args.get_renames())
+        .with_pretty(args.pretty)
+        .with_passthrough(args.passthrough)
+        .with_normalize_keys(args.normalize_keys)
+        .with_key_order(args.key_order)
+        .with_field_order(args.field_order.clone())
+        .with_strip_key_prefix(args.strip_key_prefix.clone())
+        .with_key_prefix(args.key_prefix.clone())
+        .with_encoding(args.encoding)
+        .with_lossy(args.lossy)
+        .with_repair_jsonl(args.repair_jsonl)
+        .with_source_key(args.add_source.then(|| DEFAULT_SOURCE_KEY.to_string()))
+        .with_mtime_key(args.add_mtime.then(|| DEFAULT_MTIME_KEY.to_string()))
+        .with_checksum(args.checksum_field())
+        .with_source_roots(source_roots(args))
+        .with_pipeline(pipeline)
+        .with_predicate(predicate)
+        .with_transform(transform)
+        .with_parse_limits(args.parse_limits())
+        .with_schema_filter(schema_filter)
+        .with_collect_rejects(args.rejects.is_some())
+        .with_multi_doc(args.multi_doc)
+        .with_geojson(args.geojson)
+        .with_flatten_properties(args.flatten_properties)
+        .with_parser(args.parser)
+        .with_sampler(args.sampler())
+        .with_record_size(args.record_size_guard())
+        .with_dedup_key(args.dedupe_by.clone())
+        .with_collect_schema(args.needs_schema())
+        .with_infer_schema(args.infer_schema.is_some()))
+}
+
+/// 스키마 변화 경고 출력
+fn print_schema_drift(drift: &SchemaDrift) {
+    let describe = |fields: &[(String, _)]| {
+        fields
+            .iter()
+            .map(|(path, json_type)| format!("{} ({})", path, json_type))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    warning!(
+        "  {} 스키마 변경 감지: {}",
+        "⚠️".bright_yellow(),
+        drift.path.display()
+    );
+    if !drift.new_fields.is_empty() {
+        warning!("     새 필드: {}", describe(&drift.new_fields).yellow());
+    }
+    if !drift.new_types.is_empty() {
+        warning!("     새 타입: {}", describe(&drift.new_types).yellow());
+    }
+}
+
+/// 필드별 타입 충돌 보고서 출력
+fn print_type_report(report: &TypeConflictReport) {
+    let conflicts = report.conflicts();
+
+    status!("\n{}", "🧬 필드 타입 충돌 보고서".bright_yellow().bold());
+    if conflicts.is_empty() {
+        status!("  {} 타입 충돌 없음", "✓".green());
+        return;
+    }
+
+    for conflict in &conflicts {
+        status!("  {} {}", "•".yellow(), conflict.field.bright_white());
+        for (json_type, usage) in &conflict.types {
+            let examples = usage
+                .example_files
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            status!(
+                "     {:<8} {:>8}건  예: {}",
+                json_type.name(),
+                usage.count,
+                examples.dimmed()
+            );
+        }
+    }
+}
+
+/// 표준 입력 모드 실행
+fn run_stdin_mode(args: &Args, baseline: Option<&Baseline>) -> Result<ExitStatus> {
+    check_output_mode(args)?;
+    repair_append_output(args)?;
+    let existing = load_existing_output(args)?;
+
+    let stats = Statistics::new(1)
+        .with_parser(args.effective_parser())
+        .with_detail(args.stats_detail);
+    let options = build_process_options(args)?;
+    debug!("  처리 옵션: {:?}", options);
+
+    status!("\n{}", "📥 표준 입력 읽는 중...".bright_cyan());
+
+    let stdin = std::io::stdin();
+    let result = process_reader(PathBuf::from("<stdin>"), stdin.lock(), &options);
+    stats.record_result(&result);
+
+    if let Some(error) = result.error {
+        let errors = [error_entry(
+            result.path,
+            error,
+            result.error_kind,
+            result.file_size,
+        )];
+        print_errors(&errors, true);
+        if let Some(ref log_path) = args.log {
+            write_error_log(log_path, &errors, args.error_log_format)?;
+        }
+        let report = RunReport::new(RunMode::Stdin, &stats).with_errors(&errors);
+        write_stats_report(args, &report)?;
+
+        return Ok(finish_status(
+            args,
+            ExitStatus::CompletedWithErrors,
+            baseline,
+            &report,
+        ));
+    }
+
+    let mut result = result;
+    if let Some(limit) = args.early_limit() {
+        result.truncate_lines(limit as usize);
+    }
+
+    // 정렬하면 `--limit`은 정렬 후에 적용됨
+    let written = args.limit.map_or(result.json_lines.len(), |limit| {
+        result.json_lines.len().min(limit as usize)
+    });
+    let (mut writer, pending) = create_record_writer(args, existing.as_ref())?;
+    let source = result.path.to_string_lossy();
+    write_output_records(&mut writer, args, &result, &source, &stats)?;
+    let shards = finish_record_writer(writer, pending, &stats)?;
+
+    let mut rejects = open_rejects(args)?;
+    write_rejects(&mut rejects, &result, &stats)?;
+    finish_rejects(rejects)?;
+
+    record_file_stat(args, &stats, &result);
+    let oversized: Vec<(PathBuf, usize)> = (result.oversized_records > 0)
+        .then(|| (result.path.clone(), result.oversized_records))
+        .into_iter()
+        .collect();
+    stats.print_summary();
+    print_oversized_files(&oversized);
+    print_shards(&shards);
+    let report = RunReport::new(RunMode::Stdin, &stats)
+        .with_oversized_files(&oversized)
+        .with_shards(&shards);
+    write_stats_report(args, &report)?;
+
+    status!(
+        "\n{} 저장 완료: {} ({} 개 문서)\n",
+        "✅".bright_green(),
+        saved_target(args, &shards),
+        written.to_string().bright_green()
+    );
+
+    Ok(finish_status(args, ExitStatus::Clean, baseline, &report))
+}
+
+/// 출력 파일과 중복 제거 설정으로 레코드 출력기 생성
+///
+/// `existing`이 있으면 기존 출력의 중복 제거 키를 이미 본 키로 기록합니다 (`--skip-existing`).
+///
+/// # Returns
+/// 레코드 출력기와 원자적 출력의 완성 대기 핸들 (`--no-atomic`이거나 적용 대상이 아니면 `None`)
+fn create_record_writer(
+    args: &Args,
+    existing: Option<&ExistingOutput>,
+) -> Result<(RecordWriter<Output>, Option<PendingOutput>)> {
+    let spill = args
+        .build_spill_deduplicator()
+        .context("중복 제거용 임시 폴더 생성 실패")?;
+
+    let limits = args.shard_limits();
+    let mut pending = None;
+    let output = if limits.is_enabled() {
+        OutputSink::Sharded(
+            ShardedOutput::new(&args.output, limits)
+                .with_sync_on_rotate(args.fsync_on_rotate)
+                .with_remove_stale(args.mode == WriteMode::Overwrite),
+        )
+    } else if args.output_format == OutputFormat::Bundle {
+        let (body, file) = AtomicOutput::create(body_path(&args.output)).with_context(|| {
+            format!("번들 본문 임시 파일을 만들 수 없습니다: {:?}", args.output)
+        })?;
+        pending = Some(PendingOutput::Bundle {
+            body,
+            target: args.output.clone(),
+            header: Box::new(BundleHeader::new(bundle_sources(args))),
+        });
+        OutputSink::Single(BufWriter::new(Box::new(file) as Box<dyn SyncWrite>))
+    } else if let Some(codec) = args.compress {
+        let (atomic, file) = if args.atomic_output() {
+            let (atomic, file) = AtomicOutput::create(&args.output)
+                .with_context(|| format!("임시 출력 파일을 만들 수 없습니다: {:?}", args.output))?;
+            (Some(atomic), file)
+        } else {
+            let file = File::create(&args.output)
+                .with_context(|| format!("출력 파일을 만들 수 없습니다: {:?}", args.output))?;
+            (None, file)
+        };
+        let (stream, encoding) = file
+            .try_clone()
+            .and_then(|output| codec.encode(output))
+            .with_context(|| format!("{} 압축을 시작할 수 없습니다", codec.name()))?;
+        pending = Some(PendingOutput::Compressed {
+            encoding,
+            file,
+            atomic,
+        });
+        OutputSink::Single(BufWriter::new(stream))
+    } else if args.atomic_output() {
+        let (atomic, file) = AtomicOutput::create(&args.output)
+            .with_context(|| format!("임시 출력 파일을 만들 수 없습니다: {:?}", args.output))?;
+        pending = Some(PendingOutput::Atomic(atomic));
+        OutputSink::Single(BufWriter::new(Box::new(file) as Box<dyn SyncWrite>))
+    } else if let Some(target) = args.remote_target() {
+        let (upload, pipe) = target
+            .start(args.upload_expected_size)
+            .with_context(|| format!("업로드를 시작할 수 없습니다: {}", target))?;
+        pending = Some(PendingOutput::Upload(upload));
+        OutputSink::Single(BufWriter::new(Box::new(pipe) as Box<dyn SyncWrite>))
+    } else {
+        OutputSink::Single(BufWriter::new(open_output(args)?))
+    };
+
+    let writer = RecordWriter::new(output)
+        .with_deduplicator(args.build_deduplicator().map(|dedup| match existing {
+            Some(existing) => dedup.with_seen_keys(existing.keys()),
+            None => dedup,
+        }))
+        .with_spill(spill)
+        .with_sorter(args.build_sorter())
+        .with_sync_policy(args.sync_policy());
+    Ok((writer, pending))
+}
+
+/// 파일 하나의 출력 레코드 기록 (`--annotate` 시 원본 파일 주석 줄 포함)
+fn write_output_records(
+    writer: &mut RecordWriter<Output>,
+    args: &Args,
+    result: &ProcessResult,
+    source: &str,
+    stats: &Statistics,
+) -> Result<()> {
+    for (json_line, key) in result.keyed_lines() {
+        if args.annotate {
+            writer.write_annotated(json_line, key, source, stats)?;
+        } else {
+            writer.write_record(json_line, key, stats)?;
+        }
+    }
+    Ok(())
+}
+
+/// 레코드 출력 마무리 (근사 중복 제거의 예상 키 수 초과 시 경고)
+///
+/// # Returns
+/// 만들어진 분할 파일 목록 (분할하지 않으면 빈 목록)
+fn finish_record_writer(
+    writer: RecordWriter<Output>,
+    pending: Option<PendingOutput>,
+    stats: &Statistics,
+) -> Result<Vec<ShardInfo>> {
+    if writer.is_dedup_over_capacity() {
+        warning!(
+            "\n{} {}",
+            "⚠️".bright_yellow(),
+            "고유 키 수가 --dedupe-capacity를 초과하여 위양성 확률 보장이 깨졌습니다. --dedupe-capacity를 늘리거나 --dedupe-exact를 사용하세요."
+                .yellow()
+        );
+    }
 
-    Ok(json_files)
+    // 출력 핸들을 닫아야 업로드 스레드가 입력 끝을 알 수 있음
+    let shards = writer.finish(stats)?.finish()?;
+    match pending {
+        Some(PendingOutput::Atomic(atomic)) => {
+            let temp = atomic.temp().to_path_buf();
+            atomic.commit().with_context(|| {
+                format!(
+                    "임시 출력 파일의 이름을 바꿀 수 없습니다: {:?} (--no-atomic으로 직접 기록할 수 있습니다)",
+                    temp
+                )
+            })?;
+        }
+        Some(PendingOutput::Upload(upload)) => {
+            upload
+                .finish()
+                .context("원격 업로드를 완료하지 못했습니다")?;
+        }
+        Some(PendingOutput::Compressed {
+            encoding,
+            file,
+            atomic,
+        }) => {
+            encoding
+                .finish()
+                .context("출력 압축을 완료하지 못했습니다")?;
+            file.sync_all()
+                .context("압축한 출력 파일을 저장할 수 없습니다")?;
+            if let Some(atomic) = atomic {
+                let temp = atomic.temp().to_path_buf();
+                atomic.commit().with_context(|| {
+                    format!("임시 출력 파일의 이름을 바꿀 수 없습니다: {:?}", temp)
+                })?;
+            }
+        }
+        Some(PendingOutput::Bundle {
+            body,
+            target,
+            header,
+        }) => {
+            // 본문 임시 파일은 `body`가 버려질 때 지워짐
+            let (output, file) = AtomicOutput::create(&target)
+                .with_context(|| format!("임시 출력 파일을 만들 수 없습니다: {:?}", target))?;
+            let mut writer = BufWriter::new(file);
+            let header = write_bundle(body.temp(), header.with_stats(stats), &mut writer)
+                .with_context(|| format!("번들 파일을 만들 수 없습니다: {:?}", target))?;
+            writer
+                .into_inner()
+                .map_err(|e| e.into_error())
+                .and_then(|file| file.sync_all())
+                .with_context(|| format!("번들 파일을 저장할 수 없습니다: {:?}", target))?;
+            output.commit().with_context(|| {
+                format!("임시 출력 파일의 이름을 바꿀 수 없습니다: {:?}", target)
+            })?;
+            status!(
+                "  {} 번들 머리글: 실행 ID {}, 레코드 {}개, 스키마 {}",
+                "🧾".bright_white(),
+                header.run_id,
+                header.records,
+                &header.schema_hash[..12]
+            );
+        }
+        None => {}
+    }
+    Ok(shards)
 }
 
-/// 드라이런 출력
-fn print_dry_run(json_files: &[PathBuf]) {
-    println!("\n{}", "📋 처리 예정 파일 목록:".bright_cyan());
-    for (i, path) in json_files.iter().enumerate() {
-        println!("  {}. {:?}", i + 1, path.file_name().unwrap_or_default());
+/// 번들 머리글에 기록할 입력 (표준 입력은 `-`)
+fn bundle_sources(args: &Args) -> Vec<String> {
+    if args.stdin {
+        return vec![STDIO_PATH.to_string()];
     }
-    println!(
-        "\n{} 총 {} 개의 파일이 처리될 예정입니다.",
-        "ℹ️".bright_blue(),
-        json_files.len().to_string().bright_green()
-    );
+    args.input
+        .iter()
+        .map(|input| input.display().to_string())
+        .chain(
+            args.files_from
+                .iter()
+                .map(|list| format!("@{}", list.display())),
+        )
+        .collect()
 }
 
-/// 유효성 검사 모드 실행
-fn run_validation_mode(args: &Args, json_files: Vec<PathBuf>, stats: &Statistics) -> Result<()> {
-    // 진행률 바 설정
-    let pb = create_progress_bar(json_files.len());
-
-    println!("\n{}", "🔍 유효성 검사 중...".bright_cyan());
+/// 체크포인트 저장 (`--checkpoint`, 출력은 먼저 플러시되어 있어야 함)
+fn save_checkpoint(args: &Args, checkpoint: &mut Checkpoint) -> Result<()> {
+    if let Some(ref path) = args.checkpoint {
+        checkpoint.save(path, &args.output)?;
+    }
+    Ok(())
+}
 
-    let options = ProcessOptions::new().with_validate_only(true);
-    let errors: Mutex<Vec<(PathBuf, String)>> = Mutex::new(Vec::new());
+/// 실행 보고서 저장 (`--stats-json`)
+fn write_stats_report(args: &Args, report: &RunReport) -> Result<()> {
+    if let Some(ref path) = args.stats_json {
+        report.write(path)?;
+        status!("  {} 실행 보고서: {:?}", "🤖".bright_white(), path);
+    }
+    Ok(())
+}
 
-    json_files.into_par_iter().for_each(|path| {
-        let result = process_file(path, &options);
-        pb.inc(1);
+/// 품질 기준과 기준 실행 비교를 반영한 최종 종료 상태
+///
+/// 품질 기준(`--min-success-rate`, `--max-error-count`)을 넘으면 에러와 함께 완료(1)로 올리며,
+/// 기준을 지켜도 실패 파일로 정해진 상태는 그대로 둡니다.
+fn finish_status(
+    args: &Args,
+    status: ExitStatus,
+    baseline: Option<&Baseline>,
+    report: &RunReport,
+) -> ExitStatus {
+    let status = match check_quality_gates(args, report) {
+        Some(passed) => status.with_gates(passed),
+        None => status,
+    };
+    status.max(compare_baseline(args, baseline, report))
+}
 
-        if result.is_valid {
-            stats.increment_success();
-            stats.add_bytes_read(result.file_size);
+/// 품질 기준 평가 결과 출력
+///
+/// # Returns
+/// 기준을 모두 지켰는지 (기준을 지정하지 않으면 None)
+fn check_quality_gates(args: &Args, report: &RunReport) -> Option<bool> {
+    let gates = args.quality_gates();
+    if !gates.is_enabled() {
+        return None;
+    }
 
-            if args.verbose {
-                println!(
-                    "  {} {:?}",
-                    "✓".green(),
-                    result.path.file_name().unwrap_or_default()
-                );
+    status!("\n{}", "🚧 품질 기준".bright_cyan());
+    let results = gates.check(report);
+    if results.is_empty() {
+        status!(
+            "  {} 처리한 파일이 없어 평가할 기준이 없습니다.",
+            "ℹ️".bright_blue()
+        );
+    }
+    for result in &results {
+        let line = match result.gate {
+            Gate::MinSuccessRate => {
+                format!("성공률 {:.2}% (기준 {}% 이상)", result.actual, result.limit)
             }
+            Gate::MaxErrorCount => format!(
+                "실패 파일 {}개 (기준 {}개 이하)",
+                result.actual, result.limit
+            ),
+        };
+        if result.passed {
+            status!("  {} {}", "✓".green(), line);
         } else {
-            stats.increment_validation_failed();
-            if let Some(error) = result.error {
-                errors.lock().unwrap().push((result.path, error));
-            }
+            warning!("  {} {}", "❌".red(), line.red());
         }
-    });
+    }
 
-    pb.finish_with_message("완료!");
+    let passed = results.iter().all(|result| result.passed);
+    if !passed {
+        warning!("  {} 데이터 품질이 기준에 못 미칩니다.", "⚠️".yellow());
+    }
+    Some(passed)
+}
 
-    // 에러 출력
-    let errors = errors.into_inner().unwrap();
-    print_errors(&errors, args.verbose);
+/// 기준 실행과 통계 비교 결과 출력 (`--baseline`)
+///
+/// # Returns
+/// 허용 범위를 넘게 나빠진 지표가 있으면 `CompletedWithErrors`, 아니면 `Clean`
+fn compare_baseline(args: &Args, baseline: Option<&Baseline>, report: &RunReport) -> ExitStatus {
+    let Some(baseline) = baseline else {
+        return ExitStatus::Clean;
+    };
 
-    // 로그 파일 작성
-    if let Some(ref log_path) = args.log {
-        write_error_log(log_path, &errors)?;
+    status!(
+        "\n{} (허용 범위 {}%)",
+        "📉 기준 실행 비교".bright_cyan(),
+        args.baseline_tolerance
+    );
+    match baseline.mode() {
+        Some(mode) if mode != report.mode.name() => warning!(
+            "  {} 기준 실행의 모드({})가 이번 실행({})과 다릅니다",
+            "⚠️".yellow(),
+            mode,
+            report.mode.name()
+        ),
+        _ => {}
     }
 
-    // 통계 출력
-    stats.print_validation_summary();
+    let deviations = baseline.compare(report, args.baseline_tolerance);
+    let value = |metric: &str, value: f64| {
+        if metric.starts_with("bytes_") {
+            format_bytes(value as u64)
+        } else {
+            format!("{}", value)
+        }
+    };
+    for deviation in &deviations {
+        let change = match deviation.change_percent() {
+            Some(percent) => format!("{:+.1}%", percent),
+            None => "-".to_string(),
+        };
+        let line = format!(
+            "{}: {} → {} ({})",
+            deviation.label,
+            value(deviation.metric, deviation.baseline),
+            value(deviation.metric, deviation.current),
+            change
+        );
+        if deviation.exceeded {
+            warning!("  {} {}", "⚠️".yellow(), line.yellow());
+        } else {
+            status!("  {} {}", "✓".green(), line);
+        }
+    }
 
-    if stats.get_validation_failed() == 0 {
-        println!("\n{} 모든 파일이 유효합니다!\n", "✅".bright_green());
+    let exceeded = deviations.iter().filter(|d| d.exceeded).count();
+    if exceeded == 0 {
+        status!(
+            "  {} 기준 실행과 비교해 이상 변화가 없습니다.",
+            "✅".green()
+        );
+        ExitStatus::Clean
     } else {
-        println!(
-            "\n{} {} 개의 파일에 오류가 있습니다.\n",
-            "⚠️".bright_yellow(),
-            stats.get_validation_failed().to_string().red()
+        warning!(
+            "  {} {}개 지표가 허용 범위를 넘게 나빠졌습니다.",
+            "❌".red(),
+            exceeded
         );
+        ExitStatus::CompletedWithErrors
     }
-
-    Ok(())
 }
 
-/// 변환 모드 실행
-fn run_conversion_mode(args: &Args, json_files: Vec<PathBuf>, stats: &Statistics) -> Result<()> {
-    // 출력 파일 모드 확인
-    check_output_mode(args)?;
-
-    // 진행률 바 설정
-    let pb = create_progress_bar(json_files.len());
-
-    // 처리 옵션 생성
-    let options = ProcessOptions::new()
-        .with_fields(args.get_fields())
-        .with_pretty(args.pretty);
-
-    // 병렬 처리
-    println!("\n{}", "⚡ 병렬 처리 중...".bright_cyan());
+/// 분할 파일 목록 출력
+fn print_shards(shards: &[ShardInfo]) {
+    if shards.is_empty() {
+        return;
+    }
 
-    let results: Vec<ProcessResult> = json_files
-        .into_par_iter()
-        .map(|path| {
-            let result = process_file(path, &options);
-            pb.inc(1);
-            result
-        })
-        .collect();
+    status!(
+        "\n{} 분할 파일 ({}개):",
+        "📦".bright_cyan(),
+        shards.len().to_string().bright_green()
+    );
+    for shard in shards {
+        status!(
+            "  {} {:?} ({} 레코드, {})",
+            "•".cyan(),
+            shard.path,
+            shard.records,
+            format_bytes(shard.bytes)
+        );
+    }
+}
 
-    pb.finish_with_message("완료!");
+/// 저장 위치 표시 문자열 (분할 시 첫 파일 ~ 마지막 파일)
+fn saved_target(args: &Args, shards: &[ShardInfo]) -> String {
+    match (shards.first(), shards.last()) {
+        (Some(first), Some(last)) if shards.len() > 1 => {
+            format!("{:?} ~ {:?}", first.path, last.path)
+        }
+        (Some(only), _) => format!("{:?}", only.path),
+        _ if args.writes_stdout() => "표준 출력".to_string(),
+        _ if args.remote_target().is_some() => args.output.display().to_string(),
+        _ => format!("{:?}", args.output),
+    }
+}
 
-    // 결과 수집 및 파일 쓰기
-    println!("\n{}", "💾 JSONL 파일 저장 중...".bright_cyan());
+/// 출력 모드 확인
+fn check_output_mode(args: &Args) -> Result<()> {
+    if let Some(codec) = args.compress {
+        if args.writes_stdout()
+            || args.remote_target().is_some()
+            || args.mode == WriteMode::Append
+            || args.output_format == OutputFormat::Bundle
+        {
+            anyhow::bail!(
+                "{} 압축(--compress)은 로컬 출력 파일에만 쓸 수 있습니다 (표준 출력, 원격 업로드, append, 번들 출력과 함께 사용할 수 없음)",
+                codec.name()
+            );
+        }
+    }
 
-    let output_file = open_output_file(args)?;
-    let writer = Mutex::new(BufWriter::new(output_file));
-    let mut errors: Vec<(PathBuf, String)> = Vec::new();
+    if args.output_format == OutputFormat::Bundle {
+        if !args.atomic_output() {
+            anyhow::bail!(
+                "번들 출력(--output-format bundle)은 로컬 출력 파일에만 쓸 수 있습니다 (표준 출력, 원격 업로드, 분할, append, --checkpoint, --no-atomic과 함께 사용할 수 없음)"
+            );
+        }
+        if args.pretty {
+            anyhow::bail!("번들 출력(--output-format bundle)은 --pretty와 함께 사용할 수 없습니다");
+        }
+    }
 
-    for result in results {
-        if let Some(json_line) = result.json_line {
-            let line_bytes = json_line.len() as u64 + 1; // +1 for newline
-            stats.add_bytes_read(result.file_size);
-            stats.add_bytes_written(line_bytes);
-            stats.increment_success();
+    if let Some(target) = args.remote_target() {
+        if args.sync_policy().is_enabled() {
+            anyhow::bail!(
+                "원격 업로드({})는 --fsync, --fsync-every, --fsync-on-rotate와 함께 사용할 수 없습니다 (객체는 업로드를 마쳐야 저장됨)",
+                target
+            );
+        }
+        if args.mode == WriteMode::Append {
+            anyhow::bail!(
+                "원격 업로드({})는 append 모드와 함께 사용할 수 없습니다",
+                target
+            );
+        }
+        if args.checkpoint.is_some() || args.shard_limits().is_enabled() {
+            anyhow::bail!(
+                "원격 업로드({})는 --checkpoint, --split-size/--split-records와 함께 사용할 수 없습니다",
+                target
+            );
+        }
+        return Ok(());
+    }
 
-            let mut w = writer.lock().unwrap();
-            writeln!(w, "{}", json_line)?;
+    if args.writes_stdout() {
+        if args.checkpoint.is_some() {
+            anyhow::bail!("표준 출력(-o -)은 --checkpoint와 함께 사용할 수 없습니다");
+        }
+        if args.shard_limits().is_enabled() {
+            anyhow::bail!(
+                "표준 출력(-o -)은 --split-size/--split-records와 함께 사용할 수 없습니다"
+            );
+        }
+        return Ok(());
+    }
 
-            if args.verbose {
-                println!(
-                    "  {} {:?}",
-                    "✓".green(),
-                    result.path.file_name().unwrap_or_default()
-                );
-            }
-        } else if let Some(error) = result.error {
-            stats.increment_error();
-            errors.push((result.path, error));
+    if args.shard_limits().is_enabled() {
+        if args.mode == WriteMode::Append {
+            anyhow::bail!("append 모드는 --split-size/--split-records와 함께 사용할 수 없습니다");
         }
+        let first_shard = shard_path(&args.output, 1);
+        if args.mode == WriteMode::Error && first_shard.exists() {
+            anyhow::bail!("출력 파일이 이미 존재합니다: {:?}", first_shard);
+        }
+        return Ok(());
     }
 
-    // 버퍼 플러시
-    writer.lock().unwrap().flush()?;
+    if args.mode == WriteMode::Error && args.output.exists() {
+        anyhow::bail!("출력 파일이 이미 존재합니다: {:?}", args.output);
+    }
+    Ok(())
+}
 
-    // 에러 출력
-    print_errors(&errors, args.verbose);
+/// 잘린 조각을 경고에 표시할 최대 바이트 수
+const FRAGMENT_PREVIEW_BYTES: usize = 200;
 
-    // 로그 파일 작성
-    if let Some(ref log_path) = args.log {
-        write_error_log(log_path, &errors)?;
+/// append 모드에서 이어 쓰기 전에 출력 파일의 마지막 줄 복구
+///
+/// 이전 실행이 중단되어 잘린 마지막 줄은 잘라 내고 그 조각을 기록하며,
+/// 줄바꿈만 빠진 완전한 레코드에는 줄바꿈을 붙입니다.
+fn repair_append_output(args: &Args) -> Result<()> {
+    if args.mode != WriteMode::Append || args.writes_stdout() || args.remote_target().is_some() {
+        return Ok(());
     }
+    let Some(trailing) = repair_trailing_line(&args.output)
+        .with_context(|| format!("출력 파일에 이어 쓸 수 없습니다: {:?}", args.output))?
+    else {
+        return Ok(());
+    };
 
-    // 통계 출력
-    stats.print_summary();
-
-    println!("\n{} 저장 완료: {:?}\n", "✅".bright_green(), args.output);
+    if trailing.complete {
+        detail!(
+            "  {} 출력 파일의 마지막 줄에 줄바꿈을 붙였습니다 ({}바이트 위치)",
+            "↩️".bright_white(),
+            trailing.offset
+        );
+        return Ok(());
+    }
 
+    let fragment = String::from_utf8_lossy(&trailing.content);
+    let preview = if fragment.len() > FRAGMENT_PREVIEW_BYTES {
+        let mut end = FRAGMENT_PREVIEW_BYTES;
+        while !fragment.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}…", &fragment[..end])
+    } else {
+        fragment.to_string()
+    };
+    warning!(
+        "  {} 출력 파일의 잘린 마지막 줄을 잘라 냈습니다 ({}바이트 위치, {}): {}",
+        "✂️".yellow(),
+        trailing.offset,
+        format_bytes(trailing.len()),
+        preview.dimmed()
+    );
+    debug!("  잘라 낸 조각: {}", fragment);
     Ok(())
 }
 
-/// 출력 모드 확인
-fn check_output_mode(args: &Args) -> Result<()> {
-    if args.mode == WriteMode::Error && args.output.exists() {
-        anyhow::bail!("출력 파일이 이미 존재합니다: {:?}", args.output);
+/// 출력 열기 (`-o -`이면 표준 출력)
+fn open_output(args: &Args) -> Result<Box<dyn SyncWrite>> {
+    if args.writes_stdout() {
+        return Ok(Box::new(io::stdout()));
     }
-    Ok(())
-}
 
-/// 출력 파일 열기
-fn open_output_file(args: &Args) -> Result<File> {
     let file = match args.mode {
         WriteMode::Append => OpenOptions::new()
             .create(true)
@@ -335,11 +2685,53 @@ fn open_output_file(args: &Args) -> Result<File> {
             .open(&args.output)?,
         _ => File::create(&args.output)?,
     };
-    Ok(file)
+    Ok(Box::new(file))
+}
+
+/// 제외 레코드 파일 열기 (`--rejects`, append 모드면 이어 쓰기)
+fn open_rejects(args: &Args) -> Result<Option<BufWriter<File>>> {
+    let Some(ref path) = args.rejects else {
+        return Ok(None);
+    };
+
+    let file = match args.mode {
+        WriteMode::Append => OpenOptions::new().create(true).append(true).open(path),
+        _ => File::create(path),
+    }
+    .with_context(|| format!("제외 레코드 파일을 열 수 없습니다: {:?}", path))?;
+    Ok(Some(BufWriter::new(file)))
+}
+
+/// 스키마 불일치 수 집계 및 제외된 레코드 기록 (`--rejects`)
+fn write_rejects(
+    rejects: &mut Option<BufWriter<File>>,
+    result: &ProcessResult,
+    stats: &Statistics,
+) -> Result<()> {
+    stats.add_rejected(result.rejected_records);
+    if let Some(writer) = rejects {
+        for line in &result.rejects {
+            writeln!(writer, "{}", line)?;
+        }
+    }
+    Ok(())
+}
+
+/// 제외 레코드 파일 플러시
+fn finish_rejects(rejects: Option<BufWriter<File>>) -> Result<()> {
+    if let Some(mut writer) = rejects {
+        writer.flush()?;
+    }
+    Ok(())
 }
 
-/// 진행률 바 생성
-fn create_progress_bar(total: usize) -> ProgressBar {
+/// 진행률 바 생성 (`interval`개 완료마다 한 번씩 갱신)
+///
+/// `--quiet`이거나 JSON 로그 형식이면 진행률 바를 그리지 않습니다.
+fn create_progress_bar(total: usize, interval: u64) -> BatchedProgress {
+    if !ui::shows_progress() {
+        return BatchedProgress::new(ProgressBar::hidden(), interval);
+    }
     let pb = ProgressBar::new(total as u64);
     pb.set_style(
         ProgressStyle::default_bar()
@@ -347,57 +2739,76 @@ fn create_progress_bar(total: usize) -> ProgressBar {
             .unwrap()
             .progress_chars("█▓▒░"),
     );
-    pb
+    BatchedProgress::new(pb, interval)
+}
+
+/// 처리 결과의 에러를 에러 로그 항목으로 변환
+fn error_entry(
+    path: PathBuf,
+    error: String,
+    kind: Option<&'static str>,
+    file_size: u64,
+) -> ErrorEntry {
+    ErrorEntry::new(path, kind.unwrap_or(UNKNOWN_KIND), error).with_file_size(file_size)
 }
 
-/// 에러 목록 출력
-fn print_errors(errors: &[(PathBuf, String)], verbose: bool) {
+/// 에러 목록 출력 (JSON 형식이면 에러 내용과 함께 파일마다 한 줄)
+fn print_errors(errors: &[ErrorEntry], verbose: bool) {
     if errors.is_empty() {
         return;
     }
 
-    println!("\n{}", "❌ 오류 발생 파일:".bright_red());
-    for (path, error) in errors {
-        println!("  {} {:?}", "•".red(), path.file_name().unwrap_or_default());
+    if ui::format() == LogFormat::Json {
+        for entry in errors {
+            failure!("{}: {}", entry.path.display(), entry.message);
+        }
+        return;
+    }
+
+    failure!("\n{}", "❌ 오류 발생 파일:".bright_red());
+    for entry in errors {
+        failure!(
+            "  {} {:?}",
+            "•".red(),
+            entry.path.file_name().unwrap_or_default()
+        );
         if verbose {
-            println!("    {}", error.dimmed());
+            failure!("    {}", entry.message.dimmed());
         }
     }
 }
 
-/// 에러 로그 파일 작성
-fn write_error_log(log_path: &PathBuf, errors: &[(PathBuf, String)]) -> Result<()> {
-    let mut log_file = File::create(log_path)?;
-
-    writeln!(log_file, "jconvert 에러 로그")?;
-    writeln!(log_file, "생성 시간: {}", chrono_now())?;
-    writeln!(log_file, "총 에러 수: {}", errors.len())?;
-    writeln!(log_file, "{}", "=".repeat(50))?;
+/// 크기 제한을 넘은 레코드가 있는 원본 파일 출력
+fn print_oversized_files(oversized: &[(PathBuf, usize)]) {
+    if oversized.is_empty() {
+        return;
+    }
 
-    for (path, error) in errors {
-        writeln!(log_file, "\n파일: {:?}", path)?;
-        writeln!(log_file, "에러: {}", error)?;
+    warning!(
+        "\n{}",
+        "📏 크기 제한을 넘은 레코드가 있는 파일:".bright_yellow()
+    );
+    for (path, records) in oversized {
+        warning!("  {} {} ({}건)", "•".yellow(), path.display(), records);
     }
+}
 
-    println!("\n{} 에러 로그 저장: {:?}", "📝".bright_cyan(), log_path);
+/// 에러 로그 파일 작성 (파서가 보고한 줄/칸 위치는 파일의 바이트 위치로 변환)
+fn write_error_log(log_path: &Path, errors: &[ErrorEntry], format: ErrorLogFormat) -> Result<()> {
+    let mut entries = errors.to_vec();
+    entries.iter_mut().for_each(ErrorEntry::locate);
+    error_log::write_error_log(log_path, &entries, format)
+        .with_context(|| format!("에러 로그를 저장할 수 없습니다: {:?}", log_path))?;
 
-    Ok(())
-}
+    status!("\n{} 에러 로그 저장: {:?}", "📝".bright_cyan(), log_path);
 
-/// 현재 시간 문자열 반환
-fn chrono_now() -> String {
-    use std::time::SystemTime;
-    let now = SystemTime::now();
-    let duration = now
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap_or_default();
-    format!("Unix timestamp: {}", duration.as_secs())
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use std::time::Duration;
     use tempfile::TempDir;
 
     fn create_test_json(dir: &std::path::Path, name: &str, content: &str) -> PathBuf {
@@ -414,24 +2825,140 @@ mod tests {
         create_test_json(temp_dir.path(), "other.txt", "not json");
 
         let args = Args {
-            input: temp_dir.path().to_path_buf(),
+            input: vec![temp_dir.path().to_path_buf()],
             output: PathBuf::from("output.jsonl"),
             mode: WriteMode::Overwrite,
-            pattern: None,
-            verbose: false,
-            dry_run: false,
-            validate_only: false,
-            fields: None,
-            threads: None,
-            max_depth: None,
-            log: None,
-            pretty: false,
+            ..Default::default()
+        };
+
+        let pattern_matcher = PatternMatcher::new(None).unwrap();
+        let files = collect_json_files(&args, &pattern_matcher).unwrap();
+
+        assert_eq!(files.len(), 2);
+    }
+
+    #[test]
+    fn test_collect_json_lines_files() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_json(temp_dir.path(), "a.json", r#"{"id": 1}"#);
+        create_test_json(temp_dir.path(), "b.jsonl", "{\"id\": 2}\n");
+        create_test_json(temp_dir.path(), "c.NDJSON", "{\"id\": 3}\n");
+        create_test_json(temp_dir.path(), "d.txt", "{}");
+
+        let args = Args {
+            input: vec![temp_dir.path().to_path_buf()],
+            ..Default::default()
         };
 
         let pattern_matcher = PatternMatcher::new(None).unwrap();
         let files = collect_json_files(&args, &pattern_matcher).unwrap();
 
+        assert_eq!(files.len(), 3);
+    }
+
+    #[test]
+    fn test_collect_json_files_from_multiple_sources() {
+        let temp_dir = TempDir::new().unwrap();
+        let a = temp_dir.path().join("a");
+        let b = temp_dir.path().join("b");
+        fs::create_dir_all(a.join("sub")).unwrap();
+        fs::create_dir_all(&b).unwrap();
+        create_test_json(&a, "x.json", r#"{"id": 1}"#);
+        create_test_json(&a.join("sub"), "y.json", r#"{"id": 2}"#);
+        let bx = create_test_json(&b, "x.json", r#"{"id": 3}"#);
+
+        // 목록에는 다른 폴더의 파일과 이미 포함된 파일(다른 표기)이 섞여 있음
+        let extra = create_test_json(temp_dir.path(), "extra.json", r#"{"id": 4}"#);
+        let list = temp_dir.path().join("list.txt");
+        fs::write(
+            &list,
+            format!(
+                "{}\n\n{}\n",
+                extra.display(),
+                a.join("sub/../x.json").display()
+            ),
+        )
+        .unwrap();
+
+        let args = Args {
+            input: vec![a.clone(), b.clone(), a.clone()],
+            files_from: Some(list),
+            ..Default::default()
+        };
+        let pattern_matcher = PatternMatcher::new(None).unwrap();
+        let files = collect_json_files(&args, &pattern_matcher).unwrap();
+        assert_eq!(files.len(), 4);
+        assert!(files.contains(&bx));
+        assert_eq!(files.last(), Some(&extra));
+
+        // 여러 입력 폴더는 폴더 이름을 남긴 상대 경로로 구분
+        let roots = source_roots(&args);
+        assert_eq!(relative_to_roots(&bx, &roots), "b/x.json");
+        let args = Args {
+            input: vec![b.clone()],
+            ..Default::default()
+        };
+        assert_eq!(relative_to_roots(&bx, &source_roots(&args)), "x.json");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_collect_json_files_follow_symlinks() {
+        let temp_dir = TempDir::new().unwrap();
+        let data = temp_dir.path().join("data");
+        let shared = temp_dir.path().join("shared");
+        fs::create_dir_all(&data).unwrap();
+        fs::create_dir_all(&shared).unwrap();
+        create_test_json(&data, "a.json", r#"{"id": 1}"#);
+        create_test_json(&shared, "s.json", r#"{"id": 2}"#);
+        // 외부 폴더 링크, 같은 폴더를 두 번 가리키는 링크, 상위 폴더로 돌아가는 순환 링크
+        std::os::unix::fs::symlink(&shared, data.join("linked")).unwrap();
+        std::os::unix::fs::symlink(&shared, data.join("linked_again")).unwrap();
+        std::os::unix::fs::symlink(&data, data.join("loop")).unwrap();
+
+        let mut args = Args {
+            input: vec![data.clone()],
+            ..Default::default()
+        };
+        let pattern_matcher = PatternMatcher::new(None).unwrap();
+        assert_eq!(
+            collect_json_files(&args, &pattern_matcher).unwrap(),
+            vec![data.join("a.json")]
+        );
+
+        args.follow_symlinks = true;
+        let mut files = collect_json_files(&args, &pattern_matcher).unwrap();
+        files.sort();
         assert_eq!(files.len(), 2);
+        assert_eq!(files[0], data.join("a.json"));
+        assert!(files[1].ends_with("s.json"));
+    }
+
+    #[test]
+    fn test_collect_geojson_files_only_in_geojson_mode() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_json(temp_dir.path(), "a.json", r#"{"id": 1}"#);
+        create_test_json(
+            temp_dir.path(),
+            "b.geojson",
+            r#"{"type": "FeatureCollection", "features": []}"#,
+        );
+
+        let mut args = Args {
+            input: vec![temp_dir.path().to_path_buf()],
+            ..Default::default()
+        };
+        let pattern_matcher = PatternMatcher::new(None).unwrap();
+        assert_eq!(
+            collect_json_files(&args, &pattern_matcher).unwrap().len(),
+            1
+        );
+
+        args.geojson = true;
+        assert_eq!(
+            collect_json_files(&args, &pattern_matcher).unwrap().len(),
+            2
+        );
     }
 
     #[test]
@@ -442,21 +2969,14 @@ mod tests {
         create_test_json(temp_dir.path(), "other.json", r#"{"id": 3}"#);
 
         let args = Args {
-            input: temp_dir.path().to_path_buf(),
+            input: vec![temp_dir.path().to_path_buf()],
             output: PathBuf::from("output.jsonl"),
             mode: WriteMode::Overwrite,
-            pattern: Some("*_SUM_*".to_string()),
-            verbose: false,
-            dry_run: false,
-            validate_only: false,
-            fields: None,
-            threads: None,
-            max_depth: None,
-            log: None,
-            pretty: false,
+            pattern: vec!["*_SUM_*".to_string()],
+            ..Default::default()
         };
 
-        let pattern_matcher = PatternMatcher::new(args.pattern.clone()).unwrap();
+        let pattern_matcher = PatternMatcher::with_patterns(&args.pattern, &args.exclude).unwrap();
         let files = collect_json_files(&args, &pattern_matcher).unwrap();
 
         assert_eq!(files.len(), 2);
@@ -476,18 +2996,11 @@ mod tests {
 
         // max_depth = 1 (root + 1 level down)
         let args = Args {
-            input: temp_dir.path().to_path_buf(),
+            input: vec![temp_dir.path().to_path_buf()],
             output: PathBuf::from("output.jsonl"),
             mode: WriteMode::Overwrite,
-            pattern: None,
-            verbose: false,
-            dry_run: false,
-            validate_only: false,
-            fields: None,
-            threads: None,
             max_depth: Some(2),
-            log: None,
-            pretty: false,
+            ..Default::default()
         };
 
         let pattern_matcher = PatternMatcher::new(None).unwrap();
@@ -496,4 +3009,114 @@ mod tests {
         // root.json and level1.json (not level2.json because max_depth=2 means depth 0,1)
         assert_eq!(files.len(), 2);
     }
+
+    #[test]
+    fn test_collect_json_files_with_file_filter() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_json(temp_dir.path(), "small.json", "{}");
+        create_test_json(
+            temp_dir.path(),
+            "medium.json",
+            r#"{"id": 1, "name": "medium"}"#,
+        );
+        create_test_json(
+            temp_dir.path(),
+            "large.json",
+            &format!(r#"{{"x": "{}"}}"#, "a".repeat(200)),
+        );
+
+        let args = Args {
+            input: vec![temp_dir.path().to_path_buf()],
+            min_size: Some(10),
+            max_size: Some(100),
+            ..Default::default()
+        };
+        let pattern_matcher = PatternMatcher::new(None).unwrap();
+        let files = collect_json_files(&args, &pattern_matcher).unwrap();
+        assert_eq!(files, vec![temp_dir.path().join("medium.json")]);
+
+        // 방금 만든 파일은 미래 시각 이전, 한 시간 전 이후에 수정됨
+        let args = Args {
+            input: vec![temp_dir.path().to_path_buf()],
+            newer_than: Some(SystemTime::now() - Duration::from_secs(3600)),
+            ..Default::default()
+        };
+        assert_eq!(
+            collect_json_files(&args, &pattern_matcher).unwrap().len(),
+            3
+        );
+
+        let args = Args {
+            input: vec![temp_dir.path().to_path_buf()],
+            older_than: Some(SystemTime::now() - Duration::from_secs(3600)),
+            ..Default::default()
+        };
+        assert!(collect_json_files(&args, &pattern_matcher)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_read_path_list() {
+        let list = "data/a.json\r\n\n  \ndata/backup/b.json\ndata/c.json\n";
+        let pattern_matcher =
+            PatternMatcher::with_patterns(&[], &["*/backup/*".to_string()]).unwrap();
+        let files =
+            read_path_list(list.as_bytes(), &pattern_matcher, &FileFilter::default()).unwrap();
+
+        assert_eq!(
+            files,
+            vec![PathBuf::from("data/a.json"), PathBuf::from("data/c.json")]
+        );
+    }
+
+    #[test]
+    fn test_exclude_output_files() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_json(temp_dir.path(), "data.json", r#"{"id": 1}"#);
+        create_test_json(temp_dir.path(), "merged.json", r#"{"id": 1}"#);
+        create_test_json(temp_dir.path(), "merged.0001.json", r#"{"id": 1}"#);
+        create_test_json(temp_dir.path(), "report.json", r#"{"success": 1}"#);
+
+        let mut args = Args {
+            input: vec![temp_dir.path().to_path_buf()],
+            output: temp_dir.path().join("merged.json"),
+            split_records: Some(10),
+            stats_json: Some(temp_dir.path().join("report.json")),
+            ..Default::default()
+        };
+        let pattern_matcher = PatternMatcher::new(None).unwrap();
+        let files = collect_json_files(&args, &pattern_matcher).unwrap();
+        assert_eq!(files.len(), 4);
+
+        let kept = exclude_output_files(&args, files.clone());
+        assert_eq!(kept.len(), 1);
+        assert!(kept[0].ends_with("data.json"));
+
+        args.include_output = true;
+        assert_eq!(exclude_output_files(&args, files).len(), 4);
+    }
+
+    #[test]
+    fn test_collect_json_files_with_exclude() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive_dir = temp_dir.path().join("archive");
+        fs::create_dir(&archive_dir).unwrap();
+
+        create_test_json(temp_dir.path(), "data.json", r#"{"id": 1}"#);
+        create_test_json(temp_dir.path(), "data_backup_1.json", r#"{"id": 2}"#);
+        create_test_json(&archive_dir, "old.json", r#"{"id": 3}"#);
+
+        let args = Args {
+            input: vec![temp_dir.path().to_path_buf()],
+            exclude: vec!["*_backup_*.json".to_string(), "archive/**".to_string()],
+            ..Default::default()
+        };
+
+        let pattern_matcher = PatternMatcher::with_patterns(&args.pattern, &args.exclude).unwrap();
+        let files = collect_json_files(&args, &pattern_matcher).unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert!(files[0].ends_with("data.json"));
+    }
 }
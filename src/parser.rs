@@ -0,0 +1,98 @@
+//! JSON 파서 선택 모듈
+//!
+//! 파일 하나를 통째로 읽어 파싱하는 경로에서 기본 `serde_json` 대신 SIMD 명령으로
+//! 구조를 찾는 `simd-json` 파서를 쓸 수 있습니다 (`--parser simd`, `simd-json` 기능으로 빌드 필요).
+//!
+//! `simd-json`은 입력 버퍼를 제자리에서 고쳐 쓰므로 가변 버퍼가 필요하고,
+//! 지원하지 않는 입력(파싱 한도 검사, 파싱 실패 등)은 원본을 `serde_json`으로 다시 파싱합니다.
+
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+/// JSON 파서 종류
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ParserKind {
+    /// serde_json (기본값)
+    #[default]
+    Serde,
+    /// simd-json (`simd-json` 기능으로 빌드해야 사용 가능)
+    Simd,
+}
+
+impl ParserKind {
+    /// 표시용 파서 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            ParserKind::Serde => "serde_json",
+            ParserKind::Simd => "simd-json",
+        }
+    }
+
+    /// 현재 빌드에서 사용할 수 있는 파서인지 확인
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::parser::ParserKind;
+    ///
+    /// assert!(ParserKind::Serde.is_available());
+    /// assert_eq!(ParserKind::Simd.is_available(), cfg!(feature = "simd-json"));
+    /// ```
+    pub fn is_available(&self) -> bool {
+        match self {
+            ParserKind::Serde => true,
+            ParserKind::Simd => cfg!(feature = "simd-json"),
+        }
+    }
+}
+
+impl fmt::Display for ParserKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// simd-json으로 JSON 문서 하나를 파싱
+///
+/// 파싱에 실패하거나 `simd-json` 기능 없이 빌드되었으면 `None`을 반환합니다.
+/// 실패한 경우 버퍼 내용이 바뀌었을 수 있으므로 원본을 다시 읽어 `serde_json`으로 파싱해야 합니다.
+#[cfg(feature = "simd-json")]
+pub fn parse_simd(bytes: &mut [u8]) -> Option<Value> {
+    simd_json::serde::from_slice(bytes).ok()
+}
+
+/// simd-json으로 JSON 문서 하나를 파싱 (`simd-json` 기능 없이 빌드되어 항상 `None`)
+#[cfg(not(feature = "simd-json"))]
+pub fn parse_simd(_bytes: &mut [u8]) -> Option<Value> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parser_kind_names() {
+        assert_eq!(ParserKind::default(), ParserKind::Serde);
+        assert_eq!(ParserKind::Serde.to_string(), "serde_json");
+        assert_eq!(ParserKind::Simd.to_string(), "simd-json");
+        assert_eq!(
+            serde_json::to_value(ParserKind::Simd).unwrap(),
+            serde_json::json!("simd")
+        );
+    }
+
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn test_parse_simd_matches_serde() {
+        let source =
+            br#"{"id": 1, "name": "a\"b", "tags": [1.5, null, true], "big": 18446744073709551615}"#;
+        let mut bytes = source.to_vec();
+        let parsed = parse_simd(&mut bytes).unwrap();
+        assert_eq!(parsed, serde_json::from_slice::<Value>(source).unwrap());
+
+        assert!(parse_simd(&mut br#"{"id": 1,"#.to_vec()).is_none());
+    }
+}
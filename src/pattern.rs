@@ -1,15 +1,21 @@
 //! 패턴 매칭 모듈
 //!
-//! glob 패턴을 사용한 파일 이름 필터링을 담당합니다.
+//! glob 패턴을 사용한 파일 경로 필터링을 담당합니다.
 
 use glob::Pattern;
+use std::path::Path;
 
 use crate::error::{JConvertError, Result};
 
 /// 컴파일된 패턴 매처
+///
+/// 포함 패턴 중 하나와 일치하고 제외 패턴과는 하나도 일치하지 않는 경로만 통과시킵니다.
+/// 각 패턴은 파일 이름과 입력 폴더 기준 상대 경로에 모두 적용되므로
+/// `*_backup_*.json` 같은 이름 패턴과 `archive/**` 같은 폴더 단위 패턴을 함께 쓸 수 있습니다.
 #[derive(Default)]
 pub struct PatternMatcher {
-    pattern: Option<Pattern>,
+    includes: Vec<Pattern>,
+    excludes: Vec<Pattern>,
 }
 
 impl PatternMatcher {
@@ -30,15 +36,35 @@ impl PatternMatcher {
     /// assert!(!matcher.matches("other.json"));
     /// ```
     pub fn new(pattern: Option<String>) -> Result<Self> {
-        let compiled = match pattern {
-            Some(ref p) => Some(
-                Pattern::new(p)
-                    .map_err(|_| JConvertError::InvalidPattern { pattern: p.clone() })?,
-            ),
-            None => None,
-        };
+        let includes: Vec<String> = pattern.into_iter().collect();
+        Self::with_patterns(&includes, &[])
+    }
 
-        Ok(Self { pattern: compiled })
+    /// 포함/제외 패턴 목록으로 패턴 매처 생성
+    ///
+    /// # Arguments
+    /// * `includes` - 포함 패턴 목록 (비어 있으면 모든 파일 포함)
+    /// * `excludes` - 제외 패턴 목록
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::pattern::PatternMatcher;
+    /// use std::path::Path;
+    ///
+    /// let matcher = PatternMatcher::with_patterns(
+    ///     &[],
+    ///     &["*_backup_*.json".to_string(), "archive/**".to_string()],
+    /// )
+    /// .unwrap();
+    /// assert!(matcher.matches_path(Path::new("2024/data.json")));
+    /// assert!(!matcher.matches_path(Path::new("2024/data_backup_1.json")));
+    /// assert!(!matcher.matches_path(Path::new("archive/old.json")));
+    /// ```
+    pub fn with_patterns(includes: &[String], excludes: &[String]) -> Result<Self> {
+        Ok(Self {
+            includes: compile_patterns(includes)?,
+            excludes: compile_patterns(excludes)?,
+        })
     }
 
     /// 파일 이름이 패턴과 일치하는지 확인
@@ -49,18 +75,56 @@ impl PatternMatcher {
     /// # Returns
     /// 패턴 일치 여부 (패턴이 없으면 항상 true)
     pub fn matches(&self, file_name: &str) -> bool {
-        match &self.pattern {
-            Some(p) => p.matches(file_name),
-            None => true,
-        }
+        let included =
+            self.includes.is_empty() || self.includes.iter().any(|p| p.matches(file_name));
+        included && !self.excludes.iter().any(|p| p.matches(file_name))
+    }
+
+    /// 입력 폴더 기준 상대 경로가 패턴과 일치하는지 확인
+    ///
+    /// 각 패턴은 상대 경로 전체(구분자는 `/`로 통일) 또는 파일 이름 중 하나와
+    /// 일치하면 일치한 것으로 봅니다.
+    ///
+    /// # Arguments
+    /// * `relative_path` - 입력 폴더 기준 상대 경로
+    ///
+    /// # Returns
+    /// 패턴 일치 여부 (패턴이 없으면 항상 true)
+    pub fn matches_path(&self, relative_path: &Path) -> bool {
+        let full_path = relative_path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let file_name = relative_path
+            .file_name()
+            .map(|s| s.to_string_lossy())
+            .unwrap_or_default();
+
+        let matches_any = |patterns: &[Pattern]| {
+            patterns
+                .iter()
+                .any(|p| p.matches(&full_path) || p.matches(&file_name))
+        };
+
+        let included = self.includes.is_empty() || matches_any(&self.includes);
+        included && !matches_any(&self.excludes)
     }
 
     /// 패턴이 설정되어 있는지 확인
     pub fn has_pattern(&self) -> bool {
-        self.pattern.is_some()
+        !self.includes.is_empty() || !self.excludes.is_empty()
     }
 }
 
+/// 패턴 문자열 목록 컴파일
+fn compile_patterns(patterns: &[String]) -> Result<Vec<Pattern>> {
+    patterns
+        .iter()
+        .map(|p| Pattern::new(p).map_err(|_| JConvertError::InvalidPattern { pattern: p.clone() }))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,4 +176,46 @@ mod tests {
         assert!(with_pattern.has_pattern());
         assert!(!without_pattern.has_pattern());
     }
+
+    #[test]
+    fn test_multiple_includes() {
+        let matcher =
+            PatternMatcher::with_patterns(&["a*.json".to_string(), "b*.json".to_string()], &[])
+                .unwrap();
+        assert!(matcher.matches("alpha.json"));
+        assert!(matcher.matches("beta.json"));
+        assert!(!matcher.matches("gamma.json"));
+    }
+
+    #[test]
+    fn test_exclude_file_name() {
+        let matcher =
+            PatternMatcher::with_patterns(&["*.json".to_string()], &["*_backup_*".to_string()])
+                .unwrap();
+        assert!(matcher.matches_path(Path::new("data.json")));
+        assert!(!matcher.matches_path(Path::new("data_backup_1.json")));
+        assert!(!matcher.matches_path(Path::new("sub/data_backup_2.json")));
+    }
+
+    #[test]
+    fn test_exclude_directory() {
+        let matcher = PatternMatcher::with_patterns(&[], &["archive/**".to_string()]).unwrap();
+        assert!(matcher.matches_path(Path::new("current/data.json")));
+        assert!(!matcher.matches_path(Path::new("archive/data.json")));
+        assert!(!matcher.matches_path(Path::new("archive/2023/data.json")));
+        assert!(matcher.has_pattern());
+    }
+
+    #[test]
+    fn test_include_matches_file_name_in_subdirectory() {
+        let matcher = PatternMatcher::new(Some("data?.json".to_string())).unwrap();
+        assert!(matcher.matches_path(Path::new("nested/dir/data1.json")));
+        assert!(!matcher.matches_path(Path::new("nested/dir/data12.json")));
+    }
+
+    #[test]
+    fn test_invalid_exclude_pattern() {
+        let result = PatternMatcher::with_patterns(&[], &["[invalid".to_string()]);
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,349 @@
+//! 변환 파이프라인 모듈
+//!
+//! 설정 파일에 정의된 변환 단계를 순서대로 각 레코드에 적용합니다.
+//! 여러 플래그를 조합하는 대신 변환 과정을 선언적으로 기술하고 검토할 수 있습니다.
+//!
+//! # 설정 파일 형식
+//!
+//! ```json
+//! {
+//!   "stages": [
+//!     { "select": ["id", "status", "user.name", "user.password"] },
+//!     { "rename": { "user_name": "username" } },
+//!     { "filter": { "field": "status", "equals": "active" } },
+//!     { "redact": { "fields": ["user_password"] } },
+//!     { "inject": { "source_file": "_source_file", "values": { "dataset": "v1" } } }
+//!   ]
+//! }
+//! ```
+
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use crate::error::{JConvertError, Result};
+use crate::processor::{
+    extract_fields, get_nested_field, get_nested_field_mut, inject_field, rename_field,
+    RejectedRecord,
+};
+
+/// 가림 처리 기본 대체 값
+pub const DEFAULT_REDACTION: &str = "[REDACTED]";
+
+/// 순서가 있는 변환 파이프라인
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Pipeline {
+    /// 변환 단계 목록 (정의된 순서대로 적용)
+    pub stages: Vec<Stage>,
+}
+
+/// 파이프라인 변환 단계
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    /// 지정한 필드만 추출 (`--fields`와 동일, 중첩 필드는 `_`로 평탄화)
+    Select(Vec<String>),
+    /// 필드 이름 변경 (기존 경로 → 새 경로, 점 표기법 지원)
+    ///
+    /// 새 경로 중간에 객체가 아닌 값이 있으면 값을 기존 경로에 둔 채 레코드를 제외합니다.
+    Rename(BTreeMap<String, String>),
+    /// 조건에 맞는 레코드만 유지
+    Filter(FilterStage),
+    /// 민감한 필드 값 가리기
+    Redact(RedactStage),
+    /// 원본 정보 및 고정 값 주입
+    Inject(InjectStage),
+}
+
+/// 레코드 필터 단계
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FilterStage {
+    /// 검사할 필드 (점 표기법 지원)
+    pub field: String,
+    /// 일치해야 하는 값 (없으면 필드가 존재하고 null이 아니면 유지)
+    #[serde(default)]
+    pub equals: Option<Value>,
+}
+
+/// 필드 가림 단계
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RedactStage {
+    /// 가릴 필드 목록 (점 표기법 지원)
+    pub fields: Vec<String>,
+    /// 대체 값 (기본값: "[REDACTED]")
+    #[serde(default = "default_redaction")]
+    pub replacement: Value,
+}
+
+/// 필드 주입 단계
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InjectStage {
+    /// 원본 파일 상대 경로를 주입할 키
+    #[serde(default)]
+    pub source_file: Option<String>,
+    /// 원본 파일 수정 시각을 주입할 키
+    #[serde(default)]
+    pub source_mtime: Option<String>,
+    /// 그대로 주입할 고정 값
+    #[serde(default)]
+    pub values: BTreeMap<String, Value>,
+}
+
+fn default_redaction() -> Value {
+    Value::String(DEFAULT_REDACTION.to_string())
+}
+
+/// 단계 적용 시 참조하는 레코드 원본 정보
+#[derive(Debug, Clone, Copy)]
+pub struct StageContext<'a> {
+    /// 원본 파일 상대 경로
+    pub source_file: &'a str,
+    /// 원본 파일 수정 시각 (Unix timestamp)
+    pub source_mtime: Option<u64>,
+}
+
+impl Pipeline {
+    /// 설정 파일에서 파이프라인 로드
+    ///
+    /// # Arguments
+    /// * `path` - 파이프라인 설정 JSON 파일 경로
+    pub fn load(path: &Path) -> Result<Self> {
+        let file = File::open(path).map_err(|e| JConvertError::InvalidPipeline {
+            file: path.to_path_buf(),
+            reason: e.to_string(),
+        })?;
+
+        serde_json::from_reader(BufReader::new(file)).map_err(|e| JConvertError::InvalidPipeline {
+            file: path.to_path_buf(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// 레코드에 모든 단계를 순서대로 적용
+    ///
+    /// 최상위 값이 배열이면 각 요소를 개별 레코드로 보고 단계를 적용합니다.
+    ///
+    /// # Returns
+    /// 변환된 레코드 (필터에서 제외되면 None)
+    pub fn apply(&self, record: Value, context: &StageContext) -> Option<Value> {
+        self.apply_collecting(record, context, &mut Vec::new())
+    }
+
+    /// 레코드에 파이프라인 적용 (필터에서 제외된 레코드는 이유와 함께 `rejected`에 모음)
+    ///
+    /// # Returns
+    /// 변환된 레코드 (필터에서 제외되면 None)
+    pub fn apply_collecting(
+        &self,
+        record: Value,
+        context: &StageContext,
+        rejected: &mut Vec<RejectedRecord>,
+    ) -> Option<Value> {
+        let mut apply = |record: Value| {
+            self.apply_record(record, context)
+                .map_err(|rejection| rejected.push(rejection))
+                .ok()
+        };
+        match record {
+            Value::Array(items) => {
+                Some(Value::Array(items.into_iter().filter_map(apply).collect()))
+            }
+            other => apply(other),
+        }
+    }
+
+    /// 단일 레코드에 단계 적용
+    fn apply_record(
+        &self,
+        mut record: Value,
+        context: &StageContext,
+    ) -> std::result::Result<Value, RejectedRecord> {
+        for stage in &self.stages {
+            record = stage.apply(record, context)?;
+        }
+        Ok(record)
+    }
+}
+
+impl Stage {
+    /// 단일 단계 적용
+    fn apply(
+        &self,
+        mut record: Value,
+        context: &StageContext,
+    ) -> std::result::Result<Value, RejectedRecord> {
+        match self {
+            Stage::Select(fields) => Ok(extract_fields(record, fields)),
+            Stage::Rename(mapping) => {
+                for (from, to) in mapping {
+                    if let Err(reason) = rename_field(&mut record, from, to) {
+                        return Err(RejectedRecord::new(
+                            record,
+                            format!("파이프라인 {}", reason),
+                        ));
+                    }
+                }
+                Ok(record)
+            }
+            Stage::Filter(filter) => {
+                let keep = match (get_nested_field(&record, &filter.field), &filter.equals) {
+                    (Some(value), Some(expected)) => value == expected,
+                    (Some(value), None) => !value.is_null(),
+                    (None, _) => false,
+                };
+                if keep {
+                    Ok(record)
+                } else {
+                    Err(RejectedRecord::new(
+                        record,
+                        format!("파이프라인 필터 불일치: {}", filter.field),
+                    ))
+                }
+            }
+            Stage::Redact(redact) => {
+                for field in &redact.fields {
+                    if let Some(value) = get_nested_field_mut(&mut record, field) {
+                        *value = redact.replacement.clone();
+                    }
+                }
+                Ok(record)
+            }
+            Stage::Inject(inject) => {
+                if let Some(ref key) = inject.source_file {
+                    inject_field(&mut record, key, Value::from(context.source_file));
+                }
+                if let (Some(ref key), Some(mtime)) = (&inject.source_mtime, context.source_mtime) {
+                    inject_field(&mut record, key, Value::from(mtime));
+                }
+                for (key, value) in &inject.values {
+                    inject_field(&mut record, key, value.clone());
+                }
+                Ok(record)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const CONTEXT: StageContext = StageContext {
+        source_file: "sub/a.json",
+        source_mtime: Some(1_700_000_000),
+    };
+
+    fn pipeline(config: Value) -> Pipeline {
+        serde_json::from_value(config).unwrap()
+    }
+
+    #[test]
+    fn test_pipeline_stages_in_order() {
+        let pipeline = pipeline(json!({
+            "stages": [
+                { "select": ["id", "status", "user.name", "user.password"] },
+                { "rename": { "user_name": "username" } },
+                { "filter": { "field": "status", "equals": "active" } },
+                { "redact": { "fields": ["user_password"] } },
+                { "inject": { "source_file": "_source_file", "values": { "dataset": "v1" } } }
+            ]
+        }));
+
+        let record = json!({
+            "id": 1,
+            "status": "active",
+            "user": { "name": "kim", "password": "secret" },
+            "extra": true
+        });
+
+        assert_eq!(
+            pipeline.apply(record, &CONTEXT),
+            Some(json!({
+                "id": 1,
+                "status": "active",
+                "username": "kim",
+                "user_password": "[REDACTED]",
+                "_source_file": "sub/a.json",
+                "dataset": "v1"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_filter_drops_records_and_array_items() {
+        let pipeline = pipeline(json!({
+            "stages": [{ "filter": { "field": "keep" } }]
+        }));
+
+        assert_eq!(pipeline.apply(json!({"keep": null}), &CONTEXT), None);
+        assert_eq!(
+            pipeline.apply(json!([{"keep": 1}, {"other": 2}]), &CONTEXT),
+            Some(json!([{"keep": 1}]))
+        );
+
+        let mut rejected = Vec::new();
+        let kept =
+            pipeline.apply_collecting(json!([{"keep": 1}, {"other": 2}]), &CONTEXT, &mut rejected);
+        assert_eq!(kept, Some(json!([{"keep": 1}])));
+        assert_eq!(
+            rejected,
+            vec![RejectedRecord::new(
+                json!({"other": 2}),
+                "파이프라인 필터 불일치: keep".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_rename_conflicting_target_rejects_record() {
+        let pipeline = pipeline(json!({
+            "stages": [{ "rename": { "name": "user.name" } }]
+        }));
+
+        let mut rejected = Vec::new();
+        let kept = pipeline.apply_collecting(
+            json!([{"name": "kim", "user": "lee"}, {"name": "park", "user": {"id": 1}}]),
+            &CONTEXT,
+            &mut rejected,
+        );
+        assert_eq!(kept, Some(json!([{"user": {"id": 1, "name": "park"}}])));
+        assert_eq!(
+            rejected,
+            vec![RejectedRecord::new(
+                json!({"name": "kim", "user": "lee"}),
+                "파이프라인 필드 이름 변경 실패: name -> user.name (경로 중간에 객체가 아닌 값이 있음)"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_inject_mtime_and_custom_redaction() {
+        let pipeline = pipeline(json!({
+            "stages": [
+                { "redact": { "fields": ["token"], "replacement": null } },
+                { "inject": { "source_mtime": "_mtime" } }
+            ]
+        }));
+
+        assert_eq!(
+            pipeline.apply(json!({"token": "abc"}), &CONTEXT),
+            Some(json!({"token": null, "_mtime": 1_700_000_000}))
+        );
+    }
+
+    #[test]
+    fn test_unknown_stage_rejected() {
+        let result: std::result::Result<Pipeline, _> =
+            serde_json::from_value(json!({ "stages": [{ "explode": "items" }] }));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,344 @@
+//! 실행 계획 모듈
+//!
+//! 실제 변환 없이 입력 파일 수, 전체 용량, 크기 분포를 집계하고
+//! 일부 파일을 샘플로 처리해 본 속도로 전체 처리 시간을 추정합니다.
+//! `--dry-run --preview N`은 같은 샘플링으로 출력 크기를 추정하고 변환된 처음 레코드를 보여 줍니다.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::processor::{process_file, ProcessOptions};
+
+/// 파일 크기 분포 구간 (상한 미만, 표시 이름)
+pub const SIZE_BUCKETS: &[(u64, &str)] = &[
+    (1024, "< 1 KB"),
+    (1024 * 1024, "1 KB – 1 MB"),
+    (10 * 1024 * 1024, "1 MB – 10 MB"),
+    (100 * 1024 * 1024, "10 MB – 100 MB"),
+    (u64::MAX, "≥ 100 MB"),
+];
+
+/// 기본 샘플 파일 수
+pub const DEFAULT_SAMPLE_SIZE: usize = 20;
+
+/// 크기 구간별 집계
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeBucket {
+    /// 구간 이름
+    pub label: &'static str,
+    /// 파일 수
+    pub files: usize,
+    /// 구간 전체 용량
+    pub bytes: u64,
+}
+
+/// 입력 파일 집계 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanSummary {
+    /// 파일 수
+    pub file_count: usize,
+    /// 전체 용량
+    pub total_bytes: u64,
+    /// 가장 작은 파일 크기
+    pub min_bytes: u64,
+    /// 중간 파일 크기
+    pub median_bytes: u64,
+    /// 가장 큰 파일 크기
+    pub max_bytes: u64,
+    /// 크기 구간별 분포
+    pub buckets: Vec<SizeBucket>,
+}
+
+impl PlanSummary {
+    /// 파일 크기 목록으로 집계
+    pub fn from_sizes(sizes: &[u64]) -> Self {
+        let mut sorted = sizes.to_vec();
+        sorted.sort_unstable();
+
+        let mut buckets: Vec<SizeBucket> = SIZE_BUCKETS
+            .iter()
+            .map(|(_, label)| SizeBucket {
+                label,
+                files: 0,
+                bytes: 0,
+            })
+            .collect();
+        for &size in &sorted {
+            let index = SIZE_BUCKETS
+                .iter()
+                .position(|(upper, _)| size < *upper)
+                .unwrap_or(SIZE_BUCKETS.len() - 1);
+            buckets[index].files += 1;
+            buckets[index].bytes += size;
+        }
+
+        Self {
+            file_count: sorted.len(),
+            total_bytes: sorted.iter().sum(),
+            min_bytes: sorted.first().copied().unwrap_or(0),
+            median_bytes: sorted.get(sorted.len() / 2).copied().unwrap_or(0),
+            max_bytes: sorted.last().copied().unwrap_or(0),
+            buckets,
+        }
+    }
+}
+
+/// 샘플 처리 벤치마크 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleBenchmark {
+    /// 처리한 샘플 파일 수
+    pub files: usize,
+    /// 샘플 전체 용량
+    pub bytes: u64,
+    /// 샘플 처리에 걸린 시간 (단일 스레드)
+    pub elapsed: Duration,
+}
+
+impl SampleBenchmark {
+    /// 단일 스레드 처리 속도 (바이트/초)
+    pub fn throughput(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0.0 {
+            self.bytes as f64 / secs
+        } else {
+            0.0
+        }
+    }
+
+    /// 전체 처리 시간 추정
+    ///
+    /// 샘플의 바이트당 처리 시간과 파일당 처리 시간 중 큰 값을 기준으로
+    /// 전체 작업량을 계산하고 스레드 수로 나눕니다 (디스크 병목은 고려하지 않음).
+    pub fn project(&self, summary: &PlanSummary, threads: usize) -> Duration {
+        if self.files == 0 {
+            return Duration::ZERO;
+        }
+
+        let secs = self.elapsed.as_secs_f64();
+        let by_bytes = if self.bytes > 0 {
+            secs / self.bytes as f64 * summary.total_bytes as f64
+        } else {
+            0.0
+        };
+        let by_files = secs / self.files as f64 * summary.file_count as f64;
+
+        Duration::from_secs_f64(by_bytes.max(by_files) / threads.max(1) as f64)
+    }
+}
+
+/// 샘플 변환 결과로 추정한 출력 크기
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OutputEstimate {
+    /// 처리한 샘플 파일 수
+    pub files: usize,
+    /// 변환에 실패한 샘플 파일 수
+    pub failed: usize,
+    /// 샘플 입력 용량
+    pub input_bytes: u64,
+    /// 샘플 출력 용량 (줄바꿈 포함)
+    pub output_bytes: u64,
+    /// 샘플 출력 줄 수
+    pub lines: u64,
+}
+
+impl OutputEstimate {
+    /// 입력 용량으로 전체 출력 용량 추정 (샘플 입출력 비율 적용)
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::plan::OutputEstimate;
+    ///
+    /// let estimate = OutputEstimate { files: 2, input_bytes: 1000, output_bytes: 600, lines: 4, ..Default::default() };
+    /// assert_eq!(estimate.project_bytes(10_000), 6000);
+    /// assert_eq!(estimate.project_lines(10_000), 40);
+    /// ```
+    pub fn project_bytes(&self, total_input: u64) -> u64 {
+        self.project(self.output_bytes, total_input)
+    }
+
+    /// 입력 용량으로 전체 출력 줄 수 추정
+    pub fn project_lines(&self, total_input: u64) -> u64 {
+        self.project(self.lines, total_input)
+    }
+
+    /// 샘플 값을 입력 용량 비율로 확대
+    fn project(&self, sampled: u64, total_input: u64) -> u64 {
+        if self.input_bytes == 0 {
+            return 0;
+        }
+        (sampled as f64 / self.input_bytes as f64 * total_input as f64).round() as u64
+    }
+}
+
+/// 고르게 뽑은 샘플 파일을 변환하여 출력 크기 추정 (출력은 버림)
+///
+/// # Arguments
+/// * `files` - 전체 입력 파일 목록
+/// * `sample_size` - 처리할 샘플 파일 수
+/// * `options` - 실제 변환과 같은 처리 옵션
+pub fn estimate_output(
+    files: &[PathBuf],
+    sample_size: usize,
+    options: &ProcessOptions,
+) -> OutputEstimate {
+    let mut estimate = OutputEstimate::default();
+    for path in sample_files(files, sample_size) {
+        let result = process_file(path.to_path_buf(), options);
+        estimate.files += 1;
+        estimate.input_bytes += result.file_size;
+        if result.is_valid {
+            estimate.lines += result.json_lines.len() as u64;
+            estimate.output_bytes += result
+                .json_lines
+                .iter()
+                .map(|line| line.len() as u64 + 1)
+                .sum::<u64>();
+        } else {
+            estimate.failed += 1;
+        }
+    }
+    estimate
+}
+
+/// 미리보기 레코드 (원본 파일, 출력 줄)
+pub type PreviewRecord = (PathBuf, String);
+
+/// 입력 순서대로 파일을 변환하여 처음 `count`개 출력 줄 수집
+///
+/// 변환에 실패한 파일은 건너뛰고, `count`개를 모으면 나머지 파일은 처리하지 않습니다.
+pub fn preview_records(
+    files: &[PathBuf],
+    count: usize,
+    options: &ProcessOptions,
+) -> Vec<PreviewRecord> {
+    let mut records = Vec::with_capacity(count);
+    for path in files {
+        if records.len() >= count {
+            break;
+        }
+        let result = process_file(path.to_path_buf(), options);
+        let remaining = count - records.len();
+        records.extend(
+            result
+                .json_lines
+                .into_iter()
+                .take(remaining)
+                .map(|line| (path.clone(), line)),
+        );
+    }
+    records
+}
+
+/// 고르게 뽑은 샘플 파일을 실제로 처리하여 속도 측정
+///
+/// # Arguments
+/// * `files` - 전체 입력 파일 목록
+/// * `sample_size` - 처리할 샘플 파일 수
+/// * `options` - 처리 옵션 (출력은 버림)
+pub fn benchmark_sample(
+    files: &[PathBuf],
+    sample_size: usize,
+    options: &ProcessOptions,
+) -> SampleBenchmark {
+    let sample = sample_files(files, sample_size);
+    let start = Instant::now();
+    let bytes = sample
+        .iter()
+        .map(|path| process_file(path.to_path_buf(), options).file_size)
+        .sum();
+
+    SampleBenchmark {
+        files: sample.len(),
+        bytes,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// 목록 전체에서 간격을 두고 고르게 샘플 선택
+fn sample_files(files: &[PathBuf], sample_size: usize) -> Vec<&PathBuf> {
+    if files.is_empty() || sample_size == 0 {
+        return Vec::new();
+    }
+    if files.len() <= sample_size {
+        return files.iter().collect();
+    }
+
+    (0..sample_size)
+        .map(|i| &files[i * files.len() / sample_size])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_summary_distribution() {
+        let summary = PlanSummary::from_sizes(&[10, 2048, 5 * 1024 * 1024, 100, 3000]);
+
+        assert_eq!(summary.file_count, 5);
+        assert_eq!(
+            summary.total_bytes,
+            10 + 2048 + 5 * 1024 * 1024 + 100 + 3000
+        );
+        assert_eq!(summary.min_bytes, 10);
+        assert_eq!(summary.median_bytes, 2048);
+        assert_eq!(summary.max_bytes, 5 * 1024 * 1024);
+        assert_eq!(
+            summary.buckets.iter().map(|b| b.files).collect::<Vec<_>>(),
+            vec![2, 2, 1, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_sample_files_evenly_spaced() {
+        let files: Vec<PathBuf> = (0..10).map(|i| PathBuf::from(i.to_string())).collect();
+        let sample = sample_files(&files, 3);
+        assert_eq!(sample, vec![&files[0], &files[3], &files[6]]);
+        assert_eq!(sample_files(&files, 20).len(), 10);
+    }
+
+    #[test]
+    fn test_preview_and_estimate_apply_options() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let files: Vec<PathBuf> = (0..3)
+            .map(|i| {
+                let path = dir.path().join(format!("{}.json", i));
+                std::fs::write(&path, format!(r#"{{"id": {}, "secret": "xxxxxxxx"}}"#, i)).unwrap();
+                path
+            })
+            .collect();
+        let bad = dir.path().join("bad.json");
+        std::fs::write(&bad, "{").unwrap();
+        let with_bad = [vec![bad], files.clone()].concat();
+
+        let options = ProcessOptions::new().with_exclude_fields(Some(vec!["secret".to_string()]));
+        let records = preview_records(&with_bad, 2, &options);
+        assert_eq!(
+            records,
+            vec![
+                (files[0].clone(), r#"{"id":0}"#.to_string()),
+                (files[1].clone(), r#"{"id":1}"#.to_string()),
+            ]
+        );
+
+        let estimate = estimate_output(&with_bad, 10, &options);
+        assert_eq!(estimate.files, 4);
+        assert_eq!(estimate.failed, 1);
+        assert_eq!(estimate.lines, 3);
+        assert_eq!(estimate.output_bytes, 3 * 9);
+    }
+
+    #[test]
+    fn test_projection_scales_with_threads() {
+        let benchmark = SampleBenchmark {
+            files: 2,
+            bytes: 1000,
+            elapsed: Duration::from_secs(1),
+        };
+        let summary = PlanSummary::from_sizes(&[500; 20]);
+
+        assert_eq!(benchmark.project(&summary, 1), Duration::from_secs(10));
+        assert_eq!(benchmark.project(&summary, 4), Duration::from_secs_f64(2.5));
+    }
+}
@@ -0,0 +1,96 @@
+//! 프로세스 우선순위 모듈
+//!
+//! 공유 서버에서 백그라운드 변환이 대화형 작업을 밀어내지 않도록 CPU 우선순위(nice)와
+//! I/O 우선순위를 낮춥니다 (`--nice`). Linux에서는 nice 값과 I/O 우선순위 모두,
+//! 그 밖의 Unix에서는 nice 값만, 다른 플랫폼에서는 아무것도 바꾸지 않습니다.
+//!
+//! Linux의 우선순위는 스레드 단위이며 새 스레드가 물려받으므로, 워커 스레드를 만들기 전에 적용해야 합니다.
+
+/// `--nice`를 값 없이 지정했을 때의 nice 값
+pub const DEFAULT_NICE: i32 = 10;
+
+/// 우선순위 조정 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PriorityOutcome {
+    /// CPU 우선순위(nice)를 낮췄는지 여부
+    pub cpu: bool,
+    /// I/O 우선순위를 낮췄는지 여부
+    pub io: bool,
+}
+
+/// nice 값에 대응하는 I/O 우선순위 (0~7, 클수록 낮음, 커널 기본 규칙 `(nice + 20) / 5`)
+///
+/// # Examples
+/// ```
+/// use jconvert::priority::io_priority_for;
+///
+/// assert_eq!(io_priority_for(0), 4);
+/// assert_eq!(io_priority_for(10), 6);
+/// assert_eq!(io_priority_for(19), 7);
+/// ```
+pub fn io_priority_for(nice: i32) -> i32 {
+    ((nice.clamp(-20, 19) + 20) / 5).clamp(0, 7)
+}
+
+/// 현재 프로세스(이후 만들 스레드 포함)의 CPU/I/O 우선순위를 낮춤
+///
+/// # Arguments
+/// * `nice` - nice 값 (1~19, 클수록 낮은 우선순위)
+pub fn lower_priority(nice: i32) -> PriorityOutcome {
+    PriorityOutcome {
+        cpu: set_nice(nice),
+        io: set_io_priority(io_priority_for(nice)),
+    }
+}
+
+/// nice 값 설정 (현재 스레드, 이후 만드는 스레드가 물려받음)
+#[cfg(unix)]
+fn set_nice(nice: i32) -> bool {
+    // SAFETY: 포인터 인자가 없는 단순 시스템 호출
+    unsafe { libc::setpriority(libc::PRIO_PROCESS as _, 0, nice) == 0 }
+}
+
+#[cfg(not(unix))]
+fn set_nice(_nice: i32) -> bool {
+    false
+}
+
+/// I/O 우선순위를 best-effort 클래스의 지정 수준으로 설정
+#[cfg(target_os = "linux")]
+fn set_io_priority(level: i32) -> bool {
+    const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+    const IOPRIO_CLASS_BE: libc::c_int = 2;
+    const IOPRIO_CLASS_SHIFT: libc::c_int = 13;
+
+    let priority = (IOPRIO_CLASS_BE << IOPRIO_CLASS_SHIFT) | level;
+    // SAFETY: 포인터 인자가 없는 단순 시스템 호출
+    unsafe { libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, 0, priority) == 0 }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_io_priority(_level: i32) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_io_priority_for_range() {
+        assert_eq!(io_priority_for(1), 4);
+        assert_eq!(io_priority_for(5), 5);
+        assert_eq!(io_priority_for(100), 7);
+        assert_eq!(io_priority_for(-100), 0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_lower_priority_in_thread() {
+        // 우선순위는 스레드 단위이므로 별도 스레드에서 낮춤 (다른 테스트에 영향 없음)
+        let outcome = std::thread::spawn(|| lower_priority(DEFAULT_NICE))
+            .join()
+            .unwrap();
+        assert!(outcome.cpu);
+    }
+}
@@ -2,38 +2,133 @@
 //!
 //! 개별 JSON 파일의 읽기, 파싱, 변환을 담당합니다.
 
-use memmap2::Mmap;
+use clap::ValueEnum;
+use memmap2::{Mmap, MmapMut, MmapOptions};
+use serde::de::IgnoredAny;
 use serde_json::{Map, Value};
+use std::cell::{OnceCell, RefCell};
 use std::fs::File;
-use std::io::BufReader;
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, Instant, UNIX_EPOCH};
 
+use crate::checksum::{self, ChecksumField, ChecksumScope};
+use crate::dedup;
+use crate::encoding::{self, InputEncoding};
 use crate::error::{JConvertError, Result};
+use crate::expr::Expr;
+use crate::field_order::FieldOrder;
+use crate::geojson::{self, GEOJSON_EXTENSION};
+use crate::infer::SchemaInference;
+use crate::keys::{add_key_prefix, normalize_keys, strip_key_prefix, KeyCase};
+use crate::limits::{self, ParseLimits};
+use crate::parser::{self, ParserKind};
+use crate::pipeline::{Pipeline, StageContext};
+use crate::raw_select;
+use crate::record_size::{RecordSizeGuard, SizeCheck};
+use crate::repair::JsonlRepairer;
+use crate::sample::Sampler;
+use crate::schema::FieldSchema;
+use crate::schema_filter::SchemaFilter;
+
+/// 출력 객체의 키 순서
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum KeyOrder {
+    /// serde_json 기본 동작 (기본 빌드는 사전순, `preserve_order` 기능으로 빌드하면 원본 순서)
+    #[default]
+    Default,
+    /// 원본 문서의 키 순서 유지 (`preserve_order` 기능으로 빌드해야 사용 가능)
+    Preserve,
+    /// 중첩 객체까지 모든 키를 사전순으로 정렬
+    Sort,
+}
+
+impl KeyOrder {
+    /// 현재 빌드에서 사용할 수 있는 키 순서인지 확인
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::processor::KeyOrder;
+    ///
+    /// assert!(KeyOrder::Sort.is_available());
+    /// assert_eq!(KeyOrder::Preserve.is_available(), cfg!(feature = "preserve_order"));
+    /// ```
+    pub fn is_available(&self) -> bool {
+        match self {
+            KeyOrder::Default | KeyOrder::Sort => true,
+            KeyOrder::Preserve => cfg!(feature = "preserve_order"),
+        }
+    }
+}
 
 /// 파일 처리 결과
 #[derive(Debug)]
 pub struct ProcessResult {
     /// 처리된 파일 경로
     pub path: PathBuf,
-    /// 변환된 JSON 라인 (성공 시)
-    pub json_line: Option<String>,
+    /// 변환된 JSON 라인 목록 (성공 시, 문서 하나당 한 줄)
+    pub json_lines: Vec<String>,
+    /// 출력 줄마다 직렬화 전 값에서 뽑은 중복 제거 키 (`ProcessOptions::dedup_key`가 있을 때만, `json_lines`와 같은 순서)
+    pub record_keys: Vec<Option<String>>,
     /// 에러 메시지 (실패 시)
     pub error: Option<String>,
+    /// 에러 종류 (`JConvertError::kind`, 실패 원인을 알 수 없으면 None)
+    pub error_kind: Option<&'static str>,
     /// 원본 파일 크기
     pub file_size: u64,
     /// JSON 유효성 여부
     pub is_valid: bool,
+    /// 조건/파이프라인 필터로 제외된 레코드 수
+    pub skipped_records: usize,
+    /// 읽기 권한이 없어 실패했는지 여부
+    pub is_unreadable: bool,
+    /// 스키마 필터에 맞지 않아 제외된 레코드 수
+    pub rejected_records: usize,
+    /// 제외된 레코드 줄 (`_reject_reason` 포함, `ProcessOptions::collect_rejects`일 때만)
+    pub rejects: Vec<String>,
+    /// 샘플링에서 빠진 레코드 수
+    pub sampled_out_records: usize,
+    /// 파싱 전에 UTF-8로 다시 인코딩했는지 여부 (BOM 제거, UTF-16/Latin-1 변환, lossy 교체)
+    pub reencoded: bool,
+    /// 한 줄에 붙어 있거나 여러 줄에 걸쳐 끊겨 있어 경계를 고친 JSONL 레코드 수
+    pub repaired_records: usize,
+    /// 크기 제한을 넘어 건너뛰거나 필드를 잘라 낸 레코드 수
+    pub oversized_records: usize,
+    /// 파일을 읽고 변환하는 데 걸린 시간
+    pub elapsed: Duration,
+    /// 출력 레코드의 필드 스키마 (`ProcessOptions::collect_schema`일 때만, 출력 레코드가 없으면 None)
+    pub schema: Option<FieldSchema>,
+    /// 출력 레코드의 스키마 추론 결과 (`ProcessOptions::infer_schema`일 때만, 출력 레코드가 없으면 None)
+    pub inference: Option<SchemaInference>,
 }
 
 impl ProcessResult {
     /// 성공 결과 생성
     pub fn success(path: PathBuf, json_line: String, file_size: u64) -> Self {
+        Self::success_many(path, vec![json_line], file_size)
+    }
+
+    /// 여러 줄을 담은 성공 결과 생성 (연속된 다중 문서 입력)
+    pub fn success_many(path: PathBuf, json_lines: Vec<String>, file_size: u64) -> Self {
         Self {
             path,
-            json_line: Some(json_line),
+            json_lines,
+            record_keys: Vec::new(),
             error: None,
+            error_kind: None,
             file_size,
             is_valid: true,
+            skipped_records: 0,
+            is_unreadable: false,
+            rejected_records: 0,
+            rejects: Vec::new(),
+            sampled_out_records: 0,
+            reencoded: false,
+            repaired_records: 0,
+            oversized_records: 0,
+            elapsed: Duration::ZERO,
+            schema: None,
+            inference: None,
         }
     }
 
@@ -41,10 +136,31 @@ impl ProcessResult {
     pub fn failure(path: PathBuf, error: String, file_size: u64) -> Self {
         Self {
             path,
-            json_line: None,
+            json_lines: Vec::new(),
+            record_keys: Vec::new(),
             error: Some(error),
+            error_kind: None,
             file_size,
             is_valid: false,
+            skipped_records: 0,
+            is_unreadable: false,
+            rejected_records: 0,
+            rejects: Vec::new(),
+            sampled_out_records: 0,
+            reencoded: false,
+            repaired_records: 0,
+            oversized_records: 0,
+            elapsed: Duration::ZERO,
+            schema: None,
+            inference: None,
+        }
+    }
+
+    /// 읽기 권한 없음 결과 생성
+    pub fn unreadable(path: PathBuf, error: String, file_size: u64) -> Self {
+        Self {
+            is_unreadable: true,
+            ..Self::failure(path, error, file_size)
         }
     }
 
@@ -52,12 +168,107 @@ impl ProcessResult {
     pub fn valid(path: PathBuf, file_size: u64) -> Self {
         Self {
             path,
-            json_line: None,
+            json_lines: Vec::new(),
+            record_keys: Vec::new(),
             error: None,
+            error_kind: None,
             file_size,
             is_valid: true,
+            skipped_records: 0,
+            is_unreadable: false,
+            rejected_records: 0,
+            rejects: Vec::new(),
+            sampled_out_records: 0,
+            reencoded: false,
+            repaired_records: 0,
+            oversized_records: 0,
+            elapsed: Duration::ZERO,
+            schema: None,
+            inference: None,
         }
     }
+
+    /// 에러 종류 설정
+    pub fn with_error_kind(mut self, kind: &'static str) -> Self {
+        self.error_kind = Some(kind);
+        self
+    }
+
+    /// 조건 필터로 제외된 레코드 수 설정
+    pub fn with_skipped(mut self, skipped_records: usize) -> Self {
+        self.skipped_records = skipped_records;
+        self
+    }
+
+    /// 스키마 필터로 제외된 레코드 수와 제외된 레코드 줄 설정
+    pub fn with_rejects(mut self, rejected_records: usize, rejects: Vec<String>) -> Self {
+        self.rejected_records = rejected_records;
+        self.rejects = rejects;
+        self
+    }
+
+    /// 샘플링에서 빠진 레코드 수 설정
+    pub fn with_sampled_out(mut self, sampled_out_records: usize) -> Self {
+        self.sampled_out_records = sampled_out_records;
+        self
+    }
+
+    /// 다시 인코딩 여부 설정
+    pub fn with_reencoded(mut self, reencoded: bool) -> Self {
+        self.reencoded = reencoded;
+        self
+    }
+
+    /// 처리 시간 설정
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = elapsed;
+        self
+    }
+
+    /// 경계를 고친 JSONL 레코드 수 설정
+    pub fn with_repaired(mut self, repaired_records: usize) -> Self {
+        self.repaired_records = repaired_records;
+        self
+    }
+
+    /// 크기 제한을 넘은 레코드 수 설정
+    pub fn with_oversized(mut self, oversized_records: usize) -> Self {
+        self.oversized_records = oversized_records;
+        self
+    }
+
+    /// 출력 줄과 그 중복 제거 키 (키를 뽑지 않았으면 None)
+    pub fn keyed_lines(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        let mut keys = self.record_keys.iter();
+        self.json_lines
+            .iter()
+            .map(move |line| (line.as_str(), keys.next().and_then(Option::as_deref)))
+    }
+
+    /// 출력 줄을 앞에서부터 `len`개만 남김 (중복 제거 키도 함께)
+    pub fn truncate_lines(&mut self, len: usize) {
+        self.json_lines.truncate(len);
+        self.record_keys.truncate(len);
+    }
+
+    /// 변환 결과로 성공 결과 생성 (출력 줄이 없으면 유효성 검사 성공 결과)
+    fn converted(path: PathBuf, output: DocumentOutput, file_size: u64) -> Self {
+        let mut result = if output.json_lines.is_empty() {
+            Self::valid(path, file_size)
+        } else {
+            Self::success_many(path, output.json_lines, file_size)
+        };
+        result.record_keys = output.record_keys;
+        result.schema = output.schema;
+        result.inference = output.inference.map(SchemaInference::counted_as_file);
+        result
+            .with_skipped(output.skipped)
+            .with_rejects(output.rejected, output.rejects)
+            .with_sampled_out(output.sampled_out)
+            .with_reencoded(output.reencoded)
+            .with_repaired(output.repaired)
+            .with_oversized(output.oversized)
+    }
 }
 
 /// JSON 처리 옵션
@@ -65,12 +276,72 @@ impl ProcessResult {
 pub struct ProcessOptions {
     /// 추출할 필드 목록 (None이면 전체)
     pub fields: Option<Vec<String>>,
+    /// 제외할 필드 목록 (점 표기법 지원)
+    pub exclude_fields: Option<Vec<String>>,
+    /// 필드 이름 변경 목록 (기존 경로, 새 경로)
+    pub renames: Option<Vec<(String, String)>>,
     /// Pretty 출력 여부
     pub pretty: bool,
     /// 유효성 검사만 수행
     pub validate_only: bool,
     /// 대용량 파일 임계값 (이상이면 메모리 매핑 사용)
     pub mmap_threshold: u64,
+    /// 원본 파일 경로를 주입할 키 (None이면 주입하지 않음)
+    pub source_key: Option<String>,
+    /// 원본 파일 수정 시각(Unix timestamp)을 주입할 키 (None이면 주입하지 않음)
+    pub mtime_key: Option<String>,
+    /// 원본 파일이나 레코드의 체크섬 주입 (`--add-checksum`)
+    pub checksum: Option<ChecksumField>,
+    /// 원본 파일 경로를 상대 경로로 만들 기준 폴더 목록 (경로가 처음으로 속하는 폴더 기준)
+    pub source_roots: Vec<PathBuf>,
+    /// 설정 파일로 정의한 변환 파이프라인 (설정 시 필드 선택/주입 옵션 대신 사용)
+    pub pipeline: Option<Pipeline>,
+    /// 레코드 조건 필터 (`--where`, 조건을 만족하는 레코드만 출력)
+    pub predicate: Option<Expr>,
+    /// 파싱 한도 (문자열 길이, 배열 요소 수, 중첩 깊이)
+    pub parse_limits: ParseLimits,
+    /// 레코드 스키마 필터 (`--filter-schema`, 스키마에 맞는 레코드만 출력)
+    pub schema_filter: Option<SchemaFilter>,
+    /// 제외된 레코드를 `_reject_reason`과 함께 결과에 담을지 여부 (`--rejects`)
+    pub collect_rejects: bool,
+    /// `.json` 파일 하나에 이어 붙은 여러 문서를 문서마다 한 줄로 변환 (`--multi-doc`)
+    pub multi_doc: bool,
+    /// 파일 단위 파싱에 사용할 JSON 파서 (`--parser`)
+    pub parser: ParserKind,
+    /// 레코드 샘플러 (None이면 모든 레코드 출력)
+    pub sampler: Option<Sampler>,
+    /// 레코드 변환 표현식 (결과 값이 새 레코드가 됨)
+    pub transform: Option<Expr>,
+    /// 변환이 없으면 이미 한 줄인 문서를 검증 후 원본 바이트 그대로 출력 (`--passthrough`)
+    pub passthrough: bool,
+    /// 모든 객체 키를 바꿀 표기법 (`--normalize-keys`, 필터와 변환보다 먼저 적용)
+    pub normalize_keys: Option<KeyCase>,
+    /// 출력 객체의 키 순서 (`--key-order`)
+    pub key_order: KeyOrder,
+    /// 출력 레코드의 최상위 필드 순서 (`--field-order`)
+    pub field_order: Option<FieldOrder>,
+    /// 레코드 최상위 키에서 뗄 접두사 (`--strip-key-prefix`, 필터와 변환보다 먼저 적용)
+    pub strip_key_prefix: Option<String>,
+    /// 레코드 최상위 키에 붙일 접두사 (`--key-prefix`, 원본 정보 주입 전에 적용)
+    pub key_prefix: Option<String>,
+    /// 입력 인코딩 (`--encoding`, 파싱 전에 UTF-8로 변환)
+    pub encoding: InputEncoding,
+    /// 잘못된 바이트를 에러 대신 U+FFFD로 교체 (`--lossy`)
+    pub lossy: bool,
+    /// GeoJSON `FeatureCollection`을 Feature 하나당 한 줄로 펼침 (`--geojson`)
+    pub geojson: bool,
+    /// 펼친 Feature의 `properties`를 최상위로 올림 (`--flatten-properties`)
+    pub flatten_properties: bool,
+    /// JSONL 입력에서 한 줄에 붙은 레코드를 나누고 끊긴 레코드를 이어 붙임 (`--repair-jsonl`)
+    pub repair_jsonl: bool,
+    /// 직렬화된 출력 줄 크기 제한 (`--max-record-size`)
+    pub record_size: Option<RecordSizeGuard>,
+    /// 출력 레코드에서 직렬화 전에 뽑아 둘 중복 제거 키 경로 (`--dedupe-by`)
+    pub dedup_key: Option<String>,
+    /// 출력 레코드의 필드 스키마 수집 (`--schema-drift`, `--type-report`)
+    pub collect_schema: bool,
+    /// 출력 레코드의 JSON Schema 추론 (`--infer-schema`)
+    pub infer_schema: bool,
 }
 
 impl ProcessOptions {
@@ -88,6 +359,18 @@ impl ProcessOptions {
         self
     }
 
+    /// 필드 제외 옵션 설정
+    pub fn with_exclude_fields(mut self, exclude_fields: Option<Vec<String>>) -> Self {
+        self.exclude_fields = exclude_fields;
+        self
+    }
+
+    /// 필드 이름 변경 옵션 설정
+    pub fn with_renames(mut self, renames: Option<Vec<(String, String)>>) -> Self {
+        self.renames = renames;
+        self
+    }
+
     /// Pretty 출력 설정
     pub fn with_pretty(mut self, pretty: bool) -> Self {
         self.pretty = pretty;
@@ -99,10 +382,369 @@ impl ProcessOptions {
         self.validate_only = validate_only;
         self
     }
+
+    /// 원본 바이트에서 바로 고를 수 있는 최상위 필드 목록
+    ///
+    /// 최상위 필드 선택 외에 다른 변환, 필터, 파싱 한도가 없을 때만 빠른 경로를 사용합니다.
+    fn raw_selection(&self) -> Option<&[String]> {
+        let fields = self.fields.as_deref()?;
+        let plain = raw_select::is_top_level(fields)
+            && !self.validate_only
+            && self.exclude_fields.is_none()
+            && self.renames.is_none()
+            && self.source_key.is_none()
+            && self.mtime_key.is_none()
+            && self.checksum.is_none()
+            && self.pipeline.is_none()
+            && self.predicate.is_none()
+            && self.transform.is_none()
+            && self.normalize_keys.is_none()
+            && self.strip_key_prefix.is_none()
+            && self.key_prefix.is_none()
+            && !self.geojson
+            && self.schema_filter.is_none()
+            && !self.parse_limits.is_enabled()
+            && self.parser == ParserKind::Serde;
+        plain.then_some(fields)
+    }
+
+    /// 파일 단위 파싱에 simd-json을 사용할지 확인 (파싱 한도 검사는 serde_json으로만 가능)
+    fn uses_simd(&self) -> bool {
+        self.parser == ParserKind::Simd
+            && self.parser.is_available()
+            && !self.parse_limits.is_enabled()
+    }
+
+    /// 여러 문서가 이어 붙은 `.json` 파일 처리 여부 설정
+    pub fn with_multi_doc(mut self, multi_doc: bool) -> Self {
+        self.multi_doc = multi_doc;
+        self
+    }
+
+    /// 원본 파일 경로 주입 키 설정
+    pub fn with_source_key(mut self, source_key: Option<String>) -> Self {
+        self.source_key = source_key;
+        self
+    }
+
+    /// 원본 파일 수정 시각 주입 키 설정
+    pub fn with_mtime_key(mut self, mtime_key: Option<String>) -> Self {
+        self.mtime_key = mtime_key;
+        self
+    }
+
+    /// 체크섬 주입 필드 설정
+    pub fn with_checksum(mut self, checksum: Option<ChecksumField>) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// 원본 파일 경로의 기준 폴더 설정
+    pub fn with_source_root(mut self, source_root: Option<PathBuf>) -> Self {
+        self.source_roots = source_root.into_iter().collect();
+        self
+    }
+
+    /// 원본 파일 경로의 기준 폴더 목록 설정 (입력 폴더가 여러 개일 때)
+    pub fn with_source_roots(mut self, source_roots: Vec<PathBuf>) -> Self {
+        self.source_roots = source_roots;
+        self
+    }
+
+    /// 변환 파이프라인 설정
+    pub fn with_pipeline(mut self, pipeline: Option<Pipeline>) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    /// 레코드 조건 필터 설정
+    pub fn with_predicate(mut self, predicate: Option<Expr>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    /// 파싱 한도 설정
+    pub fn with_parse_limits(mut self, limits: ParseLimits) -> Self {
+        self.parse_limits = limits;
+        self
+    }
+
+    /// 레코드 스키마 필터 설정
+    pub fn with_schema_filter(mut self, filter: Option<SchemaFilter>) -> Self {
+        self.schema_filter = filter;
+        self
+    }
+
+    /// 제외된 레코드 수집 설정
+    pub fn with_collect_rejects(mut self, collect_rejects: bool) -> Self {
+        self.collect_rejects = collect_rejects;
+        self
+    }
+
+    /// JSON 파서 설정
+    pub fn with_parser(mut self, parser: ParserKind) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// 레코드 샘플러 설정
+    pub fn with_sampler(mut self, sampler: Option<Sampler>) -> Self {
+        self.sampler = sampler;
+        self
+    }
+
+    /// 레코드 변환 표현식 설정
+    pub fn with_transform(mut self, transform: Option<Expr>) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// 한 줄 문서 그대로 출력 여부 설정
+    pub fn with_passthrough(mut self, passthrough: bool) -> Self {
+        self.passthrough = passthrough;
+        self
+    }
+
+    /// 객체 키 표기법 정규화 설정
+    pub fn with_normalize_keys(mut self, normalize_keys: Option<KeyCase>) -> Self {
+        self.normalize_keys = normalize_keys;
+        self
+    }
+
+    /// 출력 객체의 키 순서 설정
+    pub fn with_key_order(mut self, key_order: KeyOrder) -> Self {
+        self.key_order = key_order;
+        self
+    }
+
+    /// 출력 레코드의 최상위 필드 순서 설정
+    pub fn with_field_order(mut self, field_order: Option<FieldOrder>) -> Self {
+        self.field_order = field_order;
+        self
+    }
+
+    /// 최상위 키에서 뗄 접두사 설정
+    pub fn with_strip_key_prefix(mut self, prefix: Option<String>) -> Self {
+        self.strip_key_prefix = prefix;
+        self
+    }
+
+    /// 최상위 키에 붙일 접두사 설정
+    pub fn with_key_prefix(mut self, prefix: Option<String>) -> Self {
+        self.key_prefix = prefix;
+        self
+    }
+
+    /// 입력 인코딩 설정
+    pub fn with_encoding(mut self, encoding: InputEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// 잘못된 바이트 교체 여부 설정
+    pub fn with_lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    /// GeoJSON Feature 펼치기 설정
+    pub fn with_geojson(mut self, geojson: bool) -> Self {
+        self.geojson = geojson;
+        self
+    }
+
+    /// Feature 속성을 최상위로 올릴지 설정
+    pub fn with_flatten_properties(mut self, flatten_properties: bool) -> Self {
+        self.flatten_properties = flatten_properties;
+        self
+    }
+
+    /// JSONL 레코드 경계 복구 설정
+    pub fn with_repair_jsonl(mut self, repair_jsonl: bool) -> Self {
+        self.repair_jsonl = repair_jsonl;
+        self
+    }
+
+    /// 출력 줄 크기 제한 설정
+    pub fn with_record_size(mut self, record_size: Option<RecordSizeGuard>) -> Self {
+        self.record_size = record_size;
+        self
+    }
+
+    /// 중복 제거 키 경로 설정 (결과의 `record_keys`에 출력 줄마다 키를 담음)
+    pub fn with_dedup_key(mut self, dedup_key: Option<String>) -> Self {
+        self.dedup_key = dedup_key;
+        self
+    }
+
+    /// 필드 스키마 수집 설정 (결과의 `schema`에 출력 레코드의 스키마를 담음)
+    pub fn with_collect_schema(mut self, collect_schema: bool) -> Self {
+        self.collect_schema = collect_schema;
+        self
+    }
+
+    /// 스키마 추론 설정 (결과의 `inference`에 출력 레코드의 추론 결과를 담음)
+    pub fn with_infer_schema(mut self, infer_schema: bool) -> Self {
+        self.infer_schema = infer_schema;
+        self
+    }
+
+    /// 원본 바이트를 그대로 출력할 수 있는지 확인
+    ///
+    /// 레코드를 바꾸는 변환, 필터, 샘플링, pretty 출력, 파싱 한도가 하나도 없을 때만 사용합니다.
+    pub fn uses_passthrough(&self) -> bool {
+        self.passthrough
+            && !self.validate_only
+            && !self.pretty
+            && !self.multi_doc
+            && self.fields.is_none()
+            && self.exclude_fields.is_none()
+            && self.renames.is_none()
+            && self.source_key.is_none()
+            && self.mtime_key.is_none()
+            && self.checksum.is_none()
+            && self.pipeline.is_none()
+            && self.predicate.is_none()
+            && self.transform.is_none()
+            && self.normalize_keys.is_none()
+            && self.strip_key_prefix.is_none()
+            && self.key_prefix.is_none()
+            && self.key_order != KeyOrder::Sort
+            && self.field_order.is_none()
+            && !self.geojson
+            && self.schema_filter.is_none()
+            && self.sampler.is_none()
+            && !self.parse_limits.is_enabled()
+    }
+}
+
+/// 원본 파일 경로 주입 기본 키
+pub const DEFAULT_SOURCE_KEY: &str = "_source_file";
+
+/// 원본 파일 수정 시각 주입 기본 키
+pub const DEFAULT_MTIME_KEY: &str = "_source_mtime";
+
+/// 제외된 레코드에 붙이는 제외 이유 키
+pub const REJECT_REASON_KEY: &str = "_reject_reason";
+
+/// 객체가 아닌 제외된 레코드를 감싸는 키
+pub const REJECT_RECORD_KEY: &str = "_record";
+
+/// 필터에서 제외된 레코드와 제외 이유
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedRecord {
+    /// 제외된 레코드
+    pub record: Value,
+    /// 제외 이유
+    pub reason: String,
+}
+
+impl RejectedRecord {
+    /// 제외된 레코드 생성
+    pub fn new(record: Value, reason: String) -> Self {
+        Self { record, reason }
+    }
+
+    /// 제외 이유를 붙인 JSON 한 줄 생성 (`--pretty`와 무관)
+    ///
+    /// 객체는 `_reject_reason` 필드를 추가하고, 그 밖의 값은 `_record` 필드로 감쌉니다.
+    pub fn into_line(self, path: &Path) -> Result<String> {
+        serialize_reject(&self.into_value(), path)
+    }
+
+    /// 제외 이유를 붙인 JSON 값 생성 (`into_line`이 직렬화하는 값)
+    pub fn into_value(self) -> Value {
+        let reason = Value::String(self.reason);
+        match self.record {
+            Value::Object(mut map) => {
+                map.insert(REJECT_REASON_KEY.to_string(), reason);
+                Value::Object(map)
+            }
+            other => {
+                let mut map = Map::new();
+                map.insert(REJECT_RECORD_KEY.to_string(), other);
+                map.insert(REJECT_REASON_KEY.to_string(), reason);
+                Value::Object(map)
+            }
+        }
+    }
+}
+
+/// 제외된 레코드 값을 한 줄로 직렬화
+fn serialize_reject(value: &Value, path: &Path) -> Result<String> {
+    serde_json::to_string(value).map_err(|e| JConvertError::SerializeError {
+        file: path.to_path_buf(),
+        reason: e.to_string(),
+    })
+}
+
+/// 입력으로 수집하는 파일 확장자 (대소문자 무관)
+pub const INPUT_EXTENSIONS: &[&str] = &["json", "jsonl", "ndjson"];
+
+/// 한 줄에 문서 하나씩 담긴 입력 파일 확장자
+const JSON_LINES_EXTENSIONS: &[&str] = &["jsonl", "ndjson"];
+
+/// 입력으로 수집할 파일인지 확장자로 확인
+pub fn is_input_file(path: &Path) -> bool {
+    has_extension(path, INPUT_EXTENSIONS)
+}
+
+/// JSONL/NDJSON 파일인지 확장자로 확인
+pub fn is_json_lines_file(path: &Path) -> bool {
+    has_extension(path, JSON_LINES_EXTENSIONS)
+}
+
+/// GeoJSON 파일인지 확장자로 확인 (`--geojson` 모드의 추가 입력)
+pub fn is_geojson_file(path: &Path) -> bool {
+    has_extension(path, &[GEOJSON_EXTENSION])
+}
+
+/// 파일 확장자가 목록에 있는지 확인 (대소문자 무관)
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|s| s.to_str())
+        .is_some_and(|ext| extensions.iter().any(|e| ext.eq_ignore_ascii_case(e)))
+}
+
+/// 레코드가 만들어진 원본 정보
+struct RecordSource<'a> {
+    /// 원본 파일 경로
+    path: &'a Path,
+    /// 원본 파일 수정 시각 (Unix timestamp)
+    mtime: Option<u64>,
+    /// 기준 폴더에 대한 상대 경로 (문서가 여러 개여도 처음 필요할 때 한 번만 계산)
+    relative_path: OnceCell<String>,
+    /// 원본 파일 체크섬 (`--add-checksum`의 대상이 파일일 때)
+    checksum: Option<String>,
+}
+
+impl<'a> RecordSource<'a> {
+    fn new(path: &'a Path, mtime: Option<u64>) -> Self {
+        Self {
+            path,
+            mtime,
+            relative_path: OnceCell::new(),
+            checksum: None,
+        }
+    }
+
+    /// 원본 파일 체크섬 설정
+    fn with_checksum(mut self, checksum: Option<String>) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// 기준 폴더에 대한 상대 경로
+    fn relative_path(&self, roots: &[PathBuf]) -> &str {
+        self.relative_path
+            .get_or_init(|| relative_to_roots(self.path, roots))
+    }
 }
 
 /// 단일 JSON 파일 처리
 ///
+/// `.jsonl`/`.ndjson` 파일은 줄마다 문서 하나로 보고 각 줄을 검증·변환합니다.
+///
 /// # Arguments
 /// * `path` - 처리할 JSON 파일 경로
 /// * `options` - 처리 옵션
@@ -110,115 +752,1029 @@ impl ProcessOptions {
 /// # Returns
 /// 처리 결과를 담은 `ProcessResult`
 pub fn process_file(path: PathBuf, options: &ProcessOptions) -> ProcessResult {
-    let file_size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    let started = Instant::now();
+    let metadata = std::fs::metadata(&path).ok();
+    let file_size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
+    let mtime = metadata
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs());
+    let output = file_checksum(&path, options).and_then(|checksum| {
+        let source = RecordSource::new(&path, mtime).with_checksum(checksum);
+        match decode_file(&path, options) {
+            Ok(Some(decoded)) => process_decoded(&source, &decoded, options),
+            Ok(None) if is_json_lines_file(&path) => process_json_lines_file(&source, options),
+            Ok(None) => process_file_internal(&source, file_size, options),
+            Err(e) => Err(e),
+        }
+    });
 
-    match process_file_internal(&path, file_size, options) {
-        Ok(json_line) => {
-            if options.validate_only {
-                ProcessResult::valid(path, file_size)
-            } else {
-                ProcessResult::success(path, json_line, file_size)
+    let result = match output {
+        // 유효성 검사 모드이거나 모든 레코드가 필터에서 제외되면 출력 줄 없이 성공
+        Ok(output) => ProcessResult::converted(path, output, file_size),
+        Err(e @ JConvertError::PermissionDenied { .. }) => {
+            ProcessResult::unreadable(path, e.to_string(), file_size).with_error_kind(e.kind())
+        }
+        Err(e) => ProcessResult::failure(path, e.to_string(), file_size).with_error_kind(e.kind()),
+    };
+    result.with_elapsed(started.elapsed())
+}
+
+/// 원본 파일 체크섬 계산 (체크섬 대상이 파일이 아니면 None)
+fn file_checksum(path: &Path, options: &ProcessOptions) -> Result<Option<String>> {
+    match options.checksum {
+        Some(ChecksumField {
+            scope: ChecksumScope::File,
+            ..
+        }) => checksum::file_sha256(path)
+            .map(Some)
+            .map_err(|e| JConvertError::from_open_error(path, e)),
+        _ => Ok(None),
+    }
+}
+
+/// 필드를 잘라 내며 다시 직렬화한 줄에 `--field-order` 재적용
+fn reorder_line(line: String, path: &Path, options: &ProcessOptions) -> Result<String> {
+    if options.field_order.is_none() {
+        return Ok(line);
+    }
+    match serde_json::from_str::<Value>(&line) {
+        Ok(value) => serialize_value(&value, path, options),
+        Err(_) => Ok(line),
+    }
+}
+
+/// 문서 변환 결과 (여러 문서의 결과를 차례로 누적)
+#[derive(Debug, Default)]
+struct DocumentOutput {
+    /// 출력 줄 목록
+    json_lines: Vec<String>,
+    /// 출력 줄마다 뽑은 중복 제거 키 (`ProcessOptions::dedup_key`가 있을 때만)
+    record_keys: Vec<Option<String>>,
+    /// 조건/파이프라인 필터로 제외된 레코드 수
+    skipped: usize,
+    /// 스키마 필터에 맞지 않아 제외된 레코드 수
+    rejected: usize,
+    /// 제외된 레코드 줄 (`_reject_reason` 포함, 수집하도록 설정한 경우만)
+    rejects: Vec<String>,
+    /// 샘플링 대상이 된 레코드 수 (다음 레코드의 순번)
+    sampled: u64,
+    /// 샘플링에서 빠진 레코드 수
+    sampled_out: usize,
+    /// 파싱 전에 UTF-8로 다시 인코딩했는지 여부
+    reencoded: bool,
+    /// 경계를 고친 JSONL 레코드 수
+    repaired: usize,
+    /// 크기 제한을 넘은 레코드 수
+    oversized: usize,
+    /// 크기 제한을 검사한 출력 줄 수 (건너뛴 줄 포함)
+    checked: usize,
+    /// 출력 레코드의 필드 스키마 (`ProcessOptions::collect_schema`일 때만)
+    schema: Option<FieldSchema>,
+    /// 출력 레코드의 스키마 추론 결과 (`ProcessOptions::infer_schema`일 때만)
+    inference: Option<SchemaInference>,
+}
+
+impl DocumentOutput {
+    /// 값을 직렬화한 출력 줄 추가
+    fn push_value(&mut self, value: &Value, path: &Path, options: &ProcessOptions) -> Result<()> {
+        let line = serialize_value(value, path, options)?;
+        self.push_line(line, Some(value), path, options)
+    }
+
+    /// 원본 바이트 그대로인 출력 줄 추가 (`--passthrough`)
+    fn push_raw(&mut self, line: &str, path: &Path, options: &ProcessOptions) -> Result<()> {
+        self.push_line(line.to_string(), None, path, options)
+    }
+
+    /// 크기 제한(`--max-record-size`)을 적용하여 출력 줄 추가
+    ///
+    /// 중복 제거 키, 필드 스키마와 스키마 추론은 직렬화 전 값(`record`)에서 뽑습니다.
+    /// 값이 없는 줄(원본 그대로이거나 필드를 잘라 낸 줄)은 키나 스키마가 필요할 때만 그 줄을 파싱합니다.
+    fn push_line(
+        &mut self,
+        line: String,
+        mut record: Option<&Value>,
+        path: &Path,
+        options: &ProcessOptions,
+    ) -> Result<()> {
+        self.checked += 1;
+        let line = match options.record_size {
+            None => line,
+            Some(guard) => match guard.check(line, options.pretty) {
+                SizeCheck::Fits(line) => line,
+                SizeCheck::Truncated(line) => {
+                    self.oversized += 1;
+                    record = None;
+                    reorder_line(line, path, options)?
+                }
+                SizeCheck::Skipped => {
+                    self.oversized += 1;
+                    return Ok(());
+                }
+                SizeCheck::TooLarge(size) => {
+                    return Err(JConvertError::RecordTooLarge {
+                        file: path.to_path_buf(),
+                        record: self.checked,
+                        size: size as u64,
+                        limit: guard.max_bytes(),
+                    })
+                }
+            },
+        };
+
+        if options.dedup_key.is_some() || options.collect_schema || options.infer_schema {
+            let parsed;
+            let record = match record {
+                Some(record) => Some(record),
+                None => {
+                    parsed = serde_json::from_str::<Value>(&line).ok();
+                    parsed.as_ref()
+                }
+            };
+            if let Some(ref key_path) = options.dedup_key {
+                self.record_keys
+                    .push(record.and_then(|record| dedup::record_key(record, key_path)));
+            }
+            if let (true, Some(record)) = (options.collect_schema, record) {
+                self.schema
+                    .get_or_insert_with(FieldSchema::new)
+                    .add_document(record);
+            }
+            if let (true, Some(record)) = (options.infer_schema, record) {
+                self.inference
+                    .get_or_insert_with(SchemaInference::new)
+                    .add_document(record);
+            }
+        }
+        self.json_lines.push(line);
+        Ok(())
+    }
+
+    /// 다음 레코드를 샘플에 남길지 결정
+    fn sample(&mut self, sampler: &Sampler, file: &str) -> bool {
+        let keep = sampler.keeps(file, self.sampled);
+        self.sampled += 1;
+        if !keep {
+            self.sampled_out += 1;
+        }
+        keep
+    }
+
+    /// 제외된 레코드 기록 (수집하지 않으면 버림)
+    fn push_rejects(
+        &mut self,
+        rejected: Vec<RejectedRecord>,
+        path: &Path,
+        options: &ProcessOptions,
+    ) -> Result<()> {
+        if options.collect_rejects {
+            for rejection in rejected {
+                let mut value = rejection.into_value();
+                order_keys(&mut value, options.key_order);
+                self.rejects.push(serialize_reject(&value, path)?);
             }
         }
-        Err(e) => ProcessResult::failure(path, e.to_string(), file_size),
+        Ok(())
+    }
+}
+
+/// 파일 앞부분으로 인코딩을 확인하고, 필요하면 전체를 읽어 UTF-8로 변환
+///
+/// # Returns
+/// 변환한 바이트 (원본 그대로 처리해도 되면 None)
+fn decode_file(path: &Path, options: &ProcessOptions) -> Result<Option<Vec<u8>>> {
+    let mut file = File::open(path).map_err(|e| JConvertError::from_open_error(path, e))?;
+    let mut head = Vec::with_capacity(encoding::SNIFF_LEN);
+    file.by_ref()
+        .take(encoding::SNIFF_LEN as u64)
+        .read_to_end(&mut head)
+        .map_err(|e| JConvertError::from_open_error(path, e))?;
+    if !encoding::needs_decoding(&head, options.encoding, options.lossy) {
+        return Ok(None);
     }
+
+    let mut bytes = head;
+    file.read_to_end(&mut bytes)
+        .map_err(|e| JConvertError::from_open_error(path, e))?;
+    decode_bytes(path, &bytes, options)
+}
+
+/// 입력 바이트를 UTF-8로 변환 (원본 그대로 처리해도 되면 None)
+fn decode_bytes(path: &Path, bytes: &[u8], options: &ProcessOptions) -> Result<Option<Vec<u8>>> {
+    encoding::decode(bytes, options.encoding, options.lossy).map_err(|reason| {
+        JConvertError::EncodingError {
+            file: path.to_path_buf(),
+            reason,
+        }
+    })
+}
+
+/// UTF-8로 변환한 파일 내용 처리 (파일 종류와 옵션에 따라 원본 파일과 같은 경로 사용)
+fn process_decoded(
+    source: &RecordSource,
+    bytes: &[u8],
+    options: &ProcessOptions,
+) -> Result<DocumentOutput> {
+    let path = source.path;
+    let mut output = if is_json_lines_file(path) {
+        process_json_lines(source, bytes, options)?
+    } else if options.multi_doc {
+        process_documents(source, bytes, options)?
+    } else {
+        let mut output = DocumentOutput::default();
+        let parse_error = |e: serde_json::Error| JConvertError::ParseError {
+            file: path.to_path_buf(),
+            reason: e.to_string(),
+        };
+        if options.uses_passthrough() {
+            passthrough_document(source, bytes, options, &mut output)?;
+        } else if let Some(fields) = options.raw_selection() {
+            let document = parse_selected(bytes, fields, options).map_err(parse_error)?;
+            convert_parsed(document, source, options, &mut output)?;
+        } else {
+            let json = limits::parse_slice(bytes, &options.parse_limits).map_err(parse_error)?;
+            if !options.validate_only {
+                convert_document(json, source, options, &mut output)?;
+            }
+        }
+        output
+    };
+    output.reencoded = true;
+    Ok(output)
 }
 
 /// 내부 파일 처리 로직
 fn process_file_internal(
-    path: &PathBuf,
+    source: &RecordSource,
     file_size: u64,
     options: &ProcessOptions,
-) -> Result<String> {
+) -> Result<DocumentOutput> {
+    if options.multi_doc {
+        return process_multi_doc_file(source, file_size, options);
+    }
+    if options.uses_passthrough() {
+        return process_passthrough_file(source, file_size, options);
+    }
+
+    let path = source.path;
+    if let Some(fields) = options.raw_selection() {
+        // 최상위 필드 선택만 하면 원본 바이트에서 필요한 필드만 파싱
+        let mut output = DocumentOutput::default();
+        let parse_error = |e: serde_json::Error| JConvertError::ParseError {
+            file: path.to_path_buf(),
+            reason: e.to_string(),
+        };
+        let document = if file_size >= options.mmap_threshold {
+            parse_selected(&map_file(path)?, fields, options).map_err(parse_error)?
+        } else {
+            let bytes = std::fs::read(path).map_err(|e| JConvertError::from_open_error(path, e))?;
+            parse_selected(&bytes, fields, options).map_err(parse_error)?
+        };
+        convert_parsed(document, source, options, &mut output)?;
+        return Ok(output);
+    }
+
     let json: Value = if file_size >= options.mmap_threshold {
         // 대용량 파일: 메모리 매핑 사용
-        parse_with_mmap(path)?
+        parse_with_mmap(path, options)?
     } else {
         // 일반 파일: 버퍼 리더 사용
-        parse_with_reader(path)?
+        parse_with_reader(path, options)?
     };
 
+    let mut output = DocumentOutput::default();
+
     // 유효성 검사만 하는 경우
     if options.validate_only {
-        return Ok(String::new());
+        return Ok(output);
+    }
+
+    convert_document(json, source, options, &mut output)?;
+    Ok(output)
+}
+
+/// 이미 한 줄인 파일은 검증만 하고 원본 바이트를 그대로 출력 (여러 줄이면 파싱하여 한 줄로 직렬화)
+fn process_passthrough_file(
+    source: &RecordSource,
+    file_size: u64,
+    options: &ProcessOptions,
+) -> Result<DocumentOutput> {
+    let path = source.path;
+    let mut output = DocumentOutput::default();
+    if file_size >= options.mmap_threshold {
+        // 대용량 파일: 메모리 매핑 사용
+        let mmap = map_file(path)?;
+        passthrough_document(source, &mmap, options, &mut output)?;
+    } else {
+        let bytes = std::fs::read(path).map_err(|e| JConvertError::from_open_error(path, e))?;
+        passthrough_document(source, &bytes, options, &mut output)?;
+    }
+    Ok(output)
+}
+
+/// 문서 하나를 그대로 출력하거나, 여러 줄이면 변환 경로로 처리
+fn passthrough_document(
+    source: &RecordSource,
+    bytes: &[u8],
+    options: &ProcessOptions,
+    output: &mut DocumentOutput,
+) -> Result<()> {
+    let parse_error = |reason: String| JConvertError::ParseError {
+        file: source.path.to_path_buf(),
+        reason,
+    };
+
+    let trimmed = bytes.trim_ascii();
+    if !trimmed.contains(&b'\n') && !trimmed.contains(&b'\r') {
+        let line = validate_minified(trimmed).map_err(parse_error)?;
+        return output.push_raw(line, source.path, options);
+    }
+
+    let json = serde_json::from_slice(bytes).map_err(|e| parse_error(e.to_string()))?;
+    convert_document(json, source, options, output)
+}
+
+/// 한 줄 JSON 문서 검증 (값을 만들지 않고 문법과 UTF-8만 검사)
+fn validate_minified(bytes: &[u8]) -> std::result::Result<&str, String> {
+    serde_json::from_slice::<IgnoredAny>(bytes).map_err(|e| e.to_string())?;
+    std::str::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+/// 여러 JSON 문서가 이어 붙거나 공백으로 구분된 파일 처리 (`--multi-doc`, 문서 하나당 한 줄)
+fn process_multi_doc_file(
+    source: &RecordSource,
+    file_size: u64,
+    options: &ProcessOptions,
+) -> Result<DocumentOutput> {
+    let path = source.path;
+    if file_size >= options.mmap_threshold {
+        // 대용량 파일: 메모리 매핑 사용
+        let mmap = map_file(path)?;
+        process_documents(source, &mmap, options)
+    } else {
+        let bytes = std::fs::read(path).map_err(|e| JConvertError::from_open_error(path, e))?;
+        process_documents(source, &bytes, options)
+    }
+}
+
+/// JSONL/NDJSON 파일을 줄 단위로 읽어 처리 (빈 줄은 무시)
+fn process_json_lines_file(
+    source: &RecordSource,
+    options: &ProcessOptions,
+) -> Result<DocumentOutput> {
+    let path = source.path;
+    let file = File::open(path).map_err(|e| JConvertError::from_open_error(path, e))?;
+    process_json_lines(source, BufReader::new(file), options)
+}
+
+/// 리더에서 줄 단위로 문서를 읽어 처리 (빈 줄은 무시)
+fn process_json_lines<R: BufRead>(
+    source: &RecordSource,
+    mut reader: R,
+    options: &ProcessOptions,
+) -> Result<DocumentOutput> {
+    let path = source.path;
+    let mut output = DocumentOutput::default();
+    let mut repairer = options.repair_jsonl.then(JsonlRepairer::new);
+    // 줄마다 새 문자열을 할당하지 않도록 버퍼 재사용
+    let mut line = String::new();
+    let mut line_number = 0;
+    loop {
+        line_number += 1;
+        line.clear();
+        let read = reader
+            .read_line(&mut line)
+            .map_err(|e| line_error(path, line_number, e.to_string()))?;
+        if read == 0 {
+            break;
+        }
+
+        let Some(ref mut repairer) = repairer else {
+            process_line(&line, line_number, source, options, &mut output)?;
+            continue;
+        };
+        let records = repairer
+            .push_line(line.trim_end_matches('\n'))
+            .map_err(|e| line_error(path, e.line, e.reason))?;
+        for record in records {
+            process_line(&record.text, record.line, source, options, &mut output)?;
+        }
+    }
+
+    if let Some(repairer) = repairer {
+        let summary = repairer
+            .finish()
+            .map_err(|e| line_error(path, e.line, e.reason))?;
+        output.repaired = summary.repaired() as usize;
+    }
+    Ok(output)
+}
+
+/// JSONL 줄 하나(문서 하나) 검증·변환 (빈 줄은 무시)
+fn process_line(
+    line: &str,
+    line_number: u64,
+    source: &RecordSource,
+    options: &ProcessOptions,
+    output: &mut DocumentOutput,
+) -> Result<()> {
+    if line.trim().is_empty() {
+        return Ok(());
+    }
+    let parse_error = |reason: String| line_error(source.path, line_number, reason);
+
+    if options.uses_passthrough() {
+        let trimmed = line.trim_ascii();
+        validate_minified(trimmed.as_bytes()).map_err(parse_error)?;
+        return output.push_raw(trimmed, source.path, options);
+    }
+    if let Some(fields) = options.raw_selection() {
+        let document = parse_selected(line.as_bytes(), fields, options)
+            .map_err(|e| parse_error(e.to_string()))?;
+        return convert_parsed(document, source, options, output);
+    }
+
+    let json = limits::parse_slice(line.as_bytes(), &options.parse_limits)
+        .map_err(|e| parse_error(e.to_string()))?;
+
+    if options.validate_only {
+        return Ok(());
+    }
+
+    convert_document(json, source, options, output)
+}
+
+/// 줄 번호를 붙인 파싱 에러
+fn line_error(path: &Path, line_number: u64, reason: String) -> JConvertError {
+    JConvertError::ParseError {
+        file: path.to_path_buf(),
+        reason: format!("{}번째 줄: {}", line_number, reason),
+    }
+}
+
+/// 파싱된 문서
+enum ParsedDocument {
+    /// 전체를 파싱한 값 (변환 단계를 모두 거침)
+    Full(Value),
+    /// 빠른 경로로 최상위 필드를 이미 골라 낸 값 (그대로 출력)
+    Selected(Value),
+}
+
+/// 최상위 필드 선택 빠른 경로로 문서 파싱 (최상위 값이 객체가 아니면 전체 파싱)
+fn parse_selected(
+    bytes: &[u8],
+    fields: &[String],
+    options: &ProcessOptions,
+) -> serde_json::Result<ParsedDocument> {
+    match raw_select::select_top_level(bytes, fields)? {
+        Some(selected) => Ok(ParsedDocument::Selected(selected)),
+        None => limits::parse_slice(bytes, &options.parse_limits).map(ParsedDocument::Full),
+    }
+}
+
+/// 파싱된 문서를 출력 줄로 변환
+fn convert_parsed(
+    document: ParsedDocument,
+    source: &RecordSource,
+    options: &ProcessOptions,
+    output: &mut DocumentOutput,
+) -> Result<()> {
+    match document {
+        ParsedDocument::Full(json) => convert_document(json, source, options, output),
+        ParsedDocument::Selected(selected) => {
+            let Some(mut selected) = sample_records(selected, source, options, output) else {
+                return Ok(());
+            };
+            order_keys(&mut selected, options.key_order);
+            output.push_value(&selected, source.path, options)
+        }
+    }
+}
+
+/// 파싱된 문서 하나를 출력 줄로 변환
+///
+/// `--geojson`이면 `FeatureCollection`의 Feature마다 별도 레코드로 변환합니다.
+fn convert_document(
+    json: Value,
+    source: &RecordSource,
+    options: &ProcessOptions,
+    output: &mut DocumentOutput,
+) -> Result<()> {
+    if !options.geojson {
+        return convert_record(json, source, options, output);
+    }
+    match geojson::split_features(json, options.flatten_properties) {
+        Ok(features) => {
+            for feature in features {
+                convert_record(feature, source, options, output)?;
+            }
+            Ok(())
+        }
+        Err(json) => convert_record(json, source, options, output),
+    }
+}
+
+/// 레코드 하나에 키 정규화, 스키마 필터, 조건 필터와 변환을 적용하여 출력 줄 생성
+fn convert_record(
+    mut json: Value,
+    source: &RecordSource,
+    options: &ProcessOptions,
+    output: &mut DocumentOutput,
+) -> Result<()> {
+    if let Some(case) = options.normalize_keys {
+        normalize_keys(&mut json, case);
+    }
+    if let Some(ref prefix) = options.strip_key_prefix {
+        strip_key_prefix(&mut json, prefix);
+    }
+
+    let (json, rejected) = match options.schema_filter {
+        Some(ref filter) => partition_records(json, |record| {
+            filter
+                .check(record)
+                .err()
+                .map(|reason| format!("스키마 불일치: {}", reason))
+        }),
+        None => (Some(json), Vec::new()),
+    };
+    output.rejected += rejected.len();
+    output.push_rejects(rejected, source.path, options)?;
+
+    let (json, skipped) = match (json, options.predicate.as_ref()) {
+        (Some(json), Some(predicate)) => partition_records(json, |record| {
+            (!predicate.matches(record)).then(|| "조건 필터 불일치 (--where)".to_string())
+        }),
+        (json, _) => (json, Vec::new()),
+    };
+    output.skipped += skipped.len();
+    output.push_rejects(skipped, source.path, options)?;
+
+    let json = json.and_then(|json| sample_records(json, source, options, output));
+
+    let mut dropped = Vec::new();
+    if let Some(mut output_json) =
+        json.and_then(|json| transform_value(json, source, options, &mut dropped))
+    {
+        order_keys(&mut output_json, options.key_order);
+        output.push_value(&output_json, source.path, options)?;
+    }
+    output.skipped += dropped.len();
+    output.push_rejects(dropped, source.path, options)?;
+
+    Ok(())
+}
+
+/// 검사를 통과하지 못한 레코드를 제외 이유와 함께 분리
+///
+/// 최상위 값이 배열이면 각 요소를 개별 레코드로 보고 검사합니다.
+/// 모든 요소가 제외되면 문서 전체를 출력하지 않습니다.
+///
+/// # Arguments
+/// * `json` - 검사할 값
+/// * `reject_reason` - 제외할 레코드면 이유를, 남길 레코드면 `None`을 반환하는 검사 함수
+///
+/// # Returns
+/// (남은 값, 제외된 레코드 목록)
+fn partition_records(
+    json: Value,
+    reject_reason: impl Fn(&Value) -> Option<String>,
+) -> (Option<Value>, Vec<RejectedRecord>) {
+    match json {
+        Value::Array(items) => {
+            let mut kept = Vec::with_capacity(items.len());
+            let mut rejected = Vec::new();
+            for item in items {
+                match reject_reason(&item) {
+                    Some(reason) => rejected.push(RejectedRecord::new(item, reason)),
+                    None => kept.push(item),
+                }
+            }
+            if kept.is_empty() && !rejected.is_empty() {
+                (None, rejected)
+            } else {
+                (Some(Value::Array(kept)), rejected)
+            }
+        }
+        other => match reject_reason(&other) {
+            Some(reason) => (None, vec![RejectedRecord::new(other, reason)]),
+            None => (Some(other), Vec::new()),
+        },
+    }
+}
+
+/// 샘플링 적용 (`ProcessOptions::sampler`가 없으면 그대로 반환)
+///
+/// 최상위 값이 배열이면 각 요소를 개별 레코드로 보고 샘플링합니다.
+/// 샘플에서 빠진 레코드는 필터 제외와 달리 제외 레코드 파일에 기록하지 않습니다.
+fn sample_records(
+    json: Value,
+    source: &RecordSource,
+    options: &ProcessOptions,
+    output: &mut DocumentOutput,
+) -> Option<Value> {
+    let Some(ref sampler) = options.sampler else {
+        return Some(json);
+    };
+    let file = source.relative_path(&options.source_roots);
+    match json {
+        // 빈 배열은 레코드가 없으므로 그대로 출력
+        Value::Array(items) if items.is_empty() => Some(Value::Array(items)),
+        Value::Array(items) => {
+            let kept: Vec<Value> = items
+                .into_iter()
+                .filter(|_| output.sample(sampler, file))
+                .collect();
+            (!kept.is_empty()).then_some(Value::Array(kept))
+        }
+        other => output.sample(sampler, file).then_some(other),
     }
+}
 
-    // 필드 선택 처리
-    let output_json = match &options.fields {
-        Some(fields) => extract_fields(&json, fields),
+/// 파싱된 JSON 값에 변환 파이프라인 적용 (변환 표현식, 필드 선택/제외/이름 변경, 키 접두사, 원본 정보 주입)
+///
+/// # Returns
+/// 변환된 값 (파이프라인 필터나 이름 변경에서 제외되면 None, 제외된 레코드는 `dropped`에 모음)
+fn transform_value(
+    json: Value,
+    source: &RecordSource,
+    options: &ProcessOptions,
+    dropped: &mut Vec<RejectedRecord>,
+) -> Option<Value> {
+    let json = match options.transform {
+        Some(ref transform) => apply_transform(json, transform),
         None => json,
     };
 
-    // JSON 직렬화
-    let json_line = if options.pretty {
-        serde_json::to_string_pretty(&output_json)
+    if let Some(ref pipeline) = options.pipeline {
+        let context = StageContext {
+            source_file: source.relative_path(&options.source_roots),
+            source_mtime: source.mtime,
+        };
+        let mut output = pipeline.apply_collecting(json, &context, dropped)?;
+        if let Some(ref prefix) = options.key_prefix {
+            add_key_prefix(&mut output, prefix);
+        }
+        return Some(output);
+    }
+
+    let mut output = match &options.fields {
+        Some(fields) => extract_fields(json, fields),
+        None => json,
+    };
+
+    if let Some(ref fields) = options.exclude_fields {
+        exclude_fields(&mut output, fields);
+    }
+
+    if let Some(ref renames) = options.renames {
+        output = rename_fields(output, renames, dropped)?;
+    }
+
+    if let Some(ref prefix) = options.key_prefix {
+        add_key_prefix(&mut output, prefix);
+    }
+
+    if let Some(ref field) = options.checksum {
+        inject_checksum(&mut output, field, source);
+    }
+
+    if let Some(ref key) = options.source_key {
+        let relative = source.relative_path(&options.source_roots);
+        inject_field(&mut output, key, Value::from(relative));
+    }
+
+    if let (Some(ref key), Some(mtime)) = (&options.mtime_key, source.mtime) {
+        inject_field(&mut output, key, Value::from(mtime));
+    }
+
+    Some(output)
+}
+
+/// 체크섬 필드 주입 (레코드 체크섬은 최상위 값이 배열이면 각 요소마다 따로 계산)
+fn inject_checksum(json: &mut Value, field: &ChecksumField, source: &RecordSource) {
+    match field.scope {
+        ChecksumScope::File => {
+            if let Some(ref checksum) = source.checksum {
+                inject_field(json, &field.key, Value::from(checksum.as_str()));
+            }
+        }
+        ChecksumScope::Record => {
+            let records = match json {
+                Value::Array(items) => items.iter_mut().collect(),
+                record => vec![record],
+            };
+            for record in records.into_iter().filter(|record| record.is_object()) {
+                let checksum = checksum::record_sha256(record);
+                inject_field(record, &field.key, Value::from(checksum));
+            }
+        }
+    }
+}
+
+/// 변환 표현식 적용 (최상위 값이 배열이면 각 요소에 개별 적용)
+fn apply_transform(json: Value, transform: &Expr) -> Value {
+    match json {
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|item| transform.eval(item)).collect())
+        }
+        record => transform.eval(&record),
+    }
+}
+
+/// 출력 직전에 키 순서 적용 (`Sort`면 중첩 객체까지 모든 키를 사전순으로 정렬)
+///
+/// `preserve_order` 기능 없이 빌드하면 객체가 항상 정렬된 상태이므로 아무 일도 하지 않습니다.
+fn order_keys(value: &mut Value, order: KeyOrder) {
+    if order == KeyOrder::Sort {
+        value.sort_all_objects();
+    }
+}
+
+/// 기준 폴더에 대한 상대 경로 문자열 (구분자는 `/`로 통일)
+pub fn relative_source_path(path: &Path, root: Option<&Path>) -> String {
+    let relative = root
+        .and_then(|root| path.strip_prefix(root).ok())
+        .unwrap_or(path);
+
+    let joined = relative
+        .components()
+        .filter(|c| !matches!(c, Component::RootDir))
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    // 기준 폴더 밖의 절대 경로 (예: `-i -`로 받은 경로)는 앞의 `/` 유지
+    if matches!(relative.components().next(), Some(Component::RootDir)) {
+        format!("/{}", joined)
     } else {
-        serde_json::to_string(&output_json)
+        joined
     }
-    .map_err(|e| JConvertError::SerializeError {
-        file: path.clone(),
-        reason: e.to_string(),
-    })?;
+}
+
+/// 경로가 처음으로 속하는 기준 폴더에 대한 상대 경로 문자열 (어느 폴더에도 속하지 않으면 경로 그대로)
+///
+/// # Examples
+/// ```
+/// use jconvert::processor::relative_to_roots;
+/// use std::path::{Path, PathBuf};
+///
+/// let roots = vec![PathBuf::from("exports"), PathBuf::from("archive")];
+/// assert_eq!(relative_to_roots(Path::new("archive/2024/a.json"), &roots), "2024/a.json");
+/// assert_eq!(relative_to_roots(Path::new("other/b.json"), &roots), "other/b.json");
+/// ```
+pub fn relative_to_roots(path: &Path, roots: &[PathBuf]) -> String {
+    let root = roots.iter().find(|root| path.starts_with(root));
+    relative_source_path(path, root.map(PathBuf::as_path))
+}
+
+/// 객체에 필드 주입 (배열이면 각 객체 요소에 주입)
+pub(crate) fn inject_field(json: &mut Value, key: &str, value: Value) {
+    match json {
+        Value::Object(map) => {
+            map.insert(key.to_string(), value);
+        }
+        Value::Array(arr) => {
+            for item in arr {
+                if let Value::Object(map) = item {
+                    map.insert(key.to_string(), value.clone());
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 워커 스레드가 계속 붙잡아 두는 직렬화 버퍼의 최대 용량 (큰 문서 뒤에는 이 크기로 줄임)
+const MAX_RETAINED_BUFFER: usize = 1024 * 1024;
 
-    Ok(json_line)
+thread_local! {
+    /// 워커 스레드마다 재사용하는 직렬화 버퍼 (파일마다 새로 할당하지 않음)
+    static SERIALIZE_BUFFER: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// JSON 값을 출력용 문자열로 직렬화
+///
+/// 현재 워커 스레드의 버퍼에 먼저 직렬화한 뒤 결과 길이만큼만 할당하여 복사합니다.
+fn serialize_value(value: &Value, path: &Path, options: &ProcessOptions) -> Result<String> {
+    SERIALIZE_BUFFER
+        .with_borrow_mut(|buffer| {
+            buffer.clear();
+            let written = match (&options.field_order, options.pretty) {
+                (Some(order), true) => {
+                    serde_json::to_writer_pretty(&mut *buffer, &order.apply(value))
+                }
+                (Some(order), false) => serde_json::to_writer(&mut *buffer, &order.apply(value)),
+                (None, true) => serde_json::to_writer_pretty(&mut *buffer, value),
+                (None, false) => serde_json::to_writer(&mut *buffer, value),
+            };
+            // serde_json은 항상 올바른 UTF-8을 출력하므로 검사만 하고 그대로 복사
+            let line = written.map(|()| String::from_utf8_lossy(buffer).into_owned());
+            if buffer.capacity() > MAX_RETAINED_BUFFER {
+                buffer.clear();
+                buffer.shrink_to(MAX_RETAINED_BUFFER);
+            }
+            line
+        })
+        .map_err(|e| JConvertError::SerializeError {
+            file: path.to_path_buf(),
+            reason: e.to_string(),
+        })
+}
+
+/// 리더에서 JSON 문서(연속된 여러 문서 포함)를 읽어 처리
+///
+/// 표준 입력처럼 파일이 아닌 입력을 위한 진입점입니다. 공백으로 구분되거나
+/// 바로 이어 붙은 여러 문서를 순서대로 파싱하여 문서 하나당 한 줄을 만듭니다.
+///
+/// # Arguments
+/// * `label` - 결과와 에러 메시지에 표시할 입력 이름 (예: `<stdin>`)
+/// * `reader` - JSON 데이터를 제공하는 리더
+/// * `options` - 처리 옵션
+///
+/// # Returns
+/// 모든 문서의 변환 결과를 담은 `ProcessResult`
+pub fn process_reader<R: Read>(
+    label: PathBuf,
+    mut reader: R,
+    options: &ProcessOptions,
+) -> ProcessResult {
+    let mut buffer = Vec::new();
+    if let Err(e) = reader.read_to_end(&mut buffer) {
+        let error = JConvertError::FileOpenError {
+            file: label.clone(),
+            reason: e.to_string(),
+        };
+        return ProcessResult::failure(label, error.to_string(), 0).with_error_kind(error.kind());
+    }
+
+    let started = Instant::now();
+    let input_size = buffer.len() as u64;
+    let checksum = match options.checksum {
+        Some(ChecksumField {
+            scope: ChecksumScope::File,
+            ..
+        }) => Some(checksum::sha256_hex(&buffer)),
+        _ => None,
+    };
+    let source = RecordSource::new(&label, None).with_checksum(checksum);
+    let head = &buffer[..buffer.len().min(encoding::SNIFF_LEN)];
+    let decoded = if encoding::needs_decoding(head, options.encoding, options.lossy) {
+        decode_bytes(&label, &buffer, options)
+    } else {
+        Ok(None)
+    };
+    let output = decoded.and_then(|decoded| match decoded {
+        Some(decoded) => process_documents(&source, &decoded, options).map(|mut output| {
+            output.reencoded = true;
+            output
+        }),
+        None => process_documents(&source, &buffer, options),
+    });
+    let result = match output {
+        Ok(output) => ProcessResult::converted(label, output, input_size),
+        Err(e) => {
+            ProcessResult::failure(label, e.to_string(), input_size).with_error_kind(e.kind())
+        }
+    };
+    result.with_elapsed(started.elapsed())
+}
+
+/// 바이트 버퍼에 담긴 연속된 JSON 문서들을 순서대로 변환
+fn process_documents(
+    source: &RecordSource,
+    bytes: &[u8],
+    options: &ProcessOptions,
+) -> Result<DocumentOutput> {
+    let mut output = DocumentOutput::default();
+
+    for (index, document) in limits::parse_documents(bytes, &options.parse_limits).enumerate() {
+        let json = document.map_err(|e| JConvertError::ParseError {
+            file: source.path.to_path_buf(),
+            reason: format!("{}번째 문서: {}", index + 1, e),
+        })?;
+
+        if options.validate_only {
+            continue;
+        }
+
+        convert_document(json, source, options, &mut output)?;
+    }
+
+    Ok(output)
 }
 
 /// 버퍼 리더를 사용한 JSON 파싱
-fn parse_with_reader(path: &PathBuf) -> Result<Value> {
-    let file = File::open(path).map_err(|e| JConvertError::FileOpenError {
-        file: path.clone(),
-        reason: e.to_string(),
-    })?;
+///
+/// simd-json을 쓰면 파일 전체를 버퍼로 읽어 파싱하고, 실패하면 serde_json으로 다시 파싱합니다.
+fn parse_with_reader(path: &Path, options: &ProcessOptions) -> Result<Value> {
+    if options.uses_simd() {
+        let mut bytes = std::fs::read(path).map_err(|e| JConvertError::from_open_error(path, e))?;
+        if let Some(json) = parser::parse_simd(&mut bytes) {
+            return Ok(json);
+        }
+    }
+
+    let file = File::open(path).map_err(|e| JConvertError::from_open_error(path, e))?;
 
     let reader = BufReader::new(file);
-    serde_json::from_reader(reader).map_err(|e| JConvertError::ParseError {
-        file: path.clone(),
+    limits::parse_reader(reader, &options.parse_limits).map_err(|e| JConvertError::ParseError {
+        file: path.to_path_buf(),
         reason: e.to_string(),
     })
 }
 
 /// 메모리 매핑을 사용한 JSON 파싱 (대용량 파일용)
-fn parse_with_mmap(path: &PathBuf) -> Result<Value> {
-    let file = File::open(path).map_err(|e| JConvertError::FileOpenError {
-        file: path.clone(),
+///
+/// simd-json을 쓰면 원본 파일에 반영되지 않는 쓰기 가능 매핑으로 파싱하고,
+/// 실패하면 원본을 다시 매핑하여 serde_json으로 파싱합니다.
+fn parse_with_mmap(path: &Path, options: &ProcessOptions) -> Result<Value> {
+    if options.uses_simd() {
+        let mut mmap = map_file_private(path)?;
+        if let Some(json) = parser::parse_simd(&mut mmap) {
+            return Ok(json);
+        }
+    }
+
+    let mmap = map_file(path)?;
+
+    limits::parse_slice(&mmap, &options.parse_limits).map_err(|e| JConvertError::ParseError {
+        file: path.to_path_buf(),
         reason: e.to_string(),
-    })?;
+    })
+}
+
+/// 파일을 메모리에 매핑
+pub(crate) fn map_file(path: &Path) -> Result<Mmap> {
+    let file = File::open(path).map_err(|e| JConvertError::from_open_error(path, e))?;
 
-    let mmap = unsafe {
+    unsafe {
         Mmap::map(&file).map_err(|e| JConvertError::FileOpenError {
-            file: path.clone(),
+            file: path.to_path_buf(),
             reason: format!("메모리 매핑 실패: {}", e),
-        })?
-    };
+        })
+    }
+}
 
-    serde_json::from_slice(&mmap).map_err(|e| JConvertError::ParseError {
-        file: path.clone(),
-        reason: e.to_string(),
-    })
+/// 파일을 쓰기 가능한 사본으로 메모리에 매핑 (쓴 내용은 원본 파일에 반영되지 않음)
+fn map_file_private(path: &Path) -> Result<MmapMut> {
+    let file = File::open(path).map_err(|e| JConvertError::from_open_error(path, e))?;
+
+    unsafe {
+        MmapOptions::new()
+            .map_copy(&file)
+            .map_err(|e| JConvertError::FileOpenError {
+                file: path.to_path_buf(),
+                reason: format!("메모리 매핑 실패: {}", e),
+            })
+    }
 }
 
 /// JSON에서 특정 필드만 추출
 ///
+/// 원본 값은 버려지므로 선택한 값을 복사하지 않고 꺼내어 옮깁니다.
+/// 같은 필드를 두 번 지정하거나 상위/하위 경로를 함께 지정한 경우에만 복사합니다.
+///
 /// # Arguments
 /// * `json` - 원본 JSON 값
 /// * `fields` - 추출할 필드 이름 목록
 ///
 /// # Returns
 /// 선택된 필드만 포함된 새 JSON 객체
-fn extract_fields(json: &Value, fields: &[String]) -> Value {
+pub(crate) fn extract_fields(json: Value, fields: &[String]) -> Value {
+    let shared: Vec<bool> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            fields
+                .iter()
+                .enumerate()
+                .any(|(other, path)| other != index && paths_overlap(field, path))
+        })
+        .collect();
+    extract_fields_with(json, fields, &shared)
+}
+
+/// 필드별 복사 필요 여부를 미리 계산해 두고 필드 추출
+fn extract_fields_with(mut json: Value, fields: &[String], shared: &[bool]) -> Value {
     match json {
-        Value::Object(map) => {
+        Value::Object(_) => {
             let mut new_map = Map::new();
-            for field in fields {
+            for (field, &shared) in fields.iter().zip(shared) {
                 // 중첩 필드 지원 (예: "user.name")
+                let value = if shared {
+                    get_nested_field(&json, field).cloned()
+                } else {
+                    get_nested_field_mut(&mut json, field).map(Value::take)
+                };
+                let Some(value) = value else {
+                    continue;
+                };
                 if field.contains('.') {
-                    if let Some(value) = get_nested_field(json, field) {
-                        // 중첩 필드를 평탄화하여 저장
-                        let flat_key = field.replace('.', "_");
-                        new_map.insert(flat_key, value.clone());
-                    }
-                } else if let Some(value) = map.get(field) {
-                    new_map.insert(field.clone(), value.clone());
+                    // 중첩 필드를 평탄화하여 저장
+                    new_map.insert(field.replace('.', "_"), value);
+                } else {
+                    new_map.insert(field.clone(), value);
                 }
             }
             Value::Object(new_map)
@@ -226,17 +1782,104 @@ fn extract_fields(json: &Value, fields: &[String]) -> Value {
         Value::Array(arr) => {
             // 배열인 경우 각 요소에 필드 추출 적용
             Value::Array(
-                arr.iter()
-                    .map(|item| extract_fields(item, fields))
+                arr.into_iter()
+                    .map(|item| extract_fields_with(item, fields, shared))
                     .collect(),
             )
         }
-        _ => json.clone(),
+        other => other,
+    }
+}
+
+/// 두 필드 경로가 같거나 한쪽이 다른 쪽의 상위 경로인지 확인
+fn paths_overlap(a: &str, b: &str) -> bool {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    long.strip_prefix(short)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// JSON에서 지정한 필드 제거 (민감 정보 제거용)
+///
+/// 최상위 값이 배열이면 각 요소에서 제거합니다.
+///
+/// # Arguments
+/// * `json` - 수정할 JSON 값
+/// * `fields` - 제거할 필드 경로 목록 (예: "password", "user.secret")
+pub(crate) fn exclude_fields(json: &mut Value, fields: &[String]) {
+    if let Value::Array(arr) = json {
+        for item in arr {
+            exclude_fields(item, fields);
+        }
+        return;
+    }
+
+    for field in fields {
+        remove_nested_field(json, field);
+    }
+}
+
+/// JSON 필드 이름 변경
+///
+/// 기존 경로의 값을 꺼내 새 경로에 설정합니다. 최상위 값이 배열이면 각 요소를 개별 레코드로 보고 적용합니다.
+/// 새 경로에 설정할 수 없는 레코드는 값을 기존 경로에 둔 채 이유와 함께 `rejected`에 모읍니다.
+///
+/// # Arguments
+/// * `json` - 수정할 JSON 값
+/// * `renames` - (기존 경로, 새 경로) 목록 (예: ("user.name", "username"))
+/// * `rejected` - 제외된 레코드를 모을 목록
+///
+/// # Returns
+/// 이름을 바꾼 값 (모든 레코드가 제외되면 None)
+pub(crate) fn rename_fields(
+    json: Value,
+    renames: &[(String, String)],
+    rejected: &mut Vec<RejectedRecord>,
+) -> Option<Value> {
+    let mut rename = |mut record: Value| {
+        for (from, to) in renames {
+            if let Err(reason) = rename_field(&mut record, from, to) {
+                rejected.push(RejectedRecord::new(record, reason));
+                return None;
+            }
+        }
+        Some(record)
+    };
+    match json {
+        // 빈 배열은 레코드가 없으므로 그대로 출력
+        Value::Array(items) if items.is_empty() => Some(Value::Array(items)),
+        Value::Array(items) => {
+            let kept: Vec<Value> = items.into_iter().filter_map(&mut rename).collect();
+            (!kept.is_empty()).then_some(Value::Array(kept))
+        }
+        other => rename(other),
     }
 }
 
+/// 단일 필드 이름 변경
+///
+/// 기존 경로에 값이 없으면 아무것도 하지 않습니다.
+///
+/// # Returns
+/// 새 경로 중간에 객체가 아닌 값이 있어 설정하지 못하면 값을 기존 경로에 되돌리고 이유를 반환
+pub(crate) fn rename_field(
+    json: &mut Value,
+    from: &str,
+    to: &str,
+) -> std::result::Result<(), String> {
+    let Some(value) = remove_nested_field(json, from) else {
+        return Ok(());
+    };
+    set_nested_field(json, to, value).map_err(|value| {
+        restore_nested_field(json, from, value);
+        format!(
+            "필드 이름 변경 실패: {} -> {} (경로 중간에 객체가 아닌 값이 있음)",
+            from, to
+        )
+    })
+}
+
 /// 중첩 필드 값 가져오기 (예: "user.profile.name")
-fn get_nested_field<'a>(json: &'a Value, path: &str) -> Option<&'a Value> {
+pub(crate) fn get_nested_field<'a>(json: &'a Value, path: &str) -> Option<&'a Value> {
     let parts: Vec<&str> = path.split('.').collect();
     let mut current = json;
 
@@ -260,6 +1903,75 @@ fn get_nested_field<'a>(json: &'a Value, path: &str) -> Option<&'a Value> {
     Some(current)
 }
 
+/// 중첩 필드 가변 참조 가져오기
+pub(crate) fn get_nested_field_mut<'a>(json: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    let mut current = json;
+
+    for part in path.split('.') {
+        current = match current {
+            Value::Object(map) => map.get_mut(part)?,
+            Value::Array(arr) => arr.get_mut(part.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+
+    Some(current)
+}
+
+/// 중첩 필드를 제거하고 값 반환 (예: "user.password")
+pub(crate) fn remove_nested_field(json: &mut Value, path: &str) -> Option<Value> {
+    let (parent, last) = match path.rsplit_once('.') {
+        Some((parent_path, last)) => (get_nested_field_mut(json, parent_path)?, last),
+        None => (json, path),
+    };
+
+    match parent {
+        Value::Object(map) => map.remove(last),
+        _ => None,
+    }
+}
+
+/// `remove_nested_field`로 꺼낸 값을 같은 경로에 되돌림
+fn restore_nested_field(json: &mut Value, path: &str, value: Value) {
+    let parent = match path.rsplit_once('.') {
+        Some((parent_path, last)) => get_nested_field_mut(json, parent_path).map(|p| (p, last)),
+        None => Some((json, path)),
+    };
+    if let Some((Value::Object(map), last)) = parent {
+        map.insert(last.to_string(), value);
+    }
+}
+
+/// 중첩 필드 값 설정 (중간 객체가 없으면 생성)
+///
+/// # Returns
+/// 경로 중간에 객체가 아닌 값이 있어 설정하지 못하면 넘겨받은 값을 그대로 돌려주는 Err
+pub(crate) fn set_nested_field(
+    json: &mut Value,
+    path: &str,
+    value: Value,
+) -> std::result::Result<(), Value> {
+    let mut current = json;
+    let mut parts = path.split('.').peekable();
+
+    while let Some(part) = parts.next() {
+        let Value::Object(map) = current else {
+            return Err(value);
+        };
+
+        if parts.peek().is_none() {
+            map.insert(part.to_string(), value);
+            return Ok(());
+        }
+
+        current = map
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+
+    Err(value)
+}
+
 /// JSON 파일 유효성 검사만 수행
 ///
 /// # Arguments
@@ -287,7 +1999,7 @@ mod tests {
         });
 
         let fields = vec!["id".to_string(), "name".to_string()];
-        let result = extract_fields(&json, &fields);
+        let result = extract_fields(json, &fields);
 
         assert_eq!(result.get("id"), Some(&json!(1)));
         assert_eq!(result.get("name"), Some(&json!("test")));
@@ -307,7 +2019,7 @@ mod tests {
         });
 
         let fields = vec!["user.name".to_string(), "user.profile.age".to_string()];
-        let result = extract_fields(&json, &fields);
+        let result = extract_fields(json, &fields);
 
         assert_eq!(result.get("user_name"), Some(&json!("John")));
         assert_eq!(result.get("user_profile_age"), Some(&json!(30)));
@@ -321,7 +2033,7 @@ mod tests {
         ]);
 
         let fields = vec!["id".to_string(), "name".to_string()];
-        let result = extract_fields(&json, &fields);
+        let result = extract_fields(json, &fields);
 
         let arr = result.as_array().unwrap();
         assert_eq!(arr.len(), 2);
@@ -329,6 +2041,42 @@ mod tests {
         assert_eq!(arr[0].get("extra"), None);
     }
 
+    #[test]
+    fn test_extract_fields_overlapping_paths() {
+        let json = json!({"user": {"name": "John", "age": 30}, "id": 1});
+
+        // 상위/하위 경로와 중복 필드는 옮기지 않고 복사하여 모두 채움
+        let fields = vec![
+            "user".to_string(),
+            "user.name".to_string(),
+            "id".to_string(),
+            "id".to_string(),
+        ];
+        let result = extract_fields(json, &fields);
+
+        assert_eq!(
+            result,
+            json!({"user": {"name": "John", "age": 30}, "user_name": "John", "id": 1})
+        );
+        assert!(paths_overlap("a.b", "a"));
+        assert!(!paths_overlap("ab", "a"));
+    }
+
+    #[test]
+    fn test_serialize_value_reuses_worker_buffer() {
+        let options = ProcessOptions::new();
+        let big = json!({"data": "x".repeat(2 * MAX_RETAINED_BUFFER)});
+        let line = serialize_value(&big, Path::new("big.json"), &options).unwrap();
+        assert_eq!(line.len(), 2 * MAX_RETAINED_BUFFER + 11);
+
+        // 큰 문서 뒤에는 버퍼를 줄여 워커가 큰 메모리를 계속 붙잡지 않음
+        SERIALIZE_BUFFER.with_borrow(|buffer| assert!(buffer.capacity() <= MAX_RETAINED_BUFFER));
+        assert_eq!(
+            serialize_value(&json!({"id": 1}), Path::new("a.json"), &options).unwrap(),
+            r#"{"id":1}"#
+        );
+    }
+
     #[test]
     fn test_get_nested_field() {
         let json = json!({
@@ -355,4 +2103,822 @@ mod tests {
         assert!(options.pretty);
         assert!(!options.validate_only);
     }
+
+    #[test]
+    fn test_process_reader_concatenated_documents() {
+        let input = r#"{"id": 1, "name": "a"} {"id": 2, "name": "b"}
+{"id": 3, "name": "c"}"#;
+        let options = ProcessOptions::new().with_fields(Some(vec!["id".to_string()]));
+        let result = process_reader(PathBuf::from("<stdin>"), input.as_bytes(), &options);
+
+        assert!(result.is_valid);
+        assert_eq!(
+            result.json_lines,
+            vec![r#"{"id":1}"#, r#"{"id":2}"#, r#"{"id":3}"#]
+        );
+        assert_eq!(result.file_size, input.len() as u64);
+    }
+
+    #[test]
+    fn test_process_reader_invalid_document() {
+        let input = r#"{"id": 1} {"id": "#;
+        let result = process_reader(
+            PathBuf::from("<stdin>"),
+            input.as_bytes(),
+            &ProcessOptions::new(),
+        );
+
+        assert!(!result.is_valid);
+        assert!(result.json_lines.is_empty());
+        assert!(result.error.unwrap().contains("<stdin>"));
+    }
+
+    #[test]
+    fn test_process_file_multi_doc() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("events.json");
+        std::fs::write(&path, "{\"id\": 1}{\"id\": 2}\n\n[{\"id\": 3}]\n").unwrap();
+
+        // 기본 모드에서는 뒤에 이어진 문서 때문에 파싱 실패
+        let result = process_file(path.clone(), &ProcessOptions::new());
+        assert!(!result.is_valid);
+
+        let options = ProcessOptions::new().with_multi_doc(true);
+        let result = process_file(path.clone(), &options);
+        assert!(result.is_valid);
+        assert_eq!(
+            result.json_lines,
+            vec![r#"{"id":1}"#, r#"{"id":2}"#, r#"[{"id":3}]"#]
+        );
+
+        // 잘못된 문서는 몇 번째 문서인지 표시
+        std::fs::write(&path, "{\"id\": 1} {\"id\": }").unwrap();
+        let result = process_file(path, &options);
+        assert!(result.error.unwrap().contains("2번째 문서"));
+    }
+
+    #[test]
+    fn test_inject_source_metadata() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("sub")).unwrap();
+        let path = dir.path().join("sub").join("a.json");
+        std::fs::write(&path, r#"{"id": 1, "extra": true}"#).unwrap();
+
+        let options = ProcessOptions::new()
+            .with_fields(Some(vec!["id".to_string()]))
+            .with_source_key(Some(DEFAULT_SOURCE_KEY.to_string()))
+            .with_mtime_key(Some("mtime".to_string()))
+            .with_source_root(Some(dir.path().to_path_buf()));
+        let result = process_file(path, &options);
+
+        let record: Value = serde_json::from_str(&result.json_lines[0]).unwrap();
+        assert_eq!(record["id"], json!(1));
+        assert_eq!(record[DEFAULT_SOURCE_KEY], json!("sub/a.json"));
+        assert!(record["mtime"].as_u64().unwrap() > 0);
+        assert_eq!(record.get("extra"), None);
+    }
+
+    #[test]
+    fn test_relative_source_path() {
+        let root = Path::new("/data");
+        assert_eq!(
+            relative_source_path(Path::new("/data/sub/a.json"), Some(root)),
+            "sub/a.json"
+        );
+        assert_eq!(
+            relative_source_path(Path::new("/other/b.json"), Some(root)),
+            "/other/b.json"
+        );
+        assert_eq!(relative_source_path(Path::new("c.json"), None), "c.json");
+    }
+
+    #[test]
+    fn test_process_json_lines_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("events.ndjson");
+        std::fs::write(
+            &path,
+            "{\"id\":1,\"ok\":true}\n\n{\"id\":2,\"ok\":false}\n{\"id\":3,\"ok\":true}\n",
+        )
+        .unwrap();
+
+        let options = ProcessOptions::new()
+            .with_fields(Some(vec!["id".to_string()]))
+            .with_predicate(Some(Expr::parse("ok").unwrap()));
+        let result = process_file(path.clone(), &options);
+        assert!(result.error.is_none());
+        assert_eq!(result.json_lines, vec![r#"{"id":1}"#, r#"{"id":3}"#]);
+        assert_eq!(result.skipped_records, 1);
+
+        std::fs::write(&path, "{\"id\":1}\n{broken\n").unwrap();
+        let result = validate_file(path);
+        assert!(!result.is_valid);
+        assert!(result.error.unwrap().contains("2번째 줄"));
+    }
+
+    #[test]
+    fn test_process_file_with_schema_filter() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("orders.json");
+        std::fs::write(&path, r#"[{"id": 1}, {"id": "2"}, {"id": 3}]"#).unwrap();
+
+        let filter = SchemaFilter::new(json!({
+            "required": ["id"],
+            "properties": { "id": { "type": "integer" } }
+        }))
+        .unwrap();
+        let options = ProcessOptions::new()
+            .with_schema_filter(Some(filter))
+            .with_key_order(KeyOrder::Sort);
+        let result = process_file(path.clone(), &options);
+        assert!(result.error.is_none());
+        assert_eq!(result.json_lines, vec![r#"[{"id":1},{"id":3}]"#]);
+        assert_eq!(result.rejected_records, 1);
+        // 수집하도록 설정하지 않으면 제외된 레코드 줄은 만들지 않음
+        assert!(result.rejects.is_empty());
+
+        // 모든 레코드가 제외되면 출력 줄 없이 성공
+        std::fs::write(&path, r#"{"name": "x"}"#).unwrap();
+        let result = process_file(path, &options.with_collect_rejects(true));
+        assert!(result.is_valid && result.json_lines.is_empty());
+        assert_eq!(
+            result.rejects,
+            vec![r#"{"_reject_reason":"스키마 불일치: $: 필수 필드 누락: id","name":"x"}"#]
+        );
+    }
+
+    #[test]
+    fn test_rejects_collect_every_dropped_record() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("events.jsonl");
+        std::fs::write(
+            &path,
+            "{\"id\":1,\"ok\":true}\n{\"id\":2,\"ok\":false}\n7\n",
+        )
+        .unwrap();
+
+        let options = ProcessOptions::new()
+            .with_predicate(Some(Expr::parse("ok").unwrap()))
+            .with_collect_rejects(true)
+            .with_key_order(KeyOrder::Sort);
+        let result = process_file(path, &options);
+        assert_eq!(result.json_lines, vec![r#"{"id":1,"ok":true}"#]);
+        assert_eq!(result.skipped_records, 2);
+        assert_eq!(
+            result.rejects,
+            vec![
+                r#"{"_reject_reason":"조건 필터 불일치 (--where)","id":2,"ok":false}"#,
+                r#"{"_record":7,"_reject_reason":"조건 필터 불일치 (--where)"}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_process_file_with_parse_limits() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("nested.json");
+        std::fs::write(&path, r#"{"a": {"b": {"c": [1, 2, 3]}}}"#).unwrap();
+
+        let limits = ParseLimits {
+            max_depth: Some(3),
+            ..Default::default()
+        };
+        let result = process_file(
+            path.clone(),
+            &ProcessOptions::new().with_parse_limits(limits),
+        );
+        assert!(result.error.unwrap().contains("중첩 깊이"));
+
+        // 대용량(메모리 매핑) 경로도 같은 한도 적용
+        let mut options = ProcessOptions::new().with_parse_limits(ParseLimits {
+            max_array_len: Some(2),
+            ..Default::default()
+        });
+        options.mmap_threshold = 0;
+        let result = process_file(path.clone(), &options);
+        assert!(result.error.unwrap().contains("배열 요소 수"));
+
+        let result = process_file(
+            path,
+            &ProcessOptions::new().with_parse_limits(ParseLimits::hardened()),
+        );
+        assert!(result.error.is_none());
+    }
+
+    #[test]
+    fn test_process_file_with_simd_parser() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+        std::fs::write(&path, r#"{"id": 1, "name": "a\"b", "tags": [1.5, null]}"#).unwrap();
+        let broken = dir.path().join("broken.json");
+        std::fs::write(&broken, r#"{"id": 1,"#).unwrap();
+
+        // 버퍼 리더 경로와 메모리 매핑 경로 모두 serde_json과 같은 결과
+        for mmap_threshold in [u64::MAX, 0] {
+            let mut options = ProcessOptions::new()
+                .with_fields(Some(vec!["name".to_string(), "id".to_string()]))
+                .with_parser(ParserKind::Simd)
+                .with_key_order(KeyOrder::Sort);
+            options.mmap_threshold = mmap_threshold;
+
+            let result = process_file(path.clone(), &options);
+            assert_eq!(result.json_lines, vec![r#"{"id":1,"name":"a\"b"}"#]);
+
+            // 파싱에 실패하면 serde_json으로 다시 파싱하여 같은 에러 메시지
+            let result = process_file(broken.clone(), &options);
+            let expected = process_file(broken.clone(), &ProcessOptions::new());
+            assert_eq!(result.error, expected.error);
+            assert!(result.error.is_some());
+        }
+    }
+
+    #[test]
+    fn test_process_file_with_sampler() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+        let records: Vec<Value> = (0..200).map(|id| json!({"id": id})).collect();
+        std::fs::write(&path, Value::Array(records).to_string()).unwrap();
+
+        let options = ProcessOptions::new().with_sampler(Some(Sampler::new(0.25, 3)));
+        let result = process_file(path.clone(), &options);
+        let kept = serde_json::from_str::<Value>(&result.json_lines[0])
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .len();
+        assert_eq!(kept + result.sampled_out_records, 200);
+        assert!((20..80).contains(&kept), "kept {}", kept);
+
+        // 같은 시드면 같은 레코드 선택
+        assert_eq!(
+            process_file(path.clone(), &options).json_lines,
+            result.json_lines
+        );
+
+        // 빠른 경로(최상위 필드 선택)에서도 샘플링 적용
+        let single = dir.path().join("single.json");
+        std::fs::write(&single, r#"{"id": 1, "name": "a"}"#).unwrap();
+        let options = ProcessOptions::new()
+            .with_fields(Some(vec!["id".to_string()]))
+            .with_sampler(Some(Sampler::new(f64::MIN_POSITIVE, 3)));
+        let result = process_file(single, &options);
+        assert!(result.json_lines.is_empty());
+        assert_eq!(result.sampled_out_records, 1);
+    }
+
+    #[test]
+    fn test_process_file_with_transform() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+        std::fs::write(
+            &path,
+            r#"[{"id": 1, "first": "Kim", "last": "Lee", "age": 20}, {"id": 2, "first": "Park", "age": 9}]"#,
+        )
+        .unwrap();
+
+        // 조건 필터 다음, 필드 제외와 원본 정보 주입 전에 적용
+        let options = ProcessOptions::new()
+            .with_predicate(Some(Expr::parse("age > 10").unwrap()))
+            .with_transform(Some(
+                Expr::parse(
+                    ". + {name: first + ' ' + last, adult: if age >= 18 then true else false end}",
+                )
+                .unwrap(),
+            ))
+            .with_exclude_fields(Some(vec!["first".to_string(), "last".to_string()]))
+            .with_source_key(Some(DEFAULT_SOURCE_KEY.to_string()))
+            .with_source_root(Some(dir.path().to_path_buf()))
+            .with_key_order(KeyOrder::Sort);
+        let result = process_file(path.clone(), &options);
+        assert_eq!(
+            result.json_lines,
+            vec![r#"[{"_source_file":"data.json","adult":true,"age":20,"id":1,"name":"Kim Lee"}]"#]
+        );
+
+        // 최상위 필드 선택 빠른 경로를 쓰지 않고 선택한 필드로 변환
+        let options = ProcessOptions::new()
+            .with_transform(Some(Expr::parse("{id, initial: upper(first)}").unwrap()))
+            .with_fields(Some(vec!["id".to_string()]));
+        let result = process_file(path, &options);
+        assert_eq!(result.json_lines, vec![r#"[{"id":1},{"id":2}]"#]);
+    }
+
+    #[test]
+    fn test_process_file_key_order() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+        std::fs::write(&path, r#"{"b": {"d": 1, "c": [{"f": 1, "e": 2}]}, "a": 1}"#).unwrap();
+
+        let result = process_file(
+            path.clone(),
+            &ProcessOptions::new().with_key_order(KeyOrder::Sort),
+        );
+        assert_eq!(
+            result.json_lines,
+            vec![r#"{"a":1,"b":{"c":[{"e":2,"f":1}],"d":1}}"#]
+        );
+
+        // 기본 동작은 serde_json을 따름 (preserve_order 빌드에서만 원본 순서)
+        let result = process_file(path, &ProcessOptions::new());
+        let expected = if cfg!(feature = "preserve_order") {
+            r#"{"b":{"d":1,"c":[{"f":1,"e":2}]},"a":1}"#
+        } else {
+            r#"{"a":1,"b":{"c":[{"e":2,"f":1}],"d":1}}"#
+        };
+        assert_eq!(result.json_lines, vec![expected]);
+    }
+
+    #[test]
+    fn test_process_file_field_order() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+        std::fs::write(&path, r#"{"name": "kim", "ts": 7, "id": 1, "a": 0}"#).unwrap();
+
+        // 나머지 필드는 --key-order를 따르고, 원본 정보 키는 `*` 뒤에 배치
+        let options = ProcessOptions::new()
+            .with_key_order(KeyOrder::Sort)
+            .with_field_order(Some(FieldOrder::parse("id,ts,*,_source_file").unwrap()))
+            .with_source_key(Some(DEFAULT_SOURCE_KEY.to_string()))
+            .with_source_roots(vec![dir.path().to_path_buf()])
+            .with_passthrough(true);
+        assert!(!options.uses_passthrough());
+        let result = process_file(path, &options);
+        assert_eq!(
+            result.json_lines,
+            vec![r#"{"id":1,"ts":7,"a":0,"name":"kim","_source_file":"data.json"}"#]
+        );
+    }
+
+    #[test]
+    fn test_process_file_checksum() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+        let content = r#"[{"id": 1}, {"id": 2}, 3]"#;
+        std::fs::write(&path, content).unwrap();
+
+        let file_hash = checksum::sha256_hex(content.as_bytes());
+        let options = ProcessOptions::new()
+            .with_checksum(Some(ChecksumField::new("_sha", ChecksumScope::File)))
+            .with_key_order(KeyOrder::Sort)
+            .with_passthrough(true);
+        assert!(!options.uses_passthrough());
+        let result = process_file(path.clone(), &options);
+        assert_eq!(
+            result.json_lines,
+            vec![format!(
+                r#"[{{"_sha":"{0}","id":1}},{{"_sha":"{0}","id":2}},3]"#,
+                file_hash
+            )]
+        );
+
+        // 레코드 체크섬은 배열 요소마다 따로 계산
+        let options = ProcessOptions::new()
+            .with_checksum(Some(ChecksumField::new("_sha", ChecksumScope::Record)))
+            .with_key_order(KeyOrder::Sort);
+        let result = process_file(path, &options);
+        assert_eq!(
+            result.json_lines,
+            vec![format!(
+                r#"[{{"_sha":"{}","id":1}},{{"_sha":"{}","id":2}},3]"#,
+                checksum::sha256_hex(br#"{"id":1}"#),
+                checksum::sha256_hex(br#"{"id":2}"#)
+            )]
+        );
+
+        let result = process_reader(
+            PathBuf::from("<stdin>"),
+            content.as_bytes(),
+            &ProcessOptions::new()
+                .with_checksum(Some(ChecksumField::new("_sha", ChecksumScope::File))),
+        );
+        assert!(result.json_lines[0].contains(&file_hash));
+    }
+
+    #[test]
+    fn test_process_file_with_key_prefixes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("export.json");
+        std::fs::write(
+            &path,
+            r#"[{"data_id": 1, "data_status": "on"}, {"data_id": 2, "data_status": "off"}]"#,
+        )
+        .unwrap();
+
+        // 접두사를 뗀 이름으로 필터/선택하고, 붙이는 접두사는 원본 정보 키에는 적용하지 않음
+        let options = ProcessOptions::new()
+            .with_strip_key_prefix(Some("data_".to_string()))
+            .with_predicate(Some(Expr::parse("status == 'on'").unwrap()))
+            .with_fields(Some(vec!["id".to_string()]))
+            .with_key_prefix(Some("src_".to_string()))
+            .with_source_key(Some(DEFAULT_SOURCE_KEY.to_string()))
+            .with_source_root(Some(dir.path().to_path_buf()))
+            .with_key_order(KeyOrder::Sort);
+        let result = process_file(path, &options);
+        assert_eq!(
+            result.json_lines,
+            vec![r#"[{"_source_file":"export.json","src_id":1}]"#]
+        );
+    }
+
+    #[test]
+    fn test_process_file_with_record_size_limit() {
+        use crate::record_size::{OversizePolicy, RecordSizeGuard};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("records.jsonl");
+        let big = "x".repeat(100);
+        std::fs::write(
+            &path,
+            format!("{{\"id\":1}}\n{{\"id\":2,\"blob\":\"{}\"}}\n", big),
+        )
+        .unwrap();
+
+        let guard = |policy| Some(RecordSizeGuard::new(50, policy));
+        let options = ProcessOptions::new()
+            .with_record_size(guard(OversizePolicy::Skip))
+            .with_collect_schema(true);
+        let result = process_file(path.clone(), &options);
+        assert_eq!(result.json_lines, vec![r#"{"id":1}"#]);
+        assert_eq!(result.oversized_records, 1);
+        // 건너뛴 레코드는 스키마에도 넣지 않음
+        assert_eq!(
+            result.schema,
+            Some(FieldSchema::from_lines(&result.json_lines))
+        );
+
+        let options = ProcessOptions::new().with_record_size(guard(OversizePolicy::TruncateFields));
+        let result = process_file(path.clone(), &options);
+        assert_eq!(result.json_lines.len(), 2);
+        assert!(!result.json_lines[1].contains(&big));
+        assert_eq!(result.oversized_records, 1);
+
+        let options = ProcessOptions::new().with_record_size(guard(OversizePolicy::Error));
+        let result = process_file(path, &options);
+        assert!(!result.is_valid);
+        assert!(result.error.unwrap().contains("2번째 레코드"));
+    }
+
+    #[test]
+    fn test_process_file_with_repair_jsonl() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("merged.jsonl");
+        std::fs::write(&path, "{\"id\":1}{\"id\":2}\n{\"id\":\n3}\n\n{\"id\":4}\n").unwrap();
+
+        let result = process_file(path.clone(), &ProcessOptions::new());
+        assert!(!result.is_valid);
+        assert!(result.error.unwrap().contains("1번째 줄"));
+
+        let options = ProcessOptions::new().with_repair_jsonl(true);
+        let result = process_file(path.clone(), &options);
+        assert_eq!(
+            result.json_lines,
+            vec![r#"{"id":1}"#, r#"{"id":2}"#, r#"{"id":3}"#, r#"{"id":4}"#]
+        );
+        assert_eq!(result.repaired_records, 3);
+
+        // 복구한 레코드의 파싱 에러는 레코드가 시작된 줄 번호로 보고
+        std::fs::write(&path, "{\"id\":1}\n{\"id\":\n2}{\"id\": tru}\n").unwrap();
+        let result = process_file(path, &options);
+        assert!(result.error.unwrap().contains("2번째 줄"));
+    }
+
+    #[test]
+    fn test_process_file_with_geojson() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("parks.geojson");
+        std::fs::write(
+            &path,
+            r#"{"type": "FeatureCollection", "features": [
+                {"type": "Feature", "geometry": {"type": "Point", "coordinates": [1, 2]}, "properties": {"name": "A", "area": 10}},
+                {"type": "Feature", "geometry": null, "properties": {"name": "B", "area": 3}}
+            ]}"#,
+        )
+        .unwrap();
+
+        // 속성을 최상위로 올리면 조건 필터와 필드 선택에서 속성 이름을 바로 사용
+        let options = ProcessOptions::new()
+            .with_geojson(true)
+            .with_flatten_properties(true)
+            .with_predicate(Some(Expr::parse("area > 5").unwrap()))
+            .with_fields(Some(vec!["name".to_string(), "geometry".to_string()]))
+            .with_key_order(KeyOrder::Sort);
+        let result = process_file(path.clone(), &options);
+        assert_eq!(
+            result.json_lines,
+            vec![r#"{"geometry":{"coordinates":[1,2],"type":"Point"},"name":"A"}"#]
+        );
+        assert_eq!(result.skipped_records, 1);
+
+        let options = ProcessOptions::new()
+            .with_geojson(true)
+            .with_key_order(KeyOrder::Sort);
+        let result = process_file(path.clone(), &options);
+        assert_eq!(result.json_lines.len(), 2);
+        assert!(result.json_lines[1].contains(r#""properties":{"area":3,"name":"B"}"#));
+
+        // --geojson 없이는 문서 하나가 한 줄
+        assert_eq!(
+            process_file(path, &ProcessOptions::new()).json_lines.len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_process_file_with_encodings() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        // UTF-8 BOM이 붙은 JSONL과 BOM 있는 UTF-16 JSON은 자동 감지
+        let bom_jsonl = dir.path().join("bom.jsonl");
+        std::fs::write(&bom_jsonl, b"\xEF\xBB\xBF{\"id\":1}\n{\"id\":2}\n").unwrap();
+        let result = process_file(bom_jsonl.clone(), &ProcessOptions::new());
+        assert_eq!(result.json_lines, vec![r#"{"id":1}"#, r#"{"id":2}"#]);
+        assert!(result.reencoded);
+
+        let utf16 = dir.path().join("utf16.json");
+        let mut bytes = vec![0xFF, 0xFE];
+        for unit in r#"{"name": "김"}"#.encode_utf16() {
+            bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        std::fs::write(&utf16, bytes).unwrap();
+        let options = ProcessOptions::new().with_fields(Some(vec!["name".to_string()]));
+        let result = process_file(utf16, &options);
+        assert_eq!(result.json_lines, vec![r#"{"name":"김"}"#]);
+        assert!(result.reencoded);
+
+        // Latin-1은 명시해야 하며, 그렇지 않으면 UTF-8 파싱 에러 (--lossy면 U+FFFD로 교체)
+        let latin1 = dir.path().join("latin1.json");
+        std::fs::write(&latin1, b"{\"city\":\"M\xFCnchen\"}").unwrap();
+        let result = process_file(latin1.clone(), &ProcessOptions::new());
+        assert!(!result.is_valid);
+        assert!(!result.reencoded);
+        let options = ProcessOptions::new().with_encoding(InputEncoding::Latin1);
+        let result = process_file(latin1.clone(), &options);
+        assert_eq!(result.json_lines, vec![r#"{"city":"München"}"#]);
+        let result = process_file(latin1, &ProcessOptions::new().with_lossy(true));
+        assert_eq!(result.json_lines, vec!["{\"city\":\"M\u{FFFD}nchen\"}"]);
+
+        // 이미 UTF-8이면 다시 인코딩하지 않음
+        let plain = dir.path().join("plain.json");
+        std::fs::write(&plain, r#"{"id": 1}"#).unwrap();
+        assert!(!process_file(plain, &ProcessOptions::new()).reencoded);
+
+        let utf16_error = ProcessOptions::new().with_encoding(InputEncoding::Utf16);
+        let result = process_file(bom_jsonl, &utf16_error);
+        assert!(result.error.unwrap().contains("인코딩 변환 실패"));
+    }
+
+    #[test]
+    fn test_process_file_with_normalize_keys() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("data.jsonl");
+        std::fs::write(
+            &path,
+            "{\"userId\": 1, \"userProfile\": {\"firstName\": \"Kim\"}}\n{\"user_id\": 2, \"is_active\": true}\n",
+        )
+        .unwrap();
+
+        // 필터와 필드 선택은 바뀐 키 이름을 기준으로 하고, 원본 정보 키는 바꾸지 않음
+        let options = ProcessOptions::new()
+            .with_normalize_keys(Some(KeyCase::Snake))
+            .with_predicate(Some(Expr::parse("user_id >= 1").unwrap()))
+            .with_fields(Some(vec![
+                "user_id".to_string(),
+                "user_profile".to_string(),
+            ]))
+            .with_source_key(Some(DEFAULT_SOURCE_KEY.to_string()))
+            .with_source_root(Some(dir.path().to_path_buf()))
+            .with_key_order(KeyOrder::Sort);
+        let result = process_file(path.clone(), &options);
+        assert_eq!(
+            result.json_lines,
+            vec![
+                r#"{"_source_file":"data.jsonl","user_id":1,"user_profile":{"first_name":"Kim"}}"#,
+                r#"{"_source_file":"data.jsonl","user_id":2}"#,
+            ]
+        );
+
+        let options = ProcessOptions::new()
+            .with_normalize_keys(Some(KeyCase::Camel))
+            .with_key_order(KeyOrder::Sort);
+        let result = process_file(path, &options);
+        assert_eq!(result.json_lines[1], r#"{"isActive":true,"userId":2}"#);
+    }
+
+    #[test]
+    fn test_process_file_with_passthrough() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let options = ProcessOptions::new().with_passthrough(true);
+
+        // 한 줄 문서는 키 순서와 숫자 표기를 그대로 유지
+        let minified = dir.path().join("minified.json");
+        std::fs::write(&minified, "{\"b\":1.50,\"a\":[1e3]}\n").unwrap();
+        let result = process_file(minified.clone(), &options);
+        assert_eq!(result.json_lines, vec![r#"{"b":1.50,"a":[1e3]}"#]);
+
+        // 그대로 내보내는 줄도 중복 제거 키를 함께 전달
+        let keyed = options.clone().with_dedup_key(Some("b".to_string()));
+        let result = process_file(minified.clone(), &keyed);
+        assert_eq!(result.record_keys, vec![Some("1.5".to_string())]);
+
+        // 여러 줄 문서는 파싱 후 한 줄로 직렬화
+        let pretty = dir.path().join("pretty.json");
+        std::fs::write(&pretty, "{\n  \"a\": 2,\n  \"b\": 1\n}\n").unwrap();
+        let result = process_file(pretty, &options);
+        assert_eq!(result.json_lines, vec![r#"{"a":2,"b":1}"#]);
+
+        // 잘못된 한 줄 문서는 검증에서 실패
+        let broken = dir.path().join("broken.json");
+        std::fs::write(&broken, "{\"a\":}").unwrap();
+        let result = process_file(broken, &options);
+        assert!(!result.is_valid);
+
+        // JSONL 줄도 그대로 복사하고 잘못된 줄은 줄 번호와 함께 보고
+        let lines = dir.path().join("events.jsonl");
+        std::fs::write(&lines, "{\"z\":1, \"y\":2}\r\n\n[3]\n").unwrap();
+        let result = process_file(lines.clone(), &options);
+        assert_eq!(result.json_lines, vec![r#"{"z":1, "y":2}"#, "[3]"]);
+        std::fs::write(&lines, "{}\n{bad}\n").unwrap();
+        let result = process_file(lines, &options);
+        assert!(result.error.unwrap().contains("2번째 줄"));
+
+        // 레코드를 바꾸는 옵션이 있으면 사용하지 않음
+        let options = options.with_fields(Some(vec!["a".to_string()]));
+        assert!(!options.uses_passthrough());
+        let result = process_file(minified, &options);
+        assert_eq!(result.json_lines, vec![r#"{"a":[1000.0]}"#]);
+    }
+
+    #[test]
+    fn test_inject_field_into_array_objects() {
+        let mut json = json!([{"id": 1}, {"id": 2}, 3]);
+        inject_field(&mut json, "_source_file", json!("a.json"));
+
+        assert_eq!(
+            json,
+            json!([
+                {"id": 1, "_source_file": "a.json"},
+                {"id": 2, "_source_file": "a.json"},
+                3
+            ])
+        );
+    }
+
+    #[test]
+    fn test_exclude_and_rename_fields() {
+        let mut json = json!([
+            {"id": 1, "password": "p", "user": {"name": "kim", "secret": "s"}},
+            {"id": 2, "user": {"name": "lee"}}
+        ]);
+
+        exclude_fields(
+            &mut json,
+            &["password".to_string(), "user.secret".to_string()],
+        );
+        let mut rejected = Vec::new();
+        let json = rename_fields(
+            json,
+            &[
+                ("id".to_string(), "record_id".to_string()),
+                ("user.name".to_string(), "username".to_string()),
+            ],
+            &mut rejected,
+        );
+
+        assert_eq!(
+            json,
+            Some(json!([
+                {"record_id": 1, "user": {}, "username": "kim"},
+                {"record_id": 2, "user": {}, "username": "lee"}
+            ]))
+        );
+        assert!(rejected.is_empty());
+    }
+
+    #[test]
+    fn test_rename_conflicting_target_rejects_record() {
+        let renames = [("name".to_string(), "user.name".to_string())];
+        let mut rejected = Vec::new();
+
+        let json = rename_fields(
+            json!([{"name": "kim", "user": "lee"}, {"name": "park"}]),
+            &renames,
+            &mut rejected,
+        );
+        assert_eq!(json, Some(json!([{"user": {"name": "park"}}])));
+        assert_eq!(
+            rejected,
+            vec![RejectedRecord::new(
+                json!({"name": "kim", "user": "lee"}),
+                "필드 이름 변경 실패: name -> user.name (경로 중간에 객체가 아닌 값이 있음)"
+                    .to_string()
+            )]
+        );
+
+        // 단일 레코드가 제외되면 출력하지 않고, 값은 기존 경로에 남음
+        let mut rejected = Vec::new();
+        let json = rename_fields(json!({"name": "kim", "user": 1}), &renames, &mut rejected);
+        assert_eq!(json, None);
+        assert_eq!(rejected[0].record, json!({"name": "kim", "user": 1}));
+    }
+
+    #[test]
+    fn test_process_file_collects_schema() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("records.jsonl");
+        std::fs::write(
+            &path,
+            "{\"id\":1,\"tags\":[\"a\"]}\n[{\"id\":\"2\"},{\"id\":null}]\n",
+        )
+        .unwrap();
+
+        let options = ProcessOptions::new()
+            .with_collect_schema(true)
+            .with_infer_schema(true);
+        let result = process_file(path.clone(), &options);
+        assert_eq!(
+            result.schema,
+            Some(FieldSchema::from_lines(&result.json_lines))
+        );
+        assert_eq!(
+            result.inference,
+            Some(SchemaInference::from_lines(&result.json_lines))
+        );
+
+        // 원본 그대로 내보내는 줄도 같은 스키마
+        let result = process_file(path.clone(), &options.clone().with_passthrough(true));
+        assert_eq!(
+            result.schema,
+            Some(FieldSchema::from_lines(&result.json_lines))
+        );
+
+        let result = process_file(path, &ProcessOptions::new());
+        assert_eq!(result.schema, None);
+        assert_eq!(result.inference, None);
+    }
+
+    #[test]
+    fn test_process_reader_collects_dedup_keys() {
+        let input =
+            r#"{"user":{"id":1},"v":"a"} {"other":true} [{"user":{"id":2}}] {"user":{"id":"1"}}"#;
+        let options = ProcessOptions::new().with_dedup_key(Some("user.id".to_string()));
+        let mut result = process_reader(PathBuf::from("<stdin>"), input.as_bytes(), &options);
+
+        let keys: Vec<Option<&str>> = result.keyed_lines().map(|(_, key)| key).collect();
+        assert_eq!(keys, vec![Some("1"), None, None, Some("\"1\"")]);
+
+        result.truncate_lines(1);
+        assert_eq!(result.record_keys, vec![Some("1".to_string())]);
+
+        // 키 경로가 없으면 키를 뽑지 않음
+        let result = process_reader(
+            PathBuf::from("<stdin>"),
+            input.as_bytes(),
+            &ProcessOptions::new(),
+        );
+        assert!(result.record_keys.is_empty());
+        assert!(result.keyed_lines().all(|(_, key)| key.is_none()));
+    }
+
+    #[test]
+    fn test_process_reader_with_predicate() {
+        let input = r#"{"status":"active","n":1} [{"status":"idle"},{"status":"active","n":2}] {"status":"idle"}"#;
+        let options = ProcessOptions::new()
+            .with_predicate(Some(Expr::parse("status == 'active'").unwrap()))
+            .with_key_order(KeyOrder::Sort);
+        let result = process_reader(PathBuf::from("<stdin>"), input.as_bytes(), &options);
+
+        assert!(result.is_valid);
+        assert_eq!(
+            result.json_lines,
+            vec![
+                r#"{"n":1,"status":"active"}"#,
+                r#"[{"n":2,"status":"active"}]"#
+            ]
+        );
+        assert_eq!(result.skipped_records, 2);
+    }
+
+    #[test]
+    fn test_remove_and_set_nested_field() {
+        let mut json = json!({"user": {"name": "John", "password": "secret"}});
+
+        assert_eq!(
+            remove_nested_field(&mut json, "user.password"),
+            Some(json!("secret"))
+        );
+        assert_eq!(remove_nested_field(&mut json, "user.missing"), None);
+        assert_eq!(
+            set_nested_field(&mut json, "meta.owner.name", json!("admin")),
+            Ok(())
+        );
+        assert_eq!(
+            set_nested_field(&mut json, "user.name.first", json!("x")),
+            Err(json!("x"))
+        );
+
+        assert_eq!(
+            json,
+            json!({"user": {"name": "John"}, "meta": {"owner": {"name": "admin"}}})
+        );
+    }
 }
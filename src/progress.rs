@@ -0,0 +1,96 @@
+//! 진행률 표시 모듈
+//!
+//! 파일 수가 수백만 개에 이르면 파일마다 진행률 바를 갱신하는 비용이 병목이 되므로
+//! 여러 스레드의 완료 수를 원자적 카운터에 모았다가 일정 개수마다 한 번씩 반영합니다.
+
+use indicatif::ProgressBar;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// 자동 갱신 간격에서 목표로 하는 전체 갱신 횟수
+const TARGET_UPDATES: u64 = 1000;
+
+/// 자동 갱신 간격의 최댓값 (파일 수)
+const MAX_AUTO_INTERVAL: u64 = 1000;
+
+/// 전체 작업 수에 맞춘 자동 갱신 간격
+///
+/// 전체 갱신 횟수가 약 1000번이 되도록 하되 1~1000개 사이로 제한합니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::progress::auto_interval;
+///
+/// assert_eq!(auto_interval(500), 1);
+/// assert_eq!(auto_interval(200_000), 200);
+/// assert_eq!(auto_interval(5_000_000), 1000);
+/// ```
+pub fn auto_interval(total: u64) -> u64 {
+    (total / TARGET_UPDATES).clamp(1, MAX_AUTO_INTERVAL)
+}
+
+/// 일정 개수마다 한 번씩 진행률 바를 갱신하는 카운터 (여러 스레드에서 공유)
+#[derive(Debug)]
+pub struct BatchedProgress {
+    bar: ProgressBar,
+    done: AtomicU64,
+    interval: u64,
+}
+
+impl BatchedProgress {
+    /// 갱신 간격(작업 수)을 지정하여 생성 (0이면 1로 취급)
+    pub fn new(bar: ProgressBar, interval: u64) -> Self {
+        Self {
+            bar,
+            done: AtomicU64::new(0),
+            interval: interval.max(1),
+        }
+    }
+
+    /// 작업 하나 완료
+    pub fn inc(&self) {
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        if done.is_multiple_of(self.interval) {
+            self.bar.set_position(done);
+        }
+    }
+
+    /// 완료한 작업 수
+    pub fn done(&self) -> u64 {
+        self.done.load(Ordering::Relaxed)
+    }
+
+    /// 남은 완료 수를 반영하고 진행률 바 종료
+    pub fn finish_with_message(&self, message: &'static str) {
+        self.bar.set_position(self.done());
+        self.bar.finish_with_message(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+
+    #[test]
+    fn test_batched_progress_updates_per_interval() {
+        let bar = ProgressBar::hidden();
+        bar.set_length(2500);
+        let progress = BatchedProgress::new(bar.clone(), 1000);
+
+        for _ in 0..2500 {
+            progress.inc();
+        }
+        assert_eq!(progress.done(), 2500);
+        assert_eq!(bar.position(), 2000);
+
+        progress.finish_with_message("완료!");
+        assert_eq!(bar.position(), 2500);
+    }
+
+    #[test]
+    fn test_batched_progress_parallel() {
+        let progress = BatchedProgress::new(ProgressBar::hidden(), 0);
+        (0..10_000).into_par_iter().for_each(|_| progress.inc());
+        assert_eq!(progress.done(), 10_000);
+    }
+}
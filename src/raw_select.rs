@@ -0,0 +1,139 @@
+//! 원본 바이트 기반 필드 선택 모듈
+//!
+//! 최상위 필드 몇 개만 고르는 흔한 경우에 전체 `Value` 트리를 만들지 않고
+//! 원본 JSON 바이트를 한 번 훑으면서 선택한 필드의 값만 파싱합니다.
+//! 선택하지 않은 필드는 키를 빌려 와 비교한 뒤 값을 할당 없이 건너뜁니다.
+//!
+//! 결과는 전체를 파싱한 뒤 필드를 고른 것과 같습니다 (중복 키는 마지막 값 사용).
+
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::fmt;
+
+/// 원본 바이트에서 바로 고를 수 있는 필드 목록인지 확인 (중첩 필드가 없어야 함)
+pub fn is_top_level(fields: &[String]) -> bool {
+    !fields.is_empty() && fields.iter().all(|field| !field.contains('.'))
+}
+
+/// 최상위 객체에서 지정한 필드만 파싱하여 선택
+///
+/// # Arguments
+/// * `bytes` - JSON 문서 하나의 원본 바이트
+/// * `fields` - 선택할 최상위 필드 이름 목록
+///
+/// # Returns
+/// 선택된 필드만 담은 객체 (최상위 값이 객체가 아니면 `None`, 일반 경로로 처리해야 함)
+///
+/// # Examples
+/// ```
+/// use jconvert::raw_select::select_top_level;
+/// use serde_json::json;
+///
+/// let fields = vec!["id".to_string(), "name".to_string()];
+/// let bytes = br#"{"id": 1, "payload": {"big": [1, 2, 3]}, "name": "a"}"#;
+/// let selected = select_top_level(bytes, &fields).unwrap();
+/// assert_eq!(selected, Some(json!({"id": 1, "name": "a"})));
+///
+/// assert_eq!(select_top_level(b"[1, 2]", &fields).unwrap(), None);
+/// ```
+pub fn select_top_level(bytes: &[u8], fields: &[String]) -> serde_json::Result<Option<Value>> {
+    let first = bytes.iter().find(|byte| !byte.is_ascii_whitespace());
+    if first != Some(&b'{') {
+        return Ok(None);
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    let values = (&mut deserializer).deserialize_map(SelectVisitor { fields })?;
+    deserializer.end()?;
+
+    // 전체 파싱 후 선택할 때와 같은 순서로 삽입
+    let mut map = Map::new();
+    for (field, value) in fields.iter().zip(values) {
+        if let Some(value) = value {
+            map.insert(field.clone(), value);
+        }
+    }
+    Ok(Some(Value::Object(map)))
+}
+
+/// 최상위 객체를 훑으며 선택한 필드의 값만 파싱하는 방문자
+struct SelectVisitor<'f> {
+    fields: &'f [String],
+}
+
+impl<'de> Visitor<'de> for SelectVisitor<'_> {
+    /// 필드 목록과 같은 순서의 값 (같은 필드를 여러 번 지정하면 첫 번째 자리에만 채움)
+    type Value = Vec<Option<Value>>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("JSON 객체")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut values = vec![None; self.fields.len()];
+        while let Some(key) = map.next_key_seed(KeySeed)? {
+            match self.fields.iter().position(|field| *field == key) {
+                Some(index) => values[index] = Some(map.next_value()?),
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(values)
+    }
+}
+
+/// 이스케이프가 없으면 원본 바이트를 빌려 오는 객체 키
+struct KeySeed;
+
+impl<'de> DeserializeSeed<'de> for KeySeed {
+    type Value = Cow<'de, str>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeySeed {
+    type Value = Cow<'de, str>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("문자열 키")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+        Ok(Cow::Borrowed(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::extract_fields;
+    use serde_json::json;
+
+    #[test]
+    fn test_select_matches_full_parse() {
+        let fields = vec!["name".to_string(), "id".to_string(), "missing".to_string()];
+        let bytes = br#"{"id": 1, "esc\"aped": {"deep": [1, {"x": null}]},
+            "name": "aA", "id": 2.50, "tail": true}"#;
+
+        let selected = select_top_level(bytes, &fields).unwrap().unwrap();
+        let full = extract_fields(serde_json::from_slice(bytes).unwrap(), &fields);
+        assert_eq!(selected, full);
+        assert_eq!(selected, json!({"id": 2.5, "name": "aA"}));
+    }
+
+    #[test]
+    fn test_select_rejects_invalid_json() {
+        let fields = vec!["id".to_string()];
+        assert!(select_top_level(br#"{"id": 1, "x": [1, }"#, &fields).is_err());
+        assert!(select_top_level(br#"{"id": 1} trailing"#, &fields).is_err());
+        assert!(!is_top_level(&["user.name".to_string()]));
+    }
+}
@@ -0,0 +1,100 @@
+//! 출력 레코드 수 제한 모듈
+//!
+//! `--limit N`은 입력 순서대로 앞쪽 N건을 출력합니다. 파일은 병렬로 처리되므로
+//! 어떤 파일을 건너뛸지는 완료된 순서가 아니라, 입력 순서상 앞쪽 파일들이 모두 끝나
+//! 그 레코드 수만으로 N건을 채웠는지로 정합니다. 그래서 스레드 수나 처리 순서와 관계없이
+//! 항상 같은 레코드가 출력됩니다.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// 여러 스레드에서 공유하는 입력 순서 기준 레코드 수 한도
+///
+/// # Examples
+/// ```
+/// use jconvert::RecordLimit;
+///
+/// let limit = RecordLimit::new(3);
+/// limit.record(1, 5);
+/// // 0번 파일이 끝나지 않았으므로 1번 파일의 레코드만으로는 채웠다고 보지 않음
+/// assert!(!limit.is_filled_before(2));
+///
+/// limit.record(0, 0);
+/// assert!(limit.is_filled_before(2));
+/// assert!(!limit.is_filled_before(1));
+/// ```
+#[derive(Debug)]
+pub struct RecordLimit {
+    /// 출력할 최대 레코드 수
+    limit: u64,
+    /// 입력 순서상 앞쪽부터 끝난 파일들의 집계
+    prefix: Mutex<Prefix>,
+}
+
+/// 앞쪽부터 빈틈없이 끝난 파일들의 레코드 수
+#[derive(Debug, Default)]
+struct Prefix {
+    /// 아직 끝나지 않은 가장 앞 파일 번호
+    next: usize,
+    /// `next` 앞 파일들의 레코드 수 합계
+    records: u64,
+    /// `next`보다 뒤에서 먼저 끝난 파일의 레코드 수
+    finished: BTreeMap<usize, u64>,
+}
+
+impl RecordLimit {
+    /// 최대 레코드 수로 생성
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            prefix: Mutex::new(Prefix::default()),
+        }
+    }
+
+    /// `index`번 파일이 만든 레코드 수 기록
+    pub fn record(&self, index: usize, records: u64) {
+        let mut prefix = self.prefix.lock().unwrap_or_else(|e| e.into_inner());
+        prefix.finished.insert(index, records);
+        loop {
+            let next = prefix.next;
+            let Some(records) = prefix.finished.remove(&next) else {
+                break;
+            };
+            prefix.records += records;
+            prefix.next += 1;
+        }
+    }
+
+    /// `index`번 파일보다 앞쪽 파일들만으로 한도를 채웠는지 확인 (채웠으면 이 파일은 건너뛰어도 됨)
+    pub fn is_filled_before(&self, index: usize) -> bool {
+        let prefix = self.prefix.lock().unwrap_or_else(|e| e.into_inner());
+        prefix.records >= self.limit && prefix.next <= index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_out_of_order_files_do_not_fill_limit() {
+        let limit = RecordLimit::new(2);
+        limit.record(2, 10);
+        limit.record(1, 10);
+        assert!(!limit.is_filled_before(3));
+
+        limit.record(0, 1);
+        // 앞쪽 파일이 모두 끝나 한도를 채웠으므로 아직 시작하지 않은 3번 이후는 건너뛰어도 됨
+        assert!(limit.is_filled_before(3));
+        assert!(limit.is_filled_before(4));
+        assert!(!limit.is_filled_before(2));
+    }
+
+    #[test]
+    fn test_limit_not_filled() {
+        let limit = RecordLimit::new(5);
+        limit.record(0, 2);
+        limit.record(1, 2);
+        assert!(!limit.is_filled_before(2));
+    }
+}
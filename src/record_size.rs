@@ -0,0 +1,197 @@
+//! 레코드 크기 제한 모듈
+//!
+//! 비정상적으로 큰 문서 하나가 소비자가 읽을 수 없을 만큼 긴 JSONL 줄이 되지 않도록
+//! 직렬화된 출력 줄의 크기를 검사합니다 (`--max-record-size`).
+//! 제한을 넘는 레코드는 정책에 따라 건너뛰거나, 큰 필드를 잘라 내거나, 파일을 실패로 처리합니다.
+
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+/// 잘라 낸 필드 이름을 기록하는 필드
+pub const TRUNCATED_FIELDS_KEY: &str = "_truncated_fields";
+
+/// 크기 제한을 넘는 레코드 처리 정책
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum OversizePolicy {
+    /// 레코드를 출력하지 않음
+    #[default]
+    Skip,
+    /// 가장 큰 최상위 필드부터 제거하여 제한에 맞춤 (제거한 필드는 `_truncated_fields`에 기록)
+    #[value(alias = "truncate-field")]
+    TruncateFields,
+    /// 레코드가 담긴 파일을 실패로 처리
+    Error,
+}
+
+/// 출력 줄 크기 검사 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SizeCheck {
+    /// 제한 이하 (원래 줄)
+    Fits(String),
+    /// 큰 필드를 잘라 내어 제한에 맞춘 줄
+    Truncated(String),
+    /// 제한을 넘어 건너뜀
+    Skipped,
+    /// 제한을 넘어 에러 (원래 줄의 바이트 수)
+    TooLarge(usize),
+}
+
+/// 직렬화된 레코드 크기 제한
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordSizeGuard {
+    /// 출력 줄 하나의 최대 바이트 수 (줄바꿈 제외)
+    max_bytes: u64,
+    /// 제한을 넘는 레코드 처리 정책
+    policy: OversizePolicy,
+}
+
+impl RecordSizeGuard {
+    /// 크기 제한 생성
+    pub fn new(max_bytes: u64, policy: OversizePolicy) -> Self {
+        Self { max_bytes, policy }
+    }
+
+    /// 출력 줄 하나의 최대 바이트 수
+    pub fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    /// 제한을 넘는 레코드 처리 정책
+    pub fn policy(&self) -> OversizePolicy {
+        self.policy
+    }
+
+    /// 직렬화된 출력 줄 검사
+    ///
+    /// # Arguments
+    /// * `line` - 직렬화된 레코드
+    /// * `pretty` - 필드를 잘라 낸 뒤 다시 직렬화할 때 pretty 출력 사용 여부
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::record_size::{OversizePolicy, RecordSizeGuard, SizeCheck};
+    ///
+    /// let guard = RecordSizeGuard::new(40, OversizePolicy::TruncateFields);
+    /// let line = format!(r#"{{"id":1,"body":"{}"}}"#, "x".repeat(100));
+    /// let SizeCheck::Truncated(line) = guard.check(line, false) else { panic!() };
+    /// assert!(line.contains(r#""_truncated_fields":["body"]"#));
+    /// assert!(!line.contains("xxx"));
+    ///
+    /// let guard = RecordSizeGuard::new(40, OversizePolicy::Error);
+    /// assert_eq!(guard.check(r#"{"id":1}"#.to_string(), false), SizeCheck::Fits(r#"{"id":1}"#.to_string()));
+    /// ```
+    pub fn check(&self, line: String, pretty: bool) -> SizeCheck {
+        if line.len() as u64 <= self.max_bytes {
+            return SizeCheck::Fits(line);
+        }
+        match self.policy {
+            OversizePolicy::Skip => SizeCheck::Skipped,
+            OversizePolicy::Error => SizeCheck::TooLarge(line.len()),
+            OversizePolicy::TruncateFields => match self.truncate(&line, pretty) {
+                Some(truncated) => SizeCheck::Truncated(truncated),
+                None => SizeCheck::Skipped,
+            },
+        }
+    }
+
+    /// 가장 큰 최상위 필드부터 제거하여 제한에 맞춘 줄 생성
+    ///
+    /// 객체가 아니거나 필드를 모두 제거해도 제한을 넘으면 None
+    fn truncate(&self, line: &str, pretty: bool) -> Option<String> {
+        let Ok(Value::Object(mut map)) = serde_json::from_str::<Value>(line) else {
+            return None;
+        };
+
+        // 직렬화된 크기가 큰 필드부터 제거
+        let mut sizes: Vec<(String, usize)> = map
+            .iter()
+            .map(|(key, value)| (key.clone(), serialized_len(value)))
+            .collect();
+        sizes.sort_by_key(|(_, size)| std::cmp::Reverse(*size));
+
+        let mut removed = Vec::new();
+        for (key, _) in sizes {
+            map.remove(&key);
+            removed.push(Value::String(key));
+            let line = serialize_truncated(&map, &removed, pretty)?;
+            if line.len() as u64 <= self.max_bytes {
+                return Some(line);
+            }
+        }
+        None
+    }
+}
+
+/// 잘라 낸 필드 목록을 붙여 직렬화
+fn serialize_truncated(
+    map: &Map<String, Value>,
+    removed: &[Value],
+    pretty: bool,
+) -> Option<String> {
+    let mut record = map.clone();
+    record.insert(
+        TRUNCATED_FIELDS_KEY.to_string(),
+        Value::Array(removed.to_vec()),
+    );
+    let record = Value::Object(record);
+    let line = if pretty {
+        serde_json::to_string_pretty(&record)
+    } else {
+        serde_json::to_string(&record)
+    };
+    line.ok()
+}
+
+/// 값의 직렬화된 바이트 수
+fn serialized_len(value: &Value) -> usize {
+    match value {
+        Value::String(s) => s.len() + 2,
+        _ => serde_json::to_string(value).map(|s| s.len()).unwrap_or(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oversized() -> String {
+        format!(
+            r#"{{"id":7,"small":"{}","large":"{}"}}"#,
+            "s".repeat(30),
+            "l".repeat(200)
+        )
+    }
+
+    #[test]
+    fn test_skip_and_error_policies() {
+        let guard = RecordSizeGuard::new(64, OversizePolicy::Skip);
+        assert_eq!(guard.check(oversized(), false), SizeCheck::Skipped);
+
+        let guard = RecordSizeGuard::new(64, OversizePolicy::Error);
+        let line = oversized();
+        let len = line.len();
+        assert_eq!(guard.check(line, false), SizeCheck::TooLarge(len));
+    }
+
+    #[test]
+    fn test_truncate_removes_largest_fields_first() {
+        let guard = RecordSizeGuard::new(80, OversizePolicy::TruncateFields);
+        let SizeCheck::Truncated(line) = guard.check(oversized(), false) else {
+            panic!("필드를 잘라 내야 함");
+        };
+        let record: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(record["id"], 7);
+        assert_eq!(record["small"], "s".repeat(30));
+        assert!(record.get("large").is_none());
+        assert_eq!(record[TRUNCATED_FIELDS_KEY], serde_json::json!(["large"]));
+
+        // 필드를 모두 제거해도 넘거나 객체가 아니면 건너뜀
+        let guard = RecordSizeGuard::new(10, OversizePolicy::TruncateFields);
+        assert_eq!(guard.check(oversized(), false), SizeCheck::Skipped);
+        let guard = RecordSizeGuard::new(5, OversizePolicy::TruncateFields);
+        assert_eq!(
+            guard.check("[1,2,3,4]".to_string(), false),
+            SizeCheck::Skipped
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! JSONL 복구 모듈
+//!
+//! 단순한 셸 이어 붙이기(`cat a.jsonl b.jsonl`, 줄바꿈 누락)나 줄 길이 제한으로 깨진 JSONL에서
+//! 한 줄에 붙은 여러 레코드를 나누고, 여러 줄에 걸쳐 끊긴 레코드를 다시 이어 붙입니다
+//! (`--repair-jsonl`, `jconvert validate --jsonl --fix`).
+//!
+//! 끊긴 레코드는 줄바꿈 없이 이어 붙이므로 문자열이나 숫자 중간에서 끊긴 줄도 원래대로 복원됩니다.
+//! 레코드 안의 바이트는 바꾸지 않고 경계만 다시 정합니다.
+
+use serde::de::IgnoredAny;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::atomic::AtomicOutput;
+use crate::error::{JConvertError, Result};
+
+/// 복구한 레코드 하나
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepairedRecord {
+    /// 레코드가 시작된 줄 번호 (1부터)
+    pub line: u64,
+    /// 레코드 원문 (앞뒤 공백 제거)
+    pub text: String,
+}
+
+/// 복구할 수 없는 줄
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepairError {
+    /// 레코드가 시작된 줄 번호 (1부터)
+    pub line: u64,
+    /// 에러 메시지
+    pub reason: String,
+}
+
+/// 복구 결과 집계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RepairSummary {
+    /// 전체 레코드 수
+    pub records: u64,
+    /// 다른 레코드와 한 줄에 붙어 있어 나눈 레코드 수
+    pub split: u64,
+    /// 여러 줄에 걸쳐 끊겨 있어 이어 붙인 레코드 수
+    pub joined: u64,
+}
+
+impl RepairSummary {
+    /// 경계를 고친 레코드 수
+    pub fn repaired(&self) -> u64 {
+        self.split + self.joined
+    }
+}
+
+/// 줄을 차례로 받아 레코드 경계를 다시 정하는 JSONL 복구기
+///
+/// # Examples
+/// ```
+/// use jconvert::repair::JsonlRepairer;
+///
+/// let mut repairer = JsonlRepairer::new();
+/// let mut records = Vec::new();
+/// for line in ["{\"id\":1}{\"id\":2}", "{\"name\":\"Ki", "m\"}"] {
+///     records.extend(repairer.push_line(line).unwrap());
+/// }
+/// let summary = repairer.finish().unwrap();
+///
+/// let texts: Vec<&str> = records.iter().map(|r| r.text.as_str()).collect();
+/// assert_eq!(texts, vec!["{\"id\":1}", "{\"id\":2}", "{\"name\":\"Kim\"}"]);
+/// assert_eq!((summary.split, summary.joined), (2, 1));
+/// ```
+#[derive(Debug, Default)]
+pub struct JsonlRepairer {
+    /// 아직 끝나지 않은 레코드 원문
+    pending: String,
+    /// 끝나지 않은 레코드가 시작된 줄 번호
+    pending_line: u64,
+    /// 끝나지 않은 레코드가 걸친 줄 수
+    pending_lines: u64,
+    /// 마지막으로 받은 줄 번호
+    line: u64,
+    /// 집계
+    summary: RepairSummary,
+}
+
+impl JsonlRepairer {
+    /// 복구기 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 줄 하나를 받아 완성된 레코드 반환 (줄바꿈 문자는 제외하고 전달)
+    ///
+    /// 레코드가 아직 끝나지 않았으면 다음 줄과 이어 붙이기 위해 보관합니다.
+    pub fn push_line(
+        &mut self,
+        line: &str,
+    ) -> std::result::Result<Vec<RepairedRecord>, RepairError> {
+        self.line += 1;
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if self.pending.is_empty() {
+            if line.trim().is_empty() {
+                return Ok(Vec::new());
+            }
+            self.pending_line = self.line;
+            self.pending_lines = 0;
+        }
+        self.pending.push_str(line);
+        self.pending_lines += 1;
+
+        let mut records = Vec::new();
+        let mut consumed = 0;
+        let mut stream =
+            serde_json::Deserializer::from_str(&self.pending).into_iter::<IgnoredAny>();
+        loop {
+            match stream.next() {
+                Some(Ok(_)) => {
+                    let end = stream.byte_offset();
+                    records.push(self.pending[consumed..end].trim().to_string());
+                    consumed = end;
+                }
+                // 레코드가 다음 줄로 이어짐
+                Some(Err(e)) if e.is_eof() => break,
+                Some(Err(e)) => {
+                    let error = RepairError {
+                        line: self.pending_line,
+                        reason: e.to_string(),
+                    };
+                    self.pending.clear();
+                    return Err(error);
+                }
+                None => {
+                    consumed = self.pending.len();
+                    break;
+                }
+            }
+        }
+
+        let first_line = self.pending_line;
+        let joined = self.pending_lines > 1;
+        if !records.is_empty() {
+            self.summary.records += records.len() as u64;
+            if joined {
+                self.summary.joined += 1;
+            }
+            if records.len() > 1 {
+                self.summary.split += records.len() as u64;
+            }
+        }
+
+        // 이 줄에서 시작했지만 끝나지 않은 레코드는 다음 줄과 이어 붙임
+        let rest = self.pending[consumed..].trim_start().to_string();
+        if !records.is_empty() {
+            self.pending_line = self.line;
+            self.pending_lines = 1;
+        }
+        self.pending = rest;
+
+        Ok(records
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| RepairedRecord {
+                line: if index == 0 { first_line } else { self.line },
+                text,
+            })
+            .collect())
+    }
+
+    /// 입력을 마치고 집계 반환 (끝나지 않은 레코드가 남아 있으면 에러)
+    pub fn finish(self) -> std::result::Result<RepairSummary, RepairError> {
+        if self.pending.trim().is_empty() {
+            Ok(self.summary)
+        } else {
+            Err(RepairError {
+                line: self.pending_line,
+                reason: "파일 끝까지 레코드가 끝나지 않았습니다".to_string(),
+            })
+        }
+    }
+}
+
+/// JSONL 파일의 레코드 경계를 고쳐 제자리에 다시 씀 (`jconvert validate --jsonl --fix`)
+///
+/// 임시 파일에 쓴 뒤 이름을 바꾸므로, 복구할 수 없는 줄이 있으면 원본 파일은 그대로 남습니다.
+pub fn repair_file(path: &Path) -> Result<RepairSummary> {
+    let file = File::open(path).map_err(|e| JConvertError::from_open_error(path, e))?;
+    let write_error = |e: std::io::Error| JConvertError::WriteError {
+        reason: format!("{}: {}", path.display(), e),
+    };
+    let (output, temp) = AtomicOutput::create(path).map_err(write_error)?;
+    let parse_error = |error: RepairError| JConvertError::ParseError {
+        file: path.to_path_buf(),
+        reason: format!("{}번째 줄: {}", error.line, error.reason),
+    };
+
+    let mut writer = BufWriter::new(temp);
+    let mut repairer = JsonlRepairer::new();
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| JConvertError::from_open_error(path, e))?;
+        for record in repairer.push_line(&line).map_err(parse_error)? {
+            writeln!(writer, "{}", record.text).map_err(write_error)?;
+        }
+    }
+    let summary = repairer.finish().map_err(parse_error)?;
+
+    writer.flush().map_err(write_error)?;
+    drop(writer);
+    output.commit().map_err(write_error)?;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repair(lines: &[&str]) -> (Vec<RepairedRecord>, RepairSummary) {
+        let mut repairer = JsonlRepairer::new();
+        let mut records = Vec::new();
+        for line in lines {
+            records.extend(repairer.push_line(line).unwrap());
+        }
+        (records, repairer.finish().unwrap())
+    }
+
+    #[test]
+    fn test_split_concatenated_records_keeps_line_numbers() {
+        let (records, summary) = repair(&["{\"a\":1}", "", "{\"b\":2} {\"c\":3}\r", "[1,2]"]);
+        let found: Vec<(u64, &str)> = records.iter().map(|r| (r.line, r.text.as_str())).collect();
+        assert_eq!(
+            found,
+            vec![
+                (1, "{\"a\":1}"),
+                (3, "{\"b\":2}"),
+                (3, "{\"c\":3}"),
+                (4, "[1,2]")
+            ]
+        );
+        assert_eq!(summary.records, 4);
+        assert_eq!(summary.split, 2);
+        assert_eq!(summary.joined, 0);
+    }
+
+    #[test]
+    fn test_join_records_broken_across_lines() {
+        // 보기 좋게 포맷된 레코드와 문자열 중간에서 끊긴 레코드
+        let (records, summary) = repair(&[
+            "{",
+            "  \"id\": 1,",
+            "  \"tags\": [\"a\"]",
+            "}{\"text\": \"hel",
+            "lo\"}",
+        ]);
+        let texts: Vec<&str> = records.iter().map(|r| r.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec!["{  \"id\": 1,  \"tags\": [\"a\"]}", "{\"text\": \"hello\"}"]
+        );
+        assert_eq!(records[0].line, 1);
+        assert_eq!(records[1].line, 4);
+        assert_eq!(summary.joined, 2);
+        assert_eq!(summary.repaired(), 2);
+    }
+
+    #[test]
+    fn test_unrepairable_input() {
+        let mut repairer = JsonlRepairer::new();
+        assert!(repairer.push_line("{\"a\":1}").is_ok());
+        assert!(repairer.push_line("{\"b\":").is_ok());
+        let error = repairer.push_line("}").unwrap_err();
+        assert_eq!(error.line, 2);
+
+        let mut repairer = JsonlRepairer::new();
+        repairer.push_line("{\"a\":").unwrap();
+        assert_eq!(repairer.finish().unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_repair_file_in_place() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("merged.jsonl");
+        std::fs::write(&path, "{\"id\":1}{\"id\":2}\n{\"id\":\n3}\n").unwrap();
+        let summary = repair_file(&path).unwrap();
+        assert_eq!(summary.records, 3);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "{\"id\":1}\n{\"id\":2}\n{\"id\":3}\n"
+        );
+
+        // 복구할 수 없으면 원본 유지
+        std::fs::write(&path, "{\"id\":1}\n{bad}\n").unwrap();
+        assert!(repair_file(&path).is_err());
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "{\"id\":1}\n{bad}\n"
+        );
+    }
+}
@@ -0,0 +1,298 @@
+//! 실행 보고서 모듈
+//!
+//! CI 등에서 결과를 프로그램으로 확인할 수 있도록 처리 통계를 JSON 보고서로 기록합니다.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::{JConvertError, Result};
+use crate::error_log::ErrorEntry;
+use crate::parser::ParserKind;
+use crate::shard::ShardInfo;
+use crate::stats::{Statistics, StatsDetail};
+
+/// 실행 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunMode {
+    /// 폴더 변환
+    Convert,
+    /// 유효성 검사만 수행
+    Validate,
+    /// 표준 입력 변환
+    Stdin,
+}
+
+impl RunMode {
+    /// 보고서에 기록되는 이름
+    pub fn name(self) -> &'static str {
+        match self {
+            RunMode::Convert => "convert",
+            RunMode::Validate => "validate",
+            RunMode::Stdin => "stdin",
+        }
+    }
+}
+
+/// 파일별 에러
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileError {
+    /// 파일 경로
+    pub path: PathBuf,
+    /// 에러 메시지
+    pub error: String,
+    /// 에러 종류 (`JConvertError::kind`)
+    pub kind: &'static str,
+}
+
+/// 크기 제한을 넘은 레코드가 있는 파일
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OversizedFile {
+    /// 파일 경로
+    pub path: PathBuf,
+    /// 건너뛰거나 필드를 잘라 낸 레코드 수
+    pub records: usize,
+}
+
+/// 분할 파일 정보
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShardReport {
+    /// 파일 경로
+    pub path: PathBuf,
+    /// 기록된 레코드 수
+    pub records: u64,
+    /// 기록된 바이트 수
+    pub bytes: u64,
+}
+
+/// 기계가 읽을 수 있는 실행 보고서 (`--stats-json`)
+#[derive(Debug, Clone, Serialize)]
+pub struct RunReport {
+    /// 실행 모드
+    pub mode: RunMode,
+    /// 전체 파일 수
+    pub total_files: usize,
+    /// 성공 파일 수
+    pub success: usize,
+    /// 에러 파일 수
+    pub errors: usize,
+    /// 유효성 검사 실패 파일 수
+    pub validation_failed: usize,
+    /// 읽기 권한이 없는 파일 수
+    pub unreadable: usize,
+    /// 중복 제거된 레코드 수
+    pub duplicates: usize,
+    /// 내용이 같아 제외한 입력 파일 수 (`--dedup-content`)
+    pub duplicate_files: usize,
+    /// 내용이 같아 제외한 입력 파일의 전체 크기
+    pub duplicate_bytes_saved: u64,
+    /// 조건 필터로 제외된 레코드 수
+    pub skipped_records: usize,
+    /// 스키마 필터에 맞지 않아 제외된 레코드 수
+    pub rejected_records: usize,
+    /// 샘플링에서 빠진 레코드 수
+    pub sampled_out_records: usize,
+    /// 파싱 전에 UTF-8로 다시 인코딩한 파일 수
+    pub reencoded_files: usize,
+    /// 레코드 경계를 고친 JSONL 레코드 수
+    pub repaired_records: usize,
+    /// 크기 제한을 넘어 건너뛰거나 필드를 잘라 낸 레코드 수
+    pub oversized_records: usize,
+    /// 스키마 변화가 감지된 파일 수
+    pub schema_drift_files: usize,
+    /// 읽은 바이트 수
+    pub bytes_read: u64,
+    /// 쓴 바이트 수
+    pub bytes_written: u64,
+    /// 처리 시간 (초)
+    pub elapsed_secs: f64,
+    /// 파일 단위 파싱에 사용한 JSON 파서
+    pub parser: ParserKind,
+    /// 입력 처리 속도 (MB/s)
+    pub throughput_mb_per_sec: f64,
+    /// 파일별 에러 목록
+    pub file_errors: Vec<FileError>,
+    /// 크기 제한을 넘은 레코드가 있는 파일 목록
+    pub oversized_files: Vec<OversizedFile>,
+    /// 분할 파일 목록 (분할하지 않으면 빈 목록)
+    pub shards: Vec<ShardReport>,
+    /// 폴더별 집계와 가장 크거나 느린 파일 (`--stats-detail`일 때만)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<StatsDetail>,
+}
+
+impl RunReport {
+    /// 통계로부터 보고서 생성
+    pub fn new(mode: RunMode, stats: &Statistics) -> Self {
+        Self {
+            mode,
+            total_files: stats.total_files,
+            success: stats.get_success_count(),
+            errors: stats.get_error_count(),
+            validation_failed: stats.get_validation_failed(),
+            unreadable: stats.get_unreadable_count(),
+            duplicates: stats.get_duplicate_count(),
+            duplicate_files: stats.duplicate_files,
+            duplicate_bytes_saved: stats.duplicate_bytes,
+            skipped_records: stats.get_skipped_count(),
+            rejected_records: stats.get_rejected_count(),
+            sampled_out_records: stats.get_sampled_out_count(),
+            reencoded_files: stats.get_reencoded_count(),
+            repaired_records: stats.get_repaired_count(),
+            oversized_records: stats.get_oversized_count(),
+            schema_drift_files: stats.get_schema_drift_count(),
+            bytes_read: stats.get_bytes_read(),
+            bytes_written: stats.get_bytes_written(),
+            elapsed_secs: stats.elapsed().as_secs_f64(),
+            parser: stats.parser,
+            throughput_mb_per_sec: stats.throughput_mb_per_sec(),
+            file_errors: Vec::new(),
+            oversized_files: Vec::new(),
+            shards: Vec::new(),
+            detail: stats.detail(),
+        }
+    }
+
+    /// 파일별 에러 목록 설정
+    pub fn with_errors(mut self, errors: &[ErrorEntry]) -> Self {
+        self.file_errors = errors
+            .iter()
+            .map(|entry| FileError {
+                path: entry.path.clone(),
+                error: entry.message.clone(),
+                kind: entry.kind,
+            })
+            .collect();
+        self
+    }
+
+    /// 크기 제한을 넘은 레코드가 있는 파일 목록 설정
+    pub fn with_oversized_files(mut self, files: &[(PathBuf, usize)]) -> Self {
+        self.oversized_files = files
+            .iter()
+            .map(|(path, records)| OversizedFile {
+                path: path.clone(),
+                records: *records,
+            })
+            .collect();
+        self
+    }
+
+    /// 분할 파일 목록 설정
+    pub fn with_shards(mut self, shards: &[ShardInfo]) -> Self {
+        self.shards = shards
+            .iter()
+            .map(|shard| ShardReport {
+                path: shard.path.clone(),
+                records: shard.records,
+                bytes: shard.bytes,
+            })
+            .collect();
+        self
+    }
+
+    /// 보고서를 JSON 파일로 저장
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let write_error = |reason: String| JConvertError::WriteError {
+            reason: format!("{} ({})", reason, path.display()),
+        };
+
+        let file = File::create(path).map_err(|e| write_error(e.to_string()))?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer_pretty(&mut writer, self).map_err(|e| write_error(e.to_string()))?;
+        writeln!(writer).map_err(|e| write_error(e.to_string()))?;
+        writer.flush().map_err(|e| write_error(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_report_json() {
+        let stats = Statistics::new(3);
+        stats.increment_success();
+        stats.increment_success();
+        stats.increment_error();
+        stats.add_bytes_read(100);
+        stats.add_bytes_written(80);
+
+        let errors = vec![ErrorEntry::new(
+            PathBuf::from("bad.json"),
+            "parse",
+            "JSON 파싱 실패".to_string(),
+        )];
+        let shards = vec![ShardInfo {
+            path: PathBuf::from("out.0001.jsonl"),
+            records: 2,
+            bytes: 80,
+        }];
+        let report = RunReport::new(RunMode::Convert, &stats)
+            .with_errors(&errors)
+            .with_shards(&shards);
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("report.json");
+        report.write(&path).unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["mode"], "convert");
+        assert_eq!(json["total_files"], 3);
+        assert_eq!(json["success"], 2);
+        assert_eq!(json["errors"], 1);
+        assert_eq!(json["bytes_read"], 100);
+        assert_eq!(json["bytes_written"], 80);
+        assert_eq!(json["file_errors"][0]["path"], "bad.json");
+        assert_eq!(json["file_errors"][0]["kind"], "parse");
+        assert_eq!(json["shards"][0]["records"], 2);
+        assert!(json["elapsed_secs"].is_f64());
+        assert_eq!(json["parser"], "serde");
+        assert!(json["throughput_mb_per_sec"].is_f64());
+        // 상세 통계를 수집하지 않으면 필드 생략
+        assert!(json.get("detail").is_none());
+        assert_eq!(json["oversized_files"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_run_report_oversized_files() {
+        let stats = Statistics::new(1);
+        stats.add_oversized(2);
+
+        let oversized = vec![(PathBuf::from("huge.json"), 2)];
+        let report = RunReport::new(RunMode::Convert, &stats).with_oversized_files(&oversized);
+        let json = serde_json::to_value(report).unwrap();
+        assert_eq!(json["oversized_records"], 2);
+        assert_eq!(json["oversized_files"][0]["path"], "huge.json");
+        assert_eq!(json["oversized_files"][0]["records"], 2);
+    }
+
+    #[test]
+    fn test_run_report_duplicate_files() {
+        let stats = Statistics::new(2).with_duplicate_files(3, 4096);
+        let json = serde_json::to_value(RunReport::new(RunMode::Convert, &stats)).unwrap();
+        assert_eq!(json["duplicate_files"], 3);
+        assert_eq!(json["duplicate_bytes_saved"], 4096);
+    }
+
+    #[test]
+    fn test_run_report_detail() {
+        use crate::stats::FileStat;
+
+        let stats = Statistics::new(1).with_detail(true);
+        stats.record_file(FileStat {
+            path: "export/a.json".to_string(),
+            bytes: 42,
+            elapsed_secs: 0.25,
+            failed: true,
+        });
+
+        let json = serde_json::to_value(RunReport::new(RunMode::Convert, &stats)).unwrap();
+        assert_eq!(json["detail"]["directories"]["export"]["failed"], 1);
+        assert_eq!(json["detail"]["largest"][0]["path"], "export/a.json");
+        assert_eq!(json["detail"]["slowest"][0]["elapsed_secs"], 0.25);
+    }
+}
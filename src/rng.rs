@@ -0,0 +1,116 @@
+//! 난수 생성 모듈
+//!
+//! 같은 시드로 항상 같은 결과를 내는 작고 빠른 의사 난수 생성기(SplitMix64)입니다.
+//! 테스트 데이터 생성처럼 재현 가능해야 하는 곳에서 사용합니다.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 시드 기반 의사 난수 생성기 (SplitMix64)
+///
+/// 암호학적으로 안전하지 않으므로 보안 용도로 사용하면 안 됩니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::rng::Rng;
+///
+/// let mut a = Rng::new(42);
+/// let mut b = Rng::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// assert!((1..=6).contains(&a.range(1, 6)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// 시드로 생성기 생성
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// 현재 시각을 시드로 생성기 생성
+    pub fn from_time() -> Self {
+        Self::new(time_seed())
+    }
+
+    /// 같은 시드에서 번호별로 독립된 생성기 파생 (병렬 작업을 재현 가능하게 나눌 때 사용)
+    pub fn derive(seed: u64, index: u64) -> Self {
+        let mut base = Self::new(seed ^ index.wrapping_mul(0xD1B5_4A32_D192_ED03));
+        Self::new(base.next_u64())
+    }
+
+    /// 다음 64비트 난수
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `[0, 1)` 구간의 실수
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// `[low, high]` 구간의 정수 (`low > high`이면 `low`)
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        if low >= high {
+            return low;
+        }
+        let span = (high as i128 - low as i128 + 1) as u128;
+        (low as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    /// 주어진 확률로 `true`
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    /// 목록에서 하나를 무작위로 선택 (비어 있으면 `None`)
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        let index = self.range(0, items.len() as i64 - 1) as usize;
+        items.get(index)
+    }
+}
+
+/// 현재 시각으로 만든 시드
+pub fn time_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let first: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        assert_eq!(first, second);
+
+        assert_ne!(Rng::derive(7, 0).next_u64(), Rng::derive(7, 1).next_u64());
+    }
+
+    #[test]
+    fn test_range_and_f64_bounds() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3, 3)));
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+        assert_eq!(rng.range(5, 5), 5);
+        // 전체 구간에서도 넘침 없이 동작
+        rng.range(i64::MIN, i64::MAX);
+    }
+}
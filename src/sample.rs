@@ -0,0 +1,134 @@
+//! 레코드 샘플링 모듈
+//!
+//! 레코드마다 주어진 비율의 확률로 남길지 정합니다 (`--sample`).
+//! 결정은 시드, 원본 파일의 상대 경로, 파일 안에서의 레코드 순번으로만 정해지므로
+//! 스레드 수나 처리 순서와 관계없이 같은 시드로 항상 같은 부분 집합을 얻습니다.
+
+use crate::rng::Rng;
+
+/// 확률 기반 레코드 샘플러
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sampler {
+    /// 남길 비율 (0 초과 1 이하)
+    rate: f64,
+    /// 난수 시드
+    seed: u64,
+}
+
+impl Sampler {
+    /// 샘플러 생성
+    ///
+    /// # Arguments
+    /// * `rate` - 남길 비율 (0 초과 1 이하)
+    /// * `seed` - 난수 시드 (같은 시드와 입력이면 같은 레코드 선택)
+    pub fn new(rate: f64, seed: u64) -> Self {
+        Self { rate, seed }
+    }
+
+    /// 남길 비율
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// 난수 시드
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// 파일의 레코드를 남길지 결정
+    ///
+    /// # Arguments
+    /// * `file` - 원본 파일 식별 문자열 (기준 폴더에 대한 상대 경로)
+    /// * `index` - 파일 안에서의 레코드 순번 (0부터)
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::sample::Sampler;
+    ///
+    /// let sampler = Sampler::new(0.5, 42);
+    /// let first: Vec<bool> = (0..100).map(|i| sampler.keeps("a.json", i)).collect();
+    /// let second: Vec<bool> = (0..100).map(|i| sampler.keeps("a.json", i)).collect();
+    /// assert_eq!(first, second);
+    /// assert!(Sampler::new(1.0, 42).keeps("a.json", 0));
+    /// ```
+    pub fn keeps(&self, file: &str, index: u64) -> bool {
+        if self.rate >= 1.0 {
+            return true;
+        }
+        Rng::derive(self.seed ^ fnv1a(file.as_bytes()), index).next_f64() < self.rate
+    }
+}
+
+/// 샘플링 비율 파싱 (`0.1`, `10%`)
+///
+/// # Examples
+/// ```
+/// use jconvert::sample::parse_rate;
+///
+/// assert_eq!(parse_rate("0.25"), Ok(0.25));
+/// assert_eq!(parse_rate("10%"), Ok(0.1));
+/// assert!(parse_rate("0").is_err());
+/// assert!(parse_rate("1.5").is_err());
+/// ```
+pub fn parse_rate(text: &str) -> Result<f64, String> {
+    let text = text.trim();
+    let (number, scale) = match text.strip_suffix('%') {
+        Some(percent) => (percent.trim(), 100.0),
+        None => (text, 1.0),
+    };
+    let rate = number
+        .parse::<f64>()
+        .map(|value| value / scale)
+        .map_err(|_| format!("잘못된 샘플링 비율: {}", text))?;
+    if rate > 0.0 && rate <= 1.0 {
+        Ok(rate)
+    } else {
+        Err(format!(
+            "샘플링 비율은 0보다 크고 1 이하여야 합니다 (예: 0.1, 10%): {}",
+            text
+        ))
+    }
+}
+
+/// FNV-1a 64비트 해시 (파일 경로를 시드에 섞는 용도)
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF2_9CE4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sampler_rate_is_approximate() {
+        let sampler = Sampler::new(0.1, 7);
+        let kept = (0..10_000)
+            .filter(|&i| sampler.keeps("data.json", i))
+            .count();
+        assert!((800..1200).contains(&kept), "kept {}", kept);
+    }
+
+    #[test]
+    fn test_sampler_depends_on_seed_and_file() {
+        let pick = |sampler: Sampler, file: &str| -> Vec<bool> {
+            (0..64).map(|i| sampler.keeps(file, i)).collect()
+        };
+        let sampler = Sampler::new(0.5, 1);
+        assert_eq!(pick(sampler, "a.json"), pick(sampler, "a.json"));
+        assert_ne!(pick(sampler, "a.json"), pick(sampler, "b.json"));
+        assert_ne!(
+            pick(sampler, "a.json"),
+            pick(Sampler::new(0.5, 2), "a.json")
+        );
+    }
+
+    #[test]
+    fn test_parse_rate_errors() {
+        assert_eq!(parse_rate("100%"), Ok(1.0));
+        assert!(parse_rate("-0.1").is_err());
+        assert!(parse_rate("abc").is_err());
+        assert!(parse_rate("NaN").is_err());
+    }
+}
@@ -0,0 +1,374 @@
+//! 스키마 추적 모듈
+//!
+//! 출력 레코드의 필드 경로와 JSON 타입을 수집하여 파일 간 스키마 변화를 감지합니다.
+
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// JSON 값 타입
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JsonType {
+    Null,
+    Boolean,
+    Number,
+    String,
+    Array,
+    Object,
+}
+
+impl JsonType {
+    /// JSON 값의 타입 판별
+    pub fn of(value: &Value) -> Self {
+        match value {
+            Value::Null => JsonType::Null,
+            Value::Bool(_) => JsonType::Boolean,
+            Value::Number(_) => JsonType::Number,
+            Value::String(_) => JsonType::String,
+            Value::Array(_) => JsonType::Array,
+            Value::Object(_) => JsonType::Object,
+        }
+    }
+
+    /// 타입 이름 반환
+    pub fn name(&self) -> &'static str {
+        match self {
+            JsonType::Null => "null",
+            JsonType::Boolean => "boolean",
+            JsonType::Number => "number",
+            JsonType::String => "string",
+            JsonType::Array => "array",
+            JsonType::Object => "object",
+        }
+    }
+}
+
+impl fmt::Display for JsonType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 필드 경로별 타입 출현 횟수
+///
+/// 경로는 점 표기법을 사용하며, 배열 요소는 `[]`로 표시합니다 (예: `items[].id`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldSchema {
+    fields: BTreeMap<String, BTreeMap<JsonType, u64>>,
+}
+
+impl FieldSchema {
+    /// 빈 스키마 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 직렬화된 출력 레코드들로부터 스키마 수집
+    ///
+    /// 최상위 값이 배열이면 각 요소를 개별 레코드로 봅니다.
+    /// 파싱할 수 없는 줄은 무시합니다.
+    pub fn from_lines(json_lines: &[String]) -> Self {
+        let mut schema = Self::new();
+        for line in json_lines {
+            if let Ok(value) = serde_json::from_str::<Value>(line) {
+                schema.add_document(&value);
+            }
+        }
+        schema
+    }
+
+    /// 문서 하나의 레코드를 스키마에 추가
+    pub fn add_document(&mut self, value: &Value) {
+        match value {
+            Value::Array(items) => items.iter().for_each(|item| self.add_record(item)),
+            other => self.add_record(other),
+        }
+    }
+
+    /// 레코드 하나의 필드 경로와 타입을 스키마에 추가
+    pub fn add_record(&mut self, record: &Value) {
+        let mut seen = BTreeSet::new();
+        collect_fields(record, "", &mut seen);
+        for (path, json_type) in seen {
+            *self
+                .fields
+                .entry(path)
+                .or_default()
+                .entry(json_type)
+                .or_insert(0) += 1;
+        }
+    }
+
+    /// 다른 스키마를 병합
+    pub fn merge(&mut self, other: &FieldSchema) {
+        for (path, types) in &other.fields {
+            let entry = self.fields.entry(path.clone()).or_default();
+            for (json_type, count) in types {
+                *entry.entry(*json_type).or_insert(0) += count;
+            }
+        }
+    }
+
+    /// 필드 경로와 타입 조합이 포함되어 있는지 확인
+    pub fn contains(&self, path: &str, json_type: JsonType) -> bool {
+        self.fields
+            .get(path)
+            .is_some_and(|types| types.contains_key(&json_type))
+    }
+
+    /// 필드 경로가 포함되어 있는지 확인
+    pub fn contains_path(&self, path: &str) -> bool {
+        self.fields.contains_key(path)
+    }
+
+    /// 필드 경로별 타입 출현 횟수 반환
+    pub fn fields(&self) -> &BTreeMap<String, BTreeMap<JsonType, u64>> {
+        &self.fields
+    }
+
+    /// 필드가 하나도 없는지 확인
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+/// 레코드의 필드 경로와 타입을 재귀적으로 수집
+fn collect_fields(value: &Value, prefix: &str, seen: &mut BTreeSet<(String, JsonType)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                seen.insert((path.clone(), JsonType::of(child)));
+                collect_fields(child, &path, seen);
+            }
+        }
+        Value::Array(items) if !prefix.is_empty() => {
+            let path = format!("{}[]", prefix);
+            for item in items {
+                seen.insert((path.clone(), JsonType::of(item)));
+                collect_fields(item, &path, seen);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 파일에서 감지된 스키마 변화
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaDrift {
+    /// 변화가 감지된 파일
+    pub path: PathBuf,
+    /// 처음 등장한 필드 (경로, 타입)
+    pub new_fields: Vec<(String, JsonType)>,
+    /// 기존 필드에 처음 등장한 타입 (경로, 타입)
+    pub new_types: Vec<(String, JsonType)>,
+}
+
+/// 파일 간 스키마 변화 추적기
+///
+/// 처음 `baseline_files`개 파일의 스키마를 기준으로 삼고, 이후 파일에서
+/// 기준에 없던 필드나 타입이 등장하면 변화로 보고합니다. 보고된 필드와 타입은
+/// 기준에 추가되므로 같은 변화는 한 번만 보고됩니다.
+#[derive(Debug, Clone)]
+pub struct SchemaDriftTracker {
+    known: FieldSchema,
+    baseline_files: usize,
+    files_seen: usize,
+}
+
+impl SchemaDriftTracker {
+    /// 새 추적기 생성
+    ///
+    /// # Arguments
+    /// * `baseline_files` - 기준 스키마를 만들 파일 수 (최소 1)
+    pub fn new(baseline_files: usize) -> Self {
+        Self {
+            known: FieldSchema::new(),
+            baseline_files: baseline_files.max(1),
+            files_seen: 0,
+        }
+    }
+
+    /// 파일 스키마를 기록하고 기준 대비 변화 반환
+    ///
+    /// # Returns
+    /// 기준 파일 이후에 새 필드나 타입이 등장하면 `Some(SchemaDrift)`
+    pub fn observe(&mut self, path: &Path, schema: &FieldSchema) -> Option<SchemaDrift> {
+        if schema.is_empty() {
+            return None;
+        }
+
+        self.files_seen += 1;
+        if self.files_seen <= self.baseline_files {
+            self.known.merge(schema);
+            return None;
+        }
+
+        let mut new_fields = Vec::new();
+        let mut new_types = Vec::new();
+        for (field, types) in schema.fields() {
+            for json_type in types.keys() {
+                if !self.known.contains_path(field) {
+                    new_fields.push((field.clone(), *json_type));
+                } else if !self.known.contains(field, *json_type) {
+                    new_types.push((field.clone(), *json_type));
+                }
+            }
+        }
+        self.known.merge(schema);
+
+        if new_fields.is_empty() && new_types.is_empty() {
+            return None;
+        }
+
+        Some(SchemaDrift {
+            path: path.to_path_buf(),
+            new_fields,
+            new_types,
+        })
+    }
+}
+
+/// 타입 충돌 보고서에 남길 타입별 예시 파일 수
+pub const MAX_EXAMPLE_FILES: usize = 3;
+
+/// 타입별 출현 통계
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeUsage {
+    /// 해당 타입으로 나타난 레코드 수
+    pub count: u64,
+    /// 해당 타입이 나타난 예시 파일 (최대 `MAX_EXAMPLE_FILES`개)
+    pub example_files: Vec<PathBuf>,
+}
+
+/// 레코드마다 타입이 다른 필드
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeConflict {
+    /// 필드 경로
+    pub field: String,
+    /// 타입별 출현 통계
+    pub types: BTreeMap<JsonType, TypeUsage>,
+}
+
+/// 필드별 타입 충돌 보고서
+///
+/// 모든 파일의 필드 타입을 누적하여 둘 이상의 타입으로 나타난 필드를 찾습니다.
+/// `null`은 값이 없는 경우로 보아 충돌로 치지 않습니다.
+#[derive(Debug, Clone, Default)]
+pub struct TypeConflictReport {
+    fields: BTreeMap<String, BTreeMap<JsonType, TypeUsage>>,
+}
+
+impl TypeConflictReport {
+    /// 빈 보고서 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 파일 스키마를 보고서에 누적
+    pub fn observe(&mut self, path: &Path, schema: &FieldSchema) {
+        for (field, types) in schema.fields() {
+            let entry = self.fields.entry(field.clone()).or_default();
+            for (json_type, count) in types {
+                let usage = entry.entry(*json_type).or_default();
+                usage.count += count;
+                if usage.example_files.len() < MAX_EXAMPLE_FILES {
+                    usage.example_files.push(path.to_path_buf());
+                }
+            }
+        }
+    }
+
+    /// 타입 충돌이 있는 필드 목록 (필드 경로 순)
+    pub fn conflicts(&self) -> Vec<TypeConflict> {
+        self.fields
+            .iter()
+            .filter(|(_, types)| types.keys().filter(|t| **t != JsonType::Null).count() > 1)
+            .map(|(field, types)| TypeConflict {
+                field: field.clone(),
+                types: types.clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_schema_paths_and_counts() {
+        let lines = vec![
+            r#"[{"id":1,"user":{"name":"kim"}},{"id":"2","tags":[{"k":"a"}]}]"#.to_string(),
+            "not json".to_string(),
+        ];
+        let schema = FieldSchema::from_lines(&lines);
+
+        assert_eq!(schema.fields()["id"][&JsonType::Number], 1);
+        assert_eq!(schema.fields()["id"][&JsonType::String], 1);
+        assert!(schema.contains("user.name", JsonType::String));
+        assert!(schema.contains("tags[]", JsonType::Object));
+        assert!(schema.contains("tags[].k", JsonType::String));
+        assert!(!schema.contains_path("user.age"));
+    }
+
+    #[test]
+    fn test_drift_after_baseline() {
+        let schema = |value: Value| {
+            let mut schema = FieldSchema::new();
+            schema.add_document(&value);
+            schema
+        };
+
+        let mut tracker = SchemaDriftTracker::new(2);
+        assert!(tracker
+            .observe(Path::new("a.json"), &schema(json!({"id": 1})))
+            .is_none());
+        // 기준 구간 안에서는 새 필드가 있어도 보고하지 않음
+        assert!(tracker
+            .observe(Path::new("b.json"), &schema(json!({"id": 2, "name": "x"})))
+            .is_none());
+
+        let drift = tracker
+            .observe(Path::new("c.json"), &schema(json!({"id": "3", "age": 5})))
+            .unwrap();
+        assert_eq!(
+            drift.new_fields,
+            vec![("age".to_string(), JsonType::Number)]
+        );
+        assert_eq!(drift.new_types, vec![("id".to_string(), JsonType::String)]);
+
+        // 이미 보고된 변화는 다시 보고하지 않음
+        assert!(tracker
+            .observe(Path::new("d.json"), &schema(json!({"id": "4", "age": 6})))
+            .is_none());
+    }
+
+    #[test]
+    fn test_type_conflict_report() {
+        let mut report = TypeConflictReport::new();
+        report.observe(
+            Path::new("a.json"),
+            &FieldSchema::from_lines(&[r#"[{"id":1,"name":null},{"id":2,"name":"x"}]"#.into()]),
+        );
+        report.observe(
+            Path::new("b.json"),
+            &FieldSchema::from_lines(&[r#"{"id":"3","name":"y"}"#.into()]),
+        );
+
+        let conflicts = report.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].field, "id");
+        assert_eq!(conflicts[0].types[&JsonType::Number].count, 2);
+        assert_eq!(
+            conflicts[0].types[&JsonType::String].example_files,
+            vec![PathBuf::from("b.json")]
+        );
+    }
+}
@@ -0,0 +1,264 @@
+//! 레코드 스키마 필터 모듈
+//!
+//! JSON Schema의 일부로 레코드를 검사하여 스키마에 맞는 레코드만 남깁니다.
+//! 맞지 않는 레코드는 실행을 실패시키지 않고 제외되며, 필요하면 별도 파일로 모아 나중에 확인합니다.
+//!
+//! # 지원하는 스키마 키워드
+//!
+//! - `type`: `object`, `array`, `string`, `integer`, `number`, `boolean`, `null` (배열로 여러 타입 허용)
+//! - `object`: `properties`, `required`, `additionalProperties: false`
+//! - `array`: `items`, `minItems`, `maxItems`
+//! - `string`: `minLength`, `maxLength` (문자 수 기준)
+//! - `integer`/`number`: `minimum`, `maximum`
+//! - 모든 타입: `enum`, `const`
+//!
+//! 테스트 데이터 생성과 달리 `type`이 없으면 모든 타입을 허용합니다.
+
+use serde_json::Value;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use crate::error::{JConvertError, Result};
+use crate::generate::check_schema;
+
+/// 레코드 스키마 필터
+///
+/// # Examples
+/// ```
+/// use jconvert::SchemaFilter;
+/// use serde_json::json;
+///
+/// let filter = SchemaFilter::new(json!({
+///     "type": "object",
+///     "required": ["id"],
+///     "properties": { "id": { "type": "integer" } }
+/// }))
+/// .unwrap();
+/// assert!(filter.matches(&json!({"id": 1})));
+/// assert!(!filter.matches(&json!({"id": "1"})));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SchemaFilter {
+    schema: Value,
+}
+
+impl SchemaFilter {
+    /// 스키마로 필터 생성 (지원하지 않는 타입이나 잘못된 범위는 에러)
+    pub fn new(schema: Value) -> Result<Self> {
+        check_schema(&schema, "$")?;
+        Ok(Self { schema })
+    }
+
+    /// 스키마 파일을 읽어 필터 생성
+    pub fn load(path: &Path) -> Result<Self> {
+        let invalid = |reason: String| JConvertError::InvalidSchema {
+            reason: format!("{}: {}", path.display(), reason),
+        };
+        let file = File::open(path).map_err(|e| invalid(e.to_string()))?;
+        let schema: Value =
+            serde_json::from_reader(BufReader::new(file)).map_err(|e| invalid(e.to_string()))?;
+        Self::new(schema)
+    }
+
+    /// 레코드가 스키마를 만족하는지 확인
+    pub fn matches(&self, record: &Value) -> bool {
+        self.check(record).is_ok()
+    }
+
+    /// 레코드 검사 (맞지 않으면 처음 발견한 위반 사항을 `$.경로: 이유` 형식으로 반환)
+    pub fn check(&self, record: &Value) -> std::result::Result<(), String> {
+        check_value(&self.schema, record, "$")
+    }
+}
+
+/// 스키마에 대해 값 하나 검사
+fn check_value(schema: &Value, value: &Value, path: &str) -> std::result::Result<(), String> {
+    let violation = |reason: String| Err(format!("{}: {}", path, reason));
+
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        if !values.contains(value) {
+            return violation("enum에 없는 값".to_string());
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if expected != value {
+            return violation("const와 다른 값".to_string());
+        }
+    }
+
+    if let Some(types) = schema_types(schema) {
+        if !types.iter().any(|type_name| is_type(value, type_name)) {
+            return violation(format!(
+                "타입 불일치 (기대: {}, 실제: {})",
+                types.join("|"),
+                type_name(value)
+            ));
+        }
+    }
+
+    let int = |key: &str| schema.get(key).and_then(Value::as_u64);
+    match value {
+        Value::Object(map) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !map.contains_key(key) {
+                        return violation(format!("필수 필드 누락: {}", key));
+                    }
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            let closed = schema.get("additionalProperties") == Some(&Value::Bool(false));
+            for (key, child) in map {
+                match properties.and_then(|properties| properties.get(key)) {
+                    Some(child_schema) => {
+                        check_value(child_schema, child, &format!("{}.{}", path, key))?
+                    }
+                    None if closed => return violation(format!("허용되지 않은 필드: {}", key)),
+                    None => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            if int("minItems").is_some_and(|min| (items.len() as u64) < min) {
+                return violation(format!("요소 수 부족 ({}개)", items.len()));
+            }
+            if int("maxItems").is_some_and(|max| items.len() as u64 > max) {
+                return violation(format!("요소 수 초과 ({}개)", items.len()));
+            }
+            if let Some(item_schema) = schema.get("items") {
+                for item in items {
+                    check_value(item_schema, item, &format!("{}[]", path))?;
+                }
+            }
+        }
+        Value::String(text) => {
+            let len = text.chars().count() as u64;
+            if int("minLength").is_some_and(|min| len < min) {
+                return violation(format!("문자열 길이 부족 ({}자)", len));
+            }
+            if int("maxLength").is_some_and(|max| len > max) {
+                return violation(format!("문자열 길이 초과 ({}자)", len));
+            }
+        }
+        Value::Number(number) => {
+            let number = number.as_f64().unwrap_or_default();
+            let bound = |key: &str| schema.get(key).and_then(Value::as_f64);
+            if bound("minimum").is_some_and(|min| number < min) {
+                return violation(format!("minimum보다 작은 값 ({})", number));
+            }
+            if bound("maximum").is_some_and(|max| number > max) {
+                return violation(format!("maximum보다 큰 값 ({})", number));
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// 스키마의 타입 목록 (`type`이 없으면 `None`, 모든 타입 허용)
+fn schema_types(schema: &Value) -> Option<Vec<&str>> {
+    match schema.get("type") {
+        Some(Value::String(name)) => Some(vec![name.as_str()]),
+        Some(Value::Array(names)) => Some(names.iter().filter_map(Value::as_str).collect()),
+        _ => None,
+    }
+}
+
+/// 값이 JSON Schema 타입에 해당하는지 확인 (`integer`는 소수부가 없는 숫자)
+fn is_type(value: &Value, type_name: &str) -> bool {
+    match type_name {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => false,
+    }
+}
+
+/// 값의 JSON Schema 타입 이름
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn order_filter() -> SchemaFilter {
+        SchemaFilter::new(json!({
+            "type": "object",
+            "required": ["id", "status"],
+            "properties": {
+                "id": { "type": "integer", "minimum": 1 },
+                "status": { "enum": ["paid", "pending"] },
+                "note": { "type": ["string", "null"], "maxLength": 5 },
+                "items": {
+                    "type": "array",
+                    "minItems": 1,
+                    "items": { "type": "object", "properties": { "sku": { "type": "string" } } }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_schema_filter_accepts_conforming_records() {
+        let filter = order_filter();
+        assert!(filter.matches(&json!({"id": 1, "status": "paid"})));
+        assert!(filter.matches(&json!({"id": 2.0, "status": "pending", "note": null})));
+        assert!(filter
+            .matches(&json!({"id": 3, "status": "paid", "items": [{"sku": "A1"}], "extra": true})));
+    }
+
+    #[test]
+    fn test_schema_filter_reports_first_violation() {
+        let filter = order_filter();
+        let reason = |record: Value| filter.check(&record).unwrap_err();
+
+        assert_eq!(reason(json!({"id": 1})), "$: 필수 필드 누락: status");
+        assert_eq!(
+            reason(json!({"id": "1", "status": "paid"})),
+            "$.id: 타입 불일치 (기대: integer, 실제: string)"
+        );
+        assert!(reason(json!({"id": 0, "status": "paid"})).starts_with("$.id: minimum"));
+        assert!(reason(json!({"id": 1, "status": "void"})).starts_with("$.status: enum"));
+        assert!(
+            reason(json!({"id": 1, "status": "paid", "note": "too long"})).contains("길이 초과")
+        );
+        assert!(
+            reason(json!({"id": 1, "status": "paid", "items": [{"sku": 7}]}))
+                .starts_with("$.items[].sku")
+        );
+        assert!(reason(json!([1])).contains("타입 불일치"));
+    }
+
+    #[test]
+    fn test_schema_filter_additional_properties() {
+        let filter = SchemaFilter::new(json!({
+            "properties": { "id": {} },
+            "additionalProperties": false
+        }))
+        .unwrap();
+        assert!(filter.matches(&json!({"id": "any"})));
+        assert_eq!(
+            filter.check(&json!({"id": 1, "debug": true})).unwrap_err(),
+            "$: 허용되지 않은 필드: debug"
+        );
+    }
+}
@@ -0,0 +1,329 @@
+//! 출력 분할 모듈
+//!
+//! 하나의 큰 JSONL 대신 용량이나 레코드 수 기준으로 여러 파일
+//! (`output.0001.jsonl`, `output.0002.jsonl`, ...)에 나누어 기록합니다.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// 분할 기준 (둘 다 지정하면 먼저 도달한 기준으로 분할)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ShardLimits {
+    /// 파일당 최대 바이트 수
+    pub max_bytes: Option<u64>,
+    /// 파일당 최대 레코드 수
+    pub max_records: Option<u64>,
+}
+
+impl ShardLimits {
+    /// 분할 기준이 하나라도 있는지 확인
+    pub fn is_enabled(&self) -> bool {
+        self.max_bytes.is_some() || self.max_records.is_some()
+    }
+}
+
+/// 기록을 마친 분할 파일 정보
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShardInfo {
+    /// 파일 경로
+    pub path: PathBuf,
+    /// 기록된 레코드 수
+    pub records: u64,
+    /// 기록된 바이트 수
+    pub bytes: u64,
+}
+
+/// 용량/레코드 수 기준으로 파일을 바꿔 가며 기록하는 출력
+///
+/// 레코드 경계에서만 파일을 바꾸므로 레코드가 두 파일에 걸치지 않습니다.
+/// 레코드 하나가 용량 기준보다 크면 그 레코드만 담은 파일이 만들어집니다.
+#[derive(Debug)]
+pub struct ShardedOutput {
+    base: PathBuf,
+    limits: ShardLimits,
+    current: Option<BufWriter<File>>,
+    shards: Vec<ShardInfo>,
+    sync_on_rotate: bool,
+    remove_stale: bool,
+}
+
+impl ShardedOutput {
+    /// 분할 출력 생성 (첫 레코드를 쓸 때 첫 파일이 만들어짐)
+    ///
+    /// # Arguments
+    /// * `base` - 기준 출력 경로 (예: `output.jsonl` → `output.0001.jsonl`)
+    /// * `limits` - 분할 기준
+    pub fn new(base: impl Into<PathBuf>, limits: ShardLimits) -> Self {
+        Self {
+            base: base.into(),
+            limits,
+            current: None,
+            shards: Vec::new(),
+            sync_on_rotate: false,
+            remove_stale: false,
+        }
+    }
+
+    /// 분할 파일을 닫을 때마다 fsync할지 설정 (마지막 파일은 `finish` 시점)
+    pub fn with_sync_on_rotate(mut self, sync_on_rotate: bool) -> Self {
+        self.sync_on_rotate = sync_on_rotate;
+        self
+    }
+
+    /// 마칠 때 이번에 쓴 마지막 번호보다 큰 기존 분할 파일을 지울지 설정 (덮어쓰기 모드)
+    ///
+    /// 이전 실행이 더 많은 파일로 나뉘었다면 남은 뒤쪽 파일이 이번 출력과 섞여 읽히지 않도록 지웁니다.
+    pub fn with_remove_stale(mut self, remove_stale: bool) -> Self {
+        self.remove_stale = remove_stale;
+        self
+    }
+
+    /// 줄바꿈을 포함한 레코드 한 줄 기록
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let size = line.len() as u64 + 1; // +1 for newline
+        if self.current.is_none() || self.should_roll(size) {
+            self.roll()?;
+        }
+
+        let writer = self.current.as_mut().expect("현재 분할 파일은 반드시 존재");
+        writeln!(writer, "{}", line)?;
+
+        let shard = self
+            .shards
+            .last_mut()
+            .expect("현재 분할 파일 정보는 반드시 존재");
+        shard.records += 1;
+        shard.bytes += size;
+        Ok(())
+    }
+
+    /// 다음 레코드를 새 파일에 써야 하는지 확인
+    fn should_roll(&self, next_size: u64) -> bool {
+        let Some(shard) = self.shards.last() else {
+            return true;
+        };
+        if shard.records == 0 {
+            return false;
+        }
+
+        let over_records = self
+            .limits
+            .max_records
+            .is_some_and(|max| shard.records >= max);
+        let over_bytes = self
+            .limits
+            .max_bytes
+            .is_some_and(|max| shard.bytes + next_size > max);
+        over_records || over_bytes
+    }
+
+    /// 현재 파일을 닫고 다음 분할 파일 열기
+    fn roll(&mut self) -> io::Result<()> {
+        if let Some(mut writer) = self.current.take() {
+            writer.flush()?;
+            if self.sync_on_rotate {
+                writer.get_ref().sync_data()?;
+            }
+        }
+
+        let path = shard_path(&self.base, self.shards.len() + 1);
+        self.current = Some(BufWriter::new(File::create(&path)?));
+        self.shards.push(ShardInfo {
+            path,
+            records: 0,
+            bytes: 0,
+        });
+        Ok(())
+    }
+
+    /// 버퍼 플러시
+    pub fn flush(&mut self) -> io::Result<()> {
+        match self.current.as_mut() {
+            Some(writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// 버퍼를 비우고 현재 파일을 디스크에 동기화
+    pub fn sync(&mut self) -> io::Result<()> {
+        match self.current.as_mut() {
+            Some(writer) => {
+                writer.flush()?;
+                writer.get_ref().sync_data()
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// 마지막 파일을 닫고 만들어진 분할 파일 목록 반환
+    pub fn finish(mut self) -> io::Result<Vec<ShardInfo>> {
+        if self.sync_on_rotate {
+            self.sync()?;
+        } else {
+            self.flush()?;
+        }
+        if self.remove_stale {
+            self.remove_stale_shards()?;
+        }
+        Ok(self.shards)
+    }
+
+    /// 이번에 쓴 마지막 번호보다 큰 기존 분할 파일 삭제
+    fn remove_stale_shards(&self) -> io::Result<()> {
+        let dir = match self.base.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let stale = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| shard_index(&self.base, name))
+                .is_some_and(|index| index > self.shards.len());
+            if stale {
+                fs::remove_file(entry.path())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 분할 파일 경로 생성 (`dir/output.jsonl`, 1 → `dir/output.0001.jsonl`)
+pub fn shard_path(base: &Path, index: usize) -> PathBuf {
+    let stem = base
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = match base.extension() {
+        Some(ext) => format!("{}.{:04}.{}", stem, index, ext.to_string_lossy()),
+        None => format!("{}.{:04}", stem, index),
+    };
+    base.with_file_name(name)
+}
+
+/// 파일 이름이 기준 경로의 분할 파일 이름(`output.0001.jsonl` 등)인지 확인
+pub fn is_shard_name(base: &Path, file_name: &str) -> bool {
+    shard_index(base, file_name).is_some()
+}
+
+/// 분할 파일 이름의 번호 (`output.0003.jsonl` → 3, 분할 파일 이름이 아니면 None)
+fn shard_index(base: &Path, file_name: &str) -> Option<usize> {
+    let stem = base
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let rest = file_name
+        .strip_prefix(stem.as_str())
+        .and_then(|rest| rest.strip_prefix('.'))?;
+    let index = match base.extension() {
+        Some(ext) => rest.strip_suffix(&format!(".{}", ext.to_string_lossy()))?,
+        None => rest,
+    };
+    if index.len() >= 4 && index.bytes().all(|b| b.is_ascii_digit()) {
+        index.parse().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_shard_path() {
+        assert_eq!(
+            shard_path(Path::new("out/result.jsonl"), 1),
+            PathBuf::from("out/result.0001.jsonl")
+        );
+        assert_eq!(
+            shard_path(Path::new("result"), 12),
+            PathBuf::from("result.0012")
+        );
+    }
+
+    #[test]
+    fn test_is_shard_name() {
+        let base = Path::new("out/result.json");
+        assert!(is_shard_name(base, "result.0001.json"));
+        assert!(is_shard_name(base, "result.12345.json"));
+        assert!(!is_shard_name(base, "result.json"));
+        assert!(!is_shard_name(base, "result.01.json"));
+        assert!(!is_shard_name(base, "result.0001.jsonl"));
+        assert!(!is_shard_name(base, "other.0001.json"));
+    }
+
+    #[test]
+    fn test_split_by_records() {
+        let dir = TempDir::new().unwrap();
+        let limits = ShardLimits {
+            max_records: Some(2),
+            ..Default::default()
+        };
+        let mut output = ShardedOutput::new(dir.path().join("out.jsonl"), limits);
+        for i in 0..5 {
+            output.write_line(&format!("{{\"id\":{}}}", i)).unwrap();
+        }
+        let shards = output.finish().unwrap();
+
+        assert_eq!(
+            shards.iter().map(|s| s.records).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        let last = std::fs::read_to_string(dir.path().join("out.0003.jsonl")).unwrap();
+        assert_eq!(last, "{\"id\":4}\n");
+    }
+
+    #[test]
+    fn test_overwrite_removes_stale_shards() {
+        let dir = TempDir::new().unwrap();
+        let base = dir.path().join("out.jsonl");
+        for index in 1..=4 {
+            std::fs::write(shard_path(&base, index), "old\n").unwrap();
+        }
+        let other = dir.path().join("out.0009.json");
+        std::fs::write(&other, "keep\n").unwrap();
+
+        let limits = ShardLimits {
+            max_records: Some(1),
+            ..Default::default()
+        };
+        let mut output = ShardedOutput::new(&base, limits).with_remove_stale(true);
+        output.write_line("a").unwrap();
+        output.write_line("b").unwrap();
+        output.finish().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(shard_path(&base, 2)).unwrap(),
+            "b\n"
+        );
+        assert!(!shard_path(&base, 3).exists());
+        assert!(!shard_path(&base, 4).exists());
+        // 분할 파일 이름이 아닌 파일은 그대로
+        assert!(other.exists());
+    }
+
+    #[test]
+    fn test_split_by_bytes() {
+        let dir = TempDir::new().unwrap();
+        let limits = ShardLimits {
+            max_bytes: Some(10),
+            ..Default::default()
+        };
+        let mut output = ShardedOutput::new(dir.path().join("out.jsonl"), limits);
+        // 4+1, 4+1 → 10바이트, 다음 레코드는 새 파일 / 큰 레코드는 단독 파일
+        for line in ["aaaa", "bbbb", "cccc", "dddddddddddd", "e"] {
+            output.write_line(line).unwrap();
+        }
+        let shards = output.finish().unwrap();
+
+        assert_eq!(
+            shards.iter().map(|s| s.bytes).collect::<Vec<_>>(),
+            vec![10, 5, 13, 2]
+        );
+        assert!(shards.iter().all(|s| s.path.exists()));
+    }
+}
@@ -0,0 +1,406 @@
+//! 레코드 정렬 모듈
+//!
+//! 출력 레코드를 키 필드 값으로 정렬합니다 (`--sort-by`).
+//! 정렬할 레코드가 메모리 한도를 넘으면 정렬된 조각을 임시 파일로 내보낸 뒤
+//! 조각들을 k-way 병합하는 외부 정렬을 사용하므로 메모리보다 큰 데이터도 정렬할 수 있습니다.
+//!
+//! 숫자 값은 수치로, 문자열 등 나머지 값은 사전순으로 비교하며, 숫자가 문자열보다 앞에 옵니다.
+//! 키가 없거나 `null`인 레코드는 정렬 방향과 관계없이 맨 뒤에 두고,
+//! 키가 같은 레코드는 원래 순서를 유지합니다 (안정 정렬).
+
+use serde_json::Value;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::{self, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crate::processor::get_nested_field;
+use crate::spill::{self, SpillDir, SpillEntry};
+
+/// 정렬 버퍼 기본 메모리 한도 (256MB)
+pub const DEFAULT_SORT_MEMORY: u64 = 256 * 1024 * 1024;
+
+/// 정렬 키 종류 접두사 (종류 사이의 순서: 숫자 → 문자열 등 → 키 없음)
+const NUMBER_CLASS: char = '0';
+const TEXT_CLASS: char = '1';
+const MISSING_CLASS: char = '2';
+
+/// 외부 정렬기
+///
+/// 레코드를 버퍼에 모으다가 메모리 한도를 넘으면 정렬하여 임시 파일(run)로 내보내고,
+/// `finish` 시점에 run들을 병합하여 정렬된 순서로 전달합니다.
+/// 한 번도 한도를 넘지 않으면 임시 파일 없이 메모리에서 정렬합니다.
+#[derive(Debug)]
+pub struct RecordSorter {
+    key_path: String,
+    descending: bool,
+    memory_limit: u64,
+    limit: Option<u64>,
+    temp_dir: Option<PathBuf>,
+    dir: Option<SpillDir>,
+    buffer: Vec<SpillEntry>,
+    buffer_bytes: u64,
+    runs: Vec<PathBuf>,
+    next_seq: u64,
+}
+
+impl RecordSorter {
+    /// 정렬기 생성
+    ///
+    /// # Arguments
+    /// * `key_path` - 정렬 기준 필드 (점 표기법으로 중첩 필드 지정 가능)
+    pub fn new(key_path: impl Into<String>) -> Self {
+        Self {
+            key_path: key_path.into(),
+            descending: false,
+            memory_limit: DEFAULT_SORT_MEMORY,
+            limit: None,
+            temp_dir: None,
+            dir: None,
+            buffer: Vec::new(),
+            buffer_bytes: 0,
+            runs: Vec::new(),
+            next_seq: 0,
+        }
+    }
+
+    /// 내림차순 정렬 여부 설정
+    pub fn with_descending(mut self, descending: bool) -> Self {
+        self.descending = descending;
+        self
+    }
+
+    /// 메모리에 모아 둘 레코드의 최대 용량 설정 (넘으면 임시 파일로 내보냄)
+    pub fn with_memory_limit(mut self, memory_limit: u64) -> Self {
+        self.memory_limit = memory_limit;
+        self
+    }
+
+    /// 정렬 후 전달할 최대 레코드 수 설정
+    pub fn with_limit(mut self, limit: Option<u64>) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// 임시 파일을 만들 폴더 설정 (None이면 시스템 임시 폴더)
+    pub fn with_temp_dir(mut self, temp_dir: Option<PathBuf>) -> Self {
+        self.temp_dir = temp_dir;
+        self
+    }
+
+    /// 레코드 추가 (키는 `json_line`에서 추출하고 출력에는 `output`을 사용)
+    pub fn push_as(&mut self, json_line: &str, output: &str) -> io::Result<()> {
+        let key = sort_key(json_line, &self.key_path);
+        self.buffer_bytes += (key.len() + output.len()) as u64;
+        self.buffer.push(SpillEntry {
+            seq: self.next_seq,
+            key,
+            line: output.to_string(),
+        });
+        self.next_seq += 1;
+
+        if self.buffer_bytes >= self.memory_limit {
+            self.spill_buffer()?;
+        }
+        Ok(())
+    }
+
+    /// 임시 파일로 내보낸 정렬 조각 수
+    pub fn spilled_runs(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// 버퍼를 정렬하여 임시 파일 하나로 내보냄
+    fn spill_buffer(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        if self.dir.is_none() {
+            self.dir = Some(SpillDir::new(self.temp_dir.as_deref())?);
+        }
+        let path = self
+            .dir
+            .as_ref()
+            .expect("임시 폴더는 위에서 생성됨")
+            .file(&format!("run-{}", self.runs.len()));
+
+        self.sort_buffer();
+        let mut writer = spill::create_run(&path)?;
+        for entry in self.buffer.drain(..) {
+            spill::write_entry(&mut writer, &entry)?;
+        }
+        writer.flush()?;
+
+        self.buffer_bytes = 0;
+        self.runs.push(path);
+        Ok(())
+    }
+
+    /// 버퍼를 정렬 순서로 정렬
+    fn sort_buffer(&mut self) {
+        let descending = self.descending;
+        self.buffer
+            .sort_unstable_by(|a, b| compare_entries(a, b, descending));
+    }
+
+    /// 모든 레코드를 정렬된 순서로 전달
+    pub fn finish<F>(mut self, mut emit: F) -> io::Result<()>
+    where
+        F: FnMut(&str) -> io::Result<()>,
+    {
+        let mut remaining = self.limit.unwrap_or(u64::MAX);
+
+        if self.runs.is_empty() {
+            self.sort_buffer();
+            for entry in self.buffer.iter().take(remaining as usize) {
+                emit(&entry.line)?;
+            }
+            return Ok(());
+        }
+
+        self.spill_buffer()?;
+        merge_sorted_runs(&self.runs, self.descending, |entry| {
+            if remaining == 0 {
+                return Ok(false);
+            }
+            remaining -= 1;
+            emit(&entry.line)?;
+            Ok(true)
+        })
+    }
+}
+
+/// 정렬 순서로 두 항목 비교 (키가 같으면 원래 순서)
+fn compare_entries(a: &SpillEntry, b: &SpillEntry, descending: bool) -> Ordering {
+    compare_keys(&a.key, &b.key, descending).then(a.seq.cmp(&b.seq))
+}
+
+/// 인코딩된 정렬 키 비교 (키 종류 순서는 방향과 관계없이 유지하여 키 없는 레코드는 항상 뒤로)
+fn compare_keys(a: &str, b: &str, descending: bool) -> Ordering {
+    let (a_class, a_value) = a.split_at(1);
+    let (b_class, b_value) = b.split_at(1);
+    a_class.cmp(b_class).then_with(|| {
+        let order = a_value.cmp(b_value);
+        if descending {
+            order.reverse()
+        } else {
+            order
+        }
+    })
+}
+
+/// 레코드에서 바이트 순서로 비교할 수 있는 정렬 키 추출
+///
+/// 숫자는 크기 순서를 보존하는 16자리 16진수로, 나머지 값은 문자열 그대로
+/// (문자열이 아니면 JSON 표현으로) 인코딩하고 키 종류 접두사를 붙입니다.
+fn sort_key(json_line: &str, key_path: &str) -> String {
+    let record: Option<Value> = serde_json::from_str(json_line).ok();
+    match record.as_ref().and_then(|r| get_nested_field(r, key_path)) {
+        None | Some(Value::Null) => MISSING_CLASS.to_string(),
+        Some(Value::Number(number)) => match number.as_f64() {
+            Some(value) => format!("{}{:016x}", NUMBER_CLASS, ordered_bits(value)),
+            None => format!("{}{}", TEXT_CLASS, number),
+        },
+        Some(Value::String(text)) => format!("{}{}", TEXT_CLASS, text),
+        Some(other) => format!("{}{}", TEXT_CLASS, other),
+    }
+}
+
+/// 부호 없는 정수 비교가 실수 크기 순서와 같아지도록 f64 비트 변환
+fn ordered_bits(value: f64) -> u64 {
+    let bits = value.to_bits();
+    if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits | (1 << 63)
+    }
+}
+
+/// 각각 정렬된 run 파일들을 정렬 순서대로 병합
+///
+/// `emit`이 false를 반환하면 병합을 멈춥니다.
+fn merge_sorted_runs<F>(runs: &[PathBuf], descending: bool, mut emit: F) -> io::Result<()>
+where
+    F: FnMut(SpillEntry) -> io::Result<bool>,
+{
+    /// 힙에서 정렬 순서로 비교되는 run의 맨 앞 항목
+    struct Head {
+        entry: SpillEntry,
+        run: usize,
+        descending: bool,
+    }
+
+    impl PartialEq for Head {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == Ordering::Equal
+        }
+    }
+
+    impl Eq for Head {}
+
+    impl PartialOrd for Head {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Head {
+        fn cmp(&self, other: &Self) -> Ordering {
+            compare_entries(&self.entry, &other.entry, self.descending)
+        }
+    }
+
+    let mut readers = runs
+        .iter()
+        .map(|path| open_run(path))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let mut heap = BinaryHeap::with_capacity(readers.len());
+    for (run, reader) in readers.iter_mut().enumerate() {
+        if let Some(entry) = spill::read_entry(reader)? {
+            heap.push(Reverse(Head {
+                entry,
+                run,
+                descending,
+            }));
+        }
+    }
+
+    while let Some(Reverse(head)) = heap.pop() {
+        let run = head.run;
+        if !emit(head.entry)? {
+            break;
+        }
+        if let Some(entry) = spill::read_entry(&mut readers[run])? {
+            heap.push(Reverse(Head {
+                entry,
+                run,
+                descending,
+            }));
+        }
+    }
+    Ok(())
+}
+
+/// run 파일 읽기 핸들 생성
+fn open_run(path: &Path) -> io::Result<BufReader<File>> {
+    File::open(path).map(BufReader::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(sorter: RecordSorter, lines: &[&str]) -> Vec<String> {
+        let mut sorter = sorter;
+        for line in lines {
+            sorter.push_as(line, line).unwrap();
+        }
+        let mut output = Vec::new();
+        sorter
+            .finish(|line| {
+                output.push(line.to_string());
+                Ok(())
+            })
+            .unwrap();
+        output
+    }
+
+    const LINES: &[&str] = &[
+        r#"{"t":10,"v":"a"}"#,
+        r#"{"t":"b"}"#,
+        r#"{"v":"no-key"}"#,
+        r#"{"t":-2.5}"#,
+        r#"{"t":9}"#,
+        r#"{"t":10,"v":"b"}"#,
+        r#"{"t":"a"}"#,
+        r#"{"t":null}"#,
+    ];
+
+    #[test]
+    fn test_sort_numbers_then_text_then_missing() {
+        assert_eq!(
+            sorted(RecordSorter::new("t"), LINES),
+            vec![
+                r#"{"t":-2.5}"#,
+                r#"{"t":9}"#,
+                r#"{"t":10,"v":"a"}"#,
+                r#"{"t":10,"v":"b"}"#,
+                r#"{"t":"a"}"#,
+                r#"{"t":"b"}"#,
+                r#"{"v":"no-key"}"#,
+                r#"{"t":null}"#,
+            ]
+        );
+
+        // 내림차순에서도 같은 키는 원래 순서, 키 없는 레코드는 맨 뒤
+        assert_eq!(
+            sorted(RecordSorter::new("t").with_descending(true), LINES),
+            vec![
+                r#"{"t":10,"v":"a"}"#,
+                r#"{"t":10,"v":"b"}"#,
+                r#"{"t":9}"#,
+                r#"{"t":-2.5}"#,
+                r#"{"t":"b"}"#,
+                r#"{"t":"a"}"#,
+                r#"{"v":"no-key"}"#,
+                r#"{"t":null}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_external_sort_matches_in_memory() {
+        let lines: Vec<String> = (0..200)
+            .map(|i| format!(r#"{{"n":{},"i":{}}}"#, (i * 37) % 50, i))
+            .collect();
+        let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+
+        for descending in [false, true] {
+            let expected = sorted(RecordSorter::new("n").with_descending(descending), &lines);
+
+            let mut sorter = RecordSorter::new("n")
+                .with_descending(descending)
+                .with_memory_limit(256);
+            for line in &lines {
+                sorter.push_as(line, line).unwrap();
+            }
+            assert!(sorter.spilled_runs() > 1);
+            let mut output = Vec::new();
+            sorter
+                .finish(|line| {
+                    output.push(line.to_string());
+                    Ok(())
+                })
+                .unwrap();
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn test_sort_with_limit() {
+        let lines = [r#"{"n":3}"#, r#"{"n":1}"#, r#"{"n":2}"#];
+        assert_eq!(
+            sorted(RecordSorter::new("n").with_limit(Some(2)), &lines),
+            vec![r#"{"n":1}"#, r#"{"n":2}"#]
+        );
+        assert_eq!(
+            sorted(
+                RecordSorter::new("n")
+                    .with_limit(Some(2))
+                    .with_memory_limit(1),
+                &lines
+            ),
+            vec![r#"{"n":1}"#, r#"{"n":2}"#]
+        );
+    }
+
+    #[test]
+    fn test_ordered_bits() {
+        let values = [-1e10, -1.5, -0.0, 0.0, 1e-3, 2.0, 1e300];
+        for pair in values.windows(2) {
+            assert!(ordered_bits(pair[0]) <= ordered_bits(pair[1]), "{:?}", pair);
+        }
+    }
+}
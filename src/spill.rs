@@ -0,0 +1,217 @@
+//! 디스크 스필 모듈
+//!
+//! 메모리에 담기 어려운 레코드 집합을 임시 파일로 내보내고 다시 병합하는
+//! 기본 도구를 제공합니다. 디스크 기반 중복 제거 등에서 사용합니다.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 스필 파일에 기록되는 레코드 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpillEntry {
+    /// 원래 출력 순서
+    pub seq: u64,
+    /// 정렬/그룹화용 키 (없으면 빈 문자열)
+    pub key: String,
+    /// 직렬화된 레코드
+    pub line: String,
+}
+
+/// 실행 동안만 존재하는 임시 스필 폴더 (drop 시 삭제)
+#[derive(Debug)]
+pub struct SpillDir {
+    path: PathBuf,
+}
+
+impl SpillDir {
+    /// 상위 폴더 아래에 고유한 임시 폴더 생성
+    ///
+    /// # Arguments
+    /// * `parent` - 임시 폴더를 만들 위치 (None이면 시스템 임시 폴더)
+    pub fn new(parent: Option<&Path>) -> io::Result<Self> {
+        let parent = parent
+            .map(Path::to_path_buf)
+            .unwrap_or_else(std::env::temp_dir);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+
+        let path = parent.join(format!("jconvert-spill-{}-{}", std::process::id(), nanos));
+        fs::create_dir_all(&path)?;
+        Ok(Self { path })
+    }
+
+    /// 폴더 안의 파일 경로 반환
+    pub fn file(&self, name: &str) -> PathBuf {
+        self.path.join(name)
+    }
+
+    /// 폴더 경로 반환
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// 스필 항목을 길이 접두 형식으로 기록
+///
+/// 레코드에 줄바꿈이 있어도(예: pretty 출력) 안전하도록
+/// `seq(u64) | key_len(u32) | key | line_len(u32) | line` 순서로 기록합니다.
+pub fn write_entry<W: Write>(writer: &mut W, entry: &SpillEntry) -> io::Result<()> {
+    writer.write_all(&entry.seq.to_le_bytes())?;
+    writer.write_all(&(entry.key.len() as u32).to_le_bytes())?;
+    writer.write_all(entry.key.as_bytes())?;
+    writer.write_all(&(entry.line.len() as u32).to_le_bytes())?;
+    writer.write_all(entry.line.as_bytes())
+}
+
+/// 스필 항목 하나 읽기 (파일 끝이면 None)
+pub fn read_entry<R: Read>(reader: &mut R) -> io::Result<Option<SpillEntry>> {
+    let mut seq = [0u8; 8];
+    match reader.read_exact(&mut seq) {
+        Ok(()) => {}
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+
+    let key = read_string(reader)?;
+    let line = read_string(reader)?;
+    Ok(Some(SpillEntry {
+        seq: u64::from_le_bytes(seq),
+        key,
+        line,
+    }))
+}
+
+/// 길이 접두 문자열 읽기
+fn read_string<R: Read>(reader: &mut R) -> io::Result<String> {
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let mut buffer = vec![0u8; u32::from_le_bytes(len) as usize];
+    reader.read_exact(&mut buffer)?;
+    String::from_utf8(buffer).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+}
+
+/// 스필 파일 쓰기 핸들 생성
+pub fn create_run(path: &Path) -> io::Result<BufWriter<File>> {
+    Ok(BufWriter::new(File::create(path)?))
+}
+
+/// 스필 파일 전체 읽기
+pub fn read_run(path: &Path) -> io::Result<Vec<SpillEntry>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut entries = Vec::new();
+    while let Some(entry) = read_entry(&mut reader)? {
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// 각각 `seq` 오름차순인 스필 파일들을 원래 순서대로 병합
+///
+/// 파일마다 항목 하나씩만 메모리에 두는 k-way 병합이므로
+/// 전체 데이터 크기와 무관하게 메모리 사용량이 일정합니다.
+pub fn merge_runs_by_seq<F>(runs: &[PathBuf], mut emit: F) -> io::Result<()>
+where
+    F: FnMut(SpillEntry) -> io::Result<()>,
+{
+    let mut readers = runs
+        .iter()
+        .map(|path| File::open(path).map(BufReader::new))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let mut heads: Vec<Option<SpillEntry>> = Vec::with_capacity(readers.len());
+    let mut heap = BinaryHeap::new();
+    for (index, reader) in readers.iter_mut().enumerate() {
+        let head = read_entry(reader)?;
+        if let Some(ref entry) = head {
+            heap.push(Reverse((entry.seq, index)));
+        }
+        heads.push(head);
+    }
+
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let entry = heads[index].take().expect("힙에 있는 항목은 반드시 존재");
+        let next = read_entry(&mut readers[index])?;
+        if let Some(ref next_entry) = next {
+            heap.push(Reverse((next_entry.seq, index)));
+        }
+        heads[index] = next;
+        emit(entry)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(seq: u64, key: &str, line: &str) -> SpillEntry {
+        SpillEntry {
+            seq,
+            key: key.to_string(),
+            line: line.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_entry_roundtrip_with_newlines() {
+        let dir = SpillDir::new(None).unwrap();
+        let path = dir.file("run");
+
+        let mut writer = create_run(&path).unwrap();
+        write_entry(&mut writer, &entry(1, "a", "{\n  \"id\": 1\n}")).unwrap();
+        write_entry(&mut writer, &entry(2, "", "{}")).unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+
+        let entries = read_run(&path).unwrap();
+        assert_eq!(
+            entries,
+            vec![entry(1, "a", "{\n  \"id\": 1\n}"), entry(2, "", "{}")]
+        );
+    }
+
+    #[test]
+    fn test_merge_runs_by_seq() {
+        let dir = SpillDir::new(None).unwrap();
+        let runs = vec![dir.file("a"), dir.file("b")];
+
+        for (path, seqs) in runs.iter().zip([[0u64, 3, 4], [1, 2, 5]]) {
+            let mut writer = create_run(path).unwrap();
+            for seq in seqs {
+                write_entry(&mut writer, &entry(seq, "", &seq.to_string())).unwrap();
+            }
+            writer.flush().unwrap();
+        }
+
+        let mut merged = Vec::new();
+        merge_runs_by_seq(&runs, |e| {
+            merged.push(e.seq);
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(merged, vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_spill_dir_removed_on_drop() {
+        let dir = SpillDir::new(None).unwrap();
+        let path = dir.path().to_path_buf();
+        assert!(path.exists());
+        drop(dir);
+        assert!(!path.exists());
+    }
+}
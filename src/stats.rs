@@ -1,11 +1,19 @@
 //! 통계 및 유틸리티 모듈
 //!
 //! 처리 통계 수집 및 포맷팅을 담당합니다.
+//! `--stats-detail`을 지정하면 하위 폴더별 집계와 가장 크거나 느린 파일 목록도 함께 수집합니다.
 
 use colored::Colorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
+use crate::parser::ParserKind;
+use crate::processor::ProcessResult;
+use crate::status;
+
 /// 처리 통계 구조체
 #[derive(Debug, Default)]
 pub struct Statistics {
@@ -21,10 +29,124 @@ pub struct Statistics {
     pub total_bytes_written: AtomicU64,
     /// 유효성 검사 실패 수
     pub validation_failed: AtomicUsize,
+    /// 읽기 권한이 없는 파일 수
+    pub unreadable_count: AtomicUsize,
+    /// 중복 제거된 레코드 수
+    pub duplicate_count: AtomicUsize,
+    /// 내용이 같아 제외한 입력 파일 수 (`--dedup-content`)
+    pub duplicate_files: usize,
+    /// 내용이 같아 제외한 입력 파일의 전체 크기
+    pub duplicate_bytes: u64,
+    /// 스키마 변화가 감지된 파일 수
+    pub schema_drift_count: AtomicUsize,
+    /// 조건 필터로 제외된 레코드 수
+    pub skipped_count: AtomicUsize,
+    /// 스키마 필터에 맞지 않아 제외된 레코드 수
+    pub rejected_count: AtomicUsize,
+    /// 샘플링에서 빠진 레코드 수
+    pub sampled_out_count: AtomicUsize,
+    /// 파싱 전에 UTF-8로 다시 인코딩한 파일 수
+    pub reencoded_count: AtomicUsize,
+    /// 레코드 경계를 고친 JSONL 레코드 수
+    pub repaired_count: AtomicUsize,
+    /// 크기 제한을 넘어 건너뛰거나 필드를 잘라 낸 레코드 수
+    pub oversized_count: AtomicUsize,
+    /// 파일 단위 파싱에 사용한 JSON 파서
+    pub parser: ParserKind,
+    /// 폴더별/파일별 상세 통계 (`--stats-detail`일 때만)
+    detail: Option<Mutex<StatsDetail>>,
     /// 처리 시작 시간
     start_time: Option<Instant>,
 }
 
+/// 상세 통계에 남길 가장 크거나 느린 파일 수
+pub const TOP_FILES: usize = 10;
+
+/// 하위 폴더 하나의 집계
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DirectoryStats {
+    /// 처리한 파일 수
+    pub files: usize,
+    /// 실패한 파일 수
+    pub failed: usize,
+    /// 읽은 바이트 수
+    pub bytes: u64,
+}
+
+/// 파일 하나의 크기와 처리 시간
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileStat {
+    /// 입력 폴더 기준 상대 경로
+    pub path: String,
+    /// 파일 크기
+    pub bytes: u64,
+    /// 처리 시간 (초)
+    pub elapsed_secs: f64,
+    /// 실패 여부
+    pub failed: bool,
+}
+
+/// 폴더별 집계와 가장 크거나 느린 파일 목록 (`--stats-detail`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StatsDetail {
+    /// 하위 폴더별 집계 (입력 폴더 바로 아래 파일은 `.`)
+    pub directories: BTreeMap<String, DirectoryStats>,
+    /// 가장 큰 파일 (큰 순서)
+    pub largest: Vec<FileStat>,
+    /// 처리 시간이 가장 긴 파일 (느린 순서)
+    pub slowest: Vec<FileStat>,
+}
+
+impl StatsDetail {
+    /// 파일 하나의 결과 추가
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::stats::{FileStat, StatsDetail};
+    ///
+    /// let mut detail = StatsDetail::default();
+    /// for (path, bytes) in [("a/1.json", 10), ("a/2.json", 30), ("b/3.json", 20)] {
+    ///     detail.record(FileStat { path: path.to_string(), bytes, elapsed_secs: 0.0, failed: false });
+    /// }
+    /// assert_eq!(detail.directories["a"].bytes, 40);
+    /// assert_eq!(detail.largest[0].path, "a/2.json");
+    /// ```
+    pub fn record(&mut self, file: FileStat) {
+        let directory = match file.path.rsplit_once('/') {
+            Some((directory, _)) if !directory.is_empty() => directory.to_string(),
+            _ => ".".to_string(),
+        };
+        let entry = self.directories.entry(directory).or_default();
+        entry.files += 1;
+        entry.bytes += file.bytes;
+        if file.failed {
+            entry.failed += 1;
+        }
+
+        push_top(&mut self.largest, file.clone(), |a, b| a.bytes > b.bytes);
+        push_top(&mut self.slowest, file, |a, b| {
+            a.elapsed_secs > b.elapsed_secs
+        });
+    }
+}
+
+/// 순위 목록에 파일을 넣고 `TOP_FILES`개만 남김 (`ahead(a, b)`: a가 b보다 앞 순위)
+fn push_top(
+    list: &mut Vec<FileStat>,
+    file: FileStat,
+    ahead: impl Fn(&FileStat, &FileStat) -> bool,
+) {
+    if list.len() == TOP_FILES && !list.last().is_some_and(|last| ahead(&file, last)) {
+        return;
+    }
+    let index = list
+        .iter()
+        .position(|other| ahead(&file, other))
+        .unwrap_or(list.len());
+    list.insert(index, file);
+    list.truncate(TOP_FILES);
+}
+
 impl Statistics {
     /// 새 통계 인스턴스 생성
     pub fn new(total_files: usize) -> Self {
@@ -35,6 +157,42 @@ impl Statistics {
         }
     }
 
+    /// 사용한 JSON 파서 설정
+    pub fn with_parser(mut self, parser: ParserKind) -> Self {
+        self.parser = parser;
+        self
+    }
+
+    /// 내용이 같아 제외한 입력 파일 설정
+    pub fn with_duplicate_files(mut self, files: usize, bytes: u64) -> Self {
+        self.duplicate_files = files;
+        self.duplicate_bytes = bytes;
+        self
+    }
+
+    /// 폴더별/파일별 상세 통계 수집 설정
+    pub fn with_detail(mut self, enabled: bool) -> Self {
+        self.detail = enabled.then(|| Mutex::new(StatsDetail::default()));
+        self
+    }
+
+    /// 파일 하나의 결과를 상세 통계에 추가 (상세 통계를 수집하지 않으면 무시)
+    pub fn record_file(&self, file: FileStat) {
+        if let Some(ref detail) = self.detail {
+            detail
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .record(file);
+        }
+    }
+
+    /// 상세 통계 사본 반환 (수집하지 않으면 None)
+    pub fn detail(&self) -> Option<StatsDetail> {
+        self.detail
+            .as_ref()
+            .map(|detail| detail.lock().unwrap_or_else(|e| e.into_inner()).clone())
+    }
+
     /// 성공 카운트 증가
     pub fn increment_success(&self) {
         self.success_count.fetch_add(1, Ordering::Relaxed);
@@ -50,6 +208,61 @@ impl Statistics {
         self.validation_failed.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// 읽기 권한 없음 카운트 증가
+    pub fn increment_unreadable(&self) {
+        self.unreadable_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 중복 제거 카운트 증가
+    pub fn increment_duplicate(&self) {
+        self.duplicate_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 중복 제거 카운트를 한꺼번에 추가
+    pub fn add_duplicates(&self, count: usize) {
+        self.duplicate_count.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// 스키마 변화 감지 카운트 증가
+    pub fn increment_schema_drift(&self) {
+        self.schema_drift_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 조건 필터 제외 카운트 증가
+    pub fn increment_skipped(&self) {
+        self.skipped_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 조건 필터 제외 카운트를 한꺼번에 추가
+    pub fn add_skipped(&self, count: usize) {
+        self.skipped_count.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// 스키마 필터 제외 카운트를 한꺼번에 추가
+    pub fn add_rejected(&self, count: usize) {
+        self.rejected_count.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// 샘플링 제외 카운트를 한꺼번에 추가
+    pub fn add_sampled_out(&self, count: usize) {
+        self.sampled_out_count.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// 다시 인코딩한 파일 카운트 증가
+    pub fn increment_reencoded(&self) {
+        self.reencoded_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 경계를 고친 JSONL 레코드 카운트를 한꺼번에 추가
+    pub fn add_repaired(&self, count: usize) {
+        self.repaired_count.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// 크기 제한을 넘은 레코드 카운트를 한꺼번에 추가
+    pub fn add_oversized(&self, count: usize) {
+        self.oversized_count.fetch_add(count, Ordering::Relaxed);
+    }
+
     /// 읽은 바이트 추가
     pub fn add_bytes_read(&self, bytes: u64) {
         self.total_bytes_read.fetch_add(bytes, Ordering::Relaxed);
@@ -60,6 +273,32 @@ impl Statistics {
         self.total_bytes_written.fetch_add(bytes, Ordering::Relaxed);
     }
 
+    /// 파일 하나의 처리 결과 집계
+    ///
+    /// 실패한 파일은 에러(읽기 권한이 없으면 읽기 권한 없음)로 세고, 성공한 파일은 읽은 바이트와
+    /// 레코드 단위 카운트(조건 필터·샘플링 제외, 재인코딩, 경계 수정, 크기 제한 초과)를 더합니다.
+    /// 쓴 바이트는 출력 단계에서 따로 셉니다.
+    pub fn record_result(&self, result: &ProcessResult) {
+        if result.error.is_some() {
+            if result.is_unreadable {
+                self.increment_unreadable();
+            } else {
+                self.increment_error();
+            }
+            return;
+        }
+
+        self.add_bytes_read(result.file_size);
+        self.increment_success();
+        self.add_skipped(result.skipped_records);
+        self.add_sampled_out(result.sampled_out_records);
+        if result.reencoded {
+            self.increment_reencoded();
+        }
+        self.add_repaired(result.repaired_records);
+        self.add_oversized(result.oversized_records);
+    }
+
     /// 성공 수 반환
     pub fn get_success_count(&self) -> usize {
         self.success_count.load(Ordering::Relaxed)
@@ -75,6 +314,61 @@ impl Statistics {
         self.validation_failed.load(Ordering::Relaxed)
     }
 
+    /// 읽기 권한이 없는 파일 수 반환
+    pub fn get_unreadable_count(&self) -> usize {
+        self.unreadable_count.load(Ordering::Relaxed)
+    }
+
+    /// 중복 제거된 레코드 수 반환
+    pub fn get_duplicate_count(&self) -> usize {
+        self.duplicate_count.load(Ordering::Relaxed)
+    }
+
+    /// 스키마 변화가 감지된 파일 수 반환
+    pub fn get_schema_drift_count(&self) -> usize {
+        self.schema_drift_count.load(Ordering::Relaxed)
+    }
+
+    /// 조건 필터로 제외된 레코드 수 반환
+    pub fn get_skipped_count(&self) -> usize {
+        self.skipped_count.load(Ordering::Relaxed)
+    }
+
+    /// 스키마 필터에 맞지 않아 제외된 레코드 수 반환
+    pub fn get_rejected_count(&self) -> usize {
+        self.rejected_count.load(Ordering::Relaxed)
+    }
+
+    /// 샘플링에서 빠진 레코드 수 반환
+    pub fn get_sampled_out_count(&self) -> usize {
+        self.sampled_out_count.load(Ordering::Relaxed)
+    }
+
+    /// 다시 인코딩한 파일 수 반환
+    pub fn get_reencoded_count(&self) -> usize {
+        self.reencoded_count.load(Ordering::Relaxed)
+    }
+
+    /// 경계를 고친 JSONL 레코드 수 반환
+    pub fn get_repaired_count(&self) -> usize {
+        self.repaired_count.load(Ordering::Relaxed)
+    }
+
+    /// 크기 제한을 넘은 레코드 수 반환
+    pub fn get_oversized_count(&self) -> usize {
+        self.oversized_count.load(Ordering::Relaxed)
+    }
+
+    /// 읽은 바이트 수 반환
+    pub fn get_bytes_read(&self) -> u64 {
+        self.total_bytes_read.load(Ordering::Relaxed)
+    }
+
+    /// 기록한 바이트 수 반환
+    pub fn get_bytes_written(&self) -> u64 {
+        self.total_bytes_written.load(Ordering::Relaxed)
+    }
+
     /// 경과 시간 반환
     pub fn elapsed(&self) -> Duration {
         self.start_time
@@ -82,6 +376,26 @@ impl Statistics {
             .unwrap_or(Duration::ZERO)
     }
 
+    /// 입력 처리 속도 (MB/s, 읽은 바이트 기준)
+    pub fn throughput_mb_per_sec(&self) -> f64 {
+        let secs = self.elapsed().as_secs_f64();
+        if secs > 0.0 {
+            self.get_bytes_read() as f64 / (1024.0 * 1024.0) / secs
+        } else {
+            0.0
+        }
+    }
+
+    /// 파서와 처리 속도 출력
+    fn print_throughput(&self) {
+        status!("  {} 파서:         {}", "⚡".bright_yellow(), self.parser);
+        status!(
+            "  {} 처리 속도:    {:.1} MB/s",
+            "🚀".bright_cyan(),
+            self.throughput_mb_per_sec()
+        );
+    }
+
     /// 일반 처리 통계 요약 출력
     pub fn print_summary(&self) {
         let success = self.get_success_count();
@@ -90,37 +404,120 @@ impl Statistics {
         let bytes_written = self.total_bytes_written.load(Ordering::Relaxed);
         let elapsed = self.elapsed();
 
-        println!("\n{}", "═".repeat(50).bright_blue());
-        println!("{}", " 📊 처리 통계".bright_white().bold());
-        println!("{}", "═".repeat(50).bright_blue());
+        status!("\n{}", "═".repeat(50).bright_blue());
+        status!("{}", " 📊 처리 통계".bright_white().bold());
+        status!("{}", "═".repeat(50).bright_blue());
 
-        println!(
+        status!(
             "  {} 전체 파일:    {}",
             "📁".bright_cyan(),
             self.total_files
         );
-        println!(
+        status!(
             "  {} 성공:         {}",
             "✅".bright_green(),
             success.to_string().green()
         );
 
         if errors > 0 {
-            println!(
+            status!(
                 "  {} 실패:         {}",
                 "❌".bright_red(),
                 errors.to_string().red()
             );
         } else {
-            println!("  {} 실패:         {}", "✅".bright_green(), "0".green());
+            status!("  {} 실패:         {}", "✅".bright_green(), "0".green());
+        }
+
+        self.print_unreadable();
+
+        let duplicates = self.get_duplicate_count();
+        if duplicates > 0 {
+            status!(
+                "  {} 중복 제거:    {}",
+                "🧹".bright_yellow(),
+                duplicates.to_string().yellow()
+            );
+        }
+
+        if self.duplicate_files > 0 {
+            status!(
+                "  {} 중복 파일:    {} ({} 절약)",
+                "📑".bright_yellow(),
+                self.duplicate_files.to_string().yellow(),
+                format_bytes(self.duplicate_bytes)
+            );
+        }
+
+        let skipped = self.get_skipped_count();
+        if skipped > 0 {
+            status!(
+                "  {} 필터 제외:    {}",
+                "⏭️".bright_yellow(),
+                skipped.to_string().yellow()
+            );
+        }
+
+        let rejected = self.get_rejected_count();
+        if rejected > 0 {
+            status!(
+                "  {} 스키마 불일치: {}",
+                "🚫".bright_yellow(),
+                rejected.to_string().yellow()
+            );
+        }
+
+        let sampled_out = self.get_sampled_out_count();
+        if sampled_out > 0 {
+            status!(
+                "  {} 샘플링 제외:  {}",
+                "🎲".bright_yellow(),
+                sampled_out.to_string().yellow()
+            );
+        }
+
+        let reencoded = self.get_reencoded_count();
+        if reencoded > 0 {
+            status!(
+                "  {} 인코딩 변환:  {}",
+                "🔡".bright_yellow(),
+                reencoded.to_string().yellow()
+            );
+        }
+
+        let repaired = self.get_repaired_count();
+        if repaired > 0 {
+            status!(
+                "  {} 복구한 레코드: {}",
+                "🩹".bright_yellow(),
+                repaired.to_string().yellow()
+            );
+        }
+
+        let oversized = self.get_oversized_count();
+        if oversized > 0 {
+            status!(
+                "  {} 크기 제한 초과: {}",
+                "📏".bright_yellow(),
+                oversized.to_string().yellow()
+            );
+        }
+
+        let drifts = self.get_schema_drift_count();
+        if drifts > 0 {
+            status!(
+                "  {} 스키마 변경:  {}",
+                "🧬".bright_yellow(),
+                drifts.to_string().yellow()
+            );
         }
 
-        println!(
+        status!(
             "  {} 입력 용량:    {}",
             "📥".bright_yellow(),
             format_bytes(bytes_read)
         );
-        println!(
+        status!(
             "  {} 출력 용량:    {}",
             "📤".bright_magenta(),
             format_bytes(bytes_written)
@@ -128,20 +525,22 @@ impl Statistics {
 
         if self.total_files > 0 {
             let success_rate = (success as f64 / self.total_files as f64) * 100.0;
-            println!(
+            status!(
                 "  {} 성공률:       {:.1}%",
                 "📈".bright_white(),
                 success_rate
             );
         }
 
-        println!(
+        status!(
             "  {} 처리 시간:    {:.2}초",
             "⏱️".bright_cyan(),
             elapsed.as_secs_f64()
         );
+        self.print_throughput();
 
-        println!("{}", "═".repeat(50).bright_blue());
+        status!("{}", "═".repeat(50).bright_blue());
+        self.print_detail();
     }
 
     /// 유효성 검사 통계 요약 출력
@@ -150,43 +549,98 @@ impl Statistics {
         let failed = self.get_validation_failed();
         let elapsed = self.elapsed();
 
-        println!("\n{}", "═".repeat(50).bright_blue());
-        println!("{}", " 🔍 유효성 검사 결과".bright_white().bold());
-        println!("{}", "═".repeat(50).bright_blue());
+        status!("\n{}", "═".repeat(50).bright_blue());
+        status!("{}", " 🔍 유효성 검사 결과".bright_white().bold());
+        status!("{}", "═".repeat(50).bright_blue());
 
-        println!(
+        status!(
             "  {} 전체 파일:    {}",
             "📁".bright_cyan(),
             self.total_files
         );
-        println!(
+        status!(
             "  {} 유효:         {}",
             "✅".bright_green(),
             success.to_string().green()
         );
 
         if failed > 0 {
-            println!(
+            status!(
                 "  {} 무효:         {}",
                 "❌".bright_red(),
                 failed.to_string().red()
             );
         } else {
-            println!("  {} 무효:         {}", "✅".bright_green(), "0".green());
+            status!("  {} 무효:         {}", "✅".bright_green(), "0".green());
         }
 
+        self.print_unreadable();
+
         if self.total_files > 0 {
             let valid_rate = (success as f64 / self.total_files as f64) * 100.0;
-            println!("  {} 유효율:       {:.1}%", "📈".bright_white(), valid_rate);
+            status!("  {} 유효율:       {:.1}%", "📈".bright_white(), valid_rate);
         }
 
-        println!(
+        status!(
             "  {} 검사 시간:    {:.2}초",
             "⏱️".bright_cyan(),
             elapsed.as_secs_f64()
         );
+        self.print_throughput();
+
+        status!("{}", "═".repeat(50).bright_blue());
+        self.print_detail();
+    }
+
+    /// 폴더별 집계와 가장 크거나 느린 파일 출력 (`--stats-detail`일 때만)
+    fn print_detail(&self) {
+        let Some(detail) = self.detail() else {
+            return;
+        };
+
+        status!("{}", " 🗂️ 폴더별 통계".bright_white().bold());
+        for (directory, entry) in &detail.directories {
+            let failed = if entry.failed > 0 {
+                format!(", 실패 {}", entry.failed).red().to_string()
+            } else {
+                String::new()
+            };
+            status!(
+                "  {} {}: 파일 {}개, {}{}",
+                "📂".bright_cyan(),
+                directory,
+                entry.files,
+                format_bytes(entry.bytes),
+                failed
+            );
+        }
+
+        status!("{}", " 🐘 가장 큰 파일".bright_white().bold());
+        for file in &detail.largest {
+            status!("  {} {}", format_bytes(file.bytes).yellow(), file.path);
+        }
 
-        println!("{}", "═".repeat(50).bright_blue());
+        status!("{}", " 🐌 가장 느린 파일".bright_white().bold());
+        for file in &detail.slowest {
+            status!(
+                "  {} {}",
+                format!("{:.3}초", file.elapsed_secs).yellow(),
+                file.path
+            );
+        }
+        status!("{}", "═".repeat(50).bright_blue());
+    }
+
+    /// 읽기 권한이 없는 파일 수 출력 (있을 때만)
+    fn print_unreadable(&self) {
+        let unreadable = self.get_unreadable_count();
+        if unreadable > 0 {
+            status!(
+                "  {} 권한 없음:    {}",
+                "🔒".bright_red(),
+                unreadable.to_string().red()
+            );
+        }
     }
 }
 
@@ -242,6 +696,45 @@ pub fn format_duration(duration: Duration) -> String {
     }
 }
 
+/// 사람이 읽기 쉬운 용량 문자열을 바이트 수로 변환
+///
+/// 단위는 `B`, `KB`, `MB`, `GB`, `TB`(1024 배수, 대소문자 무관)를 지원하며
+/// 단위가 없으면 바이트로 봅니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::stats::parse_size;
+///
+/// assert_eq!(parse_size("500MB"), Ok(500 * 1024 * 1024));
+/// assert_eq!(parse_size("1.5 kb"), Ok(1536));
+/// assert_eq!(parse_size("42"), Ok(42));
+/// ```
+pub fn parse_size(text: &str) -> Result<u64, String> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("잘못된 용량 값: {}", text))?;
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" => 1024,
+        "M" | "MB" => 1024 * 1024,
+        "G" | "GB" => 1024 * 1024 * 1024,
+        "T" | "TB" => 1024 * 1024 * 1024 * 1024,
+        other => return Err(format!("알 수 없는 용량 단위: {}", other)),
+    };
+
+    let bytes = (number * multiplier as f64).round() as u64;
+    if bytes == 0 {
+        return Err(format!("용량은 0보다 커야 합니다: {}", text));
+    }
+    Ok(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,6 +750,16 @@ mod tests {
         assert_eq!(format_bytes(1073741824), "1.00 GB");
     }
 
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("500MB"), Ok(500 * 1024 * 1024));
+        assert_eq!(parse_size("2g"), Ok(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("10 B"), Ok(10));
+        assert!(parse_size("MB").is_err());
+        assert!(parse_size("10XB").is_err());
+        assert!(parse_size("0").is_err());
+    }
+
     #[test]
     fn test_format_duration() {
         assert_eq!(format_duration(Duration::from_millis(500)), "500ms");
@@ -265,6 +768,54 @@ mod tests {
         assert_eq!(format_duration(Duration::from_secs(3665)), "1시간 1분");
     }
 
+    #[test]
+    fn test_stats_detail_keeps_top_files() {
+        let stats = Statistics::new(30);
+        assert!(stats.detail().is_none());
+        stats.record_file(FileStat {
+            path: "a.json".to_string(),
+            bytes: 1,
+            elapsed_secs: 0.0,
+            failed: false,
+        });
+        assert!(stats.detail().is_none());
+
+        let stats = Statistics::new(30).with_detail(true);
+        for i in 0..30u64 {
+            stats.record_file(FileStat {
+                path: format!("job{}/part-{:02}.json", i % 3, i),
+                bytes: i * 10,
+                elapsed_secs: (30 - i) as f64,
+                failed: i == 4,
+            });
+        }
+        stats.record_file(FileStat {
+            path: "top.json".to_string(),
+            bytes: 5,
+            elapsed_secs: 0.5,
+            failed: false,
+        });
+
+        let detail = stats.detail().unwrap();
+        assert_eq!(detail.directories.len(), 4);
+        assert_eq!(
+            detail.directories["job1"],
+            DirectoryStats {
+                files: 10,
+                failed: 1,
+                bytes: 1450
+            }
+        );
+        assert_eq!(detail.directories["."].files, 1);
+
+        assert_eq!(detail.largest.len(), TOP_FILES);
+        assert_eq!(detail.largest[0].path, "job2/part-29.json");
+        assert_eq!(detail.largest[9].bytes, 200);
+        assert_eq!(detail.slowest.len(), TOP_FILES);
+        assert_eq!(detail.slowest[0].path, "job0/part-00.json");
+        assert_eq!(detail.slowest[9].elapsed_secs, 21.0);
+    }
+
     #[test]
     fn test_statistics_counters() {
         let stats = Statistics::new(10);
@@ -272,12 +823,59 @@ mod tests {
         stats.increment_success();
         stats.increment_success();
         stats.increment_error();
+        stats.increment_duplicate();
+        stats.increment_schema_drift();
+        stats.increment_skipped();
+        stats.add_skipped(2);
+        stats.add_sampled_out(4);
+        stats.increment_reencoded();
+        stats.add_repaired(3);
+        stats.add_oversized(2);
+        stats.increment_unreadable();
         stats.add_bytes_read(1024);
         stats.add_bytes_written(512);
 
         assert_eq!(stats.get_success_count(), 2);
         assert_eq!(stats.get_error_count(), 1);
+        assert_eq!(stats.get_duplicate_count(), 1);
+        assert_eq!(stats.get_schema_drift_count(), 1);
+        assert_eq!(stats.get_skipped_count(), 3);
+        assert_eq!(stats.get_sampled_out_count(), 4);
+        assert_eq!(stats.get_reencoded_count(), 1);
+        assert_eq!(stats.get_repaired_count(), 3);
+        assert_eq!(stats.get_oversized_count(), 2);
+        assert_eq!(stats.get_unreadable_count(), 1);
         assert_eq!(stats.total_bytes_read.load(Ordering::Relaxed), 1024);
         assert_eq!(stats.total_bytes_written.load(Ordering::Relaxed), 512);
+        // 시작 시간이 없으면 처리 속도는 0
+        assert_eq!(Statistics::default().throughput_mb_per_sec(), 0.0);
+    }
+
+    #[test]
+    fn test_record_result() {
+        use std::path::PathBuf;
+
+        let stats = Statistics::new(3);
+        let mut success = ProcessResult::success(PathBuf::from("a.json"), "{}".to_string(), 10);
+        success.skipped_records = 2;
+        success.reencoded = true;
+        stats.record_result(&success);
+
+        let mut unreadable = ProcessResult::failure(PathBuf::from("b.json"), "denied".into(), 5);
+        unreadable.is_unreadable = true;
+        stats.record_result(&unreadable);
+        stats.record_result(&ProcessResult::failure(
+            PathBuf::from("c.json"),
+            "bad".into(),
+            5,
+        ));
+
+        assert_eq!(stats.get_success_count(), 1);
+        assert_eq!(stats.get_error_count(), 1);
+        assert_eq!(stats.get_unreadable_count(), 1);
+        assert_eq!(stats.get_skipped_count(), 2);
+        assert_eq!(stats.get_reencoded_count(), 1);
+        // 실패한 파일의 크기는 읽은 바이트에 넣지 않음
+        assert_eq!(stats.total_bytes_read.load(Ordering::Relaxed), 10);
     }
 }
@@ -0,0 +1,260 @@
+//! 테스트 도우미 모듈 (`testing` 기능)
+//!
+//! jconvert를 내장한 크레이트가 통합 테스트를 쓸 때 필요한 입력 폴더를 만들어 줍니다.
+//! 유효한 JSON, 잘못된 JSON, 하위 폴더에 흩어진 JSON 파일을 시드에 따라 항상 같은 내용으로 만들고,
+//! 폴더는 `Fixtures`가 버려질 때 지워집니다.
+//!
+//! ```toml
+//! [dev-dependencies]
+//! jconvert = { version = "1", features = ["testing"] }
+//! ```
+
+use serde_json::json;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::error::{JConvertError, Result};
+use crate::generate::{generated_path, Generator};
+use crate::rng::Rng;
+
+/// 잘못된 JSON 파일 내용 (시드에 따라 하나씩 선택)
+const INVALID_CONTENTS: &[&str] = &[
+    r#"{"id": 1, broken"#,
+    r#"{"id": 1,}"#,
+    r#"[{"id": 1}, {"id": 2}"#,
+    "not json at all",
+    "",
+];
+
+/// 같은 프로세스에서 만든 폴더 이름이 겹치지 않도록 붙이는 번호
+static FIXTURE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// 테스트 입력 폴더 설정
+///
+/// # Examples
+/// ```
+/// use jconvert::testing::FixtureBuilder;
+///
+/// let fixtures = FixtureBuilder::new(42).valid(5).invalid(2).nested(2).build().unwrap();
+/// assert_eq!(fixtures.valid().len(), 5);
+/// assert_eq!(fixtures.invalid().len(), 2);
+/// assert!(fixtures.valid().iter().any(|path| path.starts_with(fixtures.path().join("level1"))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FixtureBuilder {
+    seed: u64,
+    valid: u64,
+    invalid: u64,
+    depth: usize,
+}
+
+impl FixtureBuilder {
+    /// 시드로 설정 생성 (기본값: 유효한 파일 3개, 잘못된 파일 없음, 하위 폴더 없음)
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            valid: 3,
+            invalid: 0,
+            depth: 0,
+        }
+    }
+
+    /// 유효한 JSON 파일 수 (파일마다 레코드 하나)
+    pub fn valid(mut self, count: u64) -> Self {
+        self.valid = count;
+        self
+    }
+
+    /// 잘못된 JSON 파일 수 (잘린 문서, 끝의 쉼표, 빈 파일 등)
+    pub fn invalid(mut self, count: u64) -> Self {
+        self.invalid = count;
+        self
+    }
+
+    /// 하위 폴더 깊이 (`level1/level2/...`, 유효한 파일을 최상위와 각 단계에 번갈아 배치)
+    pub fn nested(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// 임시 폴더를 만들고 파일 기록
+    pub fn build(&self) -> Result<Fixtures> {
+        let dir = FixtureDir::create(self.seed)?;
+        let levels = self.levels(dir.path())?;
+        let generator = Generator::new(record_schema())?;
+
+        let valid = (0..self.valid)
+            .map(|index| {
+                let folder = &levels[index as usize % levels.len()];
+                let path = generated_path(folder, index, self.valid);
+                generator.write_file(&path, self.seed, index, 1)?;
+                Ok(path)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut rng = Rng::derive(self.seed, u64::MAX);
+        let invalid = (0..self.invalid)
+            .map(|index| {
+                let path = dir.path().join(format!("invalid_{:06}.json", index + 1));
+                let content = rng.choose(INVALID_CONTENTS).copied().unwrap_or_default();
+                fs::write(&path, content).map_err(|e| write_error(&path, e))?;
+                Ok(path)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Fixtures {
+            valid,
+            invalid,
+            dir,
+        })
+    }
+
+    /// 최상위 폴더와 하위 폴더 목록 (얕은 순서)
+    fn levels(&self, root: &Path) -> Result<Vec<PathBuf>> {
+        let mut levels = vec![root.to_path_buf()];
+        for depth in 1..=self.depth {
+            let folder = levels[depth - 1].join(format!("level{}", depth));
+            fs::create_dir(&folder).map_err(|e| write_error(&folder, e))?;
+            levels.push(folder);
+        }
+        Ok(levels)
+    }
+}
+
+/// 만들어진 테스트 입력 폴더 (버려질 때 지워짐)
+#[derive(Debug)]
+pub struct Fixtures {
+    valid: Vec<PathBuf>,
+    invalid: Vec<PathBuf>,
+    dir: FixtureDir,
+}
+
+impl Fixtures {
+    /// 입력 폴더 경로
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// 유효한 JSON 파일 목록 (만든 순서)
+    pub fn valid(&self) -> &[PathBuf] {
+        &self.valid
+    }
+
+    /// 잘못된 JSON 파일 목록 (만든 순서)
+    pub fn invalid(&self) -> &[PathBuf] {
+        &self.invalid
+    }
+
+    /// 모든 파일 목록 (유효한 파일 다음에 잘못된 파일)
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.valid.iter().chain(&self.invalid).cloned().collect()
+    }
+}
+
+/// 버려질 때 지워지는 임시 폴더
+#[derive(Debug)]
+struct FixtureDir(PathBuf);
+
+impl FixtureDir {
+    fn create(seed: u64) -> Result<Self> {
+        let path = std::env::temp_dir().join(format!(
+            "jconvert-fixtures-{}-{:x}-{}",
+            std::process::id(),
+            seed,
+            FIXTURE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&path).map_err(|e| write_error(&path, e))?;
+        Ok(Self(path))
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for FixtureDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// 유효한 파일의 레코드 스키마 (중첩 객체와 배열 포함)
+fn record_schema() -> serde_json::Value {
+    json!({
+        "type": "object",
+        "required": ["id", "name", "active", "user", "tags"],
+        "properties": {
+            "id": { "type": "integer", "minimum": 1, "maximum": 1000000 },
+            "name": { "type": "string", "minLength": 3, "maxLength": 12 },
+            "active": { "type": "boolean" },
+            "user": {
+                "type": "object",
+                "required": ["email", "profile"],
+                "properties": {
+                    "email": { "type": "string", "format": "email" },
+                    "profile": {
+                        "type": "object",
+                        "required": ["age"],
+                        "properties": {
+                            "age": { "type": "integer", "minimum": 1, "maximum": 99 },
+                            "city": { "enum": ["Seoul", "Busan", "Incheon"] }
+                        }
+                    }
+                }
+            },
+            "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 3 }
+        }
+    })
+}
+
+fn write_error(path: &Path, e: std::io::Error) -> JConvertError {
+    JConvertError::WriteError {
+        reason: format!("{}: {}", path.display(), e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::ProcessOptions;
+    use crate::validation::{validate_files, FileOutcome};
+
+    #[test]
+    fn test_fixtures_are_deterministic() {
+        let builder = FixtureBuilder::new(7).valid(4).invalid(3).nested(1);
+        let (a, b) = (builder.build().unwrap(), builder.build().unwrap());
+        assert_ne!(a.path(), b.path());
+
+        let contents = |fixtures: &Fixtures| -> Vec<Vec<u8>> {
+            fixtures
+                .files()
+                .iter()
+                .map(|path| fs::read(path).unwrap())
+                .collect()
+        };
+        assert_eq!(contents(&a), contents(&b));
+        assert_ne!(
+            contents(&a),
+            contents(&FixtureBuilder::new(8).valid(4).build().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_fixtures_validate_as_labelled() {
+        let fixtures = FixtureBuilder::new(1)
+            .valid(6)
+            .invalid(4)
+            .nested(2)
+            .build()
+            .unwrap();
+        let report = validate_files(fixtures.files(), &ProcessOptions::new());
+        assert_eq!(report.count(FileOutcome::Valid), 6);
+        assert_eq!(report.count(FileOutcome::Invalid), 4);
+        assert!(fixtures.path().join("level1/level2").is_dir());
+
+        let path = fixtures.path().to_path_buf();
+        drop(fixtures);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,191 @@
+//! 터미널 메시지 출력 모듈
+//!
+//! 헤더, 진행 상황, 통계, 경고와 에러 같은 진단 메시지를 수준별로 표준 에러에 출력합니다.
+//! 표준 출력은 JSONL 데이터(`-o -`) 전용으로 남겨 두어 파이프와 로그 수집기가 둘을 구분할 수 있습니다.
+//!
+//! - 수준: `--quiet`(에러만), 기본(정보), `-v`(파일별 상세), `-vv`(디버그)
+//! - 형식: `--log-format text`(기존 이모지/컬러 출력), `--log-format json`(한 줄에 JSON 객체 하나)
+
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 메시지 수준 (값이 작을수록 중요)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    /// 처리 실패
+    Error = 0,
+    /// 처리는 계속하지만 확인이 필요한 상황
+    Warn = 1,
+    /// 헤더, 진행 단계, 통계 (기본)
+    Info = 2,
+    /// 파일별 상세 결과 (`-v`)
+    Verbose = 3,
+    /// 디버그 정보 (`-vv`)
+    Debug = 4,
+}
+
+impl Level {
+    /// 명령줄 옵션으로 출력할 최대 수준 결정
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::ui::Level;
+    ///
+    /// assert_eq!(Level::from_flags(true, 0), Level::Error);
+    /// assert_eq!(Level::from_flags(false, 0), Level::Info);
+    /// assert_eq!(Level::from_flags(false, 1), Level::Verbose);
+    /// assert_eq!(Level::from_flags(false, 5), Level::Debug);
+    /// ```
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Level::Error,
+            (false, 0) => Level::Info,
+            (false, 1) => Level::Verbose,
+            (false, _) => Level::Debug,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Level::Error,
+            1 => Level::Warn,
+            2 => Level::Info,
+            3 => Level::Verbose,
+            _ => Level::Debug,
+        }
+    }
+}
+
+/// 메시지 출력 형식
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// 사람이 읽는 이모지/컬러 출력
+    #[default]
+    Text,
+    /// 한 줄에 JSON 객체 하나 (`{"timestamp", "level", "message"}`, 로그 수집기용)
+    Json,
+}
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+static FORMAT: AtomicU8 = AtomicU8::new(LogFormat::Text as u8);
+
+/// 출력 수준과 형식 설정
+///
+/// JSON 형식이거나 `color`가 `false`이면 ANSI 컬러 코드를 출력하지 않습니다.
+pub fn init(level: Level, format: LogFormat, color: bool) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+    FORMAT.store(format as u8, Ordering::Relaxed);
+    if !color || format == LogFormat::Json {
+        colored::control::set_override(false);
+    }
+}
+
+/// 해당 수준의 메시지를 출력하는지 확인
+pub fn enabled(level: Level) -> bool {
+    level <= Level::from_u8(MAX_LEVEL.load(Ordering::Relaxed))
+}
+
+/// 현재 출력 형식
+pub fn format() -> LogFormat {
+    match FORMAT.load(Ordering::Relaxed) {
+        0 => LogFormat::Text,
+        _ => LogFormat::Json,
+    }
+}
+
+/// 진행률 바를 그릴지 확인 (`--quiet`이거나 JSON 형식이면 숨김)
+pub fn shows_progress() -> bool {
+    enabled(Level::Info) && format() == LogFormat::Text
+}
+
+/// JSON 형식 로그 한 줄
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    /// Unix timestamp (초, 소수점 이하 밀리초)
+    timestamp: f64,
+    level: Level,
+    message: &'a str,
+}
+
+/// 메시지 한 줄 출력 (`status!` 등 매크로에서 사용)
+#[doc(hidden)]
+pub fn print_line(level: Level, args: fmt::Arguments<'_>) {
+    if !enabled(level) {
+        return;
+    }
+
+    // 파이프가 먼저 닫혀도 메시지 출력 실패로 중단하지 않음
+    let mut stderr = io::stderr().lock();
+    match format() {
+        LogFormat::Text => {
+            let _ = writeln!(stderr, "{}", args);
+        }
+        LogFormat::Json => {
+            let text = args.to_string();
+            let message = text.trim();
+            // 구분선과 빈 줄은 사람이 읽는 출력용이므로 생략
+            if !message.chars().any(char::is_alphanumeric) {
+                return;
+            }
+            let record = JsonRecord {
+                timestamp: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| (d.as_millis() as f64) / 1000.0)
+                    .unwrap_or(0.0),
+                level,
+                message,
+            };
+            if let Ok(line) = serde_json::to_string(&record) {
+                let _ = writeln!(stderr, "{}", line);
+            }
+        }
+    }
+}
+
+/// 정보 메시지 한 줄 출력 (`println!`과 같은 형식, `--quiet`이면 생략)
+#[macro_export]
+macro_rules! status {
+    () => {
+        $crate::ui::print_line($crate::ui::Level::Info, format_args!(""))
+    };
+    ($($arg:tt)*) => {
+        $crate::ui::print_line($crate::ui::Level::Info, format_args!($($arg)*))
+    };
+}
+
+/// 파일별 상세 메시지 한 줄 출력 (`-v` 이상)
+#[macro_export]
+macro_rules! detail {
+    ($($arg:tt)*) => {
+        $crate::ui::print_line($crate::ui::Level::Verbose, format_args!($($arg)*))
+    };
+}
+
+/// 디버그 메시지 한 줄 출력 (`-vv` 이상)
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::ui::print_line($crate::ui::Level::Debug, format_args!($($arg)*))
+    };
+}
+
+/// 경고 메시지 한 줄 출력 (`--quiet`이면 생략)
+#[macro_export]
+macro_rules! warning {
+    ($($arg:tt)*) => {
+        $crate::ui::print_line($crate::ui::Level::Warn, format_args!($($arg)*))
+    };
+}
+
+/// 에러 메시지 한 줄 출력 (`--quiet`이어도 출력)
+#[macro_export]
+macro_rules! failure {
+    ($($arg:tt)*) => {
+        $crate::ui::print_line($crate::ui::Level::Error, format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,379 @@
+//! 원격 업로드 출력 모듈
+//!
+//! `-o s3://bucket/key.jsonl` 또는 `-o gs://bucket/key.jsonl`이면 출력을 로컬 디스크에 쓰지 않고
+//! `object_store` 크레이트로 원격 객체에 멀티파트 업로드합니다 (`remote-sink` 기능으로 빌드 필요).
+//! 업로드는 별도 스레드에서 진행되며, 출력 파이프에 쓴 데이터가 파트 단위로 나뉘어 올라가므로
+//! 병합 파일 전체가 디스크나 메모리에 남지 않습니다.
+//!
+//! 업로드 대기열이 차면 쓰기가 막히므로 네트워크가 느려도 메모리 사용량이 늘지 않습니다 (백프레셔).
+//! 업로드를 마치기 전에 실행이 실패하면 멀티파트 업로드를 완료하지 않고 취소하여 잘린 객체가 만들어지지 않게 합니다.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::mpsc::{Sender, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use crate::writer::SyncData;
+
+/// 멀티파트 업로드의 기본 파트 크기 (10MiB)
+pub const DEFAULT_PART_SIZE: usize = 10 * 1024 * 1024;
+
+/// S3 멀티파트 업로드의 최대 파트 수
+const MAX_PARTS: u64 = 10_000;
+
+/// 업로드 스레드로 한 번에 넘기는 데이터 크기
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+/// 업로드 스레드가 아직 받지 않은 데이터 조각의 최대 수
+#[cfg(feature = "remote-sink")]
+const QUEUE_DEPTH: usize = 8;
+
+/// 원격 저장소 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteScheme {
+    /// Amazon S3 (`s3://`)
+    S3,
+    /// Google Cloud Storage (`gs://`)
+    Gcs,
+}
+
+/// 업로드할 원격 객체
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteTarget {
+    /// 원격 저장소 종류
+    pub scheme: RemoteScheme,
+    /// 객체 URL (`s3://bucket/key`)
+    pub url: String,
+}
+
+impl RemoteTarget {
+    /// 출력 경로가 원격 객체 URL이면 업로드 대상 반환
+    ///
+    /// # Examples
+    /// ```
+    /// use jconvert::upload::{RemoteScheme, RemoteTarget};
+    /// use std::path::Path;
+    ///
+    /// let target = RemoteTarget::parse(Path::new("s3://logs/2024/merged.jsonl")).unwrap();
+    /// assert_eq!(target.scheme, RemoteScheme::S3);
+    /// assert_eq!(RemoteTarget::parse(Path::new("gs://b/k.jsonl")).unwrap().scheme, RemoteScheme::Gcs);
+    ///
+    /// assert!(RemoteTarget::parse(Path::new("result.jsonl")).is_none());
+    /// // 버킷이나 객체 이름이 없으면 원격 객체가 아님
+    /// assert!(RemoteTarget::parse(Path::new("s3://bucket")).is_none());
+    /// ```
+    pub fn parse(output: &Path) -> Option<Self> {
+        let url = output.to_str()?;
+        let (scheme, rest) = if let Some(rest) = url.strip_prefix("s3://") {
+            (RemoteScheme::S3, rest)
+        } else if let Some(rest) = url.strip_prefix("gs://") {
+            (RemoteScheme::Gcs, rest)
+        } else {
+            return None;
+        };
+
+        match rest.split_once('/') {
+            Some((bucket, key)) if !bucket.is_empty() && !key.is_empty() => Some(Self {
+                scheme,
+                url: url.to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// 버킷 안의 객체 이름
+    pub fn key(&self) -> &str {
+        let rest = &self.url[self.url.find("://").map_or(0, |i| i + 3)..];
+        rest.split_once('/').map_or("", |(_, key)| key)
+    }
+
+    /// 업로드를 시작하고 쓰기용 파이프 반환
+    ///
+    /// 인증 정보는 각 저장소의 환경 변수(`AWS_ACCESS_KEY_ID`, `GOOGLE_SERVICE_ACCOUNT` 등)에서 읽습니다.
+    ///
+    /// # Arguments
+    /// * `expected_size` - 예상 출력 크기 (파트 수 제한을 넘지 않도록 파트 크기를 정하는 데 사용)
+    #[cfg(feature = "remote-sink")]
+    pub fn start(&self, expected_size: Option<u64>) -> io::Result<(PendingUpload, UploadPipe)> {
+        use object_store::aws::AmazonS3Builder;
+        use object_store::gcp::GoogleCloudStorageBuilder;
+        use object_store::ObjectStore;
+
+        let store: Arc<dyn ObjectStore> = match self.scheme {
+            RemoteScheme::S3 => AmazonS3Builder::from_env()
+                .with_url(&self.url)
+                .build()
+                .map(|store| Arc::new(store) as Arc<dyn ObjectStore>),
+            RemoteScheme::Gcs => GoogleCloudStorageBuilder::from_env()
+                .with_url(&self.url)
+                .build()
+                .map(|store| Arc::new(store) as Arc<dyn ObjectStore>),
+        }
+        .map_err(io::Error::other)?;
+        PendingUpload::spawn(
+            store,
+            object_store::path::Path::from(self.key()),
+            part_size(expected_size),
+        )
+    }
+
+    /// 업로드를 시작하고 쓰기용 파이프 반환 (`remote-sink` 기능 없이 빌드되어 항상 에러)
+    #[cfg(not(feature = "remote-sink"))]
+    pub fn start(&self, _expected_size: Option<u64>) -> io::Result<(PendingUpload, UploadPipe)> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "원격 업로드를 쓰려면 `--features remote-sink`로 빌드해야 합니다",
+        ))
+    }
+}
+
+impl fmt::Display for RemoteTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.url)
+    }
+}
+
+/// 현재 빌드에서 원격 업로드를 사용할 수 있는지 확인
+pub fn is_available() -> bool {
+    cfg!(feature = "remote-sink")
+}
+
+/// 예상 출력 크기에 맞춘 멀티파트 업로드의 파트 크기
+///
+/// 파트 수가 S3 제한(10,000개)을 넘지 않도록 큰 출력은 파트를 키웁니다.
+///
+/// # Examples
+/// ```
+/// use jconvert::upload::{part_size, DEFAULT_PART_SIZE};
+///
+/// assert_eq!(part_size(None), DEFAULT_PART_SIZE);
+/// assert_eq!(part_size(Some(1024)), DEFAULT_PART_SIZE);
+/// // 200GB는 10MiB 파트 10,000개로 나눌 수 없음
+/// assert_eq!(part_size(Some(200_000_000_000)), 20_000_000);
+/// ```
+pub fn part_size(expected_size: Option<u64>) -> usize {
+    expected_size
+        .map(|size| size.div_ceil(MAX_PARTS))
+        .and_then(|size| usize::try_from(size).ok())
+        .map_or(DEFAULT_PART_SIZE, |size| size.max(DEFAULT_PART_SIZE))
+}
+
+/// 업로드 스레드가 실패한 이유 (쓰기 파이프에서도 보고하기 위해 공유)
+type UploadFailure = Arc<Mutex<Option<String>>>;
+
+/// 업로드 스레드로 이어진 쓰기 파이프
+#[derive(Debug)]
+pub struct UploadPipe {
+    sender: Option<SyncSender<Vec<u8>>>,
+    buffer: Vec<u8>,
+    failure: UploadFailure,
+}
+
+impl UploadPipe {
+    /// 모아 둔 데이터를 업로드 스레드로 넘김 (대기열이 차 있으면 기다림)
+    fn send_buffer(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buffer, Vec::with_capacity(CHUNK_SIZE));
+        let sent = self
+            .sender
+            .as_ref()
+            .is_some_and(|sender| sender.send(chunk).is_ok());
+        if sent {
+            return Ok(());
+        }
+        let reason = self
+            .failure
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        Err(io::Error::new(
+            io::ErrorKind::BrokenPipe,
+            format!(
+                "원격 업로드가 중단되었습니다: {}",
+                reason.as_deref().unwrap_or("업로드 스레드가 종료됨")
+            ),
+        ))
+    }
+}
+
+impl Write for UploadPipe {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= CHUNK_SIZE {
+            self.send_buffer()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.send_buffer()
+    }
+}
+
+impl SyncData for UploadPipe {
+    /// 원격 객체는 업로드를 마쳐야 저장되므로 업로드 스레드로 넘기기만 함 (`--fsync`는 함께 쓸 수 없음)
+    fn sync_data(&mut self) -> io::Result<()> {
+        self.flush()
+    }
+}
+
+impl Drop for UploadPipe {
+    fn drop(&mut self) {
+        // 남은 데이터를 넘긴 뒤 대기열을 닫아 업로드 스레드에 입력 끝을 알림
+        let _ = self.send_buffer();
+        self.sender.take();
+    }
+}
+
+/// 완료를 기다리는 업로드
+///
+/// `finish` 전에 버려지면(에러로 중단 등) 멀티파트 업로드를 완료하지 않고 취소합니다.
+#[derive(Debug)]
+pub struct PendingUpload {
+    commit: Option<Sender<()>>,
+    worker: Option<JoinHandle<io::Result<()>>>,
+}
+
+impl PendingUpload {
+    /// 업로드 스레드를 시작하고 쓰기 파이프 반환
+    ///
+    /// # Arguments
+    /// * `store` - 업로드할 객체 저장소
+    /// * `path` - 저장소 안의 객체 경로
+    /// * `part_size` - 멀티파트 업로드의 파트 크기
+    #[cfg(feature = "remote-sink")]
+    pub fn spawn(
+        store: Arc<dyn object_store::ObjectStore>,
+        path: object_store::path::Path,
+        part_size: usize,
+    ) -> io::Result<(Self, UploadPipe)> {
+        let (sender, chunks) = std::sync::mpsc::sync_channel(QUEUE_DEPTH);
+        let (commit, decision) = std::sync::mpsc::channel();
+        let failure = UploadFailure::default();
+
+        let worker_failure = Arc::clone(&failure);
+        let worker = std::thread::Builder::new()
+            .name("jconvert-upload".to_string())
+            .spawn(move || {
+                upload_chunks(store, path, part_size, chunks, decision).inspect_err(|e| {
+                    *worker_failure
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(e.to_string());
+                })
+            })?;
+
+        Ok((
+            Self {
+                commit: Some(commit),
+                worker: Some(worker),
+            },
+            UploadPipe {
+                sender: Some(sender),
+                buffer: Vec::with_capacity(CHUNK_SIZE),
+                failure,
+            },
+        ))
+    }
+
+    /// 업로드 완료 대기 (쓰기 파이프는 먼저 닫아야 함)
+    pub fn finish(mut self) -> io::Result<()> {
+        if let Some(commit) = self.commit.take() {
+            let _ = commit.send(());
+        }
+        match self.worker.take().map(JoinHandle::join) {
+            Some(Ok(result)) => result,
+            Some(Err(_)) => Err(io::Error::other("업로드 스레드가 비정상 종료되었습니다")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 대기열의 데이터를 객체에 업로드하고, 입력이 끝나면 완료 신호에 따라 업로드를 완료하거나 취소
+#[cfg(feature = "remote-sink")]
+fn upload_chunks(
+    store: Arc<dyn object_store::ObjectStore>,
+    path: object_store::path::Path,
+    part_size: usize,
+    chunks: std::sync::mpsc::Receiver<Vec<u8>>,
+    decision: std::sync::mpsc::Receiver<()>,
+) -> io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    // 파트 업로드는 런타임의 워커 스레드에서 진행되므로 다음 데이터를 기다리는 동안에도 계속됨
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()?;
+    let mut writer = object_store::buffered::BufWriter::with_capacity(store, path, part_size);
+
+    for chunk in chunks {
+        if let Err(e) = runtime.block_on(writer.write_all(&chunk)) {
+            let _ = runtime.block_on(writer.abort());
+            return Err(e);
+        }
+    }
+
+    if decision.recv().is_err() {
+        let _ = runtime.block_on(writer.abort());
+        return Err(io::Error::new(
+            io::ErrorKind::Interrupted,
+            "원격 업로드가 취소되었습니다",
+        ));
+    }
+    runtime.block_on(writer.shutdown())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_target_key() {
+        let target = RemoteTarget::parse(Path::new("s3://bucket/out.jsonl")).unwrap();
+        assert_eq!(target.key(), "out.jsonl");
+
+        let target = RemoteTarget::parse(Path::new("gs://bucket/a/b.jsonl")).unwrap();
+        assert_eq!(target.key(), "a/b.jsonl");
+        assert_eq!(target.to_string(), "gs://bucket/a/b.jsonl");
+    }
+
+    #[cfg(feature = "remote-sink")]
+    #[test]
+    fn test_pending_upload_streams_to_store() {
+        use object_store::memory::InMemory;
+        use object_store::ObjectStore;
+
+        let store = Arc::new(InMemory::new());
+        let path = object_store::path::Path::from("merged/out.jsonl");
+        let (pending, mut pipe) =
+            PendingUpload::spawn(store.clone(), path.clone(), DEFAULT_PART_SIZE).unwrap();
+        for id in 0..3 {
+            writeln!(pipe, "{{\"id\":{}}}", id).unwrap();
+        }
+        drop(pipe);
+        pending.finish().unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let uploaded = runtime
+            .block_on(async { store.get(&path).await?.bytes().await })
+            .unwrap();
+        assert_eq!(&uploaded[..], b"{\"id\":0}\n{\"id\":1}\n{\"id\":2}\n");
+
+        // 완료하지 않고 버리면 객체가 만들어지지 않음
+        let aborted = object_store::path::Path::from("aborted.jsonl");
+        let (mut pending, mut pipe) =
+            PendingUpload::spawn(store.clone(), aborted.clone(), DEFAULT_PART_SIZE).unwrap();
+        writeln!(pipe, "{{\"id\":0}}").unwrap();
+        let worker = pending.worker.take().unwrap();
+        drop(pending);
+        drop(pipe);
+        assert!(worker.join().unwrap().is_err());
+        assert!(runtime.block_on(store.head(&aborted)).is_err());
+    }
+}
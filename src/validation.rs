@@ -0,0 +1,194 @@
+//! 유효성 검사 결과 모듈
+//!
+//! 유효성 검사 모드의 파일별 결과를 구조체로 모아, 출력만 보는 대신 프로그램에서 실패한 파일을
+//! 하나씩 확인하고 처리할 수 있게 합니다.
+
+use rayon::prelude::*;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::cancel::CancellationToken;
+use crate::exit::ExitStatus;
+use crate::processor::{process_file, ProcessOptions, ProcessResult};
+
+/// 파일 하나의 유효성 검사 결과 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileOutcome {
+    /// 유효한 JSON
+    Valid,
+    /// 파싱 실패 (잘못된 JSON, 파싱 한도 초과 등)
+    Invalid,
+    /// 읽기 권한 없음
+    Unreadable,
+}
+
+/// 파일별 유효성 검사 결과
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileValidation {
+    /// 파일 경로
+    pub path: PathBuf,
+    /// 결과 종류
+    pub outcome: FileOutcome,
+    /// 파일 크기
+    pub file_size: u64,
+    /// 에러 메시지 (유효하면 None)
+    pub error: Option<String>,
+    /// 에러 종류 (`JConvertError::kind`)
+    pub error_kind: Option<&'static str>,
+}
+
+impl FileValidation {
+    /// 유효한 파일인지 확인
+    pub fn is_valid(&self) -> bool {
+        self.outcome == FileOutcome::Valid
+    }
+}
+
+impl From<ProcessResult> for FileValidation {
+    fn from(result: ProcessResult) -> Self {
+        let outcome = if result.is_valid {
+            FileOutcome::Valid
+        } else if result.is_unreadable {
+            FileOutcome::Unreadable
+        } else {
+            FileOutcome::Invalid
+        };
+        Self {
+            path: result.path,
+            outcome,
+            file_size: result.file_size,
+            error: result.error,
+            error_kind: result.error_kind,
+        }
+    }
+}
+
+/// 유효성 검사 보고서 (입력 순서대로 파일별 결과)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ValidationReport {
+    /// 파일별 결과
+    pub files: Vec<FileValidation>,
+}
+
+impl ValidationReport {
+    /// 처리 결과 목록으로 보고서 생성
+    pub fn from_results(results: impl IntoIterator<Item = ProcessResult>) -> Self {
+        Self {
+            files: results.into_iter().map(FileValidation::from).collect(),
+        }
+    }
+
+    /// 결과 종류별 파일 수
+    pub fn count(&self, outcome: FileOutcome) -> usize {
+        self.files
+            .iter()
+            .filter(|file| file.outcome == outcome)
+            .count()
+    }
+
+    /// 유효하지 않거나 읽을 수 없는 파일
+    pub fn failures(&self) -> impl Iterator<Item = &FileValidation> {
+        self.files.iter().filter(|file| !file.is_valid())
+    }
+
+    /// 모든 파일이 유효한지 확인
+    pub fn is_all_valid(&self) -> bool {
+        self.files.iter().all(FileValidation::is_valid)
+    }
+
+    /// 종료 상태 (읽기 권한 없는 파일은 `skip_unreadable`이면 실패로 보지 않음)
+    pub fn exit_status(&self, skip_unreadable: bool) -> ExitStatus {
+        let unreadable = if skip_unreadable {
+            0
+        } else {
+            self.count(FileOutcome::Unreadable)
+        };
+        ExitStatus::from_failures(self.count(FileOutcome::Invalid) + unreadable)
+    }
+}
+
+/// 여러 파일을 병렬로 유효성 검사
+///
+/// `options`의 유효성 검사 모드 여부와 관계없이 변환 없이 검사만 수행합니다.
+///
+/// # Arguments
+/// * `files` - 검사할 파일 목록
+/// * `options` - 처리 옵션 (파싱 한도, 여러 문서 모드, 파서 등)
+///
+/// # Returns
+/// 입력 순서대로 파일별 결과를 담은 보고서
+pub fn validate_files(files: Vec<PathBuf>, options: &ProcessOptions) -> ValidationReport {
+    validate_files_until(files, options, &CancellationToken::new())
+}
+
+/// 여러 파일을 병렬로 유효성 검사하되 취소되면 남은 파일을 건너뜀
+///
+/// 작업자는 파일을 하나 검사하기 전마다 `cancel`을 확인합니다.
+///
+/// # Returns
+/// 취소 전에 검사한 파일의 결과만 입력 순서대로 담은 보고서
+pub fn validate_files_until(
+    files: Vec<PathBuf>,
+    options: &ProcessOptions,
+    cancel: &CancellationToken,
+) -> ValidationReport {
+    let options = options.clone().with_validate_only(true);
+    ValidationReport {
+        files: files
+            .into_par_iter()
+            .filter_map(|path| {
+                (!cancel.is_cancelled()).then(|| FileValidation::from(process_file(path, &options)))
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_files_report() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let valid = dir.path().join("valid.json");
+        let broken = dir.path().join("broken.json");
+        std::fs::write(&valid, r#"{"id": 1}"#).unwrap();
+        std::fs::write(&broken, r#"{"id": "#).unwrap();
+
+        let report = validate_files(vec![broken.clone(), valid.clone()], &ProcessOptions::new());
+        assert_eq!(report.files.len(), 2);
+        assert_eq!(report.files[0].path, broken);
+        assert_eq!(report.files[0].outcome, FileOutcome::Invalid);
+        assert!(report.files[0].error.is_some());
+        assert!(report.files[1].is_valid());
+        assert_eq!(report.count(FileOutcome::Valid), 1);
+        assert!(!report.is_all_valid());
+        assert_eq!(report.failures().count(), 1);
+        assert_eq!(report.exit_status(false), ExitStatus::CompletedWithErrors);
+    }
+
+    #[test]
+    fn test_cancelled_validation_skips_remaining_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("valid.json");
+        std::fs::write(&path, r#"{"id": 1}"#).unwrap();
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let report = validate_files_until(vec![path], &ProcessOptions::new(), &cancel);
+        assert!(report.files.is_empty());
+    }
+
+    #[test]
+    fn test_unreadable_exit_status() {
+        let report = ValidationReport::from_results([ProcessResult::unreadable(
+            PathBuf::from("secret.json"),
+            "권한 없음".to_string(),
+            0,
+        )]);
+        assert_eq!(report.files[0].outcome, FileOutcome::Unreadable);
+        assert_eq!(report.exit_status(false), ExitStatus::CompletedWithErrors);
+        assert_eq!(report.exit_status(true), ExitStatus::Clean);
+    }
+}
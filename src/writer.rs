@@ -0,0 +1,548 @@
+//! 출력 쓰기 모듈
+//!
+//! 변환된 레코드를 중복 제거와 정렬 단계를 거쳐 출력 파일에 기록합니다.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+
+use crate::dedup::{DedupKeep, Deduplicator, SpillDeduplicator};
+use crate::shard::{ShardInfo, ShardedOutput};
+use crate::sort::RecordSorter;
+use crate::stats::Statistics;
+
+/// 디스크 동기화(fsync) 정책
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncPolicy {
+    /// 출력을 마칠 때 fsync
+    pub on_finish: bool,
+    /// 이 레코드 수를 기록할 때마다 fsync
+    pub every_records: Option<u64>,
+    /// 분할 파일을 닫을 때마다 fsync
+    pub on_rotate: bool,
+}
+
+impl SyncPolicy {
+    /// fsync 설정이 하나라도 있는지 확인
+    pub fn is_enabled(&self) -> bool {
+        self.on_finish || self.every_records.is_some() || self.on_rotate
+    }
+}
+
+/// 버퍼를 비우고 기록된 데이터를 디스크에 동기화할 수 있는 출력
+pub trait SyncData {
+    /// 데이터를 디스크에 동기화 (파일이 아니면 플러시만 수행)
+    fn sync_data(&mut self) -> io::Result<()>;
+}
+
+impl SyncData for File {
+    fn sync_data(&mut self) -> io::Result<()> {
+        File::sync_data(self)
+    }
+}
+
+impl SyncData for io::Stdout {
+    fn sync_data(&mut self) -> io::Result<()> {
+        self.flush()
+    }
+}
+
+impl<W: Write + SyncData> SyncData for BufWriter<W> {
+    fn sync_data(&mut self) -> io::Result<()> {
+        self.flush()?;
+        self.get_mut().sync_data()
+    }
+}
+
+impl<T: SyncData + ?Sized> SyncData for Box<T> {
+    fn sync_data(&mut self) -> io::Result<()> {
+        (**self).sync_data()
+    }
+}
+
+/// 동기화 가능한 출력 (파일 또는 표준 출력을 같은 타입으로 다루기 위함)
+pub trait SyncWrite: Write + SyncData {}
+
+impl<T: Write + SyncData + ?Sized> SyncWrite for T {}
+
+/// 레코드 단위로 기록하는 출력 대상
+pub trait RecordSink {
+    /// 레코드 한 줄 기록 (줄바꿈은 구현에서 추가)
+    fn write_line(&mut self, line: &str) -> io::Result<()>;
+
+    /// 버퍼 플러시
+    fn flush_records(&mut self) -> io::Result<()>;
+
+    /// 기록된 레코드를 디스크에 동기화 (기본 구현은 플러시만 수행)
+    fn sync_records(&mut self) -> io::Result<()> {
+        self.flush_records()
+    }
+}
+
+impl<W: Write> RecordSink for W {
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        writeln!(self, "{}", line)
+    }
+
+    fn flush_records(&mut self) -> io::Result<()> {
+        self.flush()
+    }
+}
+
+impl RecordSink for ShardedOutput {
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        ShardedOutput::write_line(self, line)
+    }
+
+    fn flush_records(&mut self) -> io::Result<()> {
+        self.flush()
+    }
+
+    fn sync_records(&mut self) -> io::Result<()> {
+        self.sync()
+    }
+}
+
+/// 단일 파일 또는 분할 파일 출력
+#[derive(Debug)]
+pub enum OutputSink<W: Write> {
+    /// 하나의 출력 파일
+    Single(W),
+    /// 용량/레코드 수 기준 분할 출력
+    Sharded(ShardedOutput),
+}
+
+impl<W: Write> OutputSink<W> {
+    /// 출력을 마치고 분할 파일 목록 반환 (단일 파일이면 빈 목록)
+    pub fn finish(self) -> io::Result<Vec<ShardInfo>> {
+        match self {
+            OutputSink::Single(mut output) => {
+                output.flush()?;
+                Ok(Vec::new())
+            }
+            OutputSink::Sharded(output) => output.finish(),
+        }
+    }
+}
+
+impl<W: Write + SyncData> RecordSink for OutputSink<W> {
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        match self {
+            OutputSink::Single(output) => RecordSink::write_line(output, line),
+            OutputSink::Sharded(output) => output.write_line(line),
+        }
+    }
+
+    fn flush_records(&mut self) -> io::Result<()> {
+        match self {
+            OutputSink::Single(output) => output.flush(),
+            OutputSink::Sharded(output) => output.flush(),
+        }
+    }
+
+    fn sync_records(&mut self) -> io::Result<()> {
+        match self {
+            OutputSink::Single(output) => output.sync_data(),
+            OutputSink::Sharded(output) => output.sync(),
+        }
+    }
+}
+
+/// 레코드 출력기
+///
+/// 중복 판정에는 처리 단계에서 직렬화 전에 뽑아 둔 키(`ProcessResult::record_keys`)를 사용합니다.
+/// 메모리 기반 중복 제거는 레코드를 받는 즉시 판정하고, 디스크 기반 중복 제거는
+/// 모든 레코드를 임시 파일로 내보낸 뒤 `finish` 시점에 한꺼번에 기록합니다.
+/// 메모리 기반으로 마지막 레코드를 남길 때는 레코드를 모아 두었다가
+/// `finish` 시점에 역순으로 판정합니다.
+/// 정렬기가 있으면 중복 제거를 통과한 레코드를 정렬기에 모았다가 `finish` 시점에 정렬하여 기록합니다.
+pub struct RecordWriter<W: RecordSink> {
+    output: W,
+    deduplicator: Option<Deduplicator>,
+    spill: Option<SpillDeduplicator>,
+    sorter: Option<RecordSorter>,
+    /// 마지막 레코드를 남기려고 모아 둔 (중복 제거 키, 출력 줄)
+    pending: Vec<(Option<String>, String)>,
+    sync: SyncPolicy,
+    unsynced: u64,
+}
+
+impl<W: RecordSink> RecordWriter<W> {
+    /// 새 레코드 출력기 생성
+    pub fn new(output: W) -> Self {
+        Self {
+            output,
+            deduplicator: None,
+            spill: None,
+            sorter: None,
+            pending: Vec::new(),
+            sync: SyncPolicy::default(),
+            unsynced: 0,
+        }
+    }
+
+    /// 메모리 기반 중복 제거기 설정
+    pub fn with_deduplicator(mut self, deduplicator: Option<Deduplicator>) -> Self {
+        self.deduplicator = deduplicator;
+        self
+    }
+
+    /// 디스크 기반 중복 제거기 설정
+    pub fn with_spill(mut self, spill: Option<SpillDeduplicator>) -> Self {
+        self.spill = spill;
+        self
+    }
+
+    /// 레코드 정렬기 설정
+    pub fn with_sorter(mut self, sorter: Option<RecordSorter>) -> Self {
+        self.sorter = sorter;
+        self
+    }
+
+    /// 디스크 동기화 정책 설정 (분할 파일 전환 시 동기화는 출력 쪽에서 처리)
+    pub fn with_sync_policy(mut self, sync: SyncPolicy) -> Self {
+        self.sync = sync;
+        self
+    }
+
+    /// 레코드 한 줄 기록
+    ///
+    /// # Arguments
+    /// * `json_line` - 출력할 레코드
+    /// * `key` - 중복 제거 키 (`dedup::record_key`, 키 필드가 없으면 None)
+    /// * `stats` - 출력 용량과 중복 수를 기록할 통계
+    pub fn write_record(
+        &mut self,
+        json_line: &str,
+        key: Option<&str>,
+        stats: &Statistics,
+    ) -> io::Result<()> {
+        self.write_entry(json_line, key, None, stats)
+    }
+
+    /// 원본 파일 주석 줄을 앞에 붙여 레코드 기록 (사람이 검토하기 위한 출력)
+    ///
+    /// 주석 줄과 레코드는 한 단위로 기록되므로 분할 출력에서도 떨어지지 않습니다.
+    pub fn write_annotated(
+        &mut self,
+        json_line: &str,
+        key: Option<&str>,
+        source: &str,
+        stats: &Statistics,
+    ) -> io::Result<()> {
+        self.write_entry(json_line, key, Some(annotate(json_line, source)), stats)
+    }
+
+    /// 중복 제거를 거쳐 레코드 기록
+    ///
+    /// 중복 판정은 `key`로 하고, 출력에는 `annotated`가 있으면 그것을 사용합니다.
+    fn write_entry(
+        &mut self,
+        json_line: &str,
+        key: Option<&str>,
+        annotated: Option<String>,
+        stats: &Statistics,
+    ) -> io::Result<()> {
+        if let Some(ref mut spill) = self.spill {
+            return spill.push_keyed(key, annotated.as_deref().unwrap_or(json_line));
+        }
+
+        if let Some(ref mut deduplicator) = self.deduplicator {
+            if deduplicator.keep() == DedupKeep::Last {
+                let line = annotated.unwrap_or_else(|| json_line.to_string());
+                self.pending.push((key.map(str::to_string), line));
+                return Ok(());
+            }
+            if deduplicator.is_duplicate_key(key) {
+                stats.increment_duplicate();
+                return Ok(());
+            }
+        }
+
+        let line = annotated.as_deref().unwrap_or(json_line);
+        self.emit(line, stats)
+    }
+
+    /// 출력에 한 줄 기록하고 주기적 fsync 정책 적용 (정렬기가 있으면 정렬기에 추가)
+    fn emit(&mut self, line: &str, stats: &Statistics) -> io::Result<()> {
+        if let Some(ref mut sorter) = self.sorter {
+            return sorter.push_as(strip_annotation(line), line);
+        }
+        self.write_output(line, stats)
+    }
+
+    /// 출력에 한 줄 기록하고 주기적 fsync 정책 적용
+    fn write_output(&mut self, line: &str, stats: &Statistics) -> io::Result<()> {
+        write_line(&mut self.output, line, stats)?;
+
+        if let Some(every) = self.sync.every_records {
+            self.unsynced += 1;
+            if self.unsynced >= every {
+                self.output.sync_records()?;
+                self.unsynced = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// 남은 레코드를 모두 기록하고 출력을 플러시
+    ///
+    /// # Returns
+    /// 내부 출력 핸들
+    pub fn finish(mut self, stats: &Statistics) -> io::Result<W> {
+        if let Some(spill) = self.spill.take() {
+            let duplicates = spill.finish(|json_line| self.emit(json_line, stats))?;
+            stats.add_duplicates(duplicates);
+        }
+
+        if let Some(ref mut deduplicator) = self.deduplicator {
+            let pending = std::mem::take(&mut self.pending);
+            let keep: Vec<bool> = pending
+                .iter()
+                .rev()
+                .map(|(key, _)| !deduplicator.is_duplicate_key(key.as_deref()))
+                .collect();
+
+            for ((_, line), keep) in pending.iter().zip(keep.into_iter().rev()) {
+                if keep {
+                    self.emit(line, stats)?;
+                } else {
+                    stats.increment_duplicate();
+                }
+            }
+        }
+
+        if let Some(sorter) = self.sorter.take() {
+            sorter.finish(|line| self.write_output(line, stats))?;
+        }
+
+        self.output.flush_records()?;
+        if self.sync.on_finish || self.unsynced > 0 {
+            self.output.sync_records()?;
+        }
+        Ok(self.output)
+    }
+
+    /// 기록한 레코드를 출력 파일까지 플러시
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.output.flush_records()
+    }
+
+    /// 레코드 기록을 `finish`까지 미루는지 확인 (디스크 기반 중복 제거, 마지막 레코드 유지, 정렬)
+    pub fn defers_output(&self) -> bool {
+        self.spill.is_some()
+            || self.sorter.is_some()
+            || self
+                .deduplicator
+                .as_ref()
+                .is_some_and(|dedup| dedup.keep() == DedupKeep::Last)
+    }
+
+    /// 근사 중복 제거에서 예상 키 수를 초과했는지 확인
+    pub fn is_dedup_over_capacity(&self) -> bool {
+        self.deduplicator
+            .as_ref()
+            .is_some_and(|dedup| dedup.is_over_capacity())
+    }
+}
+
+/// 원본 파일 주석 줄의 접두사
+pub const ANNOTATION_PREFIX: &str = "# source: ";
+
+/// 레코드 앞에 원본 파일 주석 줄 붙이기
+fn annotate(json_line: &str, source: &str) -> String {
+    format!("{}{}\n{}", ANNOTATION_PREFIX, source, json_line)
+}
+
+/// 주석 줄을 붙인 레코드에서 레코드 부분만 반환 (주석이 없으면 그대로)
+fn strip_annotation(line: &str) -> &str {
+    match line.strip_prefix(ANNOTATION_PREFIX) {
+        Some(rest) => rest.split_once('\n').map_or(line, |(_, record)| record),
+        None => line,
+    }
+}
+
+/// 줄바꿈을 붙여 한 줄 기록하고 출력 용량 통계에 반영
+fn write_line<W: RecordSink>(
+    output: &mut W,
+    json_line: &str,
+    stats: &Statistics,
+) -> io::Result<()> {
+    output.write_line(json_line)?;
+    stats.add_bytes_written(json_line.len() as u64 + 1); // +1 for newline
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_writer_with_deduplicator() {
+        let stats = Statistics::new(1);
+        let mut writer =
+            RecordWriter::new(Vec::new()).with_deduplicator(Some(Deduplicator::exact("id")));
+
+        for (line, key) in [
+            (r#"{"id":1}"#, "1"),
+            (r#"{"id":1}"#, "1"),
+            (r#"{"id":2}"#, "2"),
+        ] {
+            writer.write_record(line, Some(key), &stats).unwrap();
+        }
+        let output = writer.finish(&stats).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"id\":1}\n{\"id\":2}\n"
+        );
+        assert_eq!(stats.get_duplicate_count(), 1);
+        assert_eq!(
+            stats
+                .total_bytes_written
+                .load(std::sync::atomic::Ordering::Relaxed),
+            18
+        );
+    }
+
+    #[test]
+    fn test_record_writer_with_spill() {
+        let stats = Statistics::new(1);
+        let spill = SpillDeduplicator::new("id", None).unwrap();
+        let mut writer = RecordWriter::new(Vec::new()).with_spill(Some(spill));
+
+        for (line, key) in [
+            (r#"{"id":1}"#, "1"),
+            (r#"{"id":2}"#, "2"),
+            (r#"{"id":1}"#, "1"),
+        ] {
+            writer.write_record(line, Some(key), &stats).unwrap();
+        }
+        let output = writer.finish(&stats).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"id\":1}\n{\"id\":2}\n"
+        );
+        assert_eq!(stats.get_duplicate_count(), 1);
+    }
+
+    #[test]
+    fn test_record_writer_keep_last() {
+        let stats = Statistics::new(1);
+        let dedup = Deduplicator::exact("id").with_keep(DedupKeep::Last);
+        let mut writer = RecordWriter::new(Vec::new()).with_deduplicator(Some(dedup));
+
+        for (line, key) in [
+            (r#"{"id":1,"v":1}"#, "1"),
+            (r#"{"id":2}"#, "2"),
+            (r#"{"id":1,"v":2}"#, "1"),
+        ] {
+            writer.write_record(line, Some(key), &stats).unwrap();
+        }
+        let output = writer.finish(&stats).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"id\":2}\n{\"id\":1,\"v\":2}\n"
+        );
+        assert_eq!(stats.get_duplicate_count(), 1);
+    }
+
+    /// 동기화 횟수를 세는 테스트용 출력
+    #[derive(Default)]
+    struct CountingSink {
+        lines: usize,
+        syncs: Vec<usize>,
+    }
+
+    impl RecordSink for CountingSink {
+        fn write_line(&mut self, _line: &str) -> io::Result<()> {
+            self.lines += 1;
+            Ok(())
+        }
+
+        fn flush_records(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+
+        fn sync_records(&mut self) -> io::Result<()> {
+            self.syncs.push(self.lines);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_record_writer_sync_policy() {
+        let stats = Statistics::new(1);
+        let policy = SyncPolicy {
+            every_records: Some(2),
+            ..Default::default()
+        };
+        let mut writer = RecordWriter::new(CountingSink::default()).with_sync_policy(policy);
+
+        for i in 0..5 {
+            writer
+                .write_record(&format!("{{\"id\":{}}}", i), None, &stats)
+                .unwrap();
+        }
+        let output = writer.finish(&stats).unwrap();
+
+        // 2, 4번째 레코드 후 그리고 남은 1건은 완료 시 동기화
+        assert_eq!(output.syncs, vec![2, 4, 5]);
+
+        let policy = SyncPolicy {
+            on_finish: true,
+            ..Default::default()
+        };
+        let mut writer = RecordWriter::new(CountingSink::default()).with_sync_policy(policy);
+        writer.write_record("{}", None, &stats).unwrap();
+        assert_eq!(writer.finish(&stats).unwrap().syncs, vec![1]);
+    }
+
+    #[test]
+    fn test_record_writer_with_sorter_after_dedup() {
+        let stats = Statistics::new(1);
+        let mut writer = RecordWriter::new(Vec::new())
+            .with_deduplicator(Some(Deduplicator::exact("id")))
+            .with_sorter(Some(RecordSorter::new("n").with_descending(true)));
+        assert!(writer.defers_output());
+
+        writer
+            .write_record(r#"{"id":1,"n":1}"#, Some("1"), &stats)
+            .unwrap();
+        writer
+            .write_annotated(r#"{"id":2,"n":5}"#, Some("2"), "b.json", &stats)
+            .unwrap();
+        writer
+            .write_record(r#"{"id":1,"n":9}"#, Some("1"), &stats)
+            .unwrap();
+        let output = writer.finish(&stats).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "# source: b.json\n{\"id\":2,\"n\":5}\n{\"id\":1,\"n\":1}\n"
+        );
+        assert_eq!(stats.get_duplicate_count(), 1);
+    }
+
+    #[test]
+    fn test_record_writer_annotated_with_spill() {
+        let stats = Statistics::new(1);
+        let spill = SpillDeduplicator::new("id", None).unwrap();
+        let mut writer = RecordWriter::new(Vec::new()).with_spill(Some(spill));
+
+        writer
+            .write_annotated(r#"{"id":1}"#, Some("1"), "a.json", &stats)
+            .unwrap();
+        writer
+            .write_annotated(r#"{"id":1}"#, Some("1"), "b.json", &stats)
+            .unwrap();
+        let output = writer.finish(&stats).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "# source: a.json\n{\"id\":1}\n"
+        );
+        assert_eq!(stats.get_duplicate_count(), 1);
+    }
+}
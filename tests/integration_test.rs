@@ -146,7 +146,7 @@ mod processor_tests {
         let result = process_file(path, &options);
 
         assert!(result.is_valid);
-        assert!(result.json_line.is_some());
+        assert_eq!(result.json_lines.len(), 1);
         assert!(result.error.is_none());
     }
 
@@ -159,7 +159,7 @@ mod processor_tests {
         let result = process_file(path, &options);
 
         assert!(!result.is_valid);
-        assert!(result.json_line.is_none());
+        assert!(result.json_lines.is_empty());
         assert!(result.error.is_some());
     }
 
@@ -177,12 +177,65 @@ mod processor_tests {
         let result = process_file(path, &options);
 
         assert!(result.is_valid);
-        let json_line = result.json_line.unwrap();
+        let json_line = &result.json_lines[0];
         assert!(json_line.contains("\"id\":1") || json_line.contains("\"id\": 1"));
         assert!(json_line.contains("\"name\""));
         assert!(!json_line.contains("\"extra\""));
     }
 
+    #[test]
+    fn test_exclude_and_rename_fields() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = create_json_file(
+            temp_dir.path(),
+            "test.json",
+            r#"{"id": 1, "password": "p", "user": {"name": "kim"}}"#,
+        );
+
+        let options = ProcessOptions::new()
+            .with_exclude_fields(Some(vec!["password".to_string()]))
+            .with_renames(Some(vec![(
+                "user.name".to_string(),
+                "username".to_string(),
+            )]));
+        let result = process_file(path, &options);
+
+        assert!(result.is_valid);
+        assert_eq!(
+            result.json_lines,
+            vec![r#"{"id":1,"user":{},"username":"kim"}"#]
+        );
+    }
+
+    #[test]
+    fn test_where_predicate_filters_records() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = create_json_file(
+            temp_dir.path(),
+            "test.json",
+            r#"[{"status": "active", "count": 20}, {"status": "active", "count": 5}]"#,
+        );
+
+        let predicate = jconvert::Expr::parse("status == 'active' && count > 10").unwrap();
+        let options = ProcessOptions::new()
+            .with_predicate(Some(predicate.clone()))
+            .with_key_order(jconvert::KeyOrder::Sort);
+        let result = process_file(path, &options);
+
+        assert!(result.is_valid);
+        assert_eq!(
+            result.json_lines,
+            vec![r#"[{"count":20,"status":"active"}]"#]
+        );
+        assert_eq!(result.skipped_records, 1);
+
+        let path = create_json_file(temp_dir.path(), "idle.json", r#"{"status": "idle"}"#);
+        let result = process_file(path, &options);
+        assert!(result.is_valid);
+        assert!(result.json_lines.is_empty());
+        assert_eq!(result.skipped_records, 1);
+    }
+
     #[test]
     fn test_nested_field_selection() {
         let temp_dir = TempDir::new().unwrap();
@@ -196,7 +249,7 @@ mod processor_tests {
         let result = process_file(path, &options);
 
         assert!(result.is_valid);
-        let json_line = result.json_line.unwrap();
+        let json_line = &result.json_lines[0];
         assert!(json_line.contains("John"));
         assert!(!json_line.contains("meta"));
     }
@@ -210,7 +263,7 @@ mod processor_tests {
         let result = process_file(path, &options);
 
         assert!(result.is_valid);
-        let json_line = result.json_line.unwrap();
+        let json_line = &result.json_lines[0];
         // Pretty output should have newlines
         assert!(json_line.contains('\n'));
     }
@@ -224,8 +277,8 @@ mod processor_tests {
         let result = process_file(path, &options);
 
         assert!(result.is_valid);
-        // validate_only should return empty json_line
-        assert!(result.json_line.is_none() || result.json_line.as_ref().unwrap().is_empty());
+        // validate_only should return no json lines
+        assert!(result.json_lines.is_empty());
     }
 }
 
@@ -282,26 +335,33 @@ mod error_tests {
         assert!(msg.contains("JSON 파싱 실패"));
         assert!(msg.contains("test.json"));
     }
+
+    #[test]
+    fn test_open_error_classification() {
+        use std::io::{Error, ErrorKind};
+        let path = std::path::Path::new("secret.json");
+
+        let denied = JConvertError::from_open_error(path, Error::from(ErrorKind::PermissionDenied));
+        assert!(matches!(denied, JConvertError::PermissionDenied { .. }));
+        assert!(denied.to_string().contains("권한"));
+
+        let missing = JConvertError::from_open_error(path, Error::from(ErrorKind::NotFound));
+        assert!(matches!(missing, JConvertError::FileOpenError { .. }));
+    }
 }
 
 mod cli_tests {
+    use clap::Parser;
     use jconvert::cli::Args;
 
     #[test]
     fn test_get_fields_parsing() {
         let args = Args {
-            input: std::path::PathBuf::from("."),
+            input: vec![std::path::PathBuf::from(".")],
             output: std::path::PathBuf::from("out.jsonl"),
             mode: jconvert::WriteMode::Overwrite,
-            pattern: None,
-            verbose: false,
-            dry_run: false,
-            validate_only: false,
             fields: Some("id, name, description".to_string()),
-            threads: None,
-            max_depth: None,
-            log: None,
-            pretty: false,
+            ..Default::default()
         };
 
         let fields = args.get_fields().unwrap();
@@ -311,23 +371,1173 @@ mod cli_tests {
         assert_eq!(fields[2], "description");
     }
 
+    #[test]
+    fn test_plan_subcommand_parsing() {
+        use jconvert::cli::Command;
+
+        let args =
+            Args::try_parse_from(["jconvert", "plan", "-i", "./data", "--sample", "5"]).unwrap();
+        match args.command {
+            Some(Command::Plan(plan)) => {
+                assert_eq!(plan.input, std::path::PathBuf::from("./data"));
+                assert_eq!(plan.sample, 5);
+            }
+            other => panic!("plan 하위 명령이 파싱되지 않음: {:?}", other),
+        }
+
+        // 하위 명령 없이 기존 방식도 그대로 동작
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(args.command.is_none());
+    }
+
+    #[test]
+    fn test_preview_option() {
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--dry-run", "--preview", "5"])
+                .unwrap();
+        assert!(args.dry_run);
+        assert_eq!(args.preview, Some(5));
+
+        // --dry-run 없이 쓰거나 0이면 에러
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--preview", "5"]).is_err());
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--dry-run", "--preview", "0"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_generate_subcommand_parsing() {
+        use jconvert::cli::Command;
+
+        let args = Args::try_parse_from([
+            "jconvert", "generate", "--count", "10", "--schema", "s.json", "--out", "./fake",
+        ])
+        .unwrap();
+        match args.command {
+            Some(Command::Generate(generate)) => {
+                assert_eq!(generate.count, 10);
+                assert_eq!(generate.records_per_file, 1);
+                assert!(generate.seed.is_none());
+            }
+            other => panic!("generate 하위 명령이 파싱되지 않음: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_subcommand_parsing() {
+        use jconvert::cli::Command;
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "validate",
+            "--jsonl",
+            "a.jsonl",
+            "--jsonl",
+            "b.jsonl",
+            "--chunk-size",
+            "1MB",
+        ])
+        .unwrap();
+        match args.command {
+            Some(Command::Validate(validate)) => {
+                assert_eq!(validate.jsonl.len(), 2);
+                assert_eq!(validate.chunk_size, 1024 * 1024);
+                assert_eq!(validate.show, 20);
+            }
+            other => panic!("validate 하위 명령이 파싱되지 않음: {:?}", other),
+        }
+
+        // 검사할 파일은 필수
+        assert!(Args::try_parse_from(["jconvert", "validate"]).is_err());
+    }
+
+    #[test]
+    fn test_stdio_paths() {
+        let args = Args::try_parse_from(["jconvert", "-i", "-", "-o", "-"]).unwrap();
+        assert!(args.reads_path_list());
+        assert!(args.writes_stdout());
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(!args.reads_path_list());
+        assert!(!args.writes_stdout());
+    }
+
+    #[test]
+    fn test_rejects_parsing() {
+        // 스키마 필터 없이도 --where/파이프라인 필터의 제외 레코드를 기록
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--where",
+            "ok",
+            "--rejects",
+            "r.jsonl",
+        ])
+        .unwrap();
+        assert_eq!(args.rejects, Some(std::path::PathBuf::from("r.jsonl")));
+        assert!(args.filter_schema.is_none());
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        use jconvert::limits::{DEFAULT_MAX_ARRAY_LEN, DEFAULT_MAX_DEPTH};
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(!args.parse_limits().is_enabled());
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--hardened",
+            "--max-string-len",
+            "64KB",
+        ])
+        .unwrap();
+        let limits = args.parse_limits();
+        assert_eq!(limits.max_depth, Some(DEFAULT_MAX_DEPTH));
+        assert_eq!(limits.max_string_len, Some(64 * 1024));
+        assert_eq!(limits.max_array_len, Some(DEFAULT_MAX_ARRAY_LEN));
+
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--max-json-depth", "8"]).unwrap();
+        let limits = args.parse_limits();
+        assert_eq!(limits.max_depth, Some(8));
+        assert!(limits.max_string_len.is_none());
+    }
+
+    #[test]
+    fn test_infer_schema_conflicts() {
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--infer-schema", "schema.json"])
+                .unwrap();
+        assert_eq!(
+            args.infer_schema,
+            Some(std::path::PathBuf::from("schema.json"))
+        );
+
+        assert!(Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--validate-only",
+            "--infer-schema",
+            "schema.json"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_sample_and_limit_options() {
+        let args = Args::try_parse_from([
+            "jconvert", "-i", "./data", "--sample", "10%", "--seed", "7", "--limit", "100",
+        ])
+        .unwrap();
+        let sampler = args.sampler().unwrap();
+        assert_eq!(sampler.rate(), 0.1);
+        assert_eq!(sampler.seed(), 7);
+        assert_eq!(args.limit, Some(100));
+
+        // 시드는 샘플링과 함께만, 비율은 0 초과 1 이하만
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--seed", "7"]).is_err());
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--sample", "2"]).is_err());
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--limit", "0"]).is_err());
+        assert!(Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--limit",
+            "10",
+            "--checkpoint",
+            "run.ckpt"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_sort_options() {
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--sort-by",
+            "timestamp",
+            "--desc",
+            "--sort-memory",
+            "1GB",
+            "--limit",
+            "10",
+        ])
+        .unwrap();
+        assert_eq!(args.sort_by.as_deref(), Some("timestamp"));
+        assert!(args.desc);
+        assert_eq!(args.sort_memory, 1024 * 1024 * 1024);
+        // 정렬하면 최대 레코드 수는 정렬 후에 적용
+        assert_eq!(args.early_limit(), None);
+        assert!(args.build_sorter().is_some());
+
+        // 정렬 방향과 메모리 한도는 정렬 필드와 함께만
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--desc"]).is_err());
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--sort-memory", "1GB"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_normalize_keys_option() {
+        use jconvert::KeyCase;
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--normalize-keys", "snake"])
+            .unwrap();
+        assert_eq!(args.normalize_keys, Some(KeyCase::Snake));
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--normalize-keys", "kebab"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_key_prefix_options() {
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--strip-key-prefix",
+            "data_",
+            "--key-prefix",
+            "src_",
+        ])
+        .unwrap();
+        assert_eq!(args.strip_key_prefix.as_deref(), Some("data_"));
+        assert_eq!(args.key_prefix.as_deref(), Some("src_"));
+    }
+
+    #[test]
+    fn test_logging_options() {
+        use jconvert::ui::{Level, LogFormat};
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert_eq!(args.log_level(), Level::Info);
+        assert_eq!(args.log_format, LogFormat::Text);
+        assert!(!args.no_color);
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "-vv"]).unwrap();
+        assert_eq!(args.verbose, 2);
+        assert_eq!(args.log_level(), Level::Debug);
+        assert!(args.is_verbose());
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--quiet",
+            "--log-format",
+            "json",
+            "--no-color",
+        ])
+        .unwrap();
+        assert_eq!(args.log_level(), Level::Error);
+        assert_eq!(args.log_format, LogFormat::Json);
+        assert!(args.no_color);
+
+        // --quiet과 -v는 함께 쓸 수 없음
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "-q", "-v"]).is_err());
+
+        // 하위 명령에도 사용 가능
+        let args = Args::try_parse_from(["jconvert", "plan", "-i", "./data", "--quiet"]).unwrap();
+        assert!(args.quiet);
+    }
+
+    #[test]
+    fn test_error_log_format_option() {
+        use jconvert::ErrorLogFormat;
+
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--log", "errors.log"]).unwrap();
+        assert_eq!(args.error_log_format, ErrorLogFormat::Text);
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--log",
+            "errors.csv",
+            "--error-log-format",
+            "csv",
+        ])
+        .unwrap();
+        assert_eq!(args.error_log_format, ErrorLogFormat::Csv);
+
+        // --log 없이는 사용할 수 없음
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--error-log-format", "json"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_geojson_options() {
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./geodata",
+            "--geojson",
+            "--flatten-properties",
+        ])
+        .unwrap();
+        assert!(args.geojson);
+        assert!(args.flatten_properties);
+
+        // --flatten-properties는 --geojson 필요
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./geodata", "--flatten-properties"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_encoding_options() {
+        use jconvert::InputEncoding;
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert_eq!(args.encoding, InputEncoding::Auto);
+        assert!(!args.lossy);
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--encoding",
+            "utf-16",
+            "--lossy",
+        ])
+        .unwrap();
+        assert_eq!(args.encoding, InputEncoding::Utf16);
+        assert!(args.lossy);
+
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--encoding", "latin1"]).unwrap();
+        assert_eq!(args.encoding, InputEncoding::Latin1);
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--encoding", "cp949"]).is_err());
+    }
+
+    #[test]
+    fn test_multiple_inputs_and_files_from() {
+        use std::path::PathBuf;
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./a", "-i", "./b"]).unwrap();
+        assert_eq!(args.input, vec![PathBuf::from("./a"), PathBuf::from("./b")]);
+        assert_eq!(args.input_dirs().len(), 2);
+        assert!(args.has_multiple_sources());
+        assert!(!args.reads_path_list());
+
+        // 목록 파일만으로도 실행 가능 (-i 생략)
+        let args = Args::try_parse_from(["jconvert", "--files-from", "list.txt"]).unwrap();
+        assert!(args.input.is_empty());
+        assert_eq!(args.files_from, Some(PathBuf::from("list.txt")));
+        assert!(!args.has_multiple_sources());
+
+        let args = Args::try_parse_from(["jconvert", "-i", "-", "-i", "./a"]).unwrap();
+        assert!(args.reads_path_list());
+        assert_eq!(args.input_dirs(), vec![&PathBuf::from("./a")]);
+
+        assert!(Args::try_parse_from(["jconvert"]).is_err());
+        assert!(Args::try_parse_from(["jconvert", "--stdin", "--files-from", "list.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_remote_output_options() {
+        use jconvert::upload::RemoteScheme;
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(args.remote_target().is_none());
+        assert!(args.atomic_output());
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "-o",
+            "s3://bucket/merged.jsonl",
+            "--upload-expected-size",
+            "2GB",
+        ])
+        .unwrap();
+        let target = args.remote_target().unwrap();
+        assert_eq!(target.scheme, RemoteScheme::S3);
+        assert_eq!(args.upload_expected_size, Some(2 * 1024 * 1024 * 1024));
+        // 원격 출력은 임시 파일을 거치지 않음
+        assert!(!args.atomic_output());
+    }
+
+    #[test]
+    fn test_traversal_options() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(!args.follow_symlinks);
+        assert!(!args.one_file_system);
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--follow-symlinks",
+            "--one-file-system",
+        ])
+        .unwrap();
+        assert!(args.follow_symlinks);
+        assert!(args.one_file_system);
+    }
+
+    #[test]
+    fn test_dedup_content_option() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(!args.dedup_content);
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--dedup-content"]).unwrap();
+        assert!(args.dedup_content);
+    }
+
+    #[test]
+    fn test_max_record_size_option() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(args.record_size_guard().is_none());
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--max-record-size",
+            "16MB",
+            "--oversize-policy",
+            "truncate-fields",
+        ])
+        .unwrap();
+        let guard = args.record_size_guard().unwrap();
+        assert_eq!(guard.max_bytes(), 16 * 1024 * 1024);
+        assert_eq!(guard.policy(), jconvert::OversizePolicy::TruncateFields);
+
+        // --max-line-bytes 별칭과 truncate-field 정책 별칭
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--max-line-bytes",
+            "1KB",
+            "--oversize-policy",
+            "truncate-field",
+        ])
+        .unwrap();
+        assert_eq!(args.max_record_size, Some(1024));
+        assert_eq!(
+            args.oversize_policy,
+            jconvert::OversizePolicy::TruncateFields
+        );
+
+        // 정책만 지정하면 에러
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--oversize-policy", "error"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_skip_existing_option() {
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "-m",
+            "append",
+            "--skip-existing",
+            "--dedupe-by",
+            "id",
+        ])
+        .unwrap();
+        assert!(args.skip_existing);
+        assert_eq!(args.mode, jconvert::WriteMode::Append);
+
+        // 디스크 기반 중복 제거와는 함께 사용할 수 없음
+        assert!(Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--skip-existing",
+            "--dedupe-by",
+            "id",
+            "--dedupe-spill",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_dedupe_memory_option() {
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--dedupe-by",
+            "id",
+            "--dedupe-spill",
+            "--dedupe-memory",
+            "64MB",
+        ])
+        .unwrap();
+        assert_eq!(args.dedupe_memory, 64 * 1024 * 1024);
+
+        // 파티션 메모리 한도는 디스크 기반 중복 제거와 함께만
+        assert!(Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--dedupe-by",
+            "id",
+            "--dedupe-memory",
+            "64MB",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_dedupe_keep_last_spills_unless_exact() {
+        // 마지막 레코드 유지는 출력 레코드를 메모리에 모으지 않도록 디스크 기반으로 판정
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--dedupe-by",
+            "id",
+            "--dedupe-keep",
+            "last",
+        ])
+        .unwrap();
+        assert!(args.spills_dedup());
+        assert!(args.build_deduplicator().is_none());
+        assert!(args.build_spill_deduplicator().unwrap().is_some());
+
+        // --dedupe-exact를 지정하면 메모리에서 판정
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--dedupe-by",
+            "id",
+            "--dedupe-keep",
+            "last",
+            "--dedupe-exact",
+        ])
+        .unwrap();
+        assert!(!args.spills_dedup());
+        assert!(args.build_deduplicator().is_some());
+    }
+
+    #[test]
+    fn test_stats_detail_option() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(!args.stats_detail);
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--stats-detail",
+            "--stats-json",
+            "report.json",
+        ])
+        .unwrap();
+        assert!(args.stats_detail);
+        assert_eq!(
+            args.stats_json,
+            Some(std::path::PathBuf::from("report.json"))
+        );
+    }
+
+    #[test]
+    fn test_baseline_options() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert_eq!(args.baseline, None);
+
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--baseline", "last_stats.json"])
+                .unwrap();
+        assert_eq!(
+            args.baseline,
+            Some(std::path::PathBuf::from("last_stats.json"))
+        );
+        assert_eq!(args.baseline_tolerance, 10.0);
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--baseline",
+            "last_stats.json",
+            "--baseline-tolerance",
+            "2.5%",
+        ])
+        .unwrap();
+        assert_eq!(args.baseline_tolerance, 2.5);
+
+        // 허용 범위만 지정하거나 음수면 에러
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--baseline-tolerance", "5"])
+                .is_err()
+        );
+        assert!(Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--baseline",
+            "b.json",
+            "--baseline-tolerance",
+            "-1"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_quality_gate_options() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(!args.quality_gates().is_enabled());
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--min-success-rate",
+            "99.5",
+            "--max-error-count",
+            "10",
+            "--skip-unreadable",
+        ])
+        .unwrap();
+        let gates = args.quality_gates();
+        assert_eq!(gates.min_success_rate, Some(99.5));
+        assert_eq!(gates.max_error_count, Some(10));
+        assert!(gates.skip_unreadable);
+
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--min-success-rate", "150"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_output_format_option() {
+        use jconvert::OutputFormat;
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert_eq!(args.output_format, OutputFormat::Jsonl);
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--output-format", "bundle"])
+            .unwrap();
+        assert_eq!(args.output_format, OutputFormat::Bundle);
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--output-format", "csv"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_compress_option() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(args.compress.is_none());
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "-o",
+            "out.jsonl.zst",
+            "--compress",
+            "zstd",
+        ])
+        .unwrap();
+        let codec = args.compress.unwrap();
+        assert_eq!((codec.name(), codec.extension()), ("zstd", "zst"));
+
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--compress", "rar"]).is_err());
+        assert!(Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--compress",
+            "gzip",
+            "--split-records",
+            "100"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_repair_options() {
+        use jconvert::cli::Command;
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(!args.repair_jsonl);
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--repair-jsonl"]).unwrap();
+        assert!(args.repair_jsonl);
+
+        let args =
+            Args::try_parse_from(["jconvert", "validate", "--jsonl", "a.jsonl", "--fix"]).unwrap();
+        match args.command {
+            Some(Command::Validate(validate)) => assert!(validate.fix),
+            other => panic!("validate 하위 명령이 파싱되지 않음: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_key_order_option() {
+        use jconvert::KeyOrder;
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert_eq!(args.key_order, KeyOrder::Default);
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--key-order", "sort"]).unwrap();
+        assert_eq!(args.key_order, KeyOrder::Sort);
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--key-order", "random"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_checksum_options() {
+        use jconvert::{ChecksumField, ChecksumScope};
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(args.checksum_field().is_none());
+
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--add-checksum", "_sha"]).unwrap();
+        assert_eq!(
+            args.checksum_field(),
+            Some(ChecksumField::new("_sha", ChecksumScope::File))
+        );
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--add-checksum",
+            "_sha",
+            "--checksum-of",
+            "record",
+        ])
+        .unwrap();
+        assert_eq!(args.checksum_of, ChecksumScope::Record);
+
+        // --checksum-of는 --add-checksum 필요, 파이프라인과는 함께 쓸 수 없음
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--checksum-of", "record"]).is_err()
+        );
+        assert!(Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--add-checksum",
+            "_sha",
+            "--pipeline",
+            "p.json"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_field_order_option() {
+        use jconvert::FieldOrder;
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(args.field_order.is_none());
+
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--field-order",
+            "id,timestamp,*",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.field_order,
+            Some(FieldOrder::parse("id,timestamp,*").unwrap())
+        );
+
+        // `*`를 두 번 쓰거나 필드가 없으면 에러
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--field-order", "id,*,*"]).is_err()
+        );
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--field-order", "*"]).is_err());
+    }
+
+    #[test]
+    fn test_passthrough_option() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--passthrough"]).unwrap();
+        assert!(args.passthrough);
+
+        // 출력 형식을 바꾸거나 출력하지 않는 모드와는 함께 쓸 수 없음
+        assert!(
+            Args::try_parse_from(["jconvert", "-i", "./data", "--passthrough", "--pretty"])
+                .is_err()
+        );
+        assert!(Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--passthrough",
+            "--validate-only"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_nice_option() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert_eq!(args.nice, None);
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--nice"]).unwrap();
+        assert_eq!(args.nice, Some(10));
+
+        let args = Args::try_parse_from(["jconvert", "--nice", "19", "-i", "./data"]).unwrap();
+        assert_eq!(args.nice, Some(19));
+
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--nice", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_pin_threads_options() {
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--pin-threads",
+            "--cpu-list",
+            "0-3",
+        ])
+        .unwrap();
+        assert!(args.pin_threads);
+        assert_eq!(args.cpu_list.as_deref(), Some("0-3"));
+
+        // --cpu-list는 --pin-threads와 함께 써야 함
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--cpu-list", "0-3"]).is_err());
+    }
+
+    #[test]
+    fn test_parser_option() {
+        use jconvert::ParserKind;
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert_eq!(args.parser, ParserKind::Serde);
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--parser", "simd"]).unwrap();
+        assert_eq!(args.effective_parser(), ParserKind::Simd);
+
+        // 파싱 한도가 있으면 serde_json 사용
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--parser", "simd", "--hardened"])
+                .unwrap();
+        assert_eq!(args.effective_parser(), ParserKind::Serde);
+    }
+
+    #[test]
+    fn test_error_limit() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert_eq!(args.error_limit(), None);
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--fail-fast"]).unwrap();
+        assert_eq!(args.error_limit(), Some(0));
+
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--max-errors", "5"]).unwrap();
+        assert_eq!(args.error_limit(), Some(5));
+
+        // 두 옵션은 함께 쓸 수 없음
+        assert!(Args::try_parse_from([
+            "jconvert",
+            "-i",
+            "./data",
+            "--fail-fast",
+            "--max-errors",
+            "5"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_atomic_output() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert!(args.atomic_output());
+
+        // 추가 모드, 분할 출력, 표준 출력, --no-atomic은 직접 기록
+        for extra in [
+            &["-m", "append"][..],
+            &["--split-records", "10"][..],
+            &["-o", "-"][..],
+            &["--no-atomic"][..],
+            &["--checkpoint", "state.json"][..],
+        ] {
+            let args =
+                Args::try_parse_from(["jconvert", "-i", "./data"].iter().chain(extra)).unwrap();
+            assert!(!args.atomic_output(), "{:?}", extra);
+        }
+    }
+
+    #[test]
+    fn test_batch_len() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data"]).unwrap();
+        assert_eq!(args.batch_len(), 1);
+
+        let args =
+            Args::try_parse_from(["jconvert", "-i", "./data", "--batch-files", "256"]).unwrap();
+        assert_eq!(args.batch_len(), 256);
+
+        assert!(Args::try_parse_from(["jconvert", "-i", "./data", "--batch-files", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_conflicts() {
+        let args = Args::try_parse_from(["jconvert", "-i", "./data", "--checkpoint", "state.json"])
+            .unwrap();
+        assert_eq!(
+            args.checkpoint,
+            Some(std::path::PathBuf::from("state.json"))
+        );
+
+        // 분할 출력, 유효성 검사 모드와는 함께 쓸 수 없음
+        for extra in ["--validate-only", "--split-records=10"] {
+            assert!(Args::try_parse_from([
+                "jconvert",
+                "-i",
+                "./data",
+                "--checkpoint",
+                "state.json",
+                extra
+            ])
+            .is_err());
+        }
+    }
+
     #[test]
     fn test_get_fields_none() {
         let args = Args {
-            input: std::path::PathBuf::from("."),
+            input: vec![std::path::PathBuf::from(".")],
             output: std::path::PathBuf::from("out.jsonl"),
             mode: jconvert::WriteMode::Overwrite,
-            pattern: None,
-            verbose: false,
-            dry_run: false,
-            validate_only: false,
-            fields: None,
-            threads: None,
-            max_depth: None,
-            log: None,
-            pretty: false,
+            ..Default::default()
         };
 
         assert!(args.get_fields().is_none());
     }
+
+    #[test]
+    fn test_get_exclude_fields_and_renames() {
+        let args = Args::try_parse_from([
+            "jconvert",
+            "-i",
+            ".",
+            "--exclude-fields",
+            "password, secret",
+            "--rename",
+            "old:new,user.name:username",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            args.get_exclude_fields().unwrap(),
+            vec!["password".to_string(), "secret".to_string()]
+        );
+        assert_eq!(
+            args.get_renames().unwrap(),
+            vec![
+                ("old".to_string(), "new".to_string()),
+                ("user.name".to_string(), "username".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rename_rejected() {
+        let result = Args::try_parse_from(["jconvert", "-i", ".", "--rename", "old"]);
+        assert!(result.is_err());
+    }
+}
+
+mod generate_tests {
+    use super::*;
+    use jconvert::generate::{generated_path, Generator};
+    use jconvert::processor::{process_file, ProcessOptions};
+    use serde_json::json;
+
+    #[test]
+    fn test_generated_files_convert() {
+        let temp_dir = TempDir::new().unwrap();
+        let generator = Generator::new(json!({
+            "type": "object",
+            "required": ["id"],
+            "properties": {
+                "id": { "type": "integer", "minimum": 1 },
+                "created": { "type": "string", "format": "date-time" }
+            }
+        }))
+        .unwrap();
+
+        for index in 0..5 {
+            let path = generated_path(temp_dir.path(), index, 5);
+            generator.write_file(&path, 42, index, 3).unwrap();
+        }
+
+        let options = ProcessOptions::new().with_fields(Some(vec!["id".to_string()]));
+        for index in 0..5 {
+            let result = process_file(generated_path(temp_dir.path(), index, 5), &options);
+            assert!(result.error.is_none());
+            let records: serde_json::Value = serde_json::from_str(&result.json_lines[0]).unwrap();
+            assert_eq!(records.as_array().unwrap().len(), 3);
+        }
+
+        // 같은 시드와 번호로는 같은 파일
+        let again = temp_dir.path().join("again.json");
+        generator.write_file(&again, 42, 0, 3).unwrap();
+        assert_eq!(
+            fs::read_to_string(&again).unwrap(),
+            fs::read_to_string(generated_path(temp_dir.path(), 0, 5)).unwrap()
+        );
+    }
+}
+
+/// 실행 파일을 직접 실행하여 옵션 동작 확인
+mod run_tests {
+    use super::*;
+    use std::process::{Command, Output};
+
+    /// jconvert 실행 (조용한 모드)
+    fn jconvert(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_jconvert"))
+            .arg("--quiet")
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    /// 파일마다 레코드 하나씩 만들고 입력 순서를 정한 `--files-from` 목록 반환
+    fn write_records(dir: &std::path::Path, records: &[&str]) -> PathBuf {
+        let list: Vec<String> = records
+            .iter()
+            .enumerate()
+            .map(|(index, record)| {
+                let path = create_json_file(dir, &format!("r{:03}.json", index), record);
+                path.display().to_string()
+            })
+            .collect();
+        create_json_file(dir, "list.txt", &list.join("\n"))
+    }
+
+    fn read_lines(path: &std::path::Path) -> Vec<String> {
+        fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_limit_keeps_first_records_in_input_order() {
+        let temp_dir = TempDir::new().unwrap();
+        // 입력 순서가 이름순과 다르도록 역순 id
+        let records: Vec<String> = (0..60)
+            .rev()
+            .map(|id| format!(r#"{{"id": {}}}"#, id))
+            .collect();
+        let records: Vec<&str> = records.iter().map(String::as_str).collect();
+        let list = write_records(temp_dir.path(), &records);
+        let output = temp_dir.path().join("out.jsonl");
+
+        let run = jconvert(&[
+            "--files-from",
+            list.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+            "-j",
+            "4",
+            "--limit",
+            "7",
+        ]);
+        assert!(run.status.success());
+
+        let expected: Vec<String> = (53..60)
+            .rev()
+            .map(|id| format!(r#"{{"id":{}}}"#, id))
+            .collect();
+        assert_eq!(read_lines(&output), expected);
+    }
+
+    #[test]
+    fn test_dedupe_keep_last_keeps_latest_record() {
+        let temp_dir = TempDir::new().unwrap();
+        let list = write_records(
+            temp_dir.path(),
+            &[
+                r#"{"id": 1, "v": "a"}"#,
+                r#"{"id": 2, "v": "b"}"#,
+                r#"{"id": 1, "v": "c"}"#,
+            ],
+        );
+
+        // 디스크 기반(기본)과 메모리 기반(--dedupe-exact) 모두 같은 결과
+        for extra in [None, Some("--dedupe-exact")] {
+            let output = temp_dir.path().join("out.jsonl");
+            let mut args = vec![
+                "--files-from",
+                list.to_str().unwrap(),
+                "-o",
+                output.to_str().unwrap(),
+                "--dedupe-by",
+                "id",
+                "--dedupe-keep",
+                "last",
+            ];
+            args.extend(extra);
+            let run = jconvert(&args);
+            assert!(run.status.success());
+            assert_eq!(
+                read_lines(&output),
+                vec![r#"{"id":2,"v":"b"}"#, r#"{"id":1,"v":"c"}"#]
+            );
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_resumes_after_abort() {
+        let temp_dir = TempDir::new().unwrap();
+        let input = temp_dir.path().join("input");
+        fs::create_dir(&input).unwrap();
+        for id in 0..50 {
+            create_json_file(
+                &input,
+                &format!("{:03}.json", id),
+                &format!(r#"{{"id": {}}}"#, id),
+            );
+        }
+        let broken = create_json_file(&input, "025.json", r#"{"id": "#);
+        let output = temp_dir.path().join("out.jsonl");
+        let checkpoint = temp_dir.path().join("state.json");
+        let args = [
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+            "--checkpoint",
+            checkpoint.to_str().unwrap(),
+        ];
+
+        // 에러로 중단되어도 기록을 마친 파일까지 체크포인트에 남음
+        let mut first = args.to_vec();
+        first.push("--fail-fast");
+        let run = jconvert(&first);
+        assert_eq!(run.status.code(), Some(2));
+        assert!(checkpoint.exists());
+
+        // 고친 뒤 다시 실행하면 남은 파일만 이어서 처리하여 모든 레코드가 한 번씩 기록됨
+        fs::write(&broken, r#"{"id": 25}"#).unwrap();
+        let run = jconvert(&args);
+        assert!(run.status.success());
+
+        let mut lines = read_lines(&output);
+        lines.sort();
+        let mut expected: Vec<String> = (0..50).map(|id| format!(r#"{{"id":{}}}"#, id)).collect();
+        expected.sort();
+        assert_eq!(lines, expected);
+    }
 }